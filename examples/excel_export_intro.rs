@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing several Polar Rust dataframes to a worksheet using
+//! the `ExcelExport` batch interface.
+
+use polars::prelude::*;
+
+use polars_excel_writer::ExcelExport;
+
+fn main() -> PolarsResult<()> {
+    // Create some sample dataframes for the example.
+    let df1: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000, 2300, 1900, 2500],
+    )?;
+
+    let df2: DataFrame = df!(
+        "Quarter" => &["Q1", "Q2", "Q3", "Q4"],
+        "Costs" => &[450.25, 610.50, 520.75, 700.00],
+    )?;
+
+    // Write the dataframes side by side in a worksheet and save the file.
+    ExcelExport::new()
+        .sheet("Summary", |sheet| {
+            sheet.dataframe(&df1).at(0, 0);
+            sheet.dataframe(&df2).at(0, 3);
+        })
+        .save("dataframe.xlsx")?;
+
+    Ok(())
+}
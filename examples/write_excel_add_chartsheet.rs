@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates adding a chartsheet that plots dataframe columns by name.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::ChartType;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Month" => &["Jan", "Feb", "Mar", "Apr", "May", "Jun"],
+        "Sales" => &[100, 120, 140, 130, 150, 170],
+        "Costs" => &[80, 90, 85, 100, 110, 105],
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Add a chartsheet of the "Sales" and "Costs" columns by month.
+    let chartsheet =
+        xlsx_writer.add_chartsheet(ChartType::Line, Some("Month"), &["Sales", "Costs"])?;
+
+    chartsheet.set_name("Sales Chart")?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
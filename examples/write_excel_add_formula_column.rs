@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates adding a column of formulas to the output table.
+
+use polars::prelude::*;
+
+use polars_excel_writer::{ColumnPosition, PolarsXlsxWriter};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Product" => &["Apple", "Pear", "Plum", "Kiwi"],
+        "Price" => &[0.50, 0.75, 0.30, 0.45],
+        "Quantity" => &[100, 80, 150, 120],
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Add a column with the cost of each row at the end of the table.
+    xlsx_writer.add_formula_column("Cost", "[@[Price]]*[@[Quantity]]", ColumnPosition::End);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing Polar Rust dataframes to separate worksheets in an
+//! Excel workbook with different options for each worksheet.
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::TableStyle;
+
+fn main() -> PolarsResult<()> {
+    let df1: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000.0, 2000.0, 3000.0, 4000.0],
+    )?;
+
+    let df2: DataFrame = df!(
+        "Item" => &["Rent", "Wages", "Marketing"],
+        "Cost" => &[500.0, 1500.0, 250.0],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Add each dataframe with its own worksheet options.
+    xlsx_writer
+        .add_sheet("Sales", &df1)
+        .with_autofit(true)
+        .with_table_style(TableStyle::Medium4);
+
+    xlsx_writer
+        .add_sheet("Costs", &df2)
+        .with_float_format("$#,##0.00")
+        .with_table_style(TableStyle::Light9);
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates adding a column of sparklines to the output table.
+
+use polars::prelude::*;
+
+use polars_excel_writer::{ColumnPosition, PolarsXlsxWriter};
+use rust_xlsxwriter::{Sparkline, SparklineType};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Q1" => &[100, 230, 150, 175],
+        "Q2" => &[120, 210, 160, 185],
+        "Q3" => &[150, 190, 170, 160],
+        "Q4" => &[170, 250, 140, 190],
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Add a column of sparklines after the "Region" column.
+    let sparkline = Sparkline::new().set_type(SparklineType::Column);
+    xlsx_writer.add_sparkline(
+        "Trend",
+        &["Q1", "Q2", "Q3", "Q4"],
+        &sparkline,
+        ColumnPosition::After("Region".to_string()),
+    );
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing Polar Rust dataframes to an Excel file. This
+//! demonstrates adding a table of contents worksheet.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create some sample dataframes for the example.
+    let df1: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000, 2300, 1900, 2500],
+    )?;
+
+    let df2: DataFrame = df!(
+        "Product" => &["Apple", "Pear"],
+        "Units" => &[120, 80],
+        "Price" => &[1.2, 0.9],
+    )?;
+
+    // Write the dataframes to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Add a table of contents with links to the dataframes.
+    xlsx_writer.add_table_of_contents("Contents")?;
+
+    xlsx_writer.set_worksheet_name("Sales")?;
+    xlsx_writer.write_dataframe(&df1)?;
+
+    xlsx_writer.add_worksheet();
+    xlsx_writer.set_worksheet_name("Products")?;
+    xlsx_writer.write_dataframe(&df2)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of appending several Polar dataframes to a single table in an
+//! Excel worksheet.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Append a dataframe for each batch of readings.
+    for batch in 1..=3 {
+        let df: DataFrame = df!(
+            "Batch" => &[batch, batch, batch],
+            "Reading" => &[1, 2, 3],
+            "Value" => &[0.5 * batch as f64, 1.5, 2.5],
+        )?;
+
+        xlsx_writer.append_dataframe(&df)?;
+    }
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates setting the writer options from a JSON config.
+
+use polars::prelude::*;
+
+use polars_excel_writer::{ExportConfig, PolarsXlsxWriter};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000.0, 2300.0, 1900.0, 2500.0],
+    )?;
+
+    // A config that would typically be read from a file.
+    let json = r##"{
+        "autofit": true,
+        "float_format": "#,##0.00",
+        "table_style": "Light9",
+        "freeze_panes": "A2",
+        "column_options": {
+            "Sales": {"header": "Total Sales", "total": "sum"}
+        }
+    }"##;
+
+    let config: ExportConfig = serde_json::from_str(json).unwrap();
+
+    // Create a writer with the options from the config.
+    let mut xlsx_writer = PolarsXlsxWriter::from_config(&config)?;
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates writing dataframes into a template workbook.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{Format, Workbook, Worksheet, XlsxError};
+
+// Add the layout of a report page to a worksheet.
+fn add_report_layout(worksheet: &mut Worksheet, title: &str) -> Result<(), XlsxError> {
+    let title_format = Format::new().set_bold().set_font_size(16);
+    worksheet.write_with_format(0, 0, title, &title_format)?;
+    worksheet.write(1, 0, "Total:")?;
+    worksheet.write_formula(1, 1, "=SUM(Sales[Sales])")?;
+    Ok(())
+}
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000, 2300, 1900, 2500],
+    )?;
+
+    // Create the template workbook.
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet().set_name("Report")?;
+    add_report_layout(worksheet, "Sales Report")?;
+
+    // Write the dataframe into the template below the layout.
+    let mut xlsx_writer = PolarsXlsxWriter::from_workbook(workbook);
+    xlsx_writer.set_table_name("Sales")?;
+    xlsx_writer.write_dataframe_to_sheet(&df, "Report", 3, 0)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates using the Polars `SerWriter` interface with the
+//! `PolarsXlsxWriter` options.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::Format;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let mut df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000.0, 2300.0, 1900.0, 2500.0],
+    )?;
+
+    // Set up the writer with options that ExcelWriter doesn't support.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer
+        .set_header_format(Format::new().set_bold().set_font_color("#1F497D"))
+        .set_dtype_format(DataType::Float64, Format::new().set_num_format("#,##0.00"))
+        .set_zoom(150);
+
+    // Create a new file object.
+    let mut file = std::fs::File::create("dataframe.xlsx").unwrap();
+
+    // Write the dataframe to an Excel file using the Polars SerWriter
+    // interface.
+    xlsx_writer.into_ser_writer(&mut file).finish(&mut df)?;
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing Polar Rust dataframes to an Excel file. This
+//! demonstrates passing a workbook between functions that each use their own
+//! writer and options.
+
+use polars::prelude::*;
+
+use polars_excel_writer::{ColumnFormat, PolarsXlsxWriter};
+use rust_xlsxwriter::Workbook;
+
+// Write the sales data to a worksheet of the workbook.
+fn add_sales(workbook: Workbook, df: &DataFrame) -> PolarsResult<Workbook> {
+    let mut xlsx_writer = PolarsXlsxWriter::from_workbook(workbook);
+
+    xlsx_writer.add_worksheet();
+    xlsx_writer.set_worksheet_name("Sales")?;
+    xlsx_writer.set_column_format("Sales", &ColumnFormat::new().set_num_format("$#,##0"));
+    xlsx_writer.write_dataframe(df)?;
+
+    xlsx_writer.into_workbook()
+}
+
+// Write the staff data to another worksheet of the workbook.
+fn add_staff(workbook: Workbook, df: &DataFrame) -> PolarsResult<Workbook> {
+    let mut xlsx_writer = PolarsXlsxWriter::from_workbook(workbook);
+
+    xlsx_writer.add_worksheet();
+    xlsx_writer.set_worksheet_name("Staff")?;
+    xlsx_writer.set_autofit(true);
+    xlsx_writer.write_dataframe(df)?;
+
+    xlsx_writer.into_workbook()
+}
+
+fn main() -> PolarsResult<()> {
+    // Create some sample dataframes for the example.
+    let sales: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000, 2300, 1900, 2500],
+    )?;
+
+    let staff: DataFrame = df!(
+        "Name" => &["Anna Lopez", "Ben Smith", "Chen Wei"],
+        "Region" => &["North", "South", "East"],
+    )?;
+
+    // Pass the workbook between the functions.
+    let workbook = Workbook::new();
+    let workbook = add_sales(workbook, &sales)?;
+    let mut workbook = add_staff(workbook, &staff)?;
+
+    // Save the file to disk.
+    workbook.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file from async
+//! code. This requires the `async` feature.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    let runtime = tokio::runtime::Builder::new_current_thread().build()?;
+
+    runtime.block_on(write_report())
+}
+
+async fn write_report() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "String" => &["North", "South", "East", "West"],
+        "Int" => &[1, 2, 3, 4],
+        "Float" => &[1.0, 2.22, 3.333, 4.4444],
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk without blocking the runtime.
+    xlsx_writer.save_async("dataframe.xlsx").await?;
+
+    Ok(())
+}
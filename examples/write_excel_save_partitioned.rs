@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing the rows of a Polar Rust dataframe for each customer
+//! to a separate Excel file.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Customer" => &["Acme", "Globex", "Acme", "Initech", "Globex"],
+        "Item" => &["Bolts", "Nuts", "Screws", "Washers", "Bolts"],
+        "Amount" => &[120.5, 99.0, 45.25, 10.0, 310.75],
+    )?;
+
+    // Split the dataframe into a dataframe for each customer.
+    let mut frames = vec![];
+    for partition in df.partition_by_stable(["Customer"], true)? {
+        let customer = partition
+            .column("Customer")?
+            .str()?
+            .get(0)
+            .unwrap_or_default();
+        frames.push((customer.to_string(), partition));
+    }
+
+    // Set up the writer options that are used for all of the files.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_autofit(true);
+    xlsx_writer.set_float_format("$#,##0.00");
+
+    // Write a file for each customer.
+    let dir = std::env::temp_dir();
+    let paths = xlsx_writer.save_partitioned(&dir, &frames)?;
+
+    for path in paths {
+        println!("Wrote {}", path.display());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file in memory.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "String" => &["North", "South", "East", "West"],
+        "Int" => &[1, 2, 3, 4],
+        "Float" => &[1.0, 2.22, 3.333, 4.4444],
+    )?;
+
+    // Write the dataframe to an Excel file in memory.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.write_dataframe(&df)?;
+
+    let buffer = xlsx_writer.save_to_buffer()?;
+
+    println!("File size: {}", buffer.len());
+
+    Ok(())
+}
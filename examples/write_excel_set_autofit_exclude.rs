@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates excluding a column from autofit.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000, 2300, 1900, 2100],
+        "Notes" => &[
+            "Sales were in line with the forecast for the quarter",
+            "A new sales office opened at the start of the quarter",
+            "",
+            "Sales were lower than expected due to supply issues",
+        ],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Autofit the columns apart from the "Notes" column.
+    xlsx_writer
+        .set_autofit(true)
+        .set_autofit_exclude(&["Notes"]);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
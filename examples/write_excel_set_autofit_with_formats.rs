@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates autofitting columns using the width of the formatted values
+//! and a maximum column width.
+
+use chrono::prelude::*;
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1234567.891, 22345678.5, 345.25, 4567890.0],
+        "Updated" => &[
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(9, 30, 0).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 1, 2).unwrap().and_hms_opt(10, 0, 0).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 1, 3).unwrap().and_hms_opt(11, 15, 0).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 1, 4).unwrap().and_hms_opt(12, 45, 0).unwrap(),
+        ],
+        "Notes" => &[
+            "A short note",
+            "A much longer note that would make the column very wide if it was autofitted",
+            "",
+            "Another note",
+        ],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Autofit the columns using the formatted values, with a maximum width of
+    // 30 characters.
+    xlsx_writer
+        .set_dtype_format(DataType::Float64, "#,##0.00 [$€-407]")
+        .set_autofit(true)
+        .set_autofit_with_formats(true)
+        .set_autofit_max_width(30.0);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates setting alternating formats for the data rows of a plain
+//! range.
+
+use polars::prelude::*;
+
+use polars_excel_writer::{ColumnFormat, PolarsXlsxWriter};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West", "Central"],
+        "Sales" => &[1000, 2300, 1900, 2500, 1700],
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Write the data as a plain range with alternating row colors.
+    xlsx_writer.set_use_table(false);
+    xlsx_writer.set_banded_row_formats(
+        &ColumnFormat::new().set_background_color("DDEBF7"),
+        &ColumnFormat::new().set_background_color("FFFFFF"),
+    );
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
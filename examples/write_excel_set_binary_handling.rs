@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates writing a binary column as hexadecimal strings.
+
+use polars::prelude::*;
+
+use polars_excel_writer::{BinaryHandling, PolarsXlsxWriter};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "File" => &["a.txt", "b.txt", "c.txt"],
+        "Checksum" => &[
+            &[0xde_u8, 0xad, 0xbe, 0xef][..],
+            &[0xca, 0xfe, 0xba, 0xbe],
+            &[0x0b, 0xad, 0xf0, 0x0d],
+        ],
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Write the binary values as hex strings like "deadbeef".
+    xlsx_writer.set_binary_handling(BinaryHandling::Hex);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates setting the format of cells based on their values.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{Color, Format};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Account" => &["Rent", "Sales", "Refunds", "Services"],
+        "Balance" => &[-1200.0, 3450.5, -230.25, 870.0],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Set a red fill for negative balances.
+    let negative_format = Format::new().set_background_color(Color::RGB(0xFFC7CE));
+
+    xlsx_writer.set_cell_style_callback("Balance", move |value| match value {
+        AnyValue::Float64(balance) if *balance < 0.0 => Some(negative_format.clone()),
+        _ => None,
+    });
+
+    // Set a number format for the column. It is kept for the styled cells.
+    xlsx_writer.set_float_format("#,##0.00");
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
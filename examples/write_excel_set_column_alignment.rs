@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates setting the alignment of dataframe columns.
+
+use polars::prelude::*;
+use rust_xlsxwriter::FormatAlign;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Order" => &[1001, 1002, 1003, 1004],
+        "Status" => &["Shipped", "Pending", "Shipped", "Cancelled"],
+        "Total" => &[120.5, 34.25, 99.0, 15.75],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Center the order numbers and the status values.
+    xlsx_writer
+        .set_column_alignment("Order", FormatAlign::Center)
+        .set_column_alignment("Status", FormatAlign::Center)
+        .set_float_format("0.00");
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
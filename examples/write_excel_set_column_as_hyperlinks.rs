@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates writing string columns as clickable hyperlinks.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Crate" => &["polars", "rust_xlsxwriter"],
+        "Docs" => &["https://docs.rs/polars", "https://docs.rs/rust_xlsxwriter"],
+        "Repository" => &[
+            "https://github.com/pola-rs/polars",
+            "https://github.com/jmcnamara/rust_xlsxwriter",
+        ],
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Write the "Docs" urls as hyperlinks.
+    xlsx_writer.set_column_as_hyperlinks("Docs");
+
+    // Write the "Crate" names as hyperlinks to the "Repository" urls.
+    xlsx_writer.set_hyperlink_pair("Repository", "Crate");
+    xlsx_writer.set_column_hidden("Repository");
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
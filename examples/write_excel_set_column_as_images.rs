@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates writing a column of image file paths as embedded images.
+
+use polars::prelude::*;
+
+use polars_excel_writer::{ImageOptions, PolarsXlsxWriter};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Product" => &["Red", "Blue", "Yellow"],
+        "Image" => &[
+            "tests/input/images/red.png",
+            "tests/input/images/blue.png",
+            "tests/input/images/yellow.png",
+        ],
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Write the "Image" file paths as embedded images.
+    let image_options = ImageOptions::new().set_row_height(40).set_column_width(40);
+
+    xlsx_writer.set_column_as_images("Image", &image_options);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
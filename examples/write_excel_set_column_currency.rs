@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates setting currency formats for dataframe columns.
+
+use polars::prelude::*;
+
+use polars_excel_writer::{CurrencyPreset, PolarsXlsxWriter};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Item" => &["Widget", "Gadget", "Refund"],
+        "US" => &[1250.0, 830.5, -99.99],
+        "Europe" => &[1120.0, 745.25, -89.5],
+        "Swiss" => &[1180.0, 790.0, -95.0],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Set the currency formats for the sales columns.
+    xlsx_writer
+        .set_column_currency("US", CurrencyPreset::USD)
+        .set_column_currency("Europe", CurrencyPreset::EUR)
+        .set_column_currency("Swiss", CurrencyPreset::Custom("CHF".to_string()));
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates setting format properties for dataframe columns.
+
+use chrono::prelude::*;
+use polars::prelude::*;
+use rust_xlsxwriter::Color;
+
+use polars_excel_writer::{ColumnFormat, PolarsXlsxWriter};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Due" => &[
+            NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 2, 15).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 3, 15).unwrap(),
+        ],
+        "Amount" => &[1250.0, 830.5, 99.99],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Set a number format for all the float columns.
+    xlsx_writer.set_dtype_format(DataType::Float64, "#,##0.00");
+
+    // Highlight the due dates. They keep the default date format.
+    let highlight = ColumnFormat::new().set_bold().set_font_color(Color::Red);
+
+    xlsx_writer.set_column_format("Due", &highlight);
+
+    // Add a fill to the amounts. They keep the float number format.
+    let fill = ColumnFormat::new().set_background_color("#E2EFDA");
+
+    xlsx_writer.set_column_format("Amount", &fill);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates masking the values of sensitive columns.
+
+use polars::prelude::*;
+
+use polars_excel_writer::{MaskStyle, PolarsXlsxWriter};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe with sensitive data.
+    let df: DataFrame = df!(
+        "Customer" => &["Alice Smith", "Bob Jones", "Carol White"],
+        "Email" => &["alice@example.com", "bob@example.com", "carol@example.com"],
+        "Card" => &["4111222233334444", "5500111122223333", "340011112222333"],
+        "Amount" => &[120.5, 89.99, 240.0],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Mask the sensitive columns.
+    xlsx_writer
+        .set_column_masked("Customer", MaskStyle::Hash)
+        .set_column_masked("Email", MaskStyle::Replace("REDACTED".to_string()))
+        .set_column_masked("Card", MaskStyle::Partial(4));
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates formatting columns as percentages.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe with fractional and percentage values.
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Share" => &[0.25, 0.35, 0.15, 0.25],
+        "Growth" => &[5.5, -2.25, 12.0, 3.75],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Format the fractional and percentage values as percentages.
+    xlsx_writer
+        .set_column_percentage("Share", 0, true)
+        .set_column_percentage("Growth", 1, false);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
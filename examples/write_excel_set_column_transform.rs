@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates transforming the values of columns when they are written.
+
+use polars::prelude::*;
+
+use polars_excel_writer::{CurrencyPreset, PolarsXlsxWriter};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Product" => &["Widget", "Gadget", "Extended warranty"],
+        "Price" => &[1250, 830, 9999],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Convert the prices from cents to dollars.
+    xlsx_writer.set_column_transform("Price", |value| match value {
+        AnyValue::Int32(cents) => AnyValue::Float64(f64::from(cents) / 100.0),
+        value => value,
+    });
+
+    // Truncate the product names to 8 characters.
+    xlsx_writer.set_column_transform("Product", |value| match value {
+        AnyValue::String(name) if name.chars().count() > 8 => {
+            let name: String = name.chars().take(8).collect();
+            AnyValue::StringOwned(format!("{name}...").into())
+        }
+        value => value,
+    });
+
+    // Set a currency format for the converted prices.
+    xlsx_writer.set_column_currency("Price", CurrencyPreset::USD);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
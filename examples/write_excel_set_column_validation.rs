@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates adding data validations to dataframe columns.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{DataValidation, DataValidationRule};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Status" => &["Open", "Closed", "Open", "Pending"],
+        "Units" => &[10, 25, 5, 40],
+    )?;
+    let df = df
+        .lazy()
+        .with_column(col("Status").cast(DataType::Categorical(None, Default::default())))
+        .collect()?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Restrict the "Units" to whole numbers between 0 and 100.
+    let validation = DataValidation::new().allow_whole_number(DataValidationRule::Between(0, 100));
+    xlsx_writer.set_column_validation("Units", &validation);
+
+    // Add a dropdown list of the "Status" categories.
+    xlsx_writer.set_column_dropdown("Status");
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates setting the width of dataframe columns.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "String" => &["North", "South", "East", "West"],
+        "Int" => &[1, 2, 3, 4],
+        "Float" => &[1.0, 2.22, 3.333, 4.4444],
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Set the width of some of the columns.
+    xlsx_writer
+        .set_column_width("String", 20.0)
+        .set_column_width_pixels("Float", 100);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe_to_cell(&df, 1, 1)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates adding conditional formats to dataframe columns.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{ConditionalFormat3ColorScale, ConditionalFormatDataBar};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000, 2300, 1500, 1750],
+        "Growth" => &[0.5, -0.25, 0.1, 0.3],
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Add a data bar to the "Sales" column and a color scale to the "Growth"
+    // column.
+    xlsx_writer.set_conditional_format("Sales", &ConditionalFormatDataBar::new());
+    xlsx_writer.set_conditional_format("Growth", &ConditionalFormat3ColorScale::new());
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
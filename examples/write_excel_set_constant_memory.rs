@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates writing large dataframes in constant memory mode.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create some sample dataframes for the example.
+    let df1: DataFrame = df!(
+        "Id" => (0..50_000).collect::<Vec<i32>>(),
+        "Value" => (0..50_000).map(|i| f64::from(i) * 1.5).collect::<Vec<f64>>(),
+    )?;
+
+    let df2: DataFrame = df!(
+        "Id" => (0..50_000).collect::<Vec<i32>>(),
+        "Value" => (0..50_000).map(|i| f64::from(i) * 2.5).collect::<Vec<f64>>(),
+    )?;
+
+    // Write the dataframes to an Excel file in constant memory mode.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_constant_memory(true);
+
+    xlsx_writer.write_dataframe(&df1)?;
+
+    xlsx_writer.add_worksheet();
+    xlsx_writer.write_dataframe(&df2)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
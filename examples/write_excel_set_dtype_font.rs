@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates setting the font and fill for the columns of a Polars data
+//! type.
+
+use chrono::prelude::*;
+use polars::prelude::*;
+
+use polars_excel_writer::{FontOptions, PolarsXlsxWriter};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Order" => &["A-100", "A-101", "A-102"],
+        "Ordered" => &[
+            NaiveDate::from_ymd_opt(2024, 1, 5).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 1, 9).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 2, 2).unwrap(),
+        ],
+        "Shipped" => &[
+            NaiveDate::from_ymd_opt(2024, 1, 8).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 2, 6).unwrap(),
+        ],
+        "Amount" => &[125.5, 310.0, 87.25],
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Grey out the date columns, keeping the default date format.
+    let font = FontOptions::new().set_italic().set_color("#595959");
+    xlsx_writer.set_dtype_font(DataType::Date, &font);
+    xlsx_writer.set_dtype_fill(DataType::Date, "#EDEDED");
+
+    // Make the amounts bold.
+    xlsx_writer.set_dtype_font(DataType::Float64, &FontOptions::new().set_bold());
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
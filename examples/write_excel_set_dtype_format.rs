@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates setting a format for the Polars categorical data type.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::Format;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe with a categorical column.
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000, 2300, 1500, 1750],
+    )?
+    .lazy()
+    .with_column(col("Region").cast(DataType::Categorical(None, Default::default())))
+    .collect()?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Set a format for the categorical column data.
+    let format = Format::new().set_bold().set_font_color("#0000FF");
+    xlsx_writer.set_dtype_format(DataType::Categorical(None, Default::default()), format);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
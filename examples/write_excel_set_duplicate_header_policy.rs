@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates renaming duplicate table headers in the same way as Excel.
+
+use polars::prelude::*;
+
+use polars_excel_writer::{DuplicateHeaderPolicy, PolarsXlsxWriter};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Product" => &["Apple", "Pear", "Plum"],
+        "Price" => &[1.20, 0.90, 1.50],
+        "price" => &[1.10, 0.95, 1.40],
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Rename the duplicate "price" header to "price2".
+    xlsx_writer.set_duplicate_header_policy(DuplicateHeaderPolicy::Rename);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates writing large floats in scientific notation.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Body" => &["Moon", "Earth", "Jupiter", "Sun"],
+        "Mass (kg)" => &[7.342e22, 5.972e24, 1.898e27, 1.989e30],
+        "Radius (km)" => &[1737.4, 6371.0, 69911.0, 695700.0],
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Write floats greater than one million in scientific notation.
+    xlsx_writer.set_float_precision(1);
+    xlsx_writer.set_float_scientific(1_000_000.0, 3);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates setting a format for several Polars data types.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe with different integer types.
+    let df: DataFrame = df!(
+        "Product" => &["Apple", "Pear", "Plum"],
+        "Units" => &[12500i32, 8300, 21750],
+        "Revenue" => &[1562500i64, 1145400, 2175000],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Set a thousands separator format for the integer columns.
+    xlsx_writer.set_format_for_dtypes(&[DataType::Int32, DataType::Int64], "#,##0");
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates setting a format for the columns with names that match a
+//! pattern.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000.0, 2300.0, 1900.0, 2500.0],
+        "Growth_pct" => &[0.051, 0.124, -0.032, 0.087],
+        "Margin_pct" => &[0.25, 0.31, 0.22, 0.28],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Set a percentage format for the columns ending in "_pct".
+    xlsx_writer.set_format_matching("_pct$", "0.0%")?;
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates writing the header as a formatted row of cells instead of a
+//! table header.
+
+use polars::prelude::*;
+
+use polars_excel_writer::{HeaderMode, PolarsXlsxWriter};
+use rust_xlsxwriter::Format;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[100, 200, 300, 400],
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Write the header as a bold row without a table.
+    xlsx_writer.set_header_mode(HeaderMode::WrittenHeaderRow);
+    xlsx_writer.set_header_format(Format::new().set_bold());
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
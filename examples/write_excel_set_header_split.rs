@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates splitting hierarchical column names into a grouped header.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "2023|Q1" => &[100, 200, 300, 400],
+        "2023|Q2" => &[110, 210, 310, 410],
+        "2024|Q1" => &[120, 220, 320, 420],
+        "2024|Q2" => &[130, 230, 330, 430],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Write the years as merged groups above the quarters.
+    xlsx_writer.set_header_split("|");
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates adding a heatmap across several columns.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::ConditionalFormat3ColorScale;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Q1" => &[100, 250, 130, 90],
+        "Q2" => &[120, 210, 160, 110],
+        "Q3" => &[150, 190, 180, 140],
+        "Q4" => &[170, 160, 200, 180],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Add a heatmap across the quarterly columns.
+    xlsx_writer.set_heatmap(
+        &["Q1", "Q2", "Q3", "Q4"],
+        ConditionalFormat3ColorScale::new(),
+    );
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates writing large integer IDs as text cells.
+
+use polars::prelude::*;
+
+use polars_excel_writer::{LargeIntHandling, PolarsXlsxWriter};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe with IDs that are too large to be stored
+    // exactly in Excel.
+    let df: DataFrame = df!(
+        "Id" => &[1_541_815_603_606_036_480_u64, 1_541_815_603_606_036_481],
+        "Name" => &["Alpha", "Beta"],
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Write the large integers as text cells to preserve all the digits.
+    xlsx_writer.set_large_int_handling(LargeIntHandling::TextFormat);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates setting the delimiter for list values.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe with a list column.
+    let tags = Series::new(
+        "Tags".into(),
+        [
+            Series::new("".into(), ["red", "green"]),
+            Series::new("".into(), ["blue"]),
+            Series::new("".into(), ["red", "yellow", "blue"]),
+        ],
+    );
+    let ids = Series::new("Id".into(), [1, 2, 3]);
+    let df = DataFrame::new(vec![ids.into(), tags.into()])?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Set the delimiter for the list values.
+    xlsx_writer.set_list_delimiter("; ");
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
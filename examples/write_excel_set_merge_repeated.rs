@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates merging the repeated values in a key column.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::FormatAlign;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Region" => &["North", "North", "North", "South", "South", "East"],
+        "Product" => &["Apple", "Pear", "Plum", "Apple", "Pear", "Apple"],
+        "Sales" => &[100, 200, 150, 300, 250, 400],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Merge the repeated regions and align them at the top of the cells.
+    xlsx_writer.set_merge_repeated("Region");
+    xlsx_writer.set_column_alignment("Region", FormatAlign::Top);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates writing NaN and Infinity values as Excel `#N/A` errors.
+
+use polars::prelude::*;
+
+use polars_excel_writer::{NanHandling, PolarsXlsxWriter};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Sensor" => &["A", "B", "C", "D"],
+        "Reading" => &[1.5, f64::NAN, 2.25, f64::INFINITY],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Write the NaN and Infinity values as #N/A errors.
+    xlsx_writer.set_nan_handling(NanHandling::ErrorValue);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
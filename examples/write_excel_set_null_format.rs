@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates setting a format for the Null replacement values.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{Color, Format, FormatAlign};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe with Null values.
+    let df: DataFrame = df!(
+        "Item" => &["Apple", "Banana", "Cherry", "Date"],
+        "Price" => &[Some(1.25), None, Some(3.5), None],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Write Null values as "N/A" in grey, centered, italic text.
+    let null_format = Format::new()
+        .set_italic()
+        .set_font_color(Color::Gray)
+        .set_align(FormatAlign::Center);
+
+    xlsx_writer.set_null_value("N/A");
+    xlsx_writer.set_null_format(&null_format);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates writing an object column as strings.
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use polars::prelude::*;
+use polars_utils::total_ord::{TotalEq, TotalHash};
+
+use polars_excel_writer::{ObjectHandling, PolarsXlsxWriter};
+
+// A custom type stored in a Polars object column. Polars objects also require
+// the `TotalEq` and `TotalHash` traits.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+struct Version {
+    major: u32,
+    minor: u32,
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "v{}.{}", self.major, self.minor)
+    }
+}
+
+impl TotalEq for Version {
+    fn tot_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl TotalHash for Version {
+    fn tot_hash<H: Hasher>(&self, state: &mut H) {
+        self.hash(state);
+    }
+}
+
+impl PolarsObject for Version {
+    fn type_name() -> &'static str {
+        "version"
+    }
+}
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe with an object column.
+    let versions = vec![
+        Version { major: 1, minor: 2 },
+        Version { major: 2, minor: 0 },
+        Version { major: 2, minor: 1 },
+    ];
+
+    let df = DataFrame::new(vec![
+        Column::new("Package".into(), &["core", "io", "cli"]),
+        ObjectChunked::new_from_vec("Version".into(), versions)
+            .into_series()
+            .into_column(),
+    ])?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Write the object values as strings like "v1.2".
+    xlsx_writer.set_object_handling(ObjectHandling::DisplayString);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
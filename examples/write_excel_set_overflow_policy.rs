@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates splitting a dataframe that exceeds the Excel row limit across
+//! several worksheets.
+
+use polars::prelude::*;
+
+use polars_excel_writer::{OverflowPolicy, PolarsXlsxWriter};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe with more rows than fit in a worksheet.
+    let df: DataFrame = df!(
+        "Id" => (0..1_500_000).collect::<Vec<i32>>(),
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Continue any rows beyond the row limit on another worksheet.
+    xlsx_writer.set_overflow_policy(OverflowPolicy::SplitWorksheets);
+
+    xlsx_writer.write_dataframe_chunked(&df, 100_000)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates setting the page setup options for printing the worksheet.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Q1" => &[100, 110, 120, 130],
+        "Q2" => &[200, 210, 220, 230],
+        "Q3" => &[300, 310, 320, 330],
+        "Q4" => &[400, 410, 420, 430],
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Print the table in landscape on A4 paper, one page wide, with the
+    // header row repeated on each page.
+    xlsx_writer.set_landscape();
+    xlsx_writer.set_paper_size(9);
+    xlsx_writer.set_print_area_to_table(true);
+    xlsx_writer.set_repeat_header_row(true);
+    xlsx_writer.set_fit_to_pages(1, 0);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates writing the dataframe columns in parallel.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Region" => (0..10_000).map(|i| format!("Region {}", i % 7)).collect::<Vec<String>>(),
+        "Units" => (0..10_000).collect::<Vec<i32>>(),
+        "Price" => (0..10_000).map(|i| f64::from(i) * 0.25).collect::<Vec<f64>>(),
+        "Active" => (0..10_000).map(|i| i % 3 == 0).collect::<Vec<bool>>(),
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Write the columns in parallel.
+    xlsx_writer.set_parallel(true);
+
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates displaying the worksheet from right to left.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe with Hebrew headers and data.
+    let df: DataFrame = df!(
+        "אזור" => &["צפון", "דרום", "מזרח", "מערב"],
+        "מכירות" => &[100, 120, 140, 130],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Display the worksheet from right to left.
+    xlsx_writer.set_right_to_left(true);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates grouping the transactions of each account into collapsed
+//! outline groups.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Account" => &["A-100", "A-100", "A-100", "B-200", "B-200", "C-300"],
+        "Date" => &["Jan", "Feb", "Mar", "Jan", "Feb", "Jan"],
+        "Amount" => &[100, 200, 150, 300, 250, 400],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Group the rows of each account and collapse the groups.
+    xlsx_writer.set_row_grouping("Account", true);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
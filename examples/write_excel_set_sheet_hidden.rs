@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing Polar Rust dataframes to an Excel file. This
+//! demonstrates hiding a worksheet with lookup data.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create some sample dataframes for the example.
+    let sales: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[100, 120, 140, 130],
+    )?;
+    let regions: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Manager" => &["Ann", "Bob", "Cy", "Di"],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Write the sales data to the first worksheet.
+    xlsx_writer.write_dataframe(&sales)?;
+
+    // Write the lookup data to a hidden worksheet.
+    xlsx_writer.add_worksheet();
+    xlsx_writer.set_sheet_hidden(true);
+    xlsx_writer.write_dataframe(&regions)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing Polar Rust dataframes to an Excel file. This
+//! demonstrates moving a summary worksheet to the start of the workbook.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create some sample dataframes for the example.
+    let sales: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[100, 120, 140, 130],
+    )?;
+    let summary: DataFrame = df!("Total" => &[490])?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Write the sales data to the first worksheet.
+    xlsx_writer.set_worksheet_name("Sales")?;
+    xlsx_writer.write_dataframe(&sales)?;
+
+    // Write the summary to a worksheet at the start of the workbook.
+    xlsx_writer.add_worksheet();
+    xlsx_writer.set_worksheet_name("Summary")?;
+    xlsx_writer.set_sheet_position(0);
+    xlsx_writer.write_dataframe(&summary)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
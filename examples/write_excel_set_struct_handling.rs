@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates flattening a struct column into a column per field.
+
+use polars::prelude::*;
+
+use polars_excel_writer::{PolarsXlsxWriter, StructHandling};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe with a struct column.
+    let x = Series::new("x".into(), [1.5, 2.0, 3.25]);
+    let y = Series::new("y".into(), [4.0, 5.5, 6.75]);
+    let point = StructChunked::from_series("Point".into(), 3, [x, y].iter())?;
+
+    let ids = Series::new("Id".into(), [1, 2, 3]);
+    let df = DataFrame::new(vec![ids.into(), point.into_series().into()])?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Write the struct fields to the "Point.x" and "Point.y" columns.
+    xlsx_writer.set_struct_handling(StructHandling::Flatten);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
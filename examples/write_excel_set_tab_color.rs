@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing Polar Rust dataframes to an Excel file. This
+//! demonstrates setting the colors of the worksheet tabs.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::Color;
+
+fn main() -> PolarsResult<()> {
+    // Create some sample dataframes for the example.
+    let df1: DataFrame = df!("Data 1" => &[10, 20, 15, 25, 30, 20])?;
+    let df2: DataFrame = df!("Data 2" => &[1.23, 2.34, 3.56])?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Write the first dataframe with a red tab.
+    xlsx_writer.set_tab_color(Color::Red);
+    xlsx_writer.write_dataframe(&df1)?;
+
+    // Add another worksheet and write the second dataframe with a green tab.
+    xlsx_writer.add_worksheet();
+    xlsx_writer.set_tab_color(Color::Green);
+    xlsx_writer.write_dataframe(&df2)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates combining a user defined table column with a total row.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{Format, TableColumn, TableFunction};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000, 2300, 1500, 1750],
+        "Units" => &[10, 23, 15, 17],
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Add a table column with a maximum total and a currency format.
+    let column = TableColumn::new()
+        .set_total_function(TableFunction::Max)
+        .set_format(Format::new().set_num_format("$#,##0"));
+    xlsx_writer.set_table_column("Sales", &column);
+
+    // Add a total for another column.
+    xlsx_writer.set_column_total("Units", TableFunction::Sum);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates using a custom converter to write UTC datetimes in a local
+//! time zone.
+
+use chrono::prelude::*;
+use chrono::TimeDelta;
+use polars::prelude::*;
+
+use polars_excel_writer::{DefaultTemporalConverter, PolarsXlsxWriter, TemporalToExcel};
+
+// A converter that shifts UTC datetimes to Central European Time.
+struct CetConverter;
+
+impl TemporalToExcel for CetConverter {
+    fn datetime(&self, value: i64, time_unit: TimeUnit) -> Option<NaiveDateTime> {
+        let datetime = DefaultTemporalConverter.datetime(value, time_unit)?;
+        datetime.checked_add_signed(TimeDelta::hours(1))
+    }
+}
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe with UTC datetimes.
+    let df: DataFrame = df!(
+        "Datetime" => &[
+            NaiveDate::from_ymd_opt(2023, 1, 11).unwrap().and_hms_opt(1, 0, 0).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 1, 12).unwrap().and_hms_opt(2, 0, 0).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 1, 13).unwrap().and_hms_opt(3, 0, 0).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 1, 14).unwrap().and_hms_opt(4, 0, 0).unwrap(),
+        ],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Write the datetimes in the local time zone.
+    xlsx_writer.set_temporal_converter(CetConverter);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
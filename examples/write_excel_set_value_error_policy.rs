@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates writing values that can't be represented in Excel as strings.
+
+use polars::prelude::*;
+
+use polars_excel_writer::{ErrorPolicy, PolarsXlsxWriter};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe with an integer that is too large to be
+    // stored exactly in Excel.
+    let df: DataFrame = df!(
+        "Id" => &[1_i64, 9_007_199_254_740_993],
+        "Name" => &["Alpha", "Beta"],
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Write values that can't be represented in Excel as strings.
+    xlsx_writer.set_value_error_policy(ErrorPolicy::WriteAsString);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing Polar Rust dataframes to an Excel file. This
+//! demonstrates setting worksheet names that come from the data.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Sample customer names and sales for the example.
+    let customers = [
+        ("Acme: North/South", [1000, 1200]),
+        ("ACME: NORTH/SOUTH", [800, 900]),
+        ("Smith & Sons [Wholesale Division]", [2300, 2100]),
+    ];
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    for (index, (customer, sales)) in customers.iter().enumerate() {
+        let df: DataFrame = df!(
+            "Quarter" => &["Q1", "Q2"],
+            "Sales" => sales,
+        )?;
+
+        if index > 0 {
+            xlsx_writer.add_worksheet();
+        }
+
+        // Use the customer name as the worksheet name. The names are
+        // changed to "Acme_ North_South", "ACME_ NORTH_SOUTH (2)" and
+        // "Smith & Sons _Wholesale Divisio".
+        xlsx_writer.set_worksheet_name_lossy(customer)?;
+
+        xlsx_writer.write_dataframe(&df)?;
+    }
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
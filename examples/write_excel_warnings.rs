@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates getting the warnings for writer options that were ignored.
+
+use polars::prelude::*;
+
+use polars_excel_writer::{ColumnFormat, PolarsXlsxWriter};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000, 2300, 1900, 2500],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Set a format for a misspelled column name.
+    let currency = ColumnFormat::new().set_num_format("$#,##0");
+
+    xlsx_writer.set_column_format("Sale", &currency);
+
+    // Write the dataframe to Excel.
+    xlsx_writer.write_dataframe(&df)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    // Report any options that were ignored.
+    for warning in xlsx_writer.warnings() {
+        eprintln!("Warning: {}", warning.message);
+    }
+
+    Ok(())
+}
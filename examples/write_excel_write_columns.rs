@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing several Polar Rust series to an Excel file as
+//! columns.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create some sample series for the example.
+    let sales = Series::new("Sales".into(), &[100.0, 200.0, 300.0, 400.0]);
+    let costs = Series::new("Costs".into(), &[80.0, 150.0, 210.0, 260.0]);
+    let profit = (&sales - &costs)?.with_name("Profit".into());
+
+    // Write the series to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    xlsx_writer.write_columns(&[sales, costs, profit])?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates writing a large dataframe in chunks of rows using constant
+//! memory mode.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Id" => (0..10_000).collect::<Vec<i32>>(),
+        "Value" => (0..10_000).map(|i| f64::from(i) * 1.5).collect::<Vec<f64>>(),
+    )?;
+
+    // Write the dataframe to an Excel file in chunks of 1,000 rows.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    xlsx_writer.write_dataframe_chunked(&df, 1_000)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates highlighting the cells that have changed from a previous
+//! version of the dataframe.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create sample dataframes for the example.
+    let df_old: DataFrame = df!(
+        "Id" => &[1, 2, 3],
+        "Region" => &["North", "South", "East"],
+        "Sales" => &[100, 200, 300],
+    )?;
+
+    let df_new: DataFrame = df!(
+        "Id" => &[1, 3, 2, 4],
+        "Region" => &["North", "East", "South", "West"],
+        "Sales" => &[100, 350, 200, 400],
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Match the rows of the dataframes by the "Id" column.
+    xlsx_writer.set_diff_key_column("Id");
+
+    // Write the new dataframe and highlight the changed cells.
+    xlsx_writer.write_dataframe_diff(&df_old, &df_new)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
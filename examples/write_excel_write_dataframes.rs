@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing several Polar dataframes side by side in an Excel
+//! worksheet.
+
+use polars::prelude::*;
+
+use polars_excel_writer::{Layout, PolarsXlsxWriter};
+
+fn main() -> PolarsResult<()> {
+    // Create some sample dataframes for the example.
+    let df1: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000, 2300, 1900, 2500],
+    )?;
+
+    let df2: DataFrame = df!(
+        "Quarter" => &["Q1", "Q2", "Q3", "Q4"],
+        "Costs" => &[450.25, 610.50, 520.75, 700.00],
+    )?;
+
+    // Write the dataframes to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Write the dataframes side by side with a one column gap.
+    xlsx_writer.write_dataframes(&[&df1, &df2], Layout::Horizontal { gap: 1 })?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
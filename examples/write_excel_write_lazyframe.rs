@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing the result of a Polar Rust lazyframe query to an
+//! Excel file.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West", "North", "South"],
+        "Sales" => &[100, 200, 300, 400, 500, 600],
+    )?;
+
+    // Create a lazyframe query to get the total sales for each region.
+    let lf = df
+        .lazy()
+        .group_by_stable([col("Region")])
+        .agg([col("Sales").sum()]);
+
+    // Write the lazyframe query result to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    xlsx_writer.write_lazyframe(lf)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
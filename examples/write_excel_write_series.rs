@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust series to an Excel file.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+
+fn main() -> PolarsResult<()> {
+    // Create a sample series for the example.
+    let series = Series::new("Data".into(), &[10, 20, 15, 25, 30, 20]);
+
+    // Write the series to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    xlsx_writer.write_series(&series, true)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of writing a Polar Rust dataframe to an Excel file. This
+//! demonstrates using the range returned by `write_dataframe()` to add a chart
+//! that plots the data.
+
+use polars::prelude::*;
+
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{Chart, ChartType};
+
+fn main() -> PolarsResult<()> {
+    // Create a sample dataframe for the example.
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000, 2300, 1900, 2500],
+    )?;
+
+    // Write the dataframe to an Excel file.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // Write the dataframe and get the range that it was written to.
+    let range = xlsx_writer.write_dataframe(&df)?;
+
+    // Create a chart using the range of the data, below the header row.
+    let sheet_name = range.sheet_name.as_str();
+    let mut chart = Chart::new(ChartType::Column);
+    chart
+        .add_series()
+        .set_categories((
+            sheet_name,
+            range.first_row + 1,
+            range.first_col,
+            range.last_row,
+            range.first_col,
+        ))
+        .set_values((
+            sheet_name,
+            range.first_row + 1,
+            range.first_col + 1,
+            range.last_row,
+            range.first_col + 1,
+        ));
+
+    // Insert the chart to the right of the dataframe.
+    let worksheet = xlsx_writer.worksheet()?;
+    worksheet.insert_chart(0, range.last_col + 2, &chart)?;
+
+    // Save the file to disk.
+    xlsx_writer.save("dataframe.xlsx")?;
+
+    Ok(())
+}
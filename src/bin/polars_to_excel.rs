@@ -0,0 +1,164 @@
+// polars-to-excel - A command line application to convert Parquet, CSV and IPC
+// files to Excel xlsx files using polars_excel_writer.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use polars::prelude::*;
+use polars_excel_writer::{ColumnConfig, ExportConfig, PolarsXlsxWriter};
+
+const USAGE: &str = "\
+Convert a Parquet, CSV or IPC file to an Excel xlsx file.
+
+Usage: polars-to-excel [OPTIONS] <INPUT> <OUTPUT>
+
+Arguments:
+  <INPUT>   The input file. The type is taken from the file extension:
+            .parquet, .csv, .ipc, .arrow or .feather
+  <OUTPUT>  The output xlsx file
+
+Options:
+  --sheet-name <NAME>              The worksheet name
+  --autofit                        Autofit the column widths
+  --float-precision <N>            The number of decimal places for floats
+  --table-style <STYLE>            The table style, such as Medium9 or None
+  --column-format <COLUMN=FORMAT>  The number format for a column, such as
+                                   'Sales=#,##0.00'. Can be repeated
+  -h, --help                       Print this help message";
+
+// The command line arguments.
+#[derive(Default)]
+struct Args {
+    input: PathBuf,
+    output: PathBuf,
+    config: ExportConfig,
+}
+
+// The supported input file types.
+enum InputType {
+    Parquet,
+    Csv,
+    Ipc,
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match convert(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Read the input file and write it to the output xlsx file.
+fn convert(args: &Args) -> PolarsResult<()> {
+    let df = read_dataframe(&args.input)?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::from_config(&args.config)?;
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(&args.output)?;
+
+    Ok(())
+}
+
+// Read a dataframe from a Parquet, CSV or IPC file.
+fn read_dataframe(path: &Path) -> PolarsResult<DataFrame> {
+    let input_type = input_type(path)?;
+
+    match input_type {
+        InputType::Parquet => ParquetReader::new(File::open(path)?).finish(),
+        InputType::Ipc => IpcReader::new(File::open(path)?).finish(),
+        InputType::Csv => CsvReadOptions::default()
+            .with_has_header(true)
+            .try_into_reader_with_file_path(Some(path.to_path_buf()))?
+            .finish(),
+    }
+}
+
+// Get the input file type from the file extension.
+fn input_type(path: &Path) -> PolarsResult<InputType> {
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_lowercase)
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "parquet" | "pq" => Ok(InputType::Parquet),
+        "csv" => Ok(InputType::Csv),
+        "ipc" | "arrow" | "feather" => Ok(InputType::Ipc),
+        _ => polars_bail!(
+            ComputeError: "unsupported input file type: '{}'", path.display()
+        ),
+    }
+}
+
+// Parse the command line arguments into the input and output files and the
+// writer config. Returns `None` if the help message was requested.
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Args>, String> {
+    let mut parsed = Args::default();
+    let mut files = vec![];
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| format!("missing value for option '{name}'"))
+        };
+
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--autofit" => parsed.config.autofit = Some(true),
+            "--sheet-name" => parsed.config.worksheet_name = Some(value(&arg)?),
+            "--table-style" => parsed.config.table_style = Some(value(&arg)?),
+            "--float-precision" => {
+                let precision = value(&arg)?;
+                let precision = precision
+                    .parse()
+                    .map_err(|_| format!("invalid float precision: '{precision}'"))?;
+                parsed.config.float_precision = Some(precision);
+            }
+            "--column-format" => {
+                let column_format = value(&arg)?;
+                let (column, num_format) = column_format
+                    .split_once('=')
+                    .ok_or_else(|| format!("invalid column format: '{column_format}'"))?;
+
+                parsed
+                    .config
+                    .column_options
+                    .entry(column.to_string())
+                    .or_insert_with(ColumnConfig::default)
+                    .num_format = Some(num_format.to_string());
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown option '{arg}'")),
+            _ => files.push(PathBuf::from(arg)),
+        }
+    }
+
+    match <[PathBuf; 2]>::try_from(files) {
+        Ok([input, output]) => {
+            parsed.input = input;
+            parsed.output = output;
+            Ok(Some(parsed))
+        }
+        Err(_) => Err("expected an input and an output file".to_string()),
+    }
+}
@@ -0,0 +1,299 @@
+// column_buffer - Cell destinations for the written column values, including
+// an in-memory column buffer used to write columns in parallel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use rust_xlsxwriter::{Format, Formula, Worksheet, XlsxError};
+
+// A date or time value written with a date/time number format.
+#[derive(Clone, Copy)]
+pub(crate) enum TemporalValue {
+    DateTime(NaiveDateTime),
+    Date(NaiveDate),
+    Time(NaiveTime),
+}
+
+// The destination of the cell values written from a dataframe column. This is
+// either the worksheet or a column buffer that is written to the worksheet
+// later. The methods mirror the `Worksheet` methods used by the writer.
+pub(crate) trait CellSink {
+    fn write_number_cell(
+        &mut self,
+        row: u32,
+        col: u16,
+        value: f64,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError>;
+
+    fn write_string_cell(
+        &mut self,
+        row: u32,
+        col: u16,
+        value: &str,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError>;
+
+    fn write_boolean_cell(
+        &mut self,
+        row: u32,
+        col: u16,
+        value: bool,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError>;
+
+    fn write_temporal_cell(
+        &mut self,
+        row: u32,
+        col: u16,
+        value: TemporalValue,
+        format: &Format,
+    ) -> Result<(), XlsxError>;
+
+    fn write_formula_cell(
+        &mut self,
+        row: u32,
+        col: u16,
+        formula: Formula,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError>;
+
+    fn write_blank_cell(&mut self, row: u32, col: u16, format: &Format) -> Result<(), XlsxError>;
+}
+
+impl CellSink for Worksheet {
+    fn write_number_cell(
+        &mut self,
+        row: u32,
+        col: u16,
+        value: f64,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        match format {
+            Some(format) => self.write_number_with_format(row, col, value, format)?,
+            None => self.write_number(row, col, value)?,
+        };
+
+        Ok(())
+    }
+
+    fn write_string_cell(
+        &mut self,
+        row: u32,
+        col: u16,
+        value: &str,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        match format {
+            Some(format) => self.write_string_with_format(row, col, value, format)?,
+            None => self.write_string(row, col, value)?,
+        };
+
+        Ok(())
+    }
+
+    fn write_boolean_cell(
+        &mut self,
+        row: u32,
+        col: u16,
+        value: bool,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        match format {
+            Some(format) => self.write_boolean_with_format(row, col, value, format)?,
+            None => self.write_boolean(row, col, value)?,
+        };
+
+        Ok(())
+    }
+
+    fn write_temporal_cell(
+        &mut self,
+        row: u32,
+        col: u16,
+        value: TemporalValue,
+        format: &Format,
+    ) -> Result<(), XlsxError> {
+        match value {
+            TemporalValue::DateTime(datetime) => {
+                self.write_datetime_with_format(row, col, datetime, format)?
+            }
+            TemporalValue::Date(date) => self.write_datetime_with_format(row, col, date, format)?,
+            TemporalValue::Time(time) => self.write_datetime_with_format(row, col, time, format)?,
+        };
+
+        Ok(())
+    }
+
+    fn write_formula_cell(
+        &mut self,
+        row: u32,
+        col: u16,
+        formula: Formula,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        match format {
+            Some(format) => self.write_formula_with_format(row, col, formula, format)?,
+            None => self.write_formula(row, col, formula)?,
+        };
+
+        Ok(())
+    }
+
+    fn write_blank_cell(&mut self, row: u32, col: u16, format: &Format) -> Result<(), XlsxError> {
+        self.write_blank(row, col, format)?;
+
+        Ok(())
+    }
+}
+
+// The cells written from a column, stored in order so that they can be built
+// on another thread and written to the worksheet afterwards. Formats are
+// stored once for each run of cells that use the same format.
+#[cfg(feature = "parallel")]
+#[derive(Default)]
+pub(crate) struct ColumnBuffer {
+    cells: Vec<BufferedCell>,
+    formats: Vec<Format>,
+}
+
+// A buffered cell with the index of its format, if any.
+#[cfg(feature = "parallel")]
+struct BufferedCell {
+    row: u32,
+    col: u16,
+    value: BufferedValue,
+    format: Option<usize>,
+}
+
+// The value of a buffered cell.
+#[cfg(feature = "parallel")]
+enum BufferedValue {
+    Number(f64),
+    String(String),
+    Boolean(bool),
+    Temporal(TemporalValue),
+    Formula(Formula),
+    Blank,
+}
+
+#[cfg(feature = "parallel")]
+impl ColumnBuffer {
+    // Write the buffered cells to a worksheet, in the order they were added.
+    pub(crate) fn write_to_worksheet(self, worksheet: &mut Worksheet) -> Result<(), XlsxError> {
+        let formats = self.formats;
+
+        for cell in self.cells {
+            let format = cell.format.map(|index| &formats[index]);
+            let (row, col) = (cell.row, cell.col);
+
+            match (cell.value, format) {
+                (BufferedValue::Number(value), _) => {
+                    worksheet.write_number_cell(row, col, value, format)?;
+                }
+                (BufferedValue::String(value), _) => {
+                    worksheet.write_string_cell(row, col, &value, format)?;
+                }
+                (BufferedValue::Boolean(value), _) => {
+                    worksheet.write_boolean_cell(row, col, value, format)?;
+                }
+                (BufferedValue::Formula(formula), _) => {
+                    worksheet.write_formula_cell(row, col, formula, format)?;
+                }
+                (BufferedValue::Temporal(value), Some(format)) => {
+                    worksheet.write_temporal_cell(row, col, value, format)?;
+                }
+                (BufferedValue::Blank, Some(format)) => {
+                    worksheet.write_blank_cell(row, col, format)?;
+                }
+                (BufferedValue::Temporal(_) | BufferedValue::Blank, None) => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    // Add a cell to the buffer. The format is compared with the most recent
+    // format since the cells of a column generally share the same format.
+    fn push(&mut self, row: u32, col: u16, value: BufferedValue, format: Option<&Format>) {
+        let format = format.map(|format| match self.formats.last() {
+            Some(last) if last == format => self.formats.len() - 1,
+            _ => {
+                self.formats.push(format.clone());
+                self.formats.len() - 1
+            }
+        });
+
+        self.cells.push(BufferedCell {
+            row,
+            col,
+            value,
+            format,
+        });
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl CellSink for ColumnBuffer {
+    fn write_number_cell(
+        &mut self,
+        row: u32,
+        col: u16,
+        value: f64,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        self.push(row, col, BufferedValue::Number(value), format);
+        Ok(())
+    }
+
+    fn write_string_cell(
+        &mut self,
+        row: u32,
+        col: u16,
+        value: &str,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        self.push(row, col, BufferedValue::String(value.to_string()), format);
+        Ok(())
+    }
+
+    fn write_boolean_cell(
+        &mut self,
+        row: u32,
+        col: u16,
+        value: bool,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        self.push(row, col, BufferedValue::Boolean(value), format);
+        Ok(())
+    }
+
+    fn write_temporal_cell(
+        &mut self,
+        row: u32,
+        col: u16,
+        value: TemporalValue,
+        format: &Format,
+    ) -> Result<(), XlsxError> {
+        self.push(row, col, BufferedValue::Temporal(value), Some(format));
+        Ok(())
+    }
+
+    fn write_formula_cell(
+        &mut self,
+        row: u32,
+        col: u16,
+        formula: Formula,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        self.push(row, col, BufferedValue::Formula(formula), format);
+        Ok(())
+    }
+
+    fn write_blank_cell(&mut self, row: u32, col: u16, format: &Format) -> Result<(), XlsxError> {
+        self.push(row, col, BufferedValue::Blank, Some(format));
+        Ok(())
+    }
+}
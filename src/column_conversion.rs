@@ -0,0 +1,359 @@
+// column_conversion - Conversion of Polars columns and values that Excel
+// doesn't support directly.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use polars::prelude::*;
+use rust_xlsxwriter::Format;
+
+use crate::column_buffer::CellSink;
+use crate::dataframe_adapter::{
+    dataframe_from_columns, to_column, ColumnAdapter, DataFrameAdapter,
+};
+use crate::xlsx_writer::WriterOptions;
+use crate::{BinaryHandling, PolarsXlsxWriter, StructHandling};
+
+#[cfg(feature = "object")]
+use crate::ObjectHandling;
+
+impl PolarsXlsxWriter {
+    // Convert any struct columns in the dataframe according to the struct
+    // handling option.
+    pub(crate) fn convert_struct_columns<'a>(
+        df: &'a DataFrame,
+        options: &WriterOptions,
+    ) -> PolarsResult<Cow<'a, DataFrame>> {
+        let has_structs = df
+            .data_columns()
+            .iter()
+            .any(|column| matches!(column.dtype(), DataType::Struct(_)));
+
+        if !has_structs {
+            return Ok(Cow::Borrowed(df));
+        }
+
+        let mut columns = vec![];
+        for column in df.data_columns() {
+            let series = column.series();
+
+            if !matches!(series.dtype(), DataType::Struct(_)) {
+                columns.push(column.clone());
+                continue;
+            }
+
+            match options.struct_handling {
+                StructHandling::Error => polars_bail!(
+                    ComputeError:
+                    "struct column '{}' isn't supported by Excel. See set_struct_handling()",
+                    column.name()
+                ),
+                StructHandling::Flatten => {
+                    for field in Self::flatten_struct(series, series.name())? {
+                        columns.push(to_column(field));
+                    }
+                }
+                StructHandling::JsonString => {
+                    let mut values = vec![];
+                    for row in 0..series.len() {
+                        if column.value(row)?.is_null() {
+                            values.push(None);
+                        } else {
+                            values.push(Some(Self::json_value(series, row)?));
+                        }
+                    }
+
+                    columns.push(to_column(Series::new(series.name().clone(), values)));
+                }
+            }
+        }
+
+        Ok(Cow::Owned(dataframe_from_columns(columns)?))
+    }
+
+    // Convert any binary columns in the dataframe according to the binary
+    // handling option. Binary columns that are written as images are left
+    // unchanged.
+    pub(crate) fn convert_binary_columns<'a>(
+        df: &'a DataFrame,
+        options: &WriterOptions,
+    ) -> PolarsResult<Cow<'a, DataFrame>> {
+        let is_converted = |column: &Column| {
+            matches!(column.dtype(), DataType::Binary)
+                && !options.image_columns.contains_key(column.column_name())
+        };
+
+        if !df.data_columns().iter().any(is_converted) {
+            return Ok(Cow::Borrowed(df));
+        }
+
+        let mut columns = vec![];
+        for column in df.data_columns() {
+            if !is_converted(column) {
+                columns.push(column.clone());
+                continue;
+            }
+
+            let to_string: fn(&[u8]) -> String = match options.binary_handling {
+                BinaryHandling::Error => polars_bail!(
+                    ComputeError:
+                    "binary column '{}' isn't supported by Excel. See set_binary_handling()",
+                    column.name()
+                ),
+                BinaryHandling::Skip => continue,
+                BinaryHandling::Base64 => Self::base64_string,
+                BinaryHandling::Hex => Self::hex_string,
+            };
+
+            let values: Vec<Option<String>> = column
+                .series()
+                .binary()?
+                .into_iter()
+                .map(|value| value.map(to_string))
+                .collect();
+
+            columns.push(to_column(Series::new(column.name().clone(), values)));
+        }
+
+        Ok(Cow::Owned(dataframe_from_columns(columns)?))
+    }
+
+    // Convert any object columns in the dataframe according to the object
+    // handling option.
+    #[cfg(feature = "object")]
+    pub(crate) fn convert_object_columns<'a>(
+        df: Cow<'a, DataFrame>,
+        options: &WriterOptions,
+    ) -> PolarsResult<Cow<'a, DataFrame>> {
+        let is_object = |column: &Column| matches!(column.dtype(), DataType::Object(..));
+
+        if !df.data_columns().iter().any(is_object) {
+            return Ok(df);
+        }
+
+        let mut columns = vec![];
+        for column in df.data_columns() {
+            if !is_object(column) {
+                columns.push(column.clone());
+                continue;
+            }
+
+            match options.object_handling {
+                ObjectHandling::Error => polars_bail!(
+                    ComputeError:
+                    "object column '{}' isn't supported by Excel. See set_object_handling()",
+                    column.name()
+                ),
+                ObjectHandling::Skip => continue,
+                ObjectHandling::DisplayString => {}
+            }
+
+            // Object series don't support the AnyValue iterator so the values
+            // are read by index.
+            let values = (0..column.len())
+                .map(|row| {
+                    let value = column.value(row)?;
+                    Ok((!value.is_null()).then(|| value.to_string()))
+                })
+                .collect::<PolarsResult<Vec<Option<String>>>>()?;
+
+            columns.push(to_column(Series::new(column.name().clone(), values)));
+        }
+
+        Ok(Cow::Owned(dataframe_from_columns(columns)?))
+    }
+
+    // Encode binary data as a standard Base64 string with padding.
+    pub(crate) fn base64_string(bytes: &[u8]) -> String {
+        const ALPHABET: &[u8; 64] =
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+        let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+        for chunk in bytes.chunks(3) {
+            let b0 = u32::from(chunk[0]);
+            let b1 = u32::from(chunk.get(1).copied().unwrap_or(0));
+            let b2 = u32::from(chunk.get(2).copied().unwrap_or(0));
+            let triple = (b0 << 16) | (b1 << 8) | b2;
+
+            for index in 0..4 {
+                if index <= chunk.len() {
+                    let sextet = (triple >> (18 - 6 * index)) & 0x3F;
+                    encoded.push(char::from(ALPHABET[sextet as usize]));
+                } else {
+                    encoded.push('=');
+                }
+            }
+        }
+
+        encoded
+    }
+
+    // Encode binary data as a lowercase hexadecimal string.
+    pub(crate) fn hex_string(bytes: &[u8]) -> String {
+        bytes.iter().fold(String::new(), |mut encoded, byte| {
+            let _ = write!(encoded, "{byte:02x}");
+            encoded
+        })
+    }
+
+    // Flatten a struct series into a series per field, named as
+    // `parent.field`. Nested structs are flattened recursively and rows where
+    // the struct is null are set to null in the fields.
+    pub(crate) fn flatten_struct(series: &Series, prefix: &str) -> PolarsResult<Vec<Series>> {
+        let is_valid = series.is_not_null();
+        let mut fields = vec![];
+
+        for field in series.struct_()?.fields_as_series() {
+            let name = format!("{prefix}.{}", field.name());
+            let nulls = Series::full_null(name.clone().into(), field.len(), field.dtype());
+            let mut field = field.zip_with(&is_valid, &nulls)?;
+            field.rename(name.clone().into());
+
+            if matches!(field.dtype(), DataType::Struct(_)) {
+                fields.extend(Self::flatten_struct(&field, &name)?);
+            } else {
+                fields.push(field);
+            }
+        }
+
+        Ok(fields)
+    }
+
+    // Convert a series value to a JSON string. Struct values are written as
+    // JSON objects and list values as JSON arrays.
+    pub(crate) fn json_value(series: &Series, row: usize) -> PolarsResult<String> {
+        let value = series.get(row)?;
+
+        let json = match value {
+            AnyValue::Null => "null".to_string(),
+            AnyValue::Boolean(value) => value.to_string(),
+            AnyValue::Int8(_)
+            | AnyValue::Int16(_)
+            | AnyValue::Int32(_)
+            | AnyValue::Int64(_)
+            | AnyValue::UInt8(_)
+            | AnyValue::UInt16(_)
+            | AnyValue::UInt32(_)
+            | AnyValue::UInt64(_) => value.to_string(),
+            AnyValue::Float32(_) | AnyValue::Float64(_) => {
+                let number = value.extract::<f64>().unwrap_or(f64::NAN);
+                if number.is_finite() {
+                    number.to_string()
+                } else {
+                    "null".to_string()
+                }
+            }
+            AnyValue::Struct(..) | AnyValue::StructOwned(_) => {
+                let mut members = vec![];
+                for field in series.struct_()?.fields_as_series() {
+                    let name = Self::json_string(field.name());
+                    let value = Self::json_value(&field, row)?;
+                    members.push(format!("{name}:{value}"));
+                }
+                format!("{{{}}}", members.join(","))
+            }
+            AnyValue::List(list) => {
+                let elements = (0..list.len())
+                    .map(|index| Self::json_value(&list, index))
+                    .collect::<PolarsResult<Vec<String>>>()?;
+                format!("[{}]", elements.join(","))
+            }
+            AnyValue::String(value) => Self::json_string(value),
+            AnyValue::StringOwned(value) => Self::json_string(&value),
+            _ => Self::json_string(&value.to_string()),
+        };
+
+        Ok(json)
+    }
+
+    // Quote and escape a string for use in JSON.
+    pub(crate) fn json_string(value: &str) -> String {
+        let mut json = String::with_capacity(value.len() + 2);
+        json.push('"');
+
+        for char in value.chars() {
+            match char {
+                '"' => json.push_str("\\\""),
+                '\\' => json.push_str("\\\\"),
+                '\n' => json.push_str("\\n"),
+                '\r' => json.push_str("\\r"),
+                '\t' => json.push_str("\\t"),
+                char if u32::from(char) < 0x20 => {
+                    let _ = write!(json, "\\u{:04x}", u32::from(char));
+                }
+                char => json.push(char),
+            }
+        }
+
+        json.push('"');
+        json
+    }
+
+    // Write a Polars decimal value as an Excel number with a number format
+    // derived from the scale, unless there is a user defined format. Values
+    // that can't be represented exactly as an f64 are written as strings.
+    pub(crate) fn write_decimal(
+        worksheet: &mut impl CellSink,
+        row: u32,
+        col: u16,
+        value: i128,
+        scale: usize,
+        dtype_format: Option<&Format>,
+    ) -> PolarsResult<()> {
+        // The largest integer that can be represented exactly by an f64.
+        const MAX_EXACT_INT: u128 = 1 << 53;
+
+        let scale_format;
+        let format = match dtype_format {
+            Some(format) => format,
+            None => {
+                scale_format = Format::new().set_num_format(Self::decimal_num_format(scale));
+                &scale_format
+            }
+        };
+
+        if value.unsigned_abs() > MAX_EXACT_INT {
+            let value = Self::decimal_to_string(value, scale);
+            worksheet.write_string_cell(row, col, &value, Some(format))?;
+            return Ok(());
+        }
+
+        // Allow conversion of values within the exact f64 range, checked above.
+        #[allow(clippy::cast_precision_loss, clippy::cast_possible_wrap)]
+        let number = value as f64 / 10_f64.powi(scale as i32);
+
+        worksheet.write_number_cell(row, col, number, Some(format))?;
+
+        Ok(())
+    }
+
+    // Get an Excel number format for a decimal scale, for example `0.00` for a
+    // scale of 2.
+    pub(crate) fn decimal_num_format(scale: usize) -> String {
+        if scale == 0 {
+            "0".to_string()
+        } else {
+            let precision = "0".repeat(scale.min(30));
+            format!("0.{precision}")
+        }
+    }
+
+    // Convert a decimal value to a string without loss of precision.
+    pub(crate) fn decimal_to_string(value: i128, scale: usize) -> String {
+        let sign = if value < 0 { "-" } else { "" };
+        let digits = value.unsigned_abs().to_string();
+
+        if scale == 0 {
+            return format!("{sign}{digits}");
+        }
+
+        let digits = format!("{digits:0>width$}", width = scale + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+
+        format!("{sign}{integer}.{fraction}")
+    }
+}
@@ -0,0 +1,505 @@
+// column_width - Column widths, including the autofit width of formatted
+// numbers.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use polars::prelude::*;
+use rust_xlsxwriter::{utility, Worksheet};
+
+use crate::dataframe_adapter::ColumnAdapter;
+use crate::xlsx_writer::{ColumnWidth, OutputColumn, WriterOptions, DEFAULT_COLUMN_WIDTH};
+use crate::PolarsXlsxWriter;
+
+impl PolarsXlsxWriter {
+    // Autofit the worksheet columns, if required, up to any user defined
+    // maximum width. Numeric and temporal columns can then be refitted to the
+    // width of their values as rendered with the column number format.
+    pub(crate) fn autofit_columns(
+        worksheet: &mut Worksheet,
+        output_columns: &[OutputColumn],
+        col_offset: u16,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        if !options.use_autofit || options.is_autofit_deferred {
+            return Ok(());
+        }
+
+        // Allow truncation of the approximate width in pixels.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let max_width = options
+            .autofit_max_width
+            .map(|width| (width * 7.0 + 5.0).clamp(0.0, f64::from(u16::MAX)) as u16);
+
+        match max_width {
+            Some(max_width) => worksheet.autofit_to_max_width(max_width),
+            None => worksheet.autofit(),
+        };
+
+        if !options.autofit_with_formats {
+            return Ok(());
+        }
+
+        // The formatted widths are also limited to any maximum width.
+        for (col_num, output_column) in output_columns.iter().enumerate() {
+            if let Some(width) = Self::formatted_column_width(output_column, options)? {
+                let width = max_width.map_or(width, |max_width| width.min(max_width));
+                worksheet.set_column_autofit_width(col_offset + col_num as u16, width)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Get the approximate width in pixels of a numeric or temporal column, and
+    // its header, with the values rendered using the column number format.
+    // Returns `None` for other columns, which use the standard autofit.
+    pub(crate) fn formatted_column_width(
+        output_column: &OutputColumn,
+        options: &WriterOptions,
+    ) -> PolarsResult<Option<u16>> {
+        let OutputColumn::Data(column) = output_column else {
+            return Ok(None);
+        };
+
+        let dtype = column.dtype();
+        if options.image_columns.contains_key(column.column_name())
+            || options.column_transforms.contains_key(column.column_name())
+            || (Self::is_large_int_type(dtype) && options.large_int_handling.is_some())
+        {
+            return Ok(None);
+        }
+
+        if !(dtype.is_primitive_numeric()
+            || dtype.is_temporal()
+            || matches!(dtype, DataType::Decimal(_, _)))
+        {
+            return Ok(None);
+        }
+
+        // The number format of a user `Format` may not be known.
+        let Some(num_format) = output_column.num_format(options) else {
+            return Ok(None);
+        };
+
+        // Get the range of values as Excel numbers. Durations are converted to
+        // days, which is how they are written. Other temporal values are only
+        // rendered as sample dates and times so their values aren't needed.
+        let series = column.series();
+        let values = match dtype {
+            DataType::Duration(time_units) => {
+                let units_per_day = match time_units {
+                    TimeUnit::Nanoseconds => 86_400_000_000_000_f64,
+                    TimeUnit::Microseconds => 86_400_000_000_f64,
+                    TimeUnit::Milliseconds => 86_400_000_f64,
+                };
+
+                let days = series.to_physical_repr().cast(&DataType::Float64)? / units_per_day;
+                vec![days.f64()?.min(), days.f64()?.max()]
+            }
+            DataType::Date | DataType::Datetime(_, _) | DataType::Time => vec![Some(0.0)],
+            _ => {
+                let numbers = series.cast(&DataType::Float64)?;
+                vec![numbers.f64()?.min(), numbers.f64()?.max()]
+            }
+        };
+
+        let mut width = values
+            .into_iter()
+            .flatten()
+            .filter(|value| value.is_finite())
+            .map(|value| utility::cell_autofit_width(&Self::formatted_value(&num_format, value)))
+            .max()
+            .unwrap_or(0);
+
+        // Nulls are written as the user defined null value, if any.
+        if let (true, Some(null_value)) = (column.null_count() > 0, &options.null_value) {
+            width = width.max(utility::cell_autofit_width(null_value));
+        }
+
+        // Include the header, with padding for any autofilter dropdown.
+        if options.table.has_header_row() {
+            let autofilter = if options.use_table || options.use_autofilter {
+                16
+            } else {
+                0
+            };
+
+            let header_width = output_column
+                .header(options)
+                .lines()
+                .map(|line| utility::cell_autofit_width(line) + autofilter)
+                .max()
+                .unwrap_or(0);
+
+            width = width.max(header_width);
+        }
+
+        Ok(Some(width))
+    }
+
+    // Get an approximation of a number as it would be displayed by Excel with
+    // a number format. Only the parts of the format that change the width are
+    // handled, such as the decimal places, thousands separators, literal text
+    // and the longest names of dates and times.
+    #[allow(clippy::too_many_lines)]
+    pub(crate) fn formatted_value(num_format: &str, value: f64) -> String {
+        if num_format.is_empty() || num_format.eq_ignore_ascii_case("General") {
+            return value.to_string();
+        }
+
+        // Use the negative section of the format, if any, for negative values.
+        let sections = Self::number_format_sections(num_format);
+        let (section, mut value) = match sections.get(1) {
+            Some(section) if value < 0.0 => (section.as_str(), value.abs()),
+            _ => (sections[0].as_str(), value),
+        };
+
+        let chars: Vec<char> = section.chars().collect();
+        let mut text = String::new();
+        let mut digits = String::new();
+        let mut exponent = None;
+        let mut is_datetime = false;
+        let mut index = 0;
+
+        while index < chars.len() {
+            let char = chars[index];
+            index += 1;
+
+            match char {
+                // Quoted and escaped literal text.
+                '"' => {
+                    while index < chars.len() && chars[index] != '"' {
+                        text.push(chars[index]);
+                        index += 1;
+                    }
+                    index += 1;
+                }
+                '\\' => {
+                    text.extend(chars.get(index));
+                    index += 1;
+                }
+
+                // Padding the width of the next character and fill characters.
+                '_' => {
+                    text.push(' ');
+                    index += 1;
+                }
+                '*' => index += 1,
+
+                // Colors, conditions, currency symbols and elapsed times.
+                '[' => {
+                    let mut token = String::new();
+                    while index < chars.len() && chars[index] != ']' {
+                        token.push(chars[index]);
+                        index += 1;
+                    }
+                    index += 1;
+
+                    // Elapsed times repeat a single unit, such as `[hh]`, and
+                    // other letters are colors such as `[Magenta]`.
+                    let first = token.chars().next().unwrap_or_default();
+                    let units = match first.to_ascii_lowercase() {
+                        _ if !token.chars().all(|c| c.eq_ignore_ascii_case(&first)) => None,
+                        'h' => Some(24.0),
+                        'm' => Some(1440.0),
+                        's' => Some(86_400.0),
+                        _ => None,
+                    };
+
+                    if let Some(currency) = token.strip_prefix('$') {
+                        text.push_str(currency.split('-').next().unwrap_or_default());
+                    } else if let Some(units) = units {
+                        let elapsed = (value.abs() * units).trunc().to_string();
+                        let _ = write!(text, "{elapsed:0>width$}", width = token.len());
+                        is_datetime = true;
+                    }
+                }
+
+                // Number placeholders and scientific exponents, such as `E+00`.
+                '0' | '#' | '?' | '.' | ',' => digits.push(char),
+                'E' | 'e' if matches!(chars.get(index), Some('+' | '-')) => {
+                    let sign = chars[index];
+                    index += 1;
+
+                    let mut width = 0;
+                    while chars
+                        .get(index)
+                        .is_some_and(|next| matches!(next, '0' | '#' | '?'))
+                    {
+                        width += 1;
+                        index += 1;
+                    }
+
+                    exponent = Some((sign, width));
+                }
+                '%' => {
+                    value *= 100.0;
+                    text.push(char);
+                }
+
+                // Date and time tokens, such as `yyyy` or `mmm`.
+                'y' | 'Y' | 'm' | 'M' | 'd' | 'D' | 'h' | 'H' | 's' | 'S' => {
+                    let mut count = 1;
+                    while chars
+                        .get(index)
+                        .is_some_and(|next| next.eq_ignore_ascii_case(&char))
+                    {
+                        count += 1;
+                        index += 1;
+                    }
+
+                    text.push_str(&Self::datetime_token_sample(char, count));
+                    is_datetime = true;
+                }
+                'A' | 'a' => {
+                    let rest: String = chars[index - 1..].iter().collect();
+                    let rest = rest.to_ascii_uppercase();
+
+                    if rest.starts_with("AM/PM") {
+                        text.push_str("AM");
+                        index += 4;
+                    } else if rest.starts_with("A/P") {
+                        text.push('A');
+                        index += 2;
+                    } else {
+                        text.push(char);
+                    }
+                }
+
+                _ => text.push(char),
+            }
+        }
+
+        // Placeholders in date and time formats are fractional seconds.
+        if is_datetime || digits.is_empty() {
+            return text + &digits;
+        }
+
+        let (integer, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+        let decimals = fraction
+            .chars()
+            .filter(|char| matches!(char, '0' | '#' | '?'))
+            .count();
+
+        // Scientific numbers have a mantissa and a padded, signed, exponent.
+        if let Some((sign, width)) = exponent {
+            let number = format!("{value:.decimals$e}");
+            let (mantissa, power) = number.split_once('e').unwrap_or((&number, "0"));
+            let power: i32 = power.parse().unwrap_or_default();
+            let sign = match (power < 0, sign) {
+                (true, _) => "-",
+                (false, '+') => "+",
+                (false, _) => "",
+            };
+
+            return format!("{text}{mantissa}E{sign}{:0>width$}", power.abs());
+        }
+
+        // Trailing commas scale the number by 1000 and other commas are
+        // thousands separators.
+        let digit_placeholders = integer.trim_end_matches(',');
+        let scaling = integer.len() - digit_placeholders.len();
+        #[allow(clippy::cast_possible_wrap)]
+        let scale = 1000_f64.powi(scaling as i32);
+        value /= scale;
+
+        let mut number = format!("{value:.decimals$}");
+        if digit_placeholders.contains(',') {
+            number = Self::group_thousands(&number);
+        }
+
+        text + &number
+    }
+
+    // Split a number format into its `;` separated sections, ignoring quoted
+    // or escaped separators.
+    pub(crate) fn number_format_sections(num_format: &str) -> Vec<String> {
+        let mut sections = vec![];
+        let mut section = String::new();
+        let mut in_quotes = false;
+        let mut chars = num_format.chars();
+
+        while let Some(char) = chars.next() {
+            match char {
+                ';' if !in_quotes => sections.push(std::mem::take(&mut section)),
+                '\\' => {
+                    section.push(char);
+                    section.extend(chars.next());
+                }
+                _ => {
+                    if char == '"' {
+                        in_quotes = !in_quotes;
+                    }
+                    section.push(char);
+                }
+            }
+        }
+        sections.push(section);
+
+        sections
+    }
+
+    // Get the widest sample text for a date or time token, such as `yyyy` or
+    // `mmmm`, with `count` repeated characters.
+    pub(crate) fn datetime_token_sample(token: char, count: usize) -> String {
+        const MONTHS: [&str; 12] = [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ];
+        const DAYS: [&str; 7] = [
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+            "Sunday",
+        ];
+
+        let widest = |names: &mut dyn Iterator<Item = String>| {
+            names
+                .max_by_key(|name| utility::cell_autofit_width(name))
+                .unwrap_or_default()
+        };
+
+        match (token.to_ascii_lowercase(), count) {
+            ('y', 3..) => "0000".to_string(),
+            ('m', 3) => widest(&mut MONTHS.iter().map(|month| month[..3].to_string())),
+            ('m', 5) => widest(&mut MONTHS.iter().map(|month| month[..1].to_string())),
+            ('m', 4..) => widest(&mut MONTHS.iter().map(ToString::to_string)),
+            ('d', 3) => widest(&mut DAYS.iter().map(|day| day[..3].to_string())),
+            ('d', 4..) => widest(&mut DAYS.iter().map(ToString::to_string)),
+            _ => "00".to_string(),
+        }
+    }
+
+    // Add thousands separators to a formatted number string.
+    pub(crate) fn group_thousands(number: &str) -> String {
+        let (sign, number) = match number.strip_prefix('-') {
+            Some(number) => ("-", number),
+            None => ("", number),
+        };
+
+        let (integer, fraction) = match number.split_once('.') {
+            Some((integer, fraction)) => (integer, format!(".{fraction}")),
+            None => (number, String::new()),
+        };
+
+        let mut grouped = String::new();
+        for (index, digit) in integer.chars().enumerate() {
+            if index > 0 && (integer.len() - index) % 3 == 0 {
+                grouped.push(',');
+            }
+            grouped.push(digit);
+        }
+
+        format!("{sign}{grouped}{fraction}")
+    }
+
+    // Store the widths of the output columns that have a user defined width,
+    // or that are excluded from autofit, and restore the stored widths of the
+    // worksheet columns after an autofit. This stops the autofit for a later
+    // dataframe in the same worksheet from changing the fixed widths.
+    pub(crate) fn set_fixed_column_widths(
+        worksheet: &mut Worksheet,
+        fixed_widths: &mut HashMap<u16, ColumnWidth>,
+        df: &DataFrame,
+        col_offset: u16,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        for (col_num, output_column) in Self::output_columns(df, options)?.iter().enumerate() {
+            let col_num = col_offset + col_num as u16;
+            let column_name = output_column.name();
+
+            let image_width = options
+                .image_columns
+                .get(column_name)
+                .and_then(|image_options| image_options.column_width);
+
+            if let Some(width) = options.column_widths.get(column_name) {
+                fixed_widths.insert(col_num, *width);
+            } else if let Some(width) = image_width {
+                fixed_widths.insert(col_num, ColumnWidth::Pixels(width));
+            } else if options.use_autofit && options.autofit_exclude.contains(column_name) {
+                fixed_widths.insert(col_num, ColumnWidth::Characters(DEFAULT_COLUMN_WIDTH));
+            }
+        }
+
+        if !options.use_autofit {
+            return Ok(());
+        }
+
+        for (col_num, width) in fixed_widths.iter() {
+            match width {
+                ColumnWidth::Characters(width) => {
+                    worksheet.set_column_width(*col_num, *width)?;
+                }
+                ColumnWidth::Pixels(width) => {
+                    worksheet.set_column_width_pixels(*col_num, *width)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Set the default width of the date and datetime columns so that the
+    // values aren't displayed as `#####`. Columns with a user defined width
+    // are skipped and the widths are set before the data is written so that
+    // autofit and the user defined column properties take precedence.
+    pub(crate) fn set_datetime_column_widths(
+        worksheet: &mut Worksheet,
+        output_columns: &[OutputColumn],
+        col_offset: u16,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        // The formatted autofit widths are based on the column number format
+        // so the default widths aren't needed.
+        if !options.auto_datetime_width || (options.use_autofit && options.autofit_with_formats) {
+            return Ok(());
+        }
+
+        for (col_num, output_column) in output_columns.iter().enumerate() {
+            let Some(column) = output_column.column() else {
+                continue;
+            };
+
+            if options.column_widths.contains_key(output_column.name())
+                || options.column_transforms.contains_key(column.column_name())
+                || column.null_count() == column.len()
+            {
+                continue;
+            }
+
+            let dtype = match output_column {
+                OutputColumn::ListElement(..) => column.dtype().inner_dtype(),
+                _ => Some(column.dtype()),
+            };
+
+            let width = match dtype {
+                Some(DataType::Datetime(_, _)) => 18,
+                Some(DataType::Date) => 10,
+                _ => continue,
+            };
+
+            worksheet.set_column_width(col_offset + col_num as u16, width)?;
+        }
+
+        Ok(())
+    }
+}
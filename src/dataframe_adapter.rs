@@ -0,0 +1,99 @@
+// dataframe_adapter - A thin layer over the Polars dataframe and column APIs.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+// The Polars APIs used to access the columns and values of a dataframe change
+// between Polars releases, for example the change from `Series` to `Column`
+// as the dataframe column type. The writer accesses dataframes via the traits
+// and functions in this module so that a Polars version update, or a patch for
+// an unreleased Polars version, should only need changes in this file.
+
+use polars::prelude::*;
+
+// Dataframe level access to the columns.
+pub(crate) trait DataFrameAdapter {
+    // Get the columns of the dataframe, in order.
+    fn data_columns(&self) -> &[Column];
+
+    // Get a contiguous series for a column, by name.
+    fn contiguous_series(&self, name: &str) -> PolarsResult<Series>;
+
+    // Get a zero copy slice of a range of rows.
+    fn slice_rows(&self, first_row: usize, num_rows: usize) -> DataFrame;
+
+    // Get a description of the column names and types, for error messages.
+    fn schema_description(&self) -> String;
+}
+
+impl DataFrameAdapter for DataFrame {
+    fn data_columns(&self) -> &[Column] {
+        self.get_columns()
+    }
+
+    fn contiguous_series(&self, name: &str) -> PolarsResult<Series> {
+        Ok(self.column(name)?.contiguous_series())
+    }
+
+    fn slice_rows(&self, first_row: usize, num_rows: usize) -> DataFrame {
+        self.slice(i64::try_from(first_row).unwrap_or(i64::MAX), num_rows)
+    }
+
+    fn schema_description(&self) -> String {
+        self.data_columns()
+            .iter()
+            .map(|column| format!("{}: {}", column.name(), column.dtype()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+// Column level access to the name, type and values.
+pub(crate) trait ColumnAdapter {
+    // Get the column name as a string.
+    fn column_name(&self) -> &str;
+
+    // Get the column data as a series.
+    fn series(&self) -> &Series;
+
+    // Get the column data as a series with a single chunk, which is required
+    // to iterate over the values as `AnyValue`s.
+    fn contiguous_series(&self) -> Series;
+
+    // Get the value of the column at a row.
+    fn value(&self, row: usize) -> PolarsResult<AnyValue<'_>>;
+}
+
+impl ColumnAdapter for Column {
+    fn column_name(&self) -> &str {
+        self.name().as_str()
+    }
+
+    fn series(&self) -> &Series {
+        self.as_materialized_series()
+    }
+
+    fn contiguous_series(&self) -> Series {
+        self.as_materialized_series().rechunk()
+    }
+
+    fn value(&self, row: usize) -> PolarsResult<AnyValue<'_>> {
+        self.get(row)
+    }
+}
+
+// Convert a series to a dataframe column.
+pub(crate) fn to_column(series: Series) -> Column {
+    series.into()
+}
+
+// Create a dataframe from a vector of columns.
+pub(crate) fn dataframe_from_columns(columns: Vec<Column>) -> PolarsResult<DataFrame> {
+    DataFrame::new(columns)
+}
+
+// Create a dataframe from a slice of series.
+pub(crate) fn dataframe_from_series(series: &[Series]) -> PolarsResult<DataFrame> {
+    dataframe_from_columns(series.iter().cloned().map(to_column).collect())
+}
@@ -0,0 +1,231 @@
+// excel_export - A declarative batch API for writing dataframe layouts.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+use std::path::Path;
+
+use polars::prelude::*;
+
+use crate::PolarsXlsxWriter;
+
+/// `ExcelExport` writes a workbook from a declarative description of the
+/// dataframes in each worksheet.
+///
+/// Each worksheet is described by a closure that adds the dataframes for that
+/// worksheet, and their cell positions, to a [`SheetLayout`]. The dataframes
+/// are written, in the order they were added, when the workbook is saved. This
+/// is an alternative to calling
+/// [`PolarsXlsxWriter::write_dataframe_to_cell()`] for each dataframe in a
+/// multi-dataframe report.
+///
+/// The dataframes are written with the options of the underlying
+/// [`PolarsXlsxWriter`], which can be set up before it is passed to
+/// [`ExcelExport::from_writer()`], with the following changes for a shared
+/// layout:
+///
+/// - The dataframe tables are written without autofilter buttons.
+/// - Autofit, if it is turned on, is applied once per worksheet after all of
+///   the dataframes in the worksheet have been written.
+///
+/// An error is returned when the workbook is saved if the dataframes in a
+/// worksheet overlap or if a worksheet name isn't valid.
+///
+/// # Examples
+///
+/// An example of writing several Polar Rust dataframes to a worksheet using
+/// the `ExcelExport` batch interface.
+///
+/// ```
+/// # // This code is available in examples/excel_export_intro.rs
+/// #
+/// use polars::prelude::*;
+///
+/// use polars_excel_writer::ExcelExport;
+///
+/// fn main() -> PolarsResult<()> {
+///     // Create some sample dataframes for the example.
+///     let df1: DataFrame = df!(
+///         "Region" => &["North", "South", "East", "West"],
+///         "Sales" => &[1000, 2300, 1900, 2500],
+///     )?;
+///
+///     let df2: DataFrame = df!(
+///         "Quarter" => &["Q1", "Q2", "Q3", "Q4"],
+///         "Costs" => &[450.25, 610.50, 520.75, 700.00],
+///     )?;
+///
+///     // Write the dataframes side by side in a worksheet and save the file.
+///     ExcelExport::new()
+///         .sheet("Summary", |sheet| {
+///             sheet.dataframe(&df1).at(0, 0);
+///             sheet.dataframe(&df2).at(0, 3);
+///         })
+///         .save("dataframe.xlsx")?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+pub struct ExcelExport {
+    xlsx_writer: PolarsXlsxWriter,
+    sheets: Vec<SheetLayout>,
+}
+
+impl Default for ExcelExport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExcelExport {
+    /// Create a new `ExcelExport` instance with the default writer options.
+    ///
+    pub fn new() -> ExcelExport {
+        ExcelExport::from_writer(PolarsXlsxWriter::new())
+    }
+
+    /// Create a new `ExcelExport` from a configured `PolarsXlsxWriter`.
+    ///
+    /// The dataframes are written with the options of the `PolarsXlsxWriter`,
+    /// such as the number formats or autofit. Any dataframes already written
+    /// with the `PolarsXlsxWriter` are kept and the layout worksheets are
+    /// added after them.
+    ///
+    /// # Parameters
+    ///
+    /// * `xlsx_writer` - A [`PolarsXlsxWriter`] instance.
+    ///
+    pub fn from_writer(xlsx_writer: PolarsXlsxWriter) -> ExcelExport {
+        ExcelExport {
+            xlsx_writer,
+            sheets: vec![],
+        }
+    }
+
+    /// Add a worksheet and describe the dataframes written to it.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The worksheet name. It must follow the Excel rules for
+    ///   worksheet names. See
+    ///   [`PolarsXlsxWriter::set_worksheet_name()`].
+    /// * `build` - A closure that adds the dataframes for the worksheet to a
+    ///   [`SheetLayout`].
+    ///
+    pub fn sheet<F>(&mut self, name: &str, build: F) -> &mut ExcelExport
+    where
+        F: FnOnce(&mut SheetLayout),
+    {
+        let mut sheet = SheetLayout::new(name);
+        build(&mut sheet);
+        self.sheets.push(sheet);
+
+        self
+    }
+
+    /// Write the worksheets and save the workbook to a file.
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - The path of the new Excel file to create.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ComputeError`] if the dataframes in a worksheet
+    /// overlap, or one that wraps a `rust_xlsxwriter`
+    /// [`XlsxError`](rust_xlsxwriter::XlsxError) error.
+    ///
+    pub fn save<P: AsRef<Path>>(&mut self, path: P) -> PolarsResult<()> {
+        self.write_sheets()?;
+        self.xlsx_writer.save(path)
+    }
+
+    /// Write the worksheets and save the workbook to a `Vec<u8>` buffer.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ComputeError`] if the dataframes in a worksheet
+    /// overlap, or one that wraps a `rust_xlsxwriter`
+    /// [`XlsxError`](rust_xlsxwriter::XlsxError) error.
+    ///
+    pub fn save_to_buffer(&mut self) -> PolarsResult<Vec<u8>> {
+        self.write_sheets()?;
+        self.xlsx_writer.save_to_buffer()
+    }
+
+    // Write the worksheet layouts that haven't been written yet.
+    fn write_sheets(&mut self) -> PolarsResult<()> {
+        for sheet in std::mem::take(&mut self.sheets) {
+            self.xlsx_writer.write_sheet_layout(&sheet)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// `SheetLayout` holds the dataframes, and their positions, for a worksheet
+/// written via [`ExcelExport::sheet()`].
+///
+#[derive(Clone)]
+pub struct SheetLayout {
+    pub(crate) name: String,
+    pub(crate) frames: Vec<FramePlacement>,
+}
+
+impl SheetLayout {
+    // Create a new empty `SheetLayout`.
+    fn new(name: &str) -> SheetLayout {
+        SheetLayout {
+            name: name.to_string(),
+            frames: vec![],
+        }
+    }
+
+    /// Add a dataframe to the worksheet.
+    ///
+    /// The dataframe is written at the cell set with [`FramePlacement::at()`].
+    /// If a cell isn't set the dataframe is written below the previous
+    /// dataframe in the worksheet, with a one row gap, or at cell `A1` for the
+    /// first dataframe.
+    ///
+    /// # Parameters
+    ///
+    /// * `df` - A Polars dataframe.
+    ///
+    pub fn dataframe(&mut self, df: &DataFrame) -> &mut FramePlacement {
+        self.frames.push(FramePlacement {
+            df: df.clone(),
+            position: None,
+        });
+
+        let index = self.frames.len() - 1;
+        &mut self.frames[index]
+    }
+}
+
+/// `FramePlacement` holds the position of a dataframe added via
+/// [`SheetLayout::dataframe()`].
+///
+#[derive(Clone)]
+pub struct FramePlacement {
+    pub(crate) df: DataFrame,
+    pub(crate) position: Option<(u32, u16)>,
+}
+
+impl FramePlacement {
+    /// Set the cell that the dataframe is written to.
+    ///
+    /// # Parameters
+    ///
+    /// * `row` - The zero indexed row number.
+    /// * `col` - The zero indexed column number.
+    ///
+    pub fn at(&mut self, row: u32, col: u16) -> &mut FramePlacement {
+        self.position = Some((row, col));
+        self
+    }
+}
@@ -0,0 +1,540 @@
+// export_config - A serde deserializable configuration for PolarsXlsxWriter.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+use std::collections::HashMap;
+
+use polars::prelude::*;
+use rust_xlsxwriter::{FormatAlign, TableFunction, TableStyle};
+use serde::Deserialize;
+
+use crate::{ColumnFormat, HeaderMode, NanHandling, PolarsXlsxWriter};
+
+/// `ExportConfig` holds the writer options in a form that can be deserialized
+/// from a configuration file.
+///
+/// `ExportConfig` implements `serde::Deserialize` so that the Excel export
+/// options can be read from a JSON, YAML or other `serde` supported file and
+/// applied via [`PolarsXlsxWriter::from_config()`]. This allows applications
+/// to change the output format without code changes. It requires the `serde`
+/// feature.
+///
+/// All the fields are optional. Fields that aren't set leave the default
+/// writer option unchanged. Unknown fields are rejected to help catch typos in
+/// user edited files. Formats are Excel number format strings such as
+/// `"yyyy-mm-dd"` or `"#,##0.00"` and table styles are the Excel style names
+/// such as `"Medium9"` or `"TableStyleMedium9"`.
+///
+/// Options that require `rust_xlsxwriter` objects, such as conditional formats
+/// or sparklines, can be set on the writer returned by
+/// [`PolarsXlsxWriter::from_config()`].
+///
+/// # Examples
+///
+/// See the example in [`PolarsXlsxWriter::from_config()`].
+///
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ExportConfig {
+    /// Turn on/off the header row. See [`PolarsXlsxWriter::set_header()`].
+    pub header: Option<bool>,
+
+    /// Set how the header is written. One of `"TableHeader"`,
+    /// `"WrittenHeaderRow"` or `"None"`. See
+    /// [`PolarsXlsxWriter::set_header_mode()`].
+    pub header_mode: Option<HeaderMode>,
+
+    /// The delimiter used to split the column names into a grouped header. See
+    /// [`PolarsXlsxWriter::set_header_split()`].
+    pub header_split: Option<String>,
+
+    /// Turn on/off autofit. See [`PolarsXlsxWriter::set_autofit()`].
+    pub autofit: Option<bool>,
+
+    /// Turn on/off autofit using the formatted values. See
+    /// [`PolarsXlsxWriter::set_autofit_with_formats()`].
+    pub autofit_with_formats: Option<bool>,
+
+    /// The maximum autofit width in character units. See
+    /// [`PolarsXlsxWriter::set_autofit_max_width()`].
+    pub autofit_max_width: Option<f64>,
+
+    /// The names of columns to exclude from autofit. See
+    /// [`PolarsXlsxWriter::set_autofit_exclude()`].
+    pub autofit_exclude: Option<Vec<String>>,
+
+    /// The number format for dates. See
+    /// [`PolarsXlsxWriter::set_date_format()`].
+    pub date_format: Option<String>,
+
+    /// The number format for times. See
+    /// [`PolarsXlsxWriter::set_time_format()`].
+    pub time_format: Option<String>,
+
+    /// The number format for datetimes. See
+    /// [`PolarsXlsxWriter::set_datetime_format()`].
+    pub datetime_format: Option<String>,
+
+    /// The number format for floats. See
+    /// [`PolarsXlsxWriter::set_float_format()`].
+    pub float_format: Option<String>,
+
+    /// The number of decimal places for floats. See
+    /// [`PolarsXlsxWriter::set_float_precision()`].
+    pub float_precision: Option<usize>,
+
+    /// The string for null values. See
+    /// [`PolarsXlsxWriter::set_null_value()`].
+    pub null_value: Option<String>,
+
+    /// The string for NaN values. See [`PolarsXlsxWriter::set_nan_value()`].
+    pub nan_value: Option<String>,
+
+    /// The string for Infinity values. See
+    /// [`PolarsXlsxWriter::set_infinity_value()`].
+    pub infinity_value: Option<String>,
+
+    /// The string for -Infinity values. See
+    /// [`PolarsXlsxWriter::set_neg_infinity_value()`].
+    pub neg_infinity_value: Option<String>,
+
+    /// The handling of NaN and Infinity values. One of `"Strings"`,
+    /// `"Blank"`, `"ErrorValue"` or `{"String": "N/A"}`. See
+    /// [`PolarsXlsxWriter::set_nan_handling()`].
+    pub nan_handling: Option<NanHandling>,
+
+    /// Turn on/off the worksheet table. See
+    /// [`PolarsXlsxWriter::set_use_table()`].
+    pub use_table: Option<bool>,
+
+    /// The table style name. See [`PolarsXlsxWriter::set_table_style()`].
+    pub table_style: Option<String>,
+
+    /// The table name. See [`PolarsXlsxWriter::set_table_name()`].
+    pub table_name: Option<String>,
+
+    /// Turn on/off the autofilter. See
+    /// [`PolarsXlsxWriter::set_autofilter()`].
+    pub autofilter: Option<bool>,
+
+    /// The top-left cell of the scrolling area, such as `"A2"`. See
+    /// [`PolarsXlsxWriter::set_freeze_panes_cell()`].
+    pub freeze_panes: Option<String>,
+
+    /// The worksheet zoom level. See [`PolarsXlsxWriter::set_zoom()`].
+    pub zoom: Option<u16>,
+
+    /// Turn on/off the screen gridlines. See
+    /// [`PolarsXlsxWriter::set_screen_gridlines()`].
+    pub screen_gridlines: Option<bool>,
+
+    /// Display the worksheet from right to left. See
+    /// [`PolarsXlsxWriter::set_right_to_left()`].
+    pub right_to_left: Option<bool>,
+
+    /// The worksheet name. See [`PolarsXlsxWriter::set_worksheet_name()`].
+    pub worksheet_name: Option<String>,
+
+    /// The header row height in pixels. See
+    /// [`PolarsXlsxWriter::set_header_row_height()`].
+    pub header_row_height: Option<u16>,
+
+    /// The data row height in pixels. See
+    /// [`PolarsXlsxWriter::set_row_height_all()`].
+    pub row_height: Option<u16>,
+
+    /// The dataframe columns to write and their order. See
+    /// [`PolarsXlsxWriter::set_columns()`].
+    pub columns: Option<Vec<String>>,
+
+    /// The settings for individual columns, keyed by the column name.
+    pub column_options: HashMap<String, ColumnConfig>,
+}
+
+/// `ColumnConfig` holds the settings for a single column in an
+/// [`ExportConfig`].
+///
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ColumnConfig {
+    /// The header text. See [`PolarsXlsxWriter::set_column_header()`].
+    pub header: Option<String>,
+
+    /// The column width in character units. See
+    /// [`PolarsXlsxWriter::set_column_width()`].
+    pub width: Option<f64>,
+
+    /// Hide the column. See [`PolarsXlsxWriter::set_column_hidden()`].
+    pub hidden: bool,
+
+    /// The horizontal alignment of the column data. One of `"left"`,
+    /// `"center"` or `"right"`. See
+    /// [`PolarsXlsxWriter::set_column_alignment()`].
+    pub alignment: Option<String>,
+
+    /// The number format of the column data. See
+    /// [`PolarsXlsxWriter::set_column_format()`].
+    pub num_format: Option<String>,
+
+    /// Make the font of the column data bold. See
+    /// [`PolarsXlsxWriter::set_column_format()`].
+    pub bold: bool,
+
+    /// The font color of the column data as an HTML style `"#RRGGBB"` string.
+    /// See [`PolarsXlsxWriter::set_column_format()`].
+    pub font_color: Option<String>,
+
+    /// The background color of the column data as an HTML style `"#RRGGBB"`
+    /// string. See [`PolarsXlsxWriter::set_column_format()`].
+    pub background_color: Option<String>,
+
+    /// Write the column as hyperlinks. See
+    /// [`PolarsXlsxWriter::set_column_as_hyperlinks()`].
+    pub hyperlinks: bool,
+
+    /// Merge the repeated values in the column. See
+    /// [`PolarsXlsxWriter::set_merge_repeated()`].
+    pub merge_repeated: bool,
+
+    /// The table total row function. One of `"average"`, `"count"`,
+    /// `"count_numbers"`, `"max"`, `"min"`, `"sum"`, `"std_dev"` or `"var"`.
+    /// See [`PolarsXlsxWriter::set_column_total()`].
+    pub total: Option<String>,
+
+    /// The table total row label. See
+    /// [`PolarsXlsxWriter::set_total_row_label()`].
+    pub total_label: Option<String>,
+
+    /// The table total row formula, such as `"SUM([Sales])/SUM([Units])"`.
+    /// See [`PolarsXlsxWriter::set_total_row_formula()`].
+    pub total_formula: Option<String>,
+}
+
+impl PolarsXlsxWriter {
+    /// Create a new `PolarsXlsxWriter` from an [`ExportConfig`].
+    ///
+    /// Create a writer with the options from an `ExportConfig`, which is
+    /// typically deserialized from a user editable JSON or YAML file. Options
+    /// that aren't set in the config keep their default values. It requires
+    /// the `serde` feature.
+    ///
+    /// # Parameters
+    ///
+    /// * `config` - An [`ExportConfig`] struct.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ComputeError`] error is raised if the config contains
+    /// an unknown table style or total function, or an invalid freeze panes
+    /// cell, table name or worksheet name.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting the writer options from a JSON config.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_from_config.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::{ExportConfig, PolarsXlsxWriter};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[1000.0, 2300.0, 1900.0, 2500.0],
+    ///     )?;
+    ///
+    ///     // A config that would typically be read from a file.
+    ///     let json = r##"{
+    ///         "autofit": true,
+    ///         "float_format": "#,##0.00",
+    ///         "table_style": "Light9",
+    ///         "freeze_panes": "A2",
+    ///         "column_options": {
+    ///             "Sales": {"header": "Total Sales", "total": "sum"}
+    ///         }
+    ///     }"##;
+    ///
+    ///     let config: ExportConfig = serde_json::from_str(json).unwrap();
+    ///
+    ///     // Create a writer with the options from the config.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::from_config(&config)?;
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    #[allow(clippy::too_many_lines)]
+    pub fn from_config(config: &ExportConfig) -> PolarsResult<PolarsXlsxWriter> {
+        let mut xlsx_writer = PolarsXlsxWriter::new();
+
+        if let Some(has_header) = config.header {
+            xlsx_writer.set_header(has_header);
+        }
+        if let Some(use_table) = config.use_table {
+            xlsx_writer.set_use_table(use_table);
+        }
+        if let Some(header_mode) = config.header_mode {
+            xlsx_writer.set_header_mode(header_mode);
+        }
+        if let Some(delimiter) = &config.header_split {
+            xlsx_writer.set_header_split(delimiter);
+        }
+        if let Some(autofit) = config.autofit {
+            xlsx_writer.set_autofit(autofit);
+        }
+        if let Some(enable) = config.autofit_with_formats {
+            xlsx_writer.set_autofit_with_formats(enable);
+        }
+        if let Some(width) = config.autofit_max_width {
+            xlsx_writer.set_autofit_max_width(width);
+        }
+        if let Some(columns) = &config.autofit_exclude {
+            let columns: Vec<&str> = columns.iter().map(String::as_str).collect();
+            xlsx_writer.set_autofit_exclude(&columns);
+        }
+        if let Some(format) = &config.date_format {
+            xlsx_writer.set_date_format(format.as_str());
+        }
+        if let Some(format) = &config.time_format {
+            xlsx_writer.set_time_format(format.as_str());
+        }
+        if let Some(format) = &config.datetime_format {
+            xlsx_writer.set_datetime_format(format.as_str());
+        }
+        if let Some(precision) = config.float_precision {
+            xlsx_writer.set_float_precision(precision);
+        }
+        if let Some(format) = &config.float_format {
+            xlsx_writer.set_float_format(format.as_str());
+        }
+        if let Some(value) = &config.null_value {
+            xlsx_writer.set_null_value(value);
+        }
+        if let Some(value) = &config.nan_value {
+            xlsx_writer.set_nan_value(value);
+        }
+        if let Some(value) = &config.infinity_value {
+            xlsx_writer.set_infinity_value(value);
+        }
+        if let Some(value) = &config.neg_infinity_value {
+            xlsx_writer.set_neg_infinity_value(value);
+        }
+        if let Some(handling) = &config.nan_handling {
+            xlsx_writer.set_nan_handling(handling.clone());
+        }
+        if let Some(style) = &config.table_style {
+            xlsx_writer.set_table_style(Self::config_table_style(style)?);
+        }
+        if let Some(name) = &config.table_name {
+            xlsx_writer.set_table_name(name)?;
+        }
+        if let Some(enable) = config.autofilter {
+            xlsx_writer.set_autofilter(enable);
+        }
+        if let Some(cell) = &config.freeze_panes {
+            xlsx_writer.set_freeze_panes_cell(cell)?;
+        }
+        if let Some(zoom) = config.zoom {
+            xlsx_writer.set_zoom(zoom);
+        }
+        if let Some(enable) = config.screen_gridlines {
+            xlsx_writer.set_screen_gridlines(enable);
+        }
+        if let Some(enable) = config.right_to_left {
+            xlsx_writer.set_right_to_left(enable);
+        }
+        if let Some(name) = &config.worksheet_name {
+            xlsx_writer.set_worksheet_name(name)?;
+        }
+        if let Some(height) = config.header_row_height {
+            xlsx_writer.set_header_row_height(height);
+        }
+        if let Some(height) = config.row_height {
+            xlsx_writer.set_row_height_all(height);
+        }
+        if let Some(columns) = &config.columns {
+            let columns: Vec<&str> = columns.iter().map(String::as_str).collect();
+            xlsx_writer.set_columns(&columns);
+        }
+
+        for (column_name, column_config) in &config.column_options {
+            if let Some(header) = &column_config.header {
+                xlsx_writer.set_column_header(column_name, header);
+            }
+            if let Some(width) = column_config.width {
+                xlsx_writer.set_column_width(column_name, width);
+            }
+            if column_config.hidden {
+                xlsx_writer.set_column_hidden(column_name);
+            }
+            if let Some(alignment) = &column_config.alignment {
+                xlsx_writer.set_column_alignment(column_name, Self::config_alignment(alignment)?);
+            }
+            if let Some(format) = Self::config_column_format(column_config) {
+                xlsx_writer.set_column_format(column_name, &format);
+            }
+            if column_config.hyperlinks {
+                xlsx_writer.set_column_as_hyperlinks(column_name);
+            }
+            if column_config.merge_repeated {
+                xlsx_writer.set_merge_repeated(column_name);
+            }
+            if let Some(function) = &column_config.total {
+                xlsx_writer.set_column_total(column_name, Self::config_total_function(function)?);
+            }
+            if let Some(label) = &column_config.total_label {
+                xlsx_writer.set_total_row_label(column_name, label);
+            }
+            if let Some(formula) = &column_config.total_formula {
+                xlsx_writer.set_total_row_formula(column_name, formula.as_str());
+            }
+        }
+
+        Ok(xlsx_writer)
+    }
+
+    // Get the table style for a config style name, with or without the
+    // "TableStyle" prefix.
+    fn config_table_style(name: &str) -> PolarsResult<TableStyle> {
+        let name = name.strip_prefix("TableStyle").unwrap_or(name);
+
+        let style = match name {
+            "None" => Some(TableStyle::None),
+            _ => TABLE_STYLES
+                .iter()
+                .find(|style| style.to_string() == format!("TableStyle{name}"))
+                .copied(),
+        };
+
+        style.ok_or_else(|| polars_err!(ComputeError: "unknown table style in config: '{}'", name))
+    }
+
+    // Get the column format for the format properties of a column config, if
+    // any are set.
+    fn config_column_format(column_config: &ColumnConfig) -> Option<ColumnFormat> {
+        let mut format = ColumnFormat::new();
+
+        if let Some(num_format) = &column_config.num_format {
+            format = format.set_num_format(num_format);
+        }
+        if column_config.bold {
+            format = format.set_bold();
+        }
+        if let Some(color) = &column_config.font_color {
+            format = format.set_font_color(color.as_str());
+        }
+        if let Some(color) = &column_config.background_color {
+            format = format.set_background_color(color.as_str());
+        }
+
+        (format != ColumnFormat::new()).then_some(format)
+    }
+
+    // Get the horizontal alignment for a config alignment name.
+    fn config_alignment(name: &str) -> PolarsResult<FormatAlign> {
+        let align = match name {
+            "left" => FormatAlign::Left,
+            "center" => FormatAlign::Center,
+            "right" => FormatAlign::Right,
+            _ => polars_bail!(ComputeError: "unknown alignment in config: '{}'", name),
+        };
+
+        Ok(align)
+    }
+
+    // Get the table total function for a config function name.
+    fn config_total_function(name: &str) -> PolarsResult<TableFunction> {
+        let function = match name {
+            "none" => TableFunction::None,
+            "average" => TableFunction::Average,
+            "count" => TableFunction::Count,
+            "count_numbers" => TableFunction::CountNumbers,
+            "max" => TableFunction::Max,
+            "min" => TableFunction::Min,
+            "sum" => TableFunction::Sum,
+            "std_dev" => TableFunction::StdDev,
+            "var" => TableFunction::Var,
+            _ => polars_bail!(ComputeError: "unknown total function in config: '{}'", name),
+        };
+
+        Ok(function)
+    }
+}
+
+// The Excel table styles that can be set in a config, apart from `None`.
+const TABLE_STYLES: [TableStyle; 60] = [
+    TableStyle::Light1,
+    TableStyle::Light2,
+    TableStyle::Light3,
+    TableStyle::Light4,
+    TableStyle::Light5,
+    TableStyle::Light6,
+    TableStyle::Light7,
+    TableStyle::Light8,
+    TableStyle::Light9,
+    TableStyle::Light10,
+    TableStyle::Light11,
+    TableStyle::Light12,
+    TableStyle::Light13,
+    TableStyle::Light14,
+    TableStyle::Light15,
+    TableStyle::Light16,
+    TableStyle::Light17,
+    TableStyle::Light18,
+    TableStyle::Light19,
+    TableStyle::Light20,
+    TableStyle::Light21,
+    TableStyle::Medium1,
+    TableStyle::Medium2,
+    TableStyle::Medium3,
+    TableStyle::Medium4,
+    TableStyle::Medium5,
+    TableStyle::Medium6,
+    TableStyle::Medium7,
+    TableStyle::Medium8,
+    TableStyle::Medium9,
+    TableStyle::Medium10,
+    TableStyle::Medium11,
+    TableStyle::Medium12,
+    TableStyle::Medium13,
+    TableStyle::Medium14,
+    TableStyle::Medium15,
+    TableStyle::Medium16,
+    TableStyle::Medium17,
+    TableStyle::Medium18,
+    TableStyle::Medium19,
+    TableStyle::Medium20,
+    TableStyle::Medium21,
+    TableStyle::Medium22,
+    TableStyle::Medium23,
+    TableStyle::Medium24,
+    TableStyle::Medium25,
+    TableStyle::Medium26,
+    TableStyle::Medium27,
+    TableStyle::Medium28,
+    TableStyle::Dark1,
+    TableStyle::Dark2,
+    TableStyle::Dark3,
+    TableStyle::Dark4,
+    TableStyle::Dark5,
+    TableStyle::Dark6,
+    TableStyle::Dark7,
+    TableStyle::Dark8,
+    TableStyle::Dark9,
+    TableStyle::Dark10,
+    TableStyle::Dark11,
+];
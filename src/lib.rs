@@ -82,6 +82,22 @@
 //! <img src="https://rustxlsxwriter.github.io/images/write_excel_combined.png">
 //!
 //!
+//! ## Command line application
+//!
+//! The crate includes an optional `polars-to-excel` application that converts
+//! a Parquet, CSV or IPC file to an Excel file without writing any Rust code.
+//! It requires the `cli` feature:
+//!
+//! ```bash
+//! cargo install polars_excel_writer --features cli
+//!
+//! polars-to-excel --autofit --table-style Medium2 \
+//!     --column-format 'Sales=#,##0.00' sales.parquet sales.xlsx
+//! ```
+//!
+//! Run `polars-to-excel --help` to see the other options.
+//!
+//!
 //! ## Performance
 //!
 //! The table below shows the performance of writing a dataframe using Python
@@ -130,10 +146,64 @@ pub mod xlsx_writer;
 /// `SerWriter` trait.
 pub mod write;
 
+/// A module that exports the `SheetSpec` struct which holds a dataframe and the
+/// options used to write it to its own worksheet via
+/// [`PolarsXlsxWriter::add_sheet()`].
+pub mod sheet_spec;
+
+/// A module that exports the `ExcelExport` struct which writes a workbook from
+/// a declarative layout of the dataframes in each worksheet.
+pub mod excel_export;
+
+// An internal module that wraps the Polars dataframe APIs used by the writer.
+mod dataframe_adapter;
+
+// An internal module that converts Polars columns and values that Excel
+// doesn't support directly, such as structs, binary data and decimals.
+mod column_conversion;
+
+// An internal module that writes the column values to the worksheet cells or
+// to in-memory column buffers that can be filled in parallel.
+mod column_buffer;
+
+// An internal module that sets the worksheet column widths, including the
+// autofit width of numbers as rendered with their number format.
+mod column_width;
+
+// An internal module that adds Excel outline groups for the rows of a key
+// column to the worksheets when the workbook is saved.
+mod row_grouping;
+
+// An internal module that adds the worksheet table, or autofilter, for the
+// written dataframe along with any row total columns.
+mod worksheet_table;
+
+/// A module that exports the `TemporalToExcel` trait which converts Polars
+/// temporal values to the dates and times written to Excel, and the
+/// `DefaultTemporalConverter` that implements it.
+pub mod temporal;
+
+/// A module that exports the `ExportConfig` struct which holds the writer
+/// options in a form that can be deserialized from a configuration file. It
+/// requires the `serde` feature.
+#[cfg(feature = "serde")]
+pub mod export_config;
+
+#[doc(hidden)]
+pub use excel_export::*;
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use export_config::*;
+#[doc(hidden)]
+pub use sheet_spec::*;
+#[doc(hidden)]
+pub use temporal::*;
 #[doc(hidden)]
 pub use write::*;
 #[doc(hidden)]
 pub use xlsx_writer::*;
 
+pub use ExcelExport;
 pub use ExcelWriter;
 pub use PolarsXlsxWriter;
+pub use SheetSpec;
@@ -0,0 +1,419 @@
+// row_grouping - Excel outline groups for the rows of a key column.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::{Cursor, Read, Write};
+
+use ::zip::result::ZipError;
+use ::zip::write::SimpleFileOptions;
+use ::zip::{CompressionMethod, DateTime, ZipArchive, ZipWriter};
+use polars::prelude::*;
+
+use crate::dataframe_adapter::DataFrameAdapter;
+use crate::xlsx_writer::WriterOptions;
+use crate::PolarsXlsxWriter;
+
+// The outline groups for the rows of a dataframe written to a worksheet. The
+// groups are stored as zero indexed (first, last) worksheet rows of the detail
+// rows below the first row of each key. The workbook index is 0 for the main
+// workbook or the index of a workbook added by the `SplitFiles` overflow
+// policy.
+#[derive(Clone)]
+pub(crate) struct RowGroups {
+    pub(crate) workbook_index: usize,
+    pub(crate) sheet_name: String,
+    pub(crate) groups: Vec<(u32, u32)>,
+    pub(crate) collapsed: bool,
+}
+
+// The outline properties of a worksheet row.
+#[derive(Clone, Copy, Default)]
+struct RowOutline {
+    level: bool,
+    hidden: bool,
+    collapsed: bool,
+}
+
+impl PolarsXlsxWriter {
+    // Get the row groups for the runs of repeated values in the grouping
+    // column, if required. The first row of each run is the summary row of the
+    // group and the rest of the run are the detail rows. Null values aren't
+    // grouped. The worksheet is set when the row groups are stored.
+    pub(crate) fn row_groups(
+        df: &DataFrame,
+        row_offset: u32,
+        options: &WriterOptions,
+    ) -> PolarsResult<Option<RowGroups>> {
+        let Some((column_name, collapsed)) = &options.row_grouping else {
+            return Ok(None);
+        };
+
+        let Some(column) = df
+            .data_columns()
+            .iter()
+            .find(|column| column.name().as_str() == column_name)
+        else {
+            polars_bail!(ColumnNotFound: "{}", column_name);
+        };
+
+        let first_row = row_offset + u32::from(options.table.has_header_row());
+        let mut groups = vec![];
+
+        let mut start = 0;
+        for end in 1..=column.len() {
+            let value = column.get(start)?;
+
+            if end < column.len() && !value.is_null() && column.get(end)? == value {
+                continue;
+            }
+
+            if end - start > 1 {
+                let first = first_row + start as u32 + 1;
+                let last = first_row + end as u32 - 1;
+                groups.push((first, last));
+            }
+
+            start = end;
+        }
+
+        Ok(Some(RowGroups {
+            workbook_index: 0,
+            sheet_name: String::new(),
+            groups,
+            collapsed: *collapsed,
+        }))
+    }
+
+    // Store the row groups of a dataframe written to a worksheet. They are
+    // added to the worksheet xml when the workbook is saved.
+    pub(crate) fn add_row_groups(
+        &mut self,
+        workbook_index: usize,
+        sheet_name: &str,
+        row_groups: Option<RowGroups>,
+    ) {
+        if let Some(row_groups) = row_groups {
+            self.row_groups.push(RowGroups {
+                workbook_index,
+                sheet_name: sheet_name.to_string(),
+                ..row_groups
+            });
+        }
+    }
+
+    // Check if there are row groups for the main workbook, or an overflow
+    // workbook, which require the xlsx file to be saved via a buffer.
+    pub(crate) fn has_row_groups(&self, workbook_index: usize) -> bool {
+        self.row_groups
+            .iter()
+            .any(|row_groups| row_groups.workbook_index == workbook_index)
+    }
+
+    // Save the main workbook, or an overflow workbook, to a buffer with the
+    // outline levels of any row groups added to the worksheets.
+    pub(crate) fn workbook_to_buffer(&mut self, workbook_index: usize) -> PolarsResult<Vec<u8>> {
+        let workbook = if workbook_index == 0 {
+            &mut self.workbook
+        } else {
+            &mut self.overflow_workbooks[workbook_index - 1]
+        };
+
+        let buffer = workbook.save_to_buffer()?;
+
+        Self::add_row_outlines(
+            buffer,
+            &self.row_groups,
+            workbook_index,
+            self.use_zip_large_file,
+        )
+    }
+
+    // Save a workbook to a buffer with the outline levels of any row groups.
+    // This is used for workbooks that have been moved out of the writer.
+    #[cfg(feature = "async")]
+    pub(crate) fn save_workbook_to_buffer(
+        workbook: &mut rust_xlsxwriter::Workbook,
+        row_groups: &[RowGroups],
+        workbook_index: usize,
+        use_zip_large_file: bool,
+    ) -> PolarsResult<Vec<u8>> {
+        let buffer = workbook.save_to_buffer()?;
+
+        Self::add_row_outlines(buffer, row_groups, workbook_index, use_zip_large_file)
+    }
+
+    // Add the outline levels of the row groups to the worksheets of a saved
+    // xlsx file. `rust_xlsxwriter` doesn't support row outlines so the
+    // worksheet xml files are updated and the other files in the zip
+    // container are copied unchanged.
+    fn add_row_outlines(
+        buffer: Vec<u8>,
+        row_groups: &[RowGroups],
+        workbook_index: usize,
+        use_zip_large_file: bool,
+    ) -> PolarsResult<Vec<u8>> {
+        let row_groups: Vec<&RowGroups> = row_groups
+            .iter()
+            .filter(|row_groups| row_groups.workbook_index == workbook_index)
+            .collect();
+
+        if row_groups.is_empty() {
+            return Ok(buffer);
+        }
+
+        let zip_error = |error: ZipError| polars_err!(ComputeError: "{}", error);
+        let mut archive = ZipArchive::new(Cursor::new(buffer)).map_err(zip_error)?;
+
+        // Map the worksheet names to the xml files in the zip container.
+        let workbook_xml = Self::read_zip_file(&mut archive, "xl/workbook.xml")?;
+        let relationships_xml = Self::read_zip_file(&mut archive, "xl/_rels/workbook.xml.rels")?;
+        let sheet_paths = Self::worksheet_paths(&workbook_xml, &relationships_xml);
+
+        // Get the outline properties of the rows in each worksheet.
+        let mut outlines: HashMap<String, BTreeMap<u32, RowOutline>> = HashMap::new();
+        for row_groups in row_groups {
+            let Some(path) = sheet_paths.get(&row_groups.sheet_name) else {
+                continue;
+            };
+
+            let rows = outlines.entry(path.clone()).or_default();
+            for &(first, last) in &row_groups.groups {
+                if row_groups.collapsed {
+                    rows.entry(first - 1).or_default().collapsed = true;
+                }
+
+                for row in first..=last {
+                    let outline = rows.entry(row).or_default();
+                    outline.level = true;
+                    outline.hidden = row_groups.collapsed;
+                }
+            }
+        }
+
+        // Use the same zip options as `rust_xlsxwriter` for the updated files.
+        let zip_options = SimpleFileOptions::default()
+            .compression_method(CompressionMethod::Deflated)
+            .unix_permissions(0o600)
+            .last_modified_time(DateTime::default())
+            .large_file(use_zip_large_file);
+
+        let mut zip = ZipWriter::new(Cursor::new(vec![]));
+        for index in 0..archive.len() {
+            let name = archive
+                .by_index_raw(index)
+                .map_err(zip_error)?
+                .name()
+                .to_string();
+
+            match outlines.get(&name) {
+                Some(rows) if !rows.is_empty() => {
+                    let xml = Self::read_zip_file(&mut archive, &name)?;
+                    let xml = Self::outline_worksheet_xml(&xml, rows);
+
+                    zip.start_file(name, zip_options).map_err(zip_error)?;
+                    zip.write_all(xml.as_bytes())?;
+                }
+                _ => {
+                    let file = archive.by_index_raw(index).map_err(zip_error)?;
+                    zip.raw_copy_file(file).map_err(zip_error)?;
+                }
+            }
+        }
+
+        Ok(zip.finish().map_err(zip_error)?.into_inner())
+    }
+
+    // Read a file from the xlsx zip container as a string.
+    fn read_zip_file(
+        archive: &mut ZipArchive<Cursor<Vec<u8>>>,
+        name: &str,
+    ) -> PolarsResult<String> {
+        let mut file = archive
+            .by_name(name)
+            .map_err(|error| polars_err!(ComputeError: "{}", error))?;
+
+        let mut xml = String::new();
+        file.read_to_string(&mut xml)?;
+
+        Ok(xml)
+    }
+
+    // Map the worksheet names in the workbook xml file to the paths of the
+    // worksheet xml files in the zip container, via the relationship ids.
+    fn worksheet_paths(workbook_xml: &str, relationships_xml: &str) -> HashMap<String, String> {
+        let targets: HashMap<&str, &str> = Self::xml_tags(relationships_xml, "Relationship")
+            .filter_map(|tag| {
+                Some((
+                    Self::xml_attribute(tag, "Id")?,
+                    Self::xml_attribute(tag, "Target")?,
+                ))
+            })
+            .collect();
+
+        Self::xml_tags(workbook_xml, "sheet")
+            .filter_map(|tag| {
+                let name = Self::xml_attribute(tag, "name")?;
+                let target = targets.get(Self::xml_attribute(tag, "r:id")?)?;
+
+                Some((Self::unescape_xml(name), format!("xl/{target}")))
+            })
+            .collect()
+    }
+
+    // Add the outline properties to the rows of a worksheet xml file, along
+    // with the outline level of the worksheet and the position of the outline
+    // summary rows, which are above the detail rows.
+    fn outline_worksheet_xml(xml: &str, rows: &BTreeMap<u32, RowOutline>) -> String {
+        let mut xml = xml.to_string();
+
+        // Add the <outlinePr> element to the <sheetPr> element, or add a new
+        // <sheetPr> element before the <dimension> element.
+        let outline_pr = r#"<outlinePr summaryBelow="0"/>"#;
+        if let Some(start) = xml.find("<sheetPr") {
+            let end = start + xml[start..].find('>').unwrap_or_default();
+
+            if xml[..end].ends_with('/') {
+                let attributes = xml[start + "<sheetPr".len()..end - 1].to_string();
+                xml.replace_range(
+                    start..=end,
+                    &format!("<sheetPr{attributes}>{outline_pr}</sheetPr>"),
+                );
+            } else {
+                let sheet_pr_end = start + xml[start..].find("</sheetPr>").unwrap_or_default();
+                let position = match xml[start..sheet_pr_end].find("<pageSetUpPr") {
+                    Some(position) => start + position,
+                    None => sheet_pr_end,
+                };
+                xml.insert_str(position, outline_pr);
+            }
+        } else if let Some(position) = xml.find("<dimension") {
+            xml.insert_str(position, &format!("<sheetPr>{outline_pr}</sheetPr>"));
+        }
+
+        // Add the outline level of the rows to the <sheetFormatPr> element.
+        if let Some(start) = xml.find("<sheetFormatPr") {
+            let end = start + xml[start..].find("/>").unwrap_or_default();
+            let position = match xml[start..end].find(" x14ac:dyDescent") {
+                Some(position) => start + position,
+                None => end,
+            };
+            xml.insert_str(position, r#" outlineLevelRow="1""#);
+        }
+
+        // Update the existing <row> elements and add any missing rows.
+        let (Some(data_start), Some(data_end)) =
+            (xml.find("<sheetData>"), xml.find("</sheetData>"))
+        else {
+            return xml;
+        };
+        let data_start = data_start + "<sheetData>".len();
+
+        let sheet_data = &xml[data_start..data_end];
+        let mut output = String::with_capacity(sheet_data.len() + rows.len() * 40);
+        let mut rows = rows.iter().peekable();
+        let mut position = 0;
+
+        while let Some(offset) = sheet_data[position..].find("<row ") {
+            let row_start = position + offset;
+            let tag_end = row_start + sheet_data[row_start..].find('>').unwrap_or_default();
+            let row_num = Self::xml_attribute(&sheet_data[row_start..tag_end], "r")
+                .and_then(|row| row.parse::<u32>().ok())
+                .unwrap_or_default()
+                .saturating_sub(1);
+
+            output.push_str(&sheet_data[position..row_start]);
+
+            // Add any outline rows that don't have a <row> element.
+            while let Some((&row, outline)) = rows.next_if(|(&row, _)| row < row_num) {
+                output.push_str(&Self::outline_row_tag(
+                    &format!(r#"<row r="{}""#, row + 1),
+                    *outline,
+                ));
+                output.push_str("/>");
+            }
+
+            let (tag, tag_close) = match sheet_data[..tag_end].strip_suffix('/') {
+                Some(tag) => (&tag[row_start..], "/>"),
+                None => (&sheet_data[row_start..tag_end], ">"),
+            };
+
+            match rows.next_if(|(&row, _)| row == row_num) {
+                Some((_, outline)) => output.push_str(&Self::outline_row_tag(tag, *outline)),
+                None => output.push_str(tag),
+            }
+            output.push_str(tag_close);
+
+            position = tag_end + 1;
+        }
+
+        output.push_str(&sheet_data[position..]);
+        for (&row, outline) in rows {
+            output.push_str(&Self::outline_row_tag(
+                &format!(r#"<row r="{}""#, row + 1),
+                *outline,
+            ));
+            output.push_str("/>");
+        }
+
+        xml.replace_range(data_start..data_end, &output);
+        xml
+    }
+
+    // Add the outline attributes to the start of a <row> tag, in the same
+    // order as Excel.
+    fn outline_row_tag(tag: &str, outline: RowOutline) -> String {
+        let mut tag = tag.to_string();
+
+        if outline.hidden && !tag.contains(" hidden=") {
+            match tag.find(" customHeight=") {
+                Some(position) => tag.insert_str(position, r#" hidden="1""#),
+                None => tag.push_str(r#" hidden="1""#),
+            }
+        }
+
+        if outline.level {
+            tag.push_str(r#" outlineLevel="1""#);
+        }
+
+        if outline.collapsed {
+            tag.push_str(r#" collapsed="1""#);
+        }
+
+        tag
+    }
+
+    // Iterate over the start tags of the elements with a given name.
+    fn xml_tags<'a>(xml: &'a str, name: &'a str) -> impl Iterator<Item = &'a str> {
+        let start_tag = format!("<{name} ");
+
+        xml.match_indices(&start_tag)
+            .map(|(start, _)| start)
+            .collect::<Vec<usize>>()
+            .into_iter()
+            .map(move |start| {
+                let end = start + xml[start..].find('>').unwrap_or(xml.len() - start);
+                &xml[start..end]
+            })
+    }
+
+    // Get the value of an attribute in an xml start tag.
+    fn xml_attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+        let pattern = format!(" {name}=\"");
+        let start = tag.find(&pattern)? + pattern.len();
+        let end = start + tag[start..].find('"')?;
+
+        Some(&tag[start..end])
+    }
+
+    // Unescape the xml entities used by `rust_xlsxwriter` in attribute values.
+    fn unescape_xml(value: &str) -> String {
+        value
+            .replace("&quot;", "\"")
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&amp;", "&")
+    }
+}
@@ -0,0 +1,224 @@
+// sheet_spec - A set of options for a dataframe written to its own worksheet.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+use polars::prelude::*;
+use rust_xlsxwriter::{Table, TableStyle};
+
+use crate::xlsx_writer::{CellFormat, HeaderMode, WriterOptions};
+
+/// `SheetSpec` holds a dataframe and the options used to write it to its own
+/// worksheet.
+///
+/// A `SheetSpec` is created via [`PolarsXlsxWriter::add_sheet()`] and it starts
+/// with a copy of the options of the parent `PolarsXlsxWriter`. The `with_*()`
+/// methods can then be used to change the options for that worksheet only.
+/// This allows several dataframes with different options to be written to the
+/// same workbook using a single `PolarsXlsxWriter`.
+///
+/// The worksheets are added to the workbook and written, in the order they
+/// were added, when the workbook is saved.
+///
+/// [`PolarsXlsxWriter::add_sheet()`]: crate::PolarsXlsxWriter::add_sheet
+///
+#[derive(Clone)]
+pub struct SheetSpec {
+    pub(crate) name: String,
+    pub(crate) df: DataFrame,
+    pub(crate) options: WriterOptions,
+}
+
+impl SheetSpec {
+    // Create a new `SheetSpec` from a copy of the writer options.
+    pub(crate) fn new(name: &str, df: &DataFrame, options: &WriterOptions) -> SheetSpec {
+        SheetSpec {
+            name: name.to_string(),
+            df: df.clone(),
+            options: options.clone(),
+        }
+    }
+
+    /// Turn on/off the dataframe header row in the worksheet table.
+    ///
+    /// # Parameters
+    ///
+    /// * `has_header` - Export dataframe with/without header.
+    ///
+    pub fn with_header(&mut self, has_header: bool) -> &mut SheetSpec {
+        let table = self.options.table.clone().set_header_row(has_header);
+        self.options.table = table;
+        self
+    }
+
+    /// Set how the dataframe header is written.
+    ///
+    /// # Parameters
+    ///
+    /// * `header_mode` - A [`HeaderMode`] enum value.
+    ///
+    pub fn with_header_mode(&mut self, header_mode: HeaderMode) -> &mut SheetSpec {
+        self.options.set_header_mode(header_mode);
+        self
+    }
+
+    /// Turn on/off autofit for the worksheet columns.
+    ///
+    /// # Parameters
+    ///
+    /// * `autofit` - Turn autofit on/off. It is off by default.
+    ///
+    pub fn with_autofit(&mut self, autofit: bool) -> &mut SheetSpec {
+        self.options.use_autofit = autofit;
+        self
+    }
+
+    /// Set the Excel number format for time values.
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - A `rust_xlsxwriter` [`Format`](rust_xlsxwriter::Format)
+    ///   or an Excel number format string that can be converted to a
+    ///   [`CellFormat`].
+    ///
+    pub fn with_time_format(&mut self, format: impl Into<CellFormat>) -> &mut SheetSpec {
+        self.options.time_format = format.into();
+        self
+    }
+
+    /// Set the Excel number format for date values.
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - A `rust_xlsxwriter` [`Format`](rust_xlsxwriter::Format)
+    ///   or an Excel number format string that can be converted to a
+    ///   [`CellFormat`].
+    ///
+    pub fn with_date_format(&mut self, format: impl Into<CellFormat>) -> &mut SheetSpec {
+        self.options.date_format = format.into();
+        self
+    }
+
+    /// Set the Excel number format for datetime values.
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - A `rust_xlsxwriter` [`Format`](rust_xlsxwriter::Format)
+    ///   or an Excel number format string that can be converted to a
+    ///   [`CellFormat`].
+    ///
+    pub fn with_datetime_format(&mut self, format: impl Into<CellFormat>) -> &mut SheetSpec {
+        self.options.datetime_format = format.into();
+        self
+    }
+
+    /// Set the Excel number format for floats.
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - A `rust_xlsxwriter` [`Format`](rust_xlsxwriter::Format)
+    ///   or an Excel number format string that can be converted to a
+    ///   [`CellFormat`].
+    ///
+    pub fn with_float_format(&mut self, format: impl Into<CellFormat>) -> &mut SheetSpec {
+        self.options.float_format = format.into();
+        self
+    }
+
+    /// Set the Excel number precision for floats.
+    ///
+    /// # Parameters
+    ///
+    /// * `precision` - The floating point precision in the Excel range 1-30.
+    ///
+    pub fn with_float_precision(&mut self, precision: usize) -> &mut SheetSpec {
+        if (1..=30).contains(&precision) {
+            let precision = "0".repeat(precision);
+            self.options.float_format = format!("0.{precision}").into();
+        }
+        self
+    }
+
+    /// Set the value for Null values.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - A string to represent Null values in the worksheet.
+    ///
+    pub fn with_null_value(&mut self, value: impl Into<String>) -> &mut SheetSpec {
+        self.options.null_value = Some(value.into());
+        self
+    }
+
+    /// Set the worksheet table for the dataframe.
+    ///
+    /// # Parameters
+    ///
+    /// * `table` - A `rust_xlsxwriter` [`Table`] reference.
+    ///
+    pub fn with_table(&mut self, table: &Table) -> &mut SheetSpec {
+        self.options.table = table.clone();
+        self
+    }
+
+    /// Set the style of the worksheet table.
+    ///
+    /// # Parameters
+    ///
+    /// * `style` - A `rust_xlsxwriter` [`TableStyle`] enum value.
+    ///
+    pub fn with_table_style(&mut self, style: TableStyle) -> &mut SheetSpec {
+        let table = self.options.table.clone().set_style(style);
+        self.options.table = table;
+        self
+    }
+
+    /// Set the worksheet zoom factor.
+    ///
+    /// # Parameters
+    ///
+    /// * `zoom` - The worksheet zoom level. The default zoom level is 100.
+    ///
+    pub fn with_zoom(&mut self, zoom: u16) -> &mut SheetSpec {
+        self.options.zoom = zoom;
+        self
+    }
+
+    /// Freeze panes in the worksheet.
+    ///
+    /// # Parameters
+    ///
+    /// * `row` - The zero indexed row number.
+    /// * `col` - The zero indexed column number.
+    ///
+    pub fn with_freeze_panes(&mut self, row: u32, col: u16) -> &mut SheetSpec {
+        self.options.freeze_cell = (row, col);
+        self
+    }
+
+    /// Set the selected cell in the worksheet.
+    ///
+    /// # Parameters
+    ///
+    /// * `row` - The zero indexed row number.
+    /// * `col` - The zero indexed column number.
+    ///
+    pub fn with_selection(&mut self, row: u32, col: u16) -> &mut SheetSpec {
+        self.options.selection = Some((row, col));
+        self
+    }
+
+    /// Make the worksheet the active worksheet when the workbook is opened.
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    pub fn with_sheet_active(&mut self, enable: bool) -> &mut SheetSpec {
+        self.options.sheet_active = enable;
+        self
+    }
+}
@@ -0,0 +1,177 @@
+// temporal - Conversion of Polars temporal values to Excel date and times.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
+use polars::prelude::*;
+
+// The number of days from 0001-01-01 (CE) to the Unix epoch, 1970-01-01.
+const UNIX_EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
+/// A trait for converting Polars temporal values to `chrono` dates and times.
+///
+/// Polars stores `Datetime`, `Date` and `Time` values as integer offsets from
+/// the Unix epoch, or from midnight. `PolarsXlsxWriter` converts these values
+/// to the `chrono` types that are written to Excel via this trait.
+///
+/// All the methods have a default implementation, which is used by
+/// [`DefaultTemporalConverter`], so a custom converter only needs to override
+/// the conversions that it changes. A custom converter can be set with
+/// [`PolarsXlsxWriter::set_temporal_converter()`].
+///
+/// The methods return `None` if the value can't be represented by the `chrono`
+/// type. This is reported as an error when the value is written.
+///
+/// [`PolarsXlsxWriter::set_temporal_converter()`]:
+///     crate::PolarsXlsxWriter::set_temporal_converter
+///
+pub trait TemporalToExcel: Send + Sync {
+    /// Convert a Polars `Datetime` value to a [`NaiveDateTime`].
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The number of time units from the Unix epoch.
+    /// * `time_unit` - The Polars [`TimeUnit`] of the value.
+    ///
+    fn datetime(&self, value: i64, time_unit: TimeUnit) -> Option<NaiveDateTime> {
+        let datetime = match time_unit {
+            TimeUnit::Nanoseconds => Some(DateTime::from_timestamp_nanos(value)),
+            TimeUnit::Microseconds => DateTime::from_timestamp_micros(value),
+            TimeUnit::Milliseconds => DateTime::from_timestamp_millis(value),
+        };
+
+        datetime.map(|datetime| datetime.naive_utc())
+    }
+
+    /// Convert a Polars `Date` value to a [`NaiveDate`].
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The number of days from the Unix epoch.
+    ///
+    fn date(&self, value: i32) -> Option<NaiveDate> {
+        value
+            .checked_add(UNIX_EPOCH_DAYS_FROM_CE)
+            .and_then(NaiveDate::from_num_days_from_ce_opt)
+    }
+
+    /// Convert a Polars `Time` value to a [`NaiveTime`].
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The number of nanoseconds from midnight.
+    ///
+    fn time(&self, value: i64) -> Option<NaiveTime> {
+        if !(0..86_400_000_000_000).contains(&value) {
+            return None;
+        }
+
+        Some(NaiveTime::MIN + TimeDelta::nanoseconds(value))
+    }
+}
+
+/// The default converter for Polars temporal values.
+///
+/// `DefaultTemporalConverter` uses the default methods of the
+/// [`TemporalToExcel`] trait and it is the converter used by
+/// `PolarsXlsxWriter` unless another converter is set.
+///
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultTemporalConverter;
+
+impl TemporalToExcel for DefaultTemporalConverter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_datetime_conversion() {
+        let converter = DefaultTemporalConverter;
+        let expected = NaiveDate::from_ymd_opt(2023, 1, 2)
+            .unwrap()
+            .and_hms_milli_opt(3, 4, 5, 678)
+            .unwrap();
+
+        let millis = expected.and_utc().timestamp_millis();
+
+        assert_eq!(
+            converter.datetime(millis, TimeUnit::Milliseconds),
+            Some(expected)
+        );
+        assert_eq!(
+            converter.datetime(millis * 1_000, TimeUnit::Microseconds),
+            Some(expected)
+        );
+        assert_eq!(
+            converter.datetime(millis * 1_000_000, TimeUnit::Nanoseconds),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn test_datetime_before_epoch() {
+        let converter = DefaultTemporalConverter;
+        let expected = NaiveDate::from_ymd_opt(1969, 12, 31)
+            .unwrap()
+            .and_hms_opt(23, 59, 59)
+            .unwrap();
+
+        assert_eq!(
+            converter.datetime(-1_000, TimeUnit::Milliseconds),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn test_datetime_out_of_range() {
+        let converter = DefaultTemporalConverter;
+
+        assert_eq!(converter.datetime(i64::MAX, TimeUnit::Milliseconds), None);
+        assert_eq!(converter.datetime(i64::MAX, TimeUnit::Microseconds), None);
+    }
+
+    #[test]
+    fn test_date_conversion() {
+        let converter = DefaultTemporalConverter;
+
+        assert_eq!(converter.date(0), NaiveDate::from_ymd_opt(1970, 1, 1));
+        assert_eq!(converter.date(19_359), NaiveDate::from_ymd_opt(2023, 1, 2));
+        assert_eq!(converter.date(-1), NaiveDate::from_ymd_opt(1969, 12, 31));
+        assert_eq!(converter.date(i32::MAX), None);
+    }
+
+    #[test]
+    fn test_time_conversion() {
+        let converter = DefaultTemporalConverter;
+
+        assert_eq!(converter.time(0), Some(NaiveTime::MIN));
+        assert_eq!(
+            converter.time(45_296_789_000_000),
+            NaiveTime::from_hms_milli_opt(12, 34, 56, 789)
+        );
+        assert_eq!(converter.time(-1), None);
+        assert_eq!(converter.time(86_400_000_000_000), None);
+    }
+
+    #[test]
+    fn test_custom_converter() {
+        // A converter that only overrides the date conversion.
+        struct NextDayConverter;
+
+        impl TemporalToExcel for NextDayConverter {
+            fn date(&self, value: i32) -> Option<NaiveDate> {
+                DefaultTemporalConverter.date(value)?.succ_opt()
+            }
+        }
+
+        let converter = NextDayConverter;
+
+        assert_eq!(converter.date(0), NaiveDate::from_ymd_opt(1970, 1, 2));
+        assert_eq!(converter.time(0), Some(NaiveTime::MIN));
+    }
+}
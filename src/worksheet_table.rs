@@ -0,0 +1,338 @@
+// worksheet_table - Worksheet tables, autofilters and row total columns.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use polars::prelude::*;
+use rust_xlsxwriter::{utility, Format, Formula, TableColumn, Worksheet};
+
+use crate::xlsx_writer::{OutputColumn, RowTotalColumn, WriterOptions};
+use crate::PolarsXlsxWriter;
+
+impl PolarsXlsxWriter {
+    // Add a worksheet table for the output columns along with any column
+    // totals and formula columns.
+    pub(crate) fn add_worksheet_table(
+        worksheet: &mut Worksheet,
+        output_columns: &[OutputColumn],
+        row_offset: u32,
+        col_offset: u16,
+        num_rows: usize,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        // Set up the table columns, if required.
+        let mut table = options.table.clone();
+        let has_totals = options.column_totals_all.is_some()
+            || !options.column_totals.is_empty()
+            || !options.column_total_labels.is_empty();
+        let has_formulas =
+            !options.formula_columns.is_empty() || options.row_total_column.is_some();
+        let has_header_format =
+            options.header_format.is_some() || !options.column_header_formats.is_empty();
+        let has_table_columns = !options.table_columns.is_empty();
+        if has_totals || has_formulas || has_header_format || has_table_columns {
+            let columns: Vec<TableColumn> = output_columns
+                .iter()
+                .enumerate()
+                .map(|(col_num, output_column)| {
+                    // Start from the user defined table column, if any.
+                    let mut column = options
+                        .table_columns
+                        .get(output_column.name())
+                        .cloned()
+                        .unwrap_or_default();
+
+                    let function = options
+                        .column_totals
+                        .get(output_column.name())
+                        .or(options.column_totals_all.as_ref());
+
+                    // A total row label takes precedence over the function.
+                    if let Some(label) = options.column_total_labels.get(output_column.name()) {
+                        column = column.set_total_label(label);
+                    } else if let Some(function) = function {
+                        column = column.set_total_function(function.clone());
+                    }
+
+                    if let Some(header_format) = Self::header_format(output_column, options) {
+                        column = column.set_header_format(header_format);
+                    }
+
+                    match output_column {
+                        OutputColumn::Formula(formula_column) => {
+                            Ok(column.set_formula(formula_column.formula.clone()))
+                        }
+                        OutputColumn::RowTotal(row_total_column) => {
+                            let row_total = Self::row_total(
+                                output_columns,
+                                row_total_column,
+                                col_offset + col_num as u16,
+                                col_offset,
+                                options,
+                            )?;
+
+                            Ok(match row_total.formula {
+                                Some(formula) if row_total.format == Format::default() => {
+                                    column.set_formula(formula)
+                                }
+                                Some(formula) => {
+                                    column.set_formula(formula).set_format(row_total.format)
+                                }
+                                None => column,
+                            })
+                        }
+                        _ => Ok(column),
+                    }
+                })
+                .collect::<PolarsResult<_>>()?;
+
+            table = table.set_columns(&columns);
+            if has_totals {
+                table = table.set_total_row(true);
+            }
+        }
+
+        // Set the user defined table name, if any.
+        if let Some(table_name) = &options.table_name {
+            table = table.set_name(table_name);
+        }
+
+        // Replace the table autofilter with a worksheet autofilter, which
+        // supports filter conditions, if required.
+        let use_filter_conditions =
+            !options.filter_conditions.is_empty() && table.has_header_row() && num_rows > 0;
+        if use_filter_conditions {
+            table = table.set_autofilter(false);
+        }
+
+        // Create a table for the dataframe range. A table without a header row
+        // needs at least one data or total row.
+        let table_rows =
+            usize::from(table.has_header_row()) + num_rows + usize::from(table.has_total_row());
+        if table_rows == 0 {
+            return Ok(());
+        }
+        let max_col = output_columns.len();
+
+        // Add the table to the worksheet.
+        worksheet.add_table(
+            row_offset,
+            col_offset,
+            row_offset + table_rows as u32 - 1,
+            col_offset + max_col as u16 - 1,
+            &table,
+        )?;
+
+        // Add the worksheet autofilter with the filter conditions, if required.
+        if use_filter_conditions {
+            Self::add_worksheet_autofilter(
+                worksheet,
+                output_columns,
+                row_offset,
+                col_offset,
+                num_rows,
+                options,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    // Add a worksheet autofilter to the header and data rows of the output
+    // columns along with the filter conditions of any named columns.
+    pub(crate) fn add_worksheet_autofilter(
+        worksheet: &mut Worksheet,
+        output_columns: &[OutputColumn],
+        row_offset: u32,
+        col_offset: u16,
+        num_rows: usize,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        let last_row = row_offset + num_rows as u32;
+        let last_col = col_offset + output_columns.len() as u16 - 1;
+        worksheet.autofilter(row_offset, col_offset, last_row, last_col)?;
+
+        for (column_name, condition) in &options.filter_conditions {
+            if let Some(col_num) = Self::column_position(output_columns, column_name) {
+                worksheet.filter_column(col_offset + col_num as u16, condition)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Check if the output table will have a total row.
+    pub(crate) fn has_total_row(options: &WriterOptions) -> bool {
+        options.use_table
+            && (options.table.has_total_row()
+                || options.column_totals_all.is_some()
+                || !options.column_totals.is_empty()
+                || !options.column_total_labels.is_empty())
+    }
+
+    // Get the output columns that are summed by the row total column. If no
+    // source columns were specified these are the numeric dataframe columns.
+    pub(crate) fn row_total_source_columns(
+        output_columns: &[OutputColumn],
+        row_total_column: &RowTotalColumn,
+    ) -> PolarsResult<Vec<usize>> {
+        let mut source_cols = vec![];
+
+        if row_total_column.source_columns.is_empty() {
+            for (col_num, output_column) in output_columns.iter().enumerate() {
+                if let OutputColumn::Data(column) = output_column {
+                    if column.dtype().is_primitive_numeric() {
+                        source_cols.push(col_num);
+                    }
+                }
+            }
+        } else {
+            for column_name in &row_total_column.source_columns {
+                let Some(col_num) = Self::column_position(output_columns, column_name) else {
+                    polars_bail!(
+                        ColumnNotFound:
+                        "row total source column '{}' not found in dataframe",
+                        column_name
+                    );
+                };
+
+                source_cols.push(col_num);
+            }
+        }
+
+        if source_cols.is_empty() {
+            polars_bail!(ComputeError: "no numeric columns found for row totals");
+        }
+
+        source_cols.sort_unstable();
+        source_cols.dedup();
+
+        Ok(source_cols)
+    }
+
+    // Get the formula and format for the row total column. Tables with a
+    // header row sum the source columns with a structured reference to the
+    // same row, such as `=SUM(Table1[@[Q1]:[Q4]])`, which is added as the
+    // table column formula. Other ranges use cell references, which are
+    // written for each row.
+    pub(crate) fn row_total(
+        output_columns: &[OutputColumn],
+        row_total_column: &RowTotalColumn,
+        col: u16,
+        col_offset: u16,
+        options: &WriterOptions,
+    ) -> PolarsResult<RowTotal> {
+        let source_cols = Self::row_total_source_columns(output_columns, row_total_column)?;
+
+        // Group adjacent source columns into ranges of column indices.
+        let mut ranges: Vec<(usize, usize)> = vec![];
+        for &col_num in &source_cols {
+            match ranges.last_mut() {
+                Some((_, last)) if *last + 1 == col_num => *last = col_num,
+                _ => ranges.push((col_num, col_num)),
+            }
+        }
+
+        let formula = if options.use_table && options.table.has_header_row() {
+            let table_name = options.output_table_name().unwrap_or_default();
+            let header = |col_num: usize| {
+                Self::escape_structured_reference(output_columns[col_num].header(options))
+            };
+
+            let references: Vec<String> = ranges
+                .iter()
+                .map(|&(first, last)| {
+                    if first == last {
+                        format!("{table_name}[@[{}]]", header(first))
+                    } else {
+                        format!("{table_name}[@[{}]:[{}]]", header(first), header(last))
+                    }
+                })
+                .collect();
+
+            Some(Formula::new(format!("SUM({})", references.join(","))))
+        } else {
+            None
+        };
+
+        // Use the format of the source columns if they are all the same, or
+        // the float format for mixed columns.
+        let formats: Vec<Format> = source_cols
+            .iter()
+            .map(|&col_num| {
+                let output_column = &output_columns[col_num];
+                match (output_column.column_format(options), output_column.dtype()) {
+                    (Some(format), _) => format.into_owned(),
+                    (None, Some(dtype)) => Self::default_dtype_format(dtype, options),
+                    (None, None) => options.float_format.format.clone(),
+                }
+            })
+            .collect();
+
+        let format = match formats.split_first() {
+            Some((first, rest)) if rest.iter().all(|format| format == first) => first.clone(),
+            _ => options
+                .dtype_formats
+                .get(&DataType::Float64)
+                .map_or(&options.float_format.format, |format| &format.format)
+                .clone(),
+        };
+
+        Ok(RowTotal {
+            col,
+            formula,
+            ranges: ranges
+                .into_iter()
+                .map(|(first, last)| (col_offset + first as u16, col_offset + last as u16))
+                .collect(),
+            format,
+        })
+    }
+
+    // Escape the special characters in a table column name for use in a
+    // structured reference.
+    pub(crate) fn escape_structured_reference(header: &str) -> String {
+        let mut escaped = String::with_capacity(header.len());
+        for char in header.chars() {
+            if matches!(char, '[' | ']' | '#' | '\'') {
+                escaped.push('\'');
+            }
+            escaped.push(char);
+        }
+
+        escaped
+    }
+
+    // Write the row total formula for a row, if it isn't written by the table.
+    pub(crate) fn write_row_total(
+        worksheet: &mut Worksheet,
+        row: u32,
+        row_total: &RowTotal,
+    ) -> PolarsResult<()> {
+        if row_total.formula.is_some() {
+            return Ok(());
+        }
+
+        let ranges: Vec<String> = row_total
+            .ranges
+            .iter()
+            .map(|&(first_col, last_col)| utility::cell_range(row, first_col, row, last_col))
+            .collect();
+
+        let formula = Formula::new(format!("=SUM({})", ranges.join(",")));
+        worksheet.write_formula_with_format(row, row_total.col, formula, &row_total.format)?;
+
+        Ok(())
+    }
+}
+
+// The formula and format of a row total column. The formula is a structured
+// reference, which is the same for each row, or the ranges of worksheet
+// columns that are summed in each row.
+pub(crate) struct RowTotal {
+    col: u16,
+    formula: Option<Formula>,
+    ranges: Vec<(u16, u16)>,
+    format: Format,
+}
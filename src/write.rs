@@ -9,9 +9,8 @@
 use std::io::{Seek, Write};
 
 use polars::prelude::*;
-use rust_xlsxwriter::Format;
 
-use crate::PolarsXlsxWriter;
+use crate::{CellFormat, PolarsXlsxWriter};
 
 /// `ExcelWriter` implements the Polars [`SerWriter`] trait to serialize a
 /// dataframe to an Excel Xlsx file.
@@ -24,6 +23,11 @@ use crate::PolarsXlsxWriter;
 /// `ExcelWriter` uses `PolarsXlsxWriter` to do the Excel serialization which in
 /// turn uses the [`rust_xlsxwriter`] crate.
 ///
+/// The `ExcelWriter` methods only cover the most common options. To use the
+/// `SerWriter` interface with all of the `PolarsXlsxWriter` options you can
+/// configure a `PolarsXlsxWriter` and convert it to an `ExcelWriter` with
+/// [`PolarsXlsxWriter::into_ser_writer()`].
+///
 ///  # Examples
 ///
 /// An example of writing a Polar Rust dataframe to an Excel file.
@@ -106,6 +110,14 @@ impl<W> ExcelWriter<W>
 where
     W: Write,
 {
+    // Create an `ExcelWriter` that uses a configured `PolarsXlsxWriter`.
+    pub(crate) fn from_xlsx_writer(writer: W, xlsx_writer: PolarsXlsxWriter) -> Self {
+        ExcelWriter {
+            writer,
+            xlsx_writer,
+        }
+    }
+
     /// Turn on/off the dataframe header in the exported Excel file.
     ///
     /// Turn on/off the dataframe header row in the Excel table. It is on by
@@ -243,7 +255,7 @@ where
     /// <img
     /// src="https://rustxlsxwriter.github.io/images/excelwriter_time_format.png">
     ///
-    pub fn with_time_format(mut self, format: impl Into<Format>) -> Self {
+    pub fn with_time_format(mut self, format: impl Into<CellFormat>) -> Self {
         self.xlsx_writer.set_time_format(format);
         self
     }
@@ -299,7 +311,7 @@ where
     ///
     /// <img src="https://rustxlsxwriter.github.io/images/excelwriter_date_format.png">
     ///
-    pub fn with_date_format(mut self, format: impl Into<Format>) -> Self {
+    pub fn with_date_format(mut self, format: impl Into<CellFormat>) -> Self {
         self.xlsx_writer.set_date_format(format);
         self
     }
@@ -357,7 +369,7 @@ where
     /// <img
     /// src="https://rustxlsxwriter.github.io/images/excelwriter_datetime_format.png">
     ///
-    pub fn with_datetime_format(mut self, format: impl Into<Format>) -> Self {
+    pub fn with_datetime_format(mut self, format: impl Into<CellFormat>) -> Self {
         self.xlsx_writer.set_datetime_format(format);
         self
     }
@@ -412,7 +424,7 @@ where
     /// <img
     /// src="https://rustxlsxwriter.github.io/images/excelwriter_float_format.png">
     ///
-    pub fn with_float_format(mut self, format: impl Into<Format>) -> Self {
+    pub fn with_float_format(mut self, format: impl Into<CellFormat>) -> Self {
         self.xlsx_writer.set_float_format(format);
         self
     }
@@ -6,15 +6,59 @@
 
 #![warn(missing_docs)]
 
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::io::{Seek, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use polars::prelude::*;
-use polars_arrow::temporal_conversions::{
-    date32_to_date, time64ns_to_time, timestamp_ms_to_datetime, timestamp_ns_to_datetime,
-    timestamp_us_to_datetime,
+use regex::Regex;
+use rust_xlsxwriter::{
+    utility, Chart, ChartType, Color, ConditionalFormat2ColorScale, ConditionalFormat3ColorScale,
+    ConditionalFormatAverage, ConditionalFormatBlank, ConditionalFormatCell,
+    ConditionalFormatDataBar, ConditionalFormatDate, ConditionalFormatDuplicate,
+    ConditionalFormatError, ConditionalFormatFormula, ConditionalFormatIconSet,
+    ConditionalFormatText, ConditionalFormatTop, DataValidation, DocProperties, ExcelDateTime,
+    FilterCondition, Format, FormatAlign, Formula, Image, Note, Sparkline, Table, TableColumn,
+    TableFunction, TableStyle, Url, Workbook, Worksheet, XlsxError,
 };
-use rust_xlsxwriter::{Format, Table, Workbook, Worksheet};
+
+#[cfg(feature = "parallel")]
+use crate::column_buffer::ColumnBuffer;
+use crate::column_buffer::{CellSink, TemporalValue};
+use crate::dataframe_adapter::{dataframe_from_series, ColumnAdapter, DataFrameAdapter};
+use crate::row_grouping::RowGroups;
+use crate::{DefaultTemporalConverter, ExcelWriter, SheetLayout, SheetSpec, TemporalToExcel};
+
+// The maximum number of rows in an Excel worksheet.
+const EXCEL_MAX_ROWS: usize = 1_048_576;
+
+// The maximum number of columns in an Excel worksheet.
+const EXCEL_MAX_COLS: u16 = 16_384;
+
+// The number of rows written at a time in constant memory mode.
+const CONSTANT_MEMORY_CHUNK_SIZE: usize = 10_000;
+
+// The average number of consecutive rows selected by a row filter below which
+// the selected rows are copied rather than sliced from the dataframe.
+const MIN_SLICED_ROW_RUN: usize = 16;
+
+// The default Excel column width in character units.
+pub(crate) const DEFAULT_COLUMN_WIDTH: f64 = 8.43;
+
+// The maximum Excel column width in character units and the equivalent width
+// in pixels.
+const EXCEL_MAX_COLUMN_WIDTH: f64 = 255.0;
+const EXCEL_MAX_COLUMN_WIDTH_PIXELS: u16 = 1790;
+
+// The largest integer that can be stored exactly in an Excel f64 number.
+const EXCEL_MAX_INTEGER: u64 = 1 << 53;
+
+// The range of Excel dates, 1900-01-01 to 9999-12-31, in days from the Unix
+// epoch.
+const EXCEL_MIN_DATE: i64 = -25_567;
+const EXCEL_MAX_DATE: i64 = 2_932_896;
 
 /// `PolarsXlsxWriter` provides an interface to serialize Polars dataframes to
 /// Excel via the [`rust_xlsxwriter`] library. This allows Excel serialization
@@ -156,9 +200,9 @@ use rust_xlsxwriter::{Format, Table, Workbook, Worksheet};
 ///
 /// 1. `polars` to create/manipulate the dataframe.
 /// 2. `rust_xlsxwriter` to create an Excel workbook and worksheet and
-///     optionally add other features to the worksheet.
+///    optionally add other features to the worksheet.
 /// 3. `polars_excel_writer::PolarsXlsxWriter` to write the Polars dataframe to
-///     the worksheet.
+///    the worksheet.
 ///
 /// This may seem initially complicated but it divides the solution into
 /// specialized libraries that are best suited for their task and it allow you
@@ -188,6 +232,26 @@ use rust_xlsxwriter::{Format, Table, Workbook, Worksheet};
 pub struct PolarsXlsxWriter {
     pub(crate) workbook: Workbook,
     pub(crate) options: WriterOptions,
+    pub(crate) overflow_workbooks: Vec<Workbook>,
+    pub(crate) table_names: HashSet<String>,
+    pub(crate) defined_names: HashSet<String>,
+    pub(crate) constant_memory_sheets: HashSet<usize>,
+    pub(crate) blank_sheets: HashSet<usize>,
+    pub(crate) sheet_specs: Vec<SheetSpec>,
+    pub(crate) is_worksheet_used: bool,
+    pub(crate) last_layout: Option<WrittenLayout>,
+    pub(crate) appended_frame: Option<AppendedFrame>,
+    pub(crate) fixed_column_widths: HashMap<String, HashMap<u16, ColumnWidth>>,
+    pub(crate) sheet_positions: Vec<(String, usize)>,
+    pub(crate) hidden_sheets: HashSet<String>,
+    pub(crate) active_sheet: Option<String>,
+    pub(crate) written_ranges: HashMap<String, Vec<(WrittenRange, usize)>>,
+    pub(crate) table_of_contents: Option<String>,
+    pub(crate) properties: Option<DocProperties>,
+    pub(crate) is_deterministic: bool,
+    pub(crate) use_zip_large_file: bool,
+    pub(crate) row_groups: Vec<RowGroups>,
+    pub(crate) warnings: Vec<ExportWarning>,
 }
 
 impl Default for PolarsXlsxWriter {
@@ -206,7 +270,212 @@ impl PolarsXlsxWriter {
         PolarsXlsxWriter {
             workbook,
             options: WriterOptions::default(),
+            overflow_workbooks: vec![],
+            table_names: HashSet::new(),
+            defined_names: HashSet::new(),
+            constant_memory_sheets: HashSet::new(),
+            blank_sheets: HashSet::from([0]),
+            sheet_specs: vec![],
+            is_worksheet_used: false,
+            last_layout: None,
+            appended_frame: None,
+            fixed_column_widths: HashMap::new(),
+            sheet_positions: vec![],
+            hidden_sheets: HashSet::new(),
+            active_sheet: None,
+            written_ranges: HashMap::new(),
+            table_of_contents: None,
+            properties: None,
+            is_deterministic: false,
+            use_zip_large_file: false,
+            row_groups: vec![],
+            warnings: vec![],
+        }
+    }
+
+    /// Create a new `PolarsXlsxWriter` from a template workbook.
+    ///
+    /// `rust_xlsxwriter` can't read existing xlsx files so a pre-designed
+    /// workbook can't be loaded and filled in. Instead, this method uses a
+    /// `rust_xlsxwriter` [`Workbook`] created by the user, with any titles,
+    /// images, formulas or charts already added, as a template. The
+    /// dataframes can then be written to target cells in the template
+    /// worksheets via
+    /// [`write_dataframe_to_sheet()`](PolarsXlsxWriter::write_dataframe_to_sheet)
+    /// and the workbook saved in a single step with
+    /// [`save()`](PolarsXlsxWriter::save).
+    ///
+    /// Repeated template layouts can be created by calling a function that
+    /// adds the layout to each new worksheet. Formats are stored by value in
+    /// `rust_xlsxwriter` so formats used in the template and by the writer
+    /// are shared in the output file. Table names set via
+    /// [`set_table_name()`](PolarsXlsxWriter::set_table_name) are checked for
+    /// uniqueness across all the written dataframes.
+    ///
+    /// The template worksheets are treated as already used, so methods such as
+    /// [`write_dataframe()`](PolarsXlsxWriter::write_dataframe) write to the
+    /// last worksheet in the template and don't replace it.
+    ///
+    /// # Parameters
+    ///
+    /// * `workbook` - A `rust_xlsxwriter` [`Workbook`] to use as the
+    ///   template.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates writing dataframes into a template workbook.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_from_workbook.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::{Format, Workbook, Worksheet, XlsxError};
+    ///
+    /// // Add the layout of a report page to a worksheet.
+    /// fn add_report_layout(worksheet: &mut Worksheet, title: &str) -> Result<(), XlsxError> {
+    ///     let title_format = Format::new().set_bold().set_font_size(16);
+    ///     worksheet.write_with_format(0, 0, title, &title_format)?;
+    ///     worksheet.write(1, 0, "Total:")?;
+    ///     worksheet.write_formula(1, 1, "=SUM(Sales[Sales])")?;
+    ///     Ok(())
+    /// }
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[1000, 2300, 1900, 2500],
+    ///     )?;
+    ///
+    ///     // Create the template workbook.
+    ///     let mut workbook = Workbook::new();
+    ///     let worksheet = workbook.add_worksheet().set_name("Report")?;
+    ///     add_report_layout(worksheet, "Sales Report")?;
+    ///
+    ///     // Write the dataframe into the template below the layout.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::from_workbook(workbook);
+    ///     xlsx_writer.set_table_name("Sales")?;
+    ///     xlsx_writer.write_dataframe_to_sheet(&df, "Report", 3, 0)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn from_workbook(mut workbook: Workbook) -> PolarsXlsxWriter {
+        let mut xlsx_writer = PolarsXlsxWriter::new();
+        xlsx_writer.is_worksheet_used = !workbook.worksheets().is_empty();
+        xlsx_writer.blank_sheets.clear();
+        xlsx_writer.workbook = workbook;
+
+        xlsx_writer
+    }
+
+    /// Convert the `PolarsXlsxWriter` into the underlying workbook.
+    ///
+    /// Return the `rust_xlsxwriter` [`Workbook`] that the dataframes were
+    /// written to instead of saving it. This can be used with
+    /// [`from_workbook()`](PolarsXlsxWriter::from_workbook) to pass a workbook
+    /// between the components of an application, each with its own
+    /// `PolarsXlsxWriter` and writer options, and to save it once at the end.
+    ///
+    /// Any work that is normally deferred until the file is saved, such as
+    /// writing the dataframes added via
+    /// [`append_dataframe()`](PolarsXlsxWriter::append_dataframe) or
+    /// [`add_sheet()`](PolarsXlsxWriter::add_sheet), the table of contents
+    /// and the worksheet order, is carried out before the workbook is
+    /// returned.
+    ///
+    /// Note, the table and defined names used by each writer are only checked
+    /// for uniqueness within that writer. Duplicate table names across
+    /// writers are reported by `rust_xlsxwriter` when the workbook is saved.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ComputeError`] that wraps a `rust_xlsxwriter`
+    /// [`XlsxError`](rust_xlsxwriter::XlsxError) error. An error is also
+    /// raised if the [`OverflowPolicy::SplitFiles`] overflow policy has
+    /// created additional files, since they can't be returned with the
+    /// workbook.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing Polar Rust dataframes to an Excel file. This
+    /// demonstrates passing a workbook between functions that each use their
+    /// own writer and options.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_into_workbook.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::{ColumnFormat, PolarsXlsxWriter};
+    /// use rust_xlsxwriter::Workbook;
+    ///
+    /// // Write the sales data to a worksheet of the workbook.
+    /// fn add_sales(workbook: Workbook, df: &DataFrame) -> PolarsResult<Workbook> {
+    ///     let mut xlsx_writer = PolarsXlsxWriter::from_workbook(workbook);
+    ///
+    ///     xlsx_writer.add_worksheet();
+    ///     xlsx_writer.set_worksheet_name("Sales")?;
+    ///     xlsx_writer.set_column_format("Sales", &ColumnFormat::new().set_num_format("$#,##0"));
+    ///     xlsx_writer.write_dataframe(df)?;
+    ///
+    ///     xlsx_writer.into_workbook()
+    /// }
+    ///
+    /// // Write the staff data to another worksheet of the workbook.
+    /// fn add_staff(workbook: Workbook, df: &DataFrame) -> PolarsResult<Workbook> {
+    ///     let mut xlsx_writer = PolarsXlsxWriter::from_workbook(workbook);
+    ///
+    ///     xlsx_writer.add_worksheet();
+    ///     xlsx_writer.set_worksheet_name("Staff")?;
+    ///     xlsx_writer.set_autofit(true);
+    ///     xlsx_writer.write_dataframe(df)?;
+    ///
+    ///     xlsx_writer.into_workbook()
+    /// }
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create some sample dataframes for the example.
+    ///     let sales: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[1000, 2300, 1900, 2500],
+    ///     )?;
+    ///
+    ///     let staff: DataFrame = df!(
+    ///         "Name" => &["Anna Lopez", "Ben Smith", "Chen Wei"],
+    ///         "Region" => &["North", "South", "East"],
+    ///     )?;
+    ///
+    ///     // Pass the workbook between the functions.
+    ///     let workbook = Workbook::new();
+    ///     let workbook = add_sales(workbook, &sales)?;
+    ///     let mut workbook = add_staff(workbook, &staff)?;
+    ///
+    ///     // Save the file to disk.
+    ///     workbook.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn into_workbook(mut self) -> PolarsResult<Workbook> {
+        self.prepare_workbooks()?;
+
+        if !self.overflow_workbooks.is_empty() {
+            polars_bail!(
+                ComputeError:
+                "into_workbook() doesn't support files split by the SplitFiles overflow policy"
+            );
         }
+
+        Ok(self.workbook)
     }
 
     /// Write a dataframe to a worksheet.
@@ -218,6 +487,14 @@ impl PolarsXlsxWriter {
     /// The worksheet must be written to a file using
     /// [`save()`](PolarsXlsxWriter::save).
     ///
+    /// The method returns a [`WrittenRange`] with the cell range of the output
+    /// table. This can be used to refer to the data in charts, conditional
+    /// formats or defined names.
+    ///
+    /// A dataframe without any rows is written as a header row only, since an
+    /// Excel table needs at least one data row, and a dataframe without any
+    /// columns doesn't write any data.
+    ///
     /// # Parameters
     ///
     /// * `df` - A Polars dataframe.
@@ -261,13 +538,8 @@ impl PolarsXlsxWriter {
     /// <img
     /// src="https://rustxlsxwriter.github.io/images/write_excel_write_dataframe.png">
     ///
-    pub fn write_dataframe(&mut self, df: &DataFrame) -> PolarsResult<()> {
-        let options = self.options.clone();
-        let worksheet = self.worksheet()?;
-
-        Self::write_dataframe_internal(df, worksheet, 0, 0, &options)?;
-
-        Ok(())
+    pub fn write_dataframe(&mut self, df: &DataFrame) -> PolarsResult<WrittenRange> {
+        self.write_dataframe_with_overflow(df, 0, 0, None)
     }
 
     /// Writes the supplied dataframe to a user defined cell in the first sheet
@@ -287,8 +559,11 @@ impl PolarsXlsxWriter {
     ///
     /// # Errors
     ///
-    /// A [`PolarsError::ComputeError`] that wraps a `rust_xlsxwriter`
-    /// [`XlsxError`](rust_xlsxwriter::XlsxError) error.
+    /// * [`PolarsError::ComputeError`] - If the dataframe range overlaps the
+    ///   range of a dataframe previously written to the worksheet. The error
+    ///   message contains both ranges.
+    /// * [`PolarsError::ComputeError`] - A wrapped `rust_xlsxwriter`
+    ///   [`XlsxError`](rust_xlsxwriter::XlsxError) error.
     ///
     /// # Examples
     ///
@@ -336,11 +611,194 @@ impl PolarsXlsxWriter {
         df: &DataFrame,
         row: u32,
         col: u16,
-    ) -> PolarsResult<()> {
-        let options = self.options.clone();
-        let worksheet = self.worksheet()?;
+    ) -> PolarsResult<WrittenRange> {
+        self.write_dataframe_with_overflow(df, row, col, None)
+    }
+
+    /// Writes several dataframes to the same worksheet with automatic
+    /// positioning.
+    ///
+    /// Writes the dataframes to the current worksheet, starting at cell `A1`,
+    /// side by side or one below the other according to the [`Layout`]. The
+    /// position of each dataframe is calculated from the size of the previous
+    /// output table, including the header, any total row and any inserted or
+    /// expanded columns, plus the gap specified in the layout. This avoids
+    /// having to calculate the row and column offsets for
+    /// [`write_dataframe_to_cell()`](PolarsXlsxWriter::write_dataframe_to_cell)
+    /// manually.
+    ///
+    /// The method returns a [`WrittenRange`] with the cell range that each
+    /// dataframe was written to.
+    ///
+    /// # Parameters
+    ///
+    /// * `dataframes` - A slice of Polars dataframe references.
+    /// * `layout` - A [`Layout`] enum value.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ComputeError`] that wraps a `rust_xlsxwriter`
+    /// [`XlsxError`](rust_xlsxwriter::XlsxError) error.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing several Polar dataframes side by side in an
+    /// Excel worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_write_dataframes.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::{Layout, PolarsXlsxWriter};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create some sample dataframes for the example.
+    ///     let df1: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[1000, 2300, 1900, 2500],
+    ///     )?;
+    ///
+    ///     let df2: DataFrame = df!(
+    ///         "Quarter" => &["Q1", "Q2", "Q3", "Q4"],
+    ///         "Costs" => &[450.25, 610.50, 520.75, 700.00],
+    ///     )?;
+    ///
+    ///     // Write the dataframes to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write the dataframes side by side with a one column gap.
+    ///     xlsx_writer.write_dataframes(&[&df1, &df2], Layout::Horizontal { gap: 1 })?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn write_dataframes(
+        &mut self,
+        dataframes: &[&DataFrame],
+        layout: Layout,
+    ) -> PolarsResult<Vec<WrittenRange>> {
+        let mut ranges = vec![];
+        let mut row = 0;
+        let mut col = 0;
+
+        for df in dataframes {
+            let range = self.write_dataframe_with_overflow(df, row, col, None)?;
+
+            match layout {
+                Layout::Horizontal { gap } => {
+                    col = range.last_col.saturating_add(1).saturating_add(gap);
+                }
+                Layout::Vertical { gap } => {
+                    row = range.last_row.saturating_add(1).saturating_add(gap);
+                }
+            }
+
+            ranges.push(range);
+        }
+
+        Ok(ranges)
+    }
+
+    /// Append a dataframe below the previous dataframe in a worksheet.
+    ///
+    /// Appends the rows of the dataframe to a single output table in the
+    /// current worksheet. Successive calls extend the same table, with one
+    /// header row, instead of creating a separate table for each dataframe.
+    /// This is useful for incremental exports of a large number of small
+    /// dataframes to one long worksheet.
+    ///
+    /// The first call to `append_dataframe()` on a worksheet starts the table
+    /// directly below the last dataframe written to the worksheet via one of
+    /// the other `write_*()` methods, or at cell `A1` if there isn't one. The
+    /// dataframes must have the same column names and types.
+    ///
+    /// The table is written, with the options in place at the time of the
+    /// first append, when another dataframe is written, when a chart is added
+    /// via [`add_chart_for_columns()`](PolarsXlsxWriter::add_chart_for_columns)
+    /// or when the file is saved. Dataframes appended after that start a new
+    /// table below the previous one.
+    ///
+    /// If you want to repeat the header for each dataframe then use
+    /// [`write_dataframes()`](PolarsXlsxWriter::write_dataframes) with a
+    /// vertical [`Layout`] instead.
+    ///
+    /// # Parameters
+    ///
+    /// * `df` - A Polars dataframe.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError`] error if the dataframe columns don't match the
+    /// columns of the previously appended dataframes.
+    ///
+    /// # Examples
+    ///
+    /// An example of appending several Polar dataframes to a single table in
+    /// an Excel worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_append_dataframe.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Append a dataframe for each batch of readings.
+    ///     for batch in 1..=3 {
+    ///         let df: DataFrame = df!(
+    ///             "Batch" => &[batch, batch, batch],
+    ///             "Reading" => &[1, 2, 3],
+    ///             "Value" => &[0.5 * batch as f64, 1.5, 2.5],
+    ///         )?;
+    ///
+    ///         xlsx_writer.append_dataframe(&df)?;
+    ///     }
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn append_dataframe(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let sheet_name = self.current_worksheet()?.name();
+        let sheet_index = self.workbook.worksheets().len() - 1;
+
+        if let Some(appended) = &mut self.appended_frame {
+            if appended.sheet_index == sheet_index {
+                appended.df.vstack_mut(df)?;
+                return Ok(());
+            }
+        }
+
+        // Write any table appended to a previous worksheet.
+        self.write_appended_frame()?;
 
-        Self::write_dataframe_internal(df, worksheet, row, col, &options)?;
+        // Start the table below any dataframe written to the worksheet.
+        let (row, col) = match &self.last_layout {
+            Some(layout) if layout.range.sheet_name == sheet_name => (
+                layout.range.last_row.saturating_add(1),
+                layout.range.first_col,
+            ),
+            _ => (0, 0),
+        };
+
+        self.appended_frame = Some(AppendedFrame {
+            df: df.clone(),
+            options: self.options.clone(),
+            sheet_index,
+            row,
+            col,
+        });
 
         Ok(())
     }
@@ -429,56 +887,190 @@ impl PolarsXlsxWriter {
         worksheet: &mut Worksheet,
         row: u32,
         col: u16,
-    ) -> PolarsResult<()> {
-        let options = self.options.clone();
+    ) -> PolarsResult<WrittenRange> {
+        let mut options = self.options.clone();
+        let df = Self::filter_rows(df, &options)?;
+        let df = Self::select_columns(df.as_ref(), &options)?;
+        let df = Self::convert_struct_columns(df.as_ref(), &options)?;
+        let df = Self::convert_binary_columns(df.as_ref(), &options)?;
+        #[cfg(feature = "object")]
+        let df = Self::convert_object_columns(df, &options)?;
+        let df = Self::add_row_numbers(df, &options)?;
+        let df = df.as_ref();
 
-        Self::write_dataframe_internal(df, worksheet, row, col, &options)?;
+        // Write any grouped header row above the table.
+        let header_groups = Self::split_headers(df, &mut options)?;
+        let group_row = row;
+        let row = row + u32::from(header_groups.is_some());
 
-        Ok(())
+        Self::set_merge_range_mode(df, &mut options);
+        Self::set_empty_dataframe_mode(df, &mut options);
+        let renamed_headers = Self::set_unique_headers(df, &mut options)?;
+        self.add_warnings(&worksheet.name(), df, &renamed_headers, &options)?;
+
+        // The data can't be split since the worksheet's workbook isn't known.
+        let segments = Self::row_segments(df.height(), row, &options)?;
+        if segments.len() > 1 {
+            polars_bail!(
+                ComputeError:
+                "{:?} isn't supported for user supplied worksheets",
+                options.overflow_policy
+            );
+        }
+
+        // Check that the dataframe doesn't overlap a previous dataframe.
+        let range = Self::written_range(&worksheet.name(), df, row, col, &options)?;
+        self.add_written_range(
+            WrittenRange {
+                first_row: group_row,
+                ..range.clone()
+            },
+            segments[0].1,
+        )?;
+
+        // Check that the table name hasn't already been used.
+        if let Some(table_name) = options.output_table_name() {
+            self.add_table_name(table_name)?;
+        }
+
+        let (first_row, num_rows) = segments[0];
+        let df = df.slice_rows(first_row, num_rows);
+        let row_groups = Self::row_groups(&df, row, &options)?;
+
+        if let Some(header_groups) = &header_groups {
+            Self::write_header_groups(worksheet, group_row, col, header_groups, &options)?;
+        }
+
+        Self::write_data_row_heights(worksheet, &df, first_row, num_rows, row, &options)?;
+
+        match options.write_order {
+            WriteOrder::ColumnMajor => {
+                Self::write_dataframe_internal(&df, worksheet, row, col, &options)?;
+            }
+            WriteOrder::RowMajor => Self::write_dataframe_chunked_internal(
+                &df,
+                worksheet,
+                row,
+                col,
+                CONSTANT_MEMORY_CHUNK_SIZE,
+                false,
+                &options,
+            )?,
+        }
+
+        Self::merge_repeated_values(worksheet, &df, row, col, &options)?;
+        self.record_sheet_arrangement(&worksheet.name(), true, &options);
+        self.add_row_groups(0, &worksheet.name(), row_groups);
+
+        let fixed_widths = self
+            .fixed_column_widths
+            .entry(worksheet.name())
+            .or_default();
+        Self::set_fixed_column_widths(worksheet, fixed_widths, &df, col, &options)?;
+
+        Ok(range)
     }
 
-    /// Save the Workbook as an xlsx file.
+    /// Write a dataframe to a cell in a named worksheet.
     ///
-    /// The `save()` method writes all the workbook and worksheet data to
-    /// a new xlsx file. It will overwrite any existing file.
+    /// Writes the supplied dataframe to a user defined cell in an existing
+    /// worksheet of the workbook, such as a worksheet of a template workbook
+    /// added via [`from_workbook()`](PolarsXlsxWriter::from_workbook). This
+    /// allows several dataframes to be placed into a pre-designed layout and
+    /// saved in a single step. See the example in
+    /// [`from_workbook()`](PolarsXlsxWriter::from_workbook).
     ///
-    /// The method can be called multiple times so it is possible to get
-    /// incremental files at different stages of a process, or to save the same
-    /// Workbook object to different paths. However, `save()` is an
-    /// expensive operation which assembles multiple files into an xlsx/zip
-    /// container so for performance reasons you shouldn't call it
-    /// unnecessarily.
+    /// The current writer options apply to the dataframe. As with
+    /// [`write_dataframe_to_worksheet()`](PolarsXlsxWriter::write_dataframe_to_worksheet)
+    /// the data can't be split across worksheets by the overflow policy.
     ///
     /// # Parameters
     ///
-    /// * `path` - The path of the new Excel file to create as a `&str` or as a
-    ///   [`std::path`] `Path` or `PathBuf` instance.
+    /// * `df` - A Polars dataframe.
+    /// * `sheet_name` - The name of an existing worksheet.
+    /// * `row` - The zero indexed row number.
+    /// * `col` - The zero indexed column number.
     ///
     /// # Errors
     ///
     /// A [`PolarsError::ComputeError`] that wraps a `rust_xlsxwriter`
-    /// [`XlsxError`](rust_xlsxwriter::XlsxError) error.
+    /// [`XlsxError`](rust_xlsxwriter::XlsxError) error, including the error for
+    /// an unknown worksheet name.
     ///
-    pub fn save<P: AsRef<Path>>(&mut self, path: P) -> PolarsResult<()> {
-        self.workbook.save(path)?;
+    pub fn write_dataframe_to_sheet(
+        &mut self,
+        df: &DataFrame,
+        sheet_name: &str,
+        row: u32,
+        col: u16,
+    ) -> PolarsResult<WrittenRange> {
+        self.write_appended_frame()?;
+        self.is_worksheet_used = true;
 
-        Ok(())
+        // Take the workbook so that the worksheet and the writer can be
+        // borrowed at the same time. It is restored even if there is an error.
+        let mut workbook = std::mem::replace(&mut self.workbook, Workbook::new());
+        let result = match workbook.worksheet_from_name(sheet_name) {
+            Ok(worksheet) => self.write_dataframe_to_worksheet(df, worksheet, row, col),
+            Err(error) => Err(error.into()),
+        };
+        self.workbook = workbook;
+
+        result
     }
 
-    /// Turn on/off the dataframe header row in the Excel table. It is on by
-    /// default.
+    /// Write a large dataframe to a worksheet in chunks of rows.
+    ///
+    /// This method is an alternative to
+    /// [`write_dataframe()`](PolarsXlsxWriter::write_dataframe) for very large
+    /// dataframes. It uses the `rust_xlsxwriter` [constant memory mode] to
+    /// write the dataframe incrementally, `chunk_size` rows at a time, so that
+    /// the memory used by the worksheet stays bounded regardless of the size
+    /// of the dataframe.
+    ///
+    /// The current worksheet is replaced with a constant memory worksheet with
+    /// the same name, if it isn't one already. Only a new worksheet that hasn't
+    /// been changed or written to can be replaced, otherwise an error is
+    /// returned. To make changes to the worksheet before the dataframe is
+    /// written turn on
+    /// [`set_constant_memory()`](PolarsXlsxWriter::set_constant_memory) first,
+    /// so that the worksheet is a constant memory worksheet from the start.
+    ///
+    /// If the dataframe has more rows than fit in an Excel worksheet (1,048,576
+    /// including the header) the remaining rows can be written to additional
+    /// worksheets or files via
+    /// [`set_overflow_policy()`](PolarsXlsxWriter::set_overflow_policy).
+    ///
+    /// Since constant memory worksheets don't retain the written data the
+    /// [`set_autofit()`](PolarsXlsxWriter::set_autofit) option and the merged
+    /// cells of [`set_merge_repeated()`](PolarsXlsxWriter::set_merge_repeated)
+    /// aren't supported by this method. They are ignored with a
+    /// [`WarningKind::UnsupportedOption`] warning.
+    ///
+    /// [constant memory mode]:
+    ///     https://docs.rs/rust_xlsxwriter/latest/rust_xlsxwriter/performance/index.html#constant-memory-mode
     ///
     /// # Parameters
     ///
-    /// * `has_header` - Export dataframe with/without header.
+    /// * `df` - A Polars dataframe.
+    /// * `chunk_size` - The number of dataframe rows to process at a time.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ComputeError`] that wraps a `rust_xlsxwriter`
+    /// [`XlsxError`](rust_xlsxwriter::XlsxError) error. An error is also
+    /// returned if `chunk_size` is 0, if the current worksheet can't be
+    /// replaced by a constant memory worksheet or if the dataframe doesn't
+    /// start below the rows already written to a constant memory worksheet.
     ///
     /// # Examples
     ///
     /// An example of writing a Polar Rust dataframe to an Excel file. This
-    /// demonstrates saving the dataframe without a header.
+    /// demonstrates writing a large dataframe in chunks of rows using constant
+    /// memory mode.
     ///
     /// ```
-    /// # // This code is available in examples/write_excel_set_header.rs
+    /// # // This code is available in examples/write_excel_write_dataframe_chunked.rs
     /// #
     /// # use polars::prelude::*;
     /// #
@@ -487,20 +1079,14 @@ impl PolarsXlsxWriter {
     /// fn main() -> PolarsResult<()> {
     ///     // Create a sample dataframe for the example.
     ///     let df: DataFrame = df!(
-    ///         "String" => &["North", "South", "East", "West"],
-    ///         "Int" => &[1, 2, 3, 4],
-    ///         "Float" => &[1.0, 2.22, 3.333, 4.4444],
-    ///     )
-    ///     .unwrap();
+    ///         "Id" => (0..10_000).collect::<Vec<i32>>(),
+    ///         "Value" => (0..10_000).map(|i| f64::from(i) * 1.5).collect::<Vec<f64>>(),
+    ///     )?;
     ///
-    ///     // Write the dataframe to an Excel file.
+    ///     // Write the dataframe to an Excel file in chunks of 1,000 rows.
     ///     let mut xlsx_writer = PolarsXlsxWriter::new();
     ///
-    ///     // Turn off the default header.
-    ///     xlsx_writer.set_header(false);
-    ///
-    ///     // Write the dataframe to Excel.
-    ///     xlsx_writer.write_dataframe(&df)?;
+    ///     xlsx_writer.write_dataframe_chunked(&df, 1_000)?;
     ///
     ///     // Save the file to disk.
     ///     xlsx_writer.save("dataframe.xlsx")?;
@@ -509,64 +1095,70 @@ impl PolarsXlsxWriter {
     /// }
     /// ```
     ///
-    /// Output file:
+    pub fn write_dataframe_chunked(
+        &mut self,
+        df: &DataFrame,
+        chunk_size: usize,
+    ) -> PolarsResult<WrittenRange> {
+        if chunk_size == 0 {
+            polars_bail!(ComputeError: "chunk_size must be greater than 0");
+        }
+
+        self.write_dataframe_with_overflow(df, 0, 0, Some(chunk_size))
+    }
+
+    /// Write a Polars lazyframe to a worksheet.
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/excelwriter_has_header_off.png">
+    /// Collects the lazyframe and writes the resulting dataframe to cell `(0,
+    /// 0)` of the current worksheet, in the same way as
+    /// [`write_dataframe()`](PolarsXlsxWriter::write_dataframe). The query is
+    /// run with the Polars streaming engine, where possible, which can reduce
+    /// the memory used by the query itself.
     ///
-    pub fn set_header(&mut self, has_header: bool) -> &mut PolarsXlsxWriter {
-        let table = self.options.table.clone().set_header_row(has_header);
-        self.options.table = table;
-        self
-    }
-
-    /// Set the Excel number format for time values.
+    /// Note, the result of the query is collected into a dataframe before it
+    /// is written, so it must fit in memory. For a large result use
+    /// [`set_constant_memory()`](PolarsXlsxWriter::set_constant_memory) to
+    /// reduce the memory used by the worksheet data.
     ///
-    /// [Datetimes in Excel] are stored as f64 floats with a format used to
-    /// display them. The default time format used by this library is
-    /// `hh:mm:ss;@`. This method can be used to specify an alternative user
-    /// defined format.
+    /// # Parameters
     ///
-    /// [Datetimes in Excel]:
-    ///     https://docs.rs/rust_xlsxwriter/latest/rust_xlsxwriter/struct.ExcelDateTime.html#datetimes-in-excel
+    /// * `lf` - A Polars lazyframe.
     ///
-    /// # Parameters
+    /// # Errors
     ///
-    /// * `format` - A `rust_xlsxwriter` [`Format`] or an Excel number format
-    ///   string that can be converted to a `Format`.
+    /// A [`PolarsError`] error if the lazyframe query fails or a
+    /// [`PolarsError::ComputeError`] that wraps a `rust_xlsxwriter`
+    /// [`XlsxError`](rust_xlsxwriter::XlsxError) error.
     ///
     /// # Examples
     ///
-    /// An example of writing a Polar Rust dataframe to an Excel file. This example
-    /// demonstrates how to change the default format for Polars time types.
+    /// An example of writing the result of a Polar Rust lazyframe query to an
+    /// Excel file.
     ///
     /// ```
-    /// # // This code is available in examples/write_excel_time_format.rs
+    /// # // This code is available in examples/write_excel_write_lazyframe.rs
+    /// #
+    /// # use polars::prelude::*;
     /// #
-    /// use chrono::prelude::*;
-    /// use polars::prelude::*;
-    ///
     /// use polars_excel_writer::PolarsXlsxWriter;
     ///
     /// fn main() -> PolarsResult<()> {
     ///     // Create a sample dataframe for the example.
     ///     let df: DataFrame = df!(
-    ///         "Time" => &[
-    ///             NaiveTime::from_hms_milli_opt(2, 00, 3, 456).unwrap(),
-    ///             NaiveTime::from_hms_milli_opt(2, 18, 3, 456).unwrap(),
-    ///             NaiveTime::from_hms_milli_opt(2, 37, 3, 456).unwrap(),
-    ///             NaiveTime::from_hms_milli_opt(2, 59, 3, 456).unwrap(),
-    ///         ],
+    ///         "Region" => &["North", "South", "East", "West", "North", "South"],
+    ///         "Sales" => &[100, 200, 300, 400, 500, 600],
     ///     )?;
     ///
-    ///     // Write the dataframe to an Excel file.
-    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///     // Create a lazyframe query to get the total sales for each region.
+    ///     let lf = df
+    ///         .lazy()
+    ///         .group_by_stable([col("Region")])
+    ///         .agg([col("Sales").sum()]);
     ///
-    ///     // Set the time format.
-    ///     xlsx_writer.set_time_format("hh:mm");
+    ///     // Write the lazyframe query result to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
     ///
-    ///     // Write the dataframe to Excel.
-    ///     xlsx_writer.write_dataframe(&df)?;
+    ///     xlsx_writer.write_lazyframe(lf)?;
     ///
     ///     // Save the file to disk.
     ///     xlsx_writer.save("dataframe.xlsx")?;
@@ -575,63 +1167,52 @@ impl PolarsXlsxWriter {
     /// }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/excelwriter_time_format.png">
-    ///
-    pub fn set_time_format(&mut self, format: impl Into<Format>) -> &mut PolarsXlsxWriter {
-        self.options.time_format = format.into();
-        self
+    pub fn write_lazyframe(&mut self, lf: LazyFrame) -> PolarsResult<WrittenRange> {
+        let df = lf.with_streaming(true).collect()?;
+
+        self.write_dataframe_with_overflow(&df, 0, 0, None)
     }
 
-    /// Set the Excel number format for date values.
-    ///
-    /// [Datetimes in Excel] are stored as f64 floats with a format used to
-    /// display them. The default date format used by this library is
-    /// `yyyy-mm-dd;@`. This method can be used to specify an alternative user
-    /// defined format.
+    /// Write a single Polars series to a worksheet.
     ///
-    /// [Datetimes in Excel]:
-    ///     https://docs.rs/rust_xlsxwriter/latest/rust_xlsxwriter/struct.ExcelDateTime.html#datetimes-in-excel
+    /// Writes the supplied series to cell `(0, 0)` in the first sheet of a new
+    /// Excel workbook, in the same way as a one column dataframe written via
+    /// [`write_dataframe()`](PolarsXlsxWriter::write_dataframe). The series
+    /// name is used as the column header. This is a convenience method for
+    /// writing the result of an expression or a computed column without first
+    /// creating a dataframe.
     ///
     /// # Parameters
     ///
-    /// * `format` - A `rust_xlsxwriter` [`Format`] or an Excel number format
-    ///   string that can be converted to a `Format`.
+    /// * `series` - A Polars series.
+    /// * `header` - Write the series name as a header. If this is `false` the
+    ///   header is turned off for this write only, see
+    ///   [`set_header()`](PolarsXlsxWriter::set_header).
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ComputeError`] that wraps a `rust_xlsxwriter`
+    /// [`XlsxError`](rust_xlsxwriter::XlsxError) error.
     ///
     /// # Examples
     ///
-    /// An example of writing a Polar Rust dataframe to an Excel file. This example
-    /// demonstrates how to change the default format for Polars date types.
+    /// An example of writing a Polar Rust series to an Excel file.
     ///
     /// ```
-    /// # // This code is available in examples/write_excel_date_format.rs
+    /// # // This code is available in examples/write_excel_write_series.rs
+    /// #
+    /// # use polars::prelude::*;
     /// #
-    /// use chrono::prelude::*;
-    /// use polars::prelude::*;
-    ///
     /// use polars_excel_writer::PolarsXlsxWriter;
     ///
     /// fn main() -> PolarsResult<()> {
-    ///     // Create a sample dataframe for the example.
-    ///     let df: DataFrame = df!(
-    ///         "Date" => &[
-    ///             NaiveDate::from_ymd_opt(2023, 1, 11),
-    ///             NaiveDate::from_ymd_opt(2023, 1, 12),
-    ///             NaiveDate::from_ymd_opt(2023, 1, 13),
-    ///             NaiveDate::from_ymd_opt(2023, 1, 14),
-    ///         ],
-    ///     )?;
+    ///     // Create a sample series for the example.
+    ///     let series = Series::new("Data".into(), &[10, 20, 15, 25, 30, 20]);
     ///
-    ///     // Create a new Excel writer.
+    ///     // Write the series to an Excel file.
     ///     let mut xlsx_writer = PolarsXlsxWriter::new();
     ///
-    ///     // Set the date format.
-    ///     xlsx_writer.set_date_format("mmm d yyyy");
-    ///
-    ///     // Write the dataframe to Excel.
-    ///     xlsx_writer.write_dataframe(&df)?;
+    ///     xlsx_writer.write_series(&series, true)?;
     ///
     ///     // Save the file to disk.
     ///     xlsx_writer.save("dataframe.xlsx")?;
@@ -640,62 +1221,62 @@ impl PolarsXlsxWriter {
     /// }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img src="https://rustxlsxwriter.github.io/images/excelwriter_date_format.png">
-    ///
-    pub fn set_date_format(&mut self, format: impl Into<Format>) -> &mut PolarsXlsxWriter {
-        self.options.date_format = format.into();
-        self
+    pub fn write_series(&mut self, series: &Series, header: bool) -> PolarsResult<WrittenRange> {
+        let df = series.clone().into_frame();
+
+        // Turn off the header for this write only, if required.
+        let table = self.options.table.clone();
+        if !header {
+            self.options.table = table.clone().set_header_row(false);
+        }
+
+        let range = self.write_dataframe_with_overflow(&df, 0, 0, None);
+        self.options.table = table;
+
+        range
     }
 
-    /// Set the Excel number format for datetime values.
-    ///
-    /// [Datetimes in Excel] are stored as f64 floats with a format used to
-    /// display them. The default datetime format used by this library is
-    /// `yyyy-mm-dd hh:mm:ss`. This method can be used to specify an alternative
-    /// user defined format.
+    /// Write a set of Polars series to a worksheet as columns.
     ///
-    /// [Datetimes in Excel]:
-    ///     https://docs.rs/rust_xlsxwriter/latest/rust_xlsxwriter/struct.ExcelDateTime.html#datetimes-in-excel
+    /// Writes the supplied series to cell `(0, 0)` in the first sheet of a new
+    /// Excel workbook as adjacent columns, in the same way as a dataframe
+    /// written via [`write_dataframe()`](PolarsXlsxWriter::write_dataframe).
+    /// This is a convenience method for writing an ad-hoc set of columns, such
+    /// as the results of several expressions, without first creating a
+    /// dataframe.
     ///
     /// # Parameters
     ///
-    /// * `format` - A `rust_xlsxwriter` [`Format`] or an Excel number format
-    ///   string that can be converted to a `Format`.
+    /// * `columns` - A slice of Polars series.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError`] error if the series have different lengths or
+    /// duplicate names, or a [`PolarsError::ComputeError`] that wraps a
+    /// `rust_xlsxwriter` [`XlsxError`](rust_xlsxwriter::XlsxError) error.
     ///
     /// # Examples
     ///
-    /// An example of writing a Polar Rust dataframe to an Excel file. This example
-    /// demonstrates how to change the default format for Polars datetime types.
+    /// An example of writing several Polar Rust series to an Excel file as
+    /// columns.
     ///
     /// ```
-    /// # // This code is available in examples/write_excel_datetime_format.rs
+    /// # // This code is available in examples/write_excel_write_columns.rs
+    /// #
+    /// # use polars::prelude::*;
     /// #
-    /// use chrono::prelude::*;
-    /// use polars::prelude::*;
-    ///
     /// use polars_excel_writer::PolarsXlsxWriter;
     ///
     /// fn main() -> PolarsResult<()> {
-    ///     // Create a sample dataframe for the example.
-    ///     let df: DataFrame = df!(
-    ///         "Datetime" => &[
-    ///             NaiveDate::from_ymd_opt(2023, 1, 11).unwrap().and_hms_opt(1, 0, 0).unwrap(),
-    ///             NaiveDate::from_ymd_opt(2023, 1, 12).unwrap().and_hms_opt(2, 0, 0).unwrap(),
-    ///             NaiveDate::from_ymd_opt(2023, 1, 13).unwrap().and_hms_opt(3, 0, 0).unwrap(),
-    ///             NaiveDate::from_ymd_opt(2023, 1, 14).unwrap().and_hms_opt(4, 0, 0).unwrap(),
-    ///         ],
-    ///     )?;
+    ///     // Create some sample series for the example.
+    ///     let sales = Series::new("Sales".into(), &[100.0, 200.0, 300.0, 400.0]);
+    ///     let costs = Series::new("Costs".into(), &[80.0, 150.0, 210.0, 260.0]);
+    ///     let profit = (&sales - &costs)?.with_name("Profit".into());
     ///
-    ///     // Write the dataframe to an Excel file.
+    ///     // Write the series to an Excel file.
     ///     let mut xlsx_writer = PolarsXlsxWriter::new();
     ///
-    ///     // Set the datetime format.
-    ///     xlsx_writer.set_datetime_format("hh::mm - mmm d yyyy");
-    ///
-    ///     // Write the dataframe to Excel.
-    ///     xlsx_writer.write_dataframe(&df)?;
+    ///     xlsx_writer.write_columns(&[sales, costs, profit])?;
     ///
     ///     // Save the file to disk.
     ///     xlsx_writer.save("dataframe.xlsx")?;
@@ -704,63 +1285,78 @@ impl PolarsXlsxWriter {
     /// }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/excelwriter_datetime_format.png">
-    ///
-    pub fn set_datetime_format(&mut self, format: impl Into<Format>) -> &mut PolarsXlsxWriter {
-        self.options.datetime_format = format.into();
-        self
+    pub fn write_columns(&mut self, columns: &[Series]) -> PolarsResult<WrittenRange> {
+        let df = dataframe_from_series(columns)?;
+
+        self.write_dataframe_with_overflow(&df, 0, 0, None)
     }
 
-    /// Set the Excel number format for floats.
-    ///
-    /// Set the Excel number format for f32/f64 float types using an Excel
-    /// number format string. These format strings can be obtained from the
-    /// `Format Cells -> Number` dialog in Excel.
+    /// Write a dataframe and highlight the cells that differ from another
+    /// dataframe.
     ///
-    /// See the [Number Format Categories] section and subsequent Number Format
-    /// sections in the `rust_xlsxwriter` documentation.
+    /// Writes `df_new` in the same way as
+    /// [`write_dataframe()`](PolarsXlsxWriter::write_dataframe) and highlights
+    /// the data cells whose values differ from the matching cells in `df_old`.
+    /// This is useful for comparing two snapshots of the same data.
     ///
-    /// [Number Format Categories]:
-    ///     https://docs.rs/rust_xlsxwriter/latest/rust_xlsxwriter/struct.Format.html#number-format-categories
+    /// By default the rows of the dataframes are matched by position. They can
+    /// also be matched by the values of a key column via
+    /// [`set_diff_key_column()`](PolarsXlsxWriter::set_diff_key_column). Cells
+    /// in rows or columns that aren't in `df_old` are also highlighted since
+    /// they are new. Rows that are only in `df_old` aren't shown.
     ///
-    /// Note, the numeric values aren't truncated in Excel, this option just
-    /// controls the display of the number.
+    /// The highlight is added as a conditional format so it doesn't change the
+    /// number formats of the cells. The format can be changed via
+    /// [`set_diff_format()`](PolarsXlsxWriter::set_diff_format). Only the
+    /// first worksheet is highlighted if the rows are split by
+    /// [`set_overflow_policy()`](PolarsXlsxWriter::set_overflow_policy).
     ///
     /// # Parameters
     ///
-    /// * `format` - A `rust_xlsxwriter` [`Format`] or an Excel number format
-    ///   string that can be converted to a `Format`.
+    /// * `df_old` - The Polars dataframe to compare against.
+    /// * `df_new` - The Polars dataframe to write.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ColumnNotFound`] error if the diff key column isn't in
+    /// both dataframes or a [`PolarsError::ComputeError`] that wraps a
+    /// `rust_xlsxwriter` [`XlsxError`](rust_xlsxwriter::XlsxError) error.
     ///
     /// # Examples
     ///
     /// An example of writing a Polar Rust dataframe to an Excel file. This
-    /// demonstrates setting an Excel number format for floats.
+    /// demonstrates highlighting the cells that have changed from a previous
+    /// version of the dataframe.
     ///
     /// ```
-    /// # // This code is available in examples/write_excel_float_format.rs
+    /// # // This code is available in examples/write_excel_write_dataframe_diff.rs
     /// #
     /// # use polars::prelude::*;
     /// #
     /// use polars_excel_writer::PolarsXlsxWriter;
     ///
     /// fn main() -> PolarsResult<()> {
-    ///     // Create a sample dataframe for the example.
-    ///     let df: DataFrame = df!(
-    ///         "Float" => &[1000.0, 2000.22, 3000.333, 4000.4444],
-    ///     )
-    ///     .unwrap();
+    ///     // Create sample dataframes for the example.
+    ///     let df_old: DataFrame = df!(
+    ///         "Id" => &[1, 2, 3],
+    ///         "Region" => &["North", "South", "East"],
+    ///         "Sales" => &[100, 200, 300],
+    ///     )?;
+    ///
+    ///     let df_new: DataFrame = df!(
+    ///         "Id" => &[1, 3, 2, 4],
+    ///         "Region" => &["North", "East", "South", "West"],
+    ///         "Sales" => &[100, 350, 200, 400],
+    ///     )?;
     ///
     ///     // Write the dataframe to an Excel file.
     ///     let mut xlsx_writer = PolarsXlsxWriter::new();
     ///
-    ///     // Set the float format.
-    ///     xlsx_writer.set_float_format("#,##0.00");
+    ///     // Match the rows of the dataframes by the "Id" column.
+    ///     xlsx_writer.set_diff_key_column("Id");
     ///
-    ///     // Write the dataframe to Excel.
-    ///     xlsx_writer.write_dataframe(&df)?;
+    ///     // Write the new dataframe and highlight the changed cells.
+    ///     xlsx_writer.write_dataframe_diff(&df_old, &df_new)?;
     ///
     ///     // Save the file to disk.
     ///     xlsx_writer.save("dataframe.xlsx")?;
@@ -769,38 +1365,75 @@ impl PolarsXlsxWriter {
     /// }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/excelwriter_float_format.png">
-    ///
-    pub fn set_float_format(&mut self, format: impl Into<Format>) -> &mut PolarsXlsxWriter {
-        self.options.float_format = format.into();
-        self
+    pub fn write_dataframe_diff(
+        &mut self,
+        df_old: &DataFrame,
+        df_new: &DataFrame,
+    ) -> PolarsResult<WrittenRange> {
+        // Match the rows before writing so that key errors are raised first.
+        let old_rows = Self::diff_matching_rows(df_old, df_new, &self.options)?;
+
+        // Apply any row filter here so that the highlighted rows can be mapped
+        // to the rows that are written.
+        let (df_new, old_rows) = match Self::selected_row_runs(df_new, &self.options) {
+            Some(runs) => {
+                let old_rows = runs
+                    .iter()
+                    .flat_map(|(first_row, num_rows)| &old_rows[*first_row..first_row + num_rows])
+                    .copied()
+                    .collect();
+
+                (Cow::Owned(Self::take_row_runs(df_new, &runs)?), old_rows)
+            }
+            None => (Cow::Borrowed(df_new), old_rows),
+        };
+
+        let row_filter = self.options.row_filter.take();
+        let range = self.write_dataframe_with_overflow(&df_new, 0, 0, None);
+        self.options.row_filter = row_filter;
+
+        let range = range?;
+        self.add_diff_highlights(df_old, &df_new, &old_rows, &range)?;
+
+        Ok(range)
     }
 
-    /// Set the Excel number precision for floats.
+    /// Write a dataframe to a worksheet per value of a column.
     ///
-    /// Set the number precision of all floats exported from the dataframe to
-    /// Excel. The precision is converted to an Excel number format (see
-    /// [`set_float_format()`](PolarsXlsxWriter::set_float_format) above), so for
-    /// example 3 is converted to the Excel format `0.000`.
+    /// Partitions the dataframe by the values of the `by` column and writes
+    /// each partition to its own worksheet, in the order that the values first
+    /// occur. The worksheets are named after the values. This replaces the
+    /// usual group by and loop code required to split a dataframe into
+    /// worksheets.
     ///
-    /// Note, the numeric values aren't truncated in Excel, this option just
-    /// controls the display of the number.
+    /// The worksheet names are adjusted to follow the Excel rules. Invalid
+    /// characters such as `/` or `:` are replaced with underscores, names are
+    /// truncated to 31 characters and duplicate names, which are case
+    /// insensitive in Excel, are given a suffix such as ` (2)`. Null values
+    /// are written to a worksheet named `null`.
+    ///
+    /// The first partition is written to the current worksheet if it hasn't
+    /// been used. The others are written to new worksheets at the end of the
+    /// workbook.
     ///
     /// # Parameters
     ///
-    /// * `precision` - The floating point precision in the Excel range 1-30.
+    /// * `df` - A Polars dataframe.
+    /// * `by` - The name of the column to partition the dataframe by.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ColumnNotFound`] error if the `by` column isn't in the
+    /// dataframe or a [`PolarsError::ComputeError`] that wraps a
+    /// `rust_xlsxwriter` [`XlsxError`](rust_xlsxwriter::XlsxError) error.
     ///
     /// # Examples
     ///
-    /// An example of writing a Polar Rust dataframe to an Excel file. This example
-    /// demonstrates how to set the precision of the float output. Setting the
-    /// precision to 3 is equivalent to an Excel number format of `0.000`.
+    /// An example of writing a Polar Rust dataframe to an Excel file with a
+    /// worksheet for each value of a column.
     ///
     /// ```
-    /// # // This code is available in examples/write_excel_float_precision.rs
+    /// # // This code is available in examples/write_excel_write_dataframe_partitioned.rs
     /// #
     /// # use polars::prelude::*;
     /// #
@@ -809,18 +1442,16 @@ impl PolarsXlsxWriter {
     /// fn main() -> PolarsResult<()> {
     ///     // Create a sample dataframe for the example.
     ///     let df: DataFrame = df!(
-    ///         "Float" => &[1.0, 2.22, 3.333, 4.4444],
-    ///     )
-    ///     .unwrap();
+    ///         "Region" => &["North", "South", "North", "East", "South"],
+    ///         "Quarter" => &["Q1", "Q1", "Q2", "Q2", "Q3"],
+    ///         "Sales" => &[100, 200, 300, 400, 500],
+    ///     )?;
     ///
     ///     // Write the dataframe to an Excel file.
     ///     let mut xlsx_writer = PolarsXlsxWriter::new();
     ///
-    ///     // Set the float precision.
-    ///     xlsx_writer.set_float_precision(3);
-    ///
-    ///     // Write the dataframe to Excel.
-    ///     xlsx_writer.write_dataframe(&df)?;
+    ///     // Write the data for each region to its own worksheet.
+    ///     xlsx_writer.write_dataframe_partitioned(&df, "Region")?;
     ///
     ///     // Save the file to disk.
     ///     xlsx_writer.save("dataframe.xlsx")?;
@@ -829,125 +1460,198 @@ impl PolarsXlsxWriter {
     /// }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/excelwriter_float_precision.png">
-    ///
-    pub fn set_float_precision(&mut self, precision: usize) -> &mut PolarsXlsxWriter {
-        if (1..=30).contains(&precision) {
-            let precision = "0".repeat(precision);
-            self.options.float_format = Format::new().set_num_format(format!("0.{precision}"));
+    pub fn write_dataframe_partitioned(
+        &mut self,
+        df: &DataFrame,
+        by: &str,
+    ) -> PolarsResult<Vec<WrittenRange>> {
+        let partitions = df.partition_by_stable([by], true)?;
+
+        let mut sheet_names: HashSet<String> = self
+            .workbook
+            .worksheets()
+            .iter()
+            .map(|worksheet| worksheet.name().to_lowercase())
+            .collect();
+
+        let mut ranges = vec![];
+        for (index, partition) in partitions.iter().enumerate() {
+            let key = partition.column(by)?.get(0)?;
+            let key = key
+                .get_str()
+                .map_or_else(|| key.to_string(), str::to_string);
+
+            // Use the initial worksheet for the first partition if it hasn't
+            // been used, otherwise add a new worksheet.
+            let use_current =
+                index == 0 && !self.is_worksheet_used && self.workbook.worksheets().len() == 1;
+
+            if use_current {
+                let current_name = self.workbook.worksheets()[0].name().to_lowercase();
+                sheet_names.remove(&current_name);
+            } else {
+                self.add_new_worksheet();
+            }
+
+            let sheet_name = Self::lossy_sheet_name(&key, &sheet_names);
+            sheet_names.insert(sheet_name.to_lowercase());
+            self.set_worksheet_name(sheet_name)?;
+
+            ranges.push(self.write_dataframe_with_overflow(partition, 0, 0, None)?);
         }
-        self
+
+        Ok(ranges)
     }
 
-    /// Replace Null values in the exported dataframe with string values.
+    /// Add a chart that plots dataframe columns by name.
     ///
-    /// By default Null values in a dataframe aren't exported to Excel and will
-    /// appear as empty cells. If you wish you can specify a string such as
-    /// "Null", "NULL" or "N/A" as an alternative.
+    /// Adds a `rust_xlsxwriter` [`Chart`] to the current worksheet with a
+    /// series for each of the `y_columns` of the last dataframe written to the
+    /// worksheet. The series ranges are calculated from the layout of the
+    /// output table and the series names are taken from the column headers.
+    ///
+    /// This avoids having to calculate the row and column ranges of the data
+    /// manually, as shown in the example for
+    /// [`write_dataframe_to_worksheet()`](PolarsXlsxWriter::write_dataframe_to_worksheet).
+    ///
+    /// If the dataframe was split by the overflow policy only the data in the
+    /// first worksheet is plotted.
     ///
     /// # Parameters
     ///
-    /// * `value` - A replacement string for Null values.
+    /// * `chart_type` - A `rust_xlsxwriter` [`ChartType`] enum value.
+    /// * `x_column` - An optional column name for the chart categories or, for
+    ///   scatter charts, the X values.
+    /// * `y_columns` - The names of the columns to plot as chart series.
+    /// * `position` - The zero indexed `(row, col)` cell to insert the chart
+    ///   at.
+    ///
+    /// # Errors
+    ///
+    /// * [`PolarsError::ComputeError`] - If a dataframe hasn't been written to
+    ///   the worksheet or if it doesn't have any data rows.
+    /// * [`PolarsError::ColumnNotFound`] - If a column name isn't in the output
+    ///   table.
+    /// * [`PolarsError::ComputeError`] - A wrapped `rust_xlsxwriter`
+    ///   [`XlsxError`](rust_xlsxwriter::XlsxError) error.
     ///
     /// # Examples
     ///
     /// An example of writing a Polar Rust dataframe to an Excel file. This
-    /// demonstrates setting a value for Null values in the dataframe. The default
-    /// is to write them as blank cells.
+    /// demonstrates adding a chart that plots dataframe columns by name.
     ///
     /// ```
-    /// # // This code is available in examples/write_excel_null_values.rs
+    /// # // This code is available in examples/write_excel_add_chart_for_columns.rs
     /// #
     /// # use polars::prelude::*;
     /// #
-    /// # use polars_excel_writer::PolarsXlsxWriter;
-    /// #
-    /// # fn main() -> PolarsResult<()> {
-    /// #     // Create a dataframe with Null values.
-    /// #     let csv_string = "Foo,Bar\nNULL,B\nA,B\nA,NULL\nA,B\n";
-    /// #     let buffer = std::io::Cursor::new(csv_string);
-    /// #     let df = CsvReadOptions::default()
-    /// #         .map_parse_options(|parse_options| {
-    /// #             parse_options.with_null_values(Some(NullValues::AllColumnsSingle("NULL".into())))
-    /// #         })
-    /// #         .into_reader_with_file_handle(buffer)
-    /// #         .finish()
-    /// #         .unwrap();
-    /// #
-    ///     // Write the dataframe to an Excel file.
-    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::ChartType;
     ///
-    ///     // Set an output string value for Null.
-    ///     xlsx_writer.set_null_value("Null");
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Month" => &["Jan", "Feb", "Mar", "Apr", "May", "Jun"],
+    ///         "Sales" => &[100, 120, 140, 130, 150, 170],
+    ///         "Costs" => &[80, 90, 85, 100, 110, 105],
+    ///     )?;
     ///
-    ///     // Write the dataframe to Excel.
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
     ///     xlsx_writer.write_dataframe(&df)?;
     ///
+    ///     // Add a chart of the "Sales" and "Costs" columns by month.
+    ///     xlsx_writer.add_chart_for_columns(
+    ///         ChartType::Column,
+    ///         Some("Month"),
+    ///         &["Sales", "Costs"],
+    ///         (1, 4),
+    ///     )?;
+    ///
     ///     // Save the file to disk.
     ///     xlsx_writer.save("dataframe.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
-    ///
-    /// Output file:
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/excelwriter_null_values.png">
+    ///     Ok(())
+    /// }
+    /// ```
     ///
-    pub fn set_null_value(&mut self, value: impl Into<String>) -> &mut PolarsXlsxWriter {
-        self.options.null_value = Some(value.into());
-        self
+    pub fn add_chart_for_columns(
+        &mut self,
+        chart_type: ChartType,
+        x_column: Option<&str>,
+        y_columns: &[&str],
+        position: (u32, u16),
+    ) -> PolarsResult<()> {
+        let chart = self.chart_for_columns(chart_type, x_column, y_columns)?;
+
+        self.worksheet()?
+            .insert_chart(position.0, position.1, &chart)?;
+
+        Ok(())
     }
 
-    /// Replace NaN values in the exported dataframe with string values.
+    /// Add a chartsheet that plots dataframe columns by name.
     ///
-    /// By default [`f64::NAN`] values in a dataframe are exported as the string
-    /// "NAN" since Excel does not support NaN values.
+    /// Adds an Excel chartsheet, a worksheet that only contains a full page
+    /// chart, with a series for each of the `y_columns` of the last dataframe
+    /// written. The series are set up in the same way as
+    /// [`add_chart_for_columns()`](PolarsXlsxWriter::add_chart_for_columns).
+    /// This is useful for dashboard style workbooks with full page charts.
     ///
-    /// This method can be used to supply an alternative string value. See the
-    /// example below.
+    /// The chartsheet is inserted before the current worksheet so that any
+    /// later dataframes are still written to the current worksheet. The
+    /// chartsheets are named `Chart1`, `Chart2`, etc., by default. The name
+    /// can be changed via the returned chartsheet.
     ///
     /// # Parameters
     ///
-    /// * `value` - A replacement string for Null values.
+    /// * `chart_type` - A `rust_xlsxwriter` [`ChartType`] enum value.
+    /// * `x_column` - An optional column name for the chart categories or, for
+    ///   scatter charts, the X values.
+    /// * `y_columns` - The names of the columns to plot as chart series.
+    ///
+    /// # Errors
+    ///
+    /// * [`PolarsError::ComputeError`] - If a dataframe hasn't been written to
+    ///   the worksheet or if it doesn't have any data rows.
+    /// * [`PolarsError::ColumnNotFound`] - If a column name isn't in the output
+    ///   table.
+    /// * [`PolarsError::ComputeError`] - A wrapped `rust_xlsxwriter`
+    ///   [`XlsxError`](rust_xlsxwriter::XlsxError) error.
     ///
     /// # Examples
     ///
     /// An example of writing a Polar Rust dataframe to an Excel file. This
-    /// demonstrates handling NaN and Infinity values with custom string
-    /// representations.
+    /// demonstrates adding a chartsheet that plots dataframe columns by name.
     ///
     /// ```
-    /// # // This code is available in examples/write_excel_set_nan_value.rs
+    /// # // This code is available in examples/write_excel_add_chartsheet.rs
+    /// #
+    /// # use polars::prelude::*;
     /// #
-    /// use polars::prelude::*;
-    ///
     /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::ChartType;
     ///
     /// fn main() -> PolarsResult<()> {
     ///     // Create a sample dataframe for the example.
     ///     let df: DataFrame = df!(
-    ///         "Default" => &["NAN", "INF", "-INF"],
-    ///         "Custom" => &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY],
+    ///         "Month" => &["Jan", "Feb", "Mar", "Apr", "May", "Jun"],
+    ///         "Sales" => &[100, 120, 140, 130, 150, 170],
+    ///         "Costs" => &[80, 90, 85, 100, 110, 105],
     ///     )?;
     ///
     ///     // Write the dataframe to an Excel file.
     ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///     xlsx_writer.write_dataframe(&df)?;
     ///
-    ///     // Set custom values for NaN, Infinity, and -Infinity.
-    ///     xlsx_writer.set_nan_value("NaN");
-    ///     xlsx_writer.set_infinity_value("Infinity");
-    ///     xlsx_writer.set_neg_infinity_value("-Infinity");
-    ///
-    ///     // Autofit the output data, for clarity.
-    ///     xlsx_writer.set_autofit(true);
+    ///     // Add a chartsheet of the "Sales" and "Costs" columns by month.
+    ///     let chartsheet = xlsx_writer.add_chartsheet(
+    ///         ChartType::Line,
+    ///         Some("Month"),
+    ///         &["Sales", "Costs"],
+    ///     )?;
     ///
-    ///     // Write the dataframe to Excel.
-    ///     xlsx_writer.write_dataframe(&df)?;
+    ///     chartsheet.set_name("Sales Chart")?;
     ///
     ///     // Save the file to disk.
     ///     xlsx_writer.save("dataframe.xlsx")?;
@@ -956,72 +1660,153 @@ impl PolarsXlsxWriter {
     /// }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img src="https://rustxlsxwriter.github.io/images/write_excel_set_nan_value.png">
-    ///
-    pub fn set_nan_value(&mut self, value: impl Into<String>) -> &mut PolarsXlsxWriter {
-        self.options.nan_value = Some(value.into());
-        self
+    pub fn add_chartsheet(
+        &mut self,
+        chart_type: ChartType,
+        x_column: Option<&str>,
+        y_columns: &[&str],
+    ) -> PolarsResult<&mut Worksheet> {
+        let chart = self.chart_for_columns(chart_type, x_column, y_columns)?;
+
+        // Move the chartsheet before the current worksheet, which is the last
+        // worksheet in the workbook.
+        self.workbook.add_chartsheet().insert_chart(0, 0, &chart)?;
+
+        let index = self.workbook.worksheets().len() - 2;
+        self.shift_sheet_indices(index);
+
+        let worksheets = self.workbook.worksheets_mut();
+        let chartsheet = worksheets.pop().unwrap();
+        worksheets.insert(index, chartsheet);
+
+        Ok(&mut worksheets[index])
     }
 
-    /// Replace Infinity values in the exported dataframe with string values.
-    ///
-    /// By default [`f64::INFINITY`] values in a dataframe are exported as the
-    /// string "INF" since Excel does not support Infinity values.
-    ///
-    /// This method can be used to supply an alternative string value. See the
-    /// `set_nan_value()` example above.
-    ///
-    /// # Parameters
-    ///
-    /// * `value` - A replacement string for Null values.
-    ///
-    pub fn set_infinity_value(&mut self, value: impl Into<String>) -> &mut PolarsXlsxWriter {
-        self.options.infinity_value = Some(value.into());
-        self
+    // Create a chart with a series for each of the `y_columns` of the last
+    // dataframe written.
+    fn chart_for_columns(
+        &mut self,
+        chart_type: ChartType,
+        x_column: Option<&str>,
+        y_columns: &[&str],
+    ) -> PolarsResult<Chart> {
+        self.write_appended_frame()?;
+
+        let Some(layout) = &self.last_layout else {
+            polars_bail!(ComputeError: "a dataframe must be written before adding a chart");
+        };
+
+        let range = &layout.range;
+        let sheet_name = range.sheet_name.as_str();
+        let header_row = range.first_row;
+        let first_row = range.first_row + u32::from(layout.has_header);
+        let last_row = range.last_row - u32::from(layout.has_total_row);
+
+        if first_row > last_row {
+            polars_bail!(ComputeError: "the dataframe doesn't have any data rows to chart");
+        }
+
+        // Get the worksheet column of a dataframe column by name.
+        let column_number = |name: &str| -> PolarsResult<u16> {
+            match layout.column_names.iter().position(|column| column == name) {
+                Some(index) => Ok(range.first_col + index as u16),
+                None => polars_bail!(ColumnNotFound: "{}", name),
+            }
+        };
+
+        let x_col = x_column.map(column_number).transpose()?;
+
+        let mut chart = Chart::new(chart_type);
+        for name in y_columns {
+            let col = column_number(name)?;
+            let series = chart
+                .add_series()
+                .set_values((sheet_name, first_row, col, last_row, col));
+
+            if layout.has_header {
+                series.set_name((sheet_name, header_row, col));
+            } else {
+                series.set_name(*name);
+            }
+
+            if let Some(x_col) = x_col {
+                series.set_categories((sheet_name, first_row, x_col, last_row, x_col));
+            }
+        }
+
+        Ok(chart)
     }
 
-    /// Replace Negative Infinity values in the exported dataframe with string
-    /// values.
+    /// Save the Workbook as an xlsx file.
     ///
-    /// By default [`f64::NEG_INFINITY`] values in a dataframe are exported as
-    /// the string "-INF" since Excel does not support Infinity values.
+    /// The `save()` method writes all the workbook and worksheet data to
+    /// a new xlsx file. It will overwrite any existing file.
     ///
-    /// This method can be used to supply an alternative string value. See the
-    /// `set_nan_value()` example above.
+    /// The method can be called multiple times so it is possible to get
+    /// incremental files at different stages of a process, or to save the same
+    /// Workbook object to different paths. However, `save()` is an
+    /// expensive operation which assembles multiple files into an xlsx/zip
+    /// container so for performance reasons you shouldn't call it
+    /// unnecessarily.
     ///
     /// # Parameters
     ///
-    /// * `value` - A replacement string for Null values.
+    /// * `path` - The path of the new Excel file to create as a `&str` or as a
+    ///   [`std::path`] `Path` or `PathBuf` instance.
     ///
-    pub fn set_neg_infinity_value(&mut self, value: impl Into<String>) -> &mut PolarsXlsxWriter {
-        self.options.neg_infinity_value = Some(value.into());
-        self
-    }
-
-    /// Simulate autofit for columns in the dataframe output.
+    /// # Errors
     ///
-    /// Use a simulated autofit to adjust dataframe columns to the maximum
-    /// string or number widths.
+    /// A [`PolarsError::ComputeError`] that wraps a `rust_xlsxwriter`
+    /// [`XlsxError`](rust_xlsxwriter::XlsxError) error.
     ///
-    /// **Note**: There are several limitations to this autofit method, see the
-    /// `rust_xlsxwriter` docs on [`Worksheet::autofit()`] for details.
+    pub fn save<P: AsRef<Path>>(&mut self, path: P) -> PolarsResult<()> {
+        self.prepare_workbooks()?;
+
+        // Workbooks with row groups are saved via a buffer, see
+        // set_row_grouping().
+        if self.has_row_groups(0) {
+            std::fs::write(&path, self.workbook_to_buffer(0)?)?;
+        } else {
+            self.workbook.save(&path)?;
+        }
+
+        // Save any additional files for rows split by the overflow policy.
+        for index in 1..=self.overflow_workbooks.len() {
+            let overflow_path = Self::overflow_path(path.as_ref(), index + 1);
+
+            if self.has_row_groups(index) {
+                std::fs::write(&overflow_path, self.workbook_to_buffer(index)?)?;
+            } else {
+                self.overflow_workbooks[index - 1].save(overflow_path)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Save the Workbook as an xlsx file and return it as a buffer.
     ///
-    /// [`Worksheet::autofit()`]:
-    ///     https://docs.rs/rust_xlsxwriter/latest/rust_xlsxwriter/worksheet/struct.Worksheet.html#method.autofit
+    /// The `save_to_buffer()` method is similar to
+    /// [`save()`](PolarsXlsxWriter::save) except that it returns the xlsx file
+    /// as a `Vec<u8>` buffer instead of writing it to disk. This is useful in
+    /// web services, for example to return the file as the body of an HTTP
+    /// response, or in other environments where the file system isn't
+    /// available.
     ///
-    /// # Parameters
+    /// # Errors
     ///
-    /// * `autofit` - Turn autofit on/off. It is off by default.
+    /// A [`PolarsError::ComputeError`] that wraps a `rust_xlsxwriter`
+    /// [`XlsxError`](rust_xlsxwriter::XlsxError) error. An error is also
+    /// raised if the [`OverflowPolicy::SplitFiles`] overflow policy has
+    /// created additional files, since they can't be returned in the buffer.
     ///
     /// # Examples
     ///
-    /// An example of writing a Polar Rust dataframe to an Excel file. This example
-    /// demonstrates autofitting column widths in the output worksheet.
+    /// An example of writing a Polar Rust dataframe to an Excel file in
+    /// memory.
     ///
     /// ```
-    /// # // This code is available in examples/write_excel_autofit.rs
+    /// # // This code is available in examples/write_excel_save_to_buffer.rs
     /// #
     /// # use polars::prelude::*;
     /// #
@@ -1030,491 +1815,646 @@ impl PolarsXlsxWriter {
     /// fn main() -> PolarsResult<()> {
     ///     // Create a sample dataframe for the example.
     ///     let df: DataFrame = df!(
-    ///         "Col 1" => &["A", "B", "C", "D"],
-    ///         "Column 2" => &["A", "B", "C", "D"],
-    ///         "Column 3" => &["Hello", "World", "Hello, world", "Ciao"],
-    ///         "Column 4" => &[1234567, 12345678, 123456789, 1234567],
+    ///         "String" => &["North", "South", "East", "West"],
+    ///         "Int" => &[1, 2, 3, 4],
+    ///         "Float" => &[1.0, 2.22, 3.333, 4.4444],
     ///     )?;
     ///
-    ///     // Create a new Excel writer.
+    ///     // Write the dataframe to an Excel file in memory.
     ///     let mut xlsx_writer = PolarsXlsxWriter::new();
-    ///
-    ///     // Autofit the output data.
-    ///     xlsx_writer.set_autofit(true);
-    ///
-    ///     // Write the dataframe to Excel.
     ///     xlsx_writer.write_dataframe(&df)?;
     ///
-    ///     // Save the file to disk.
-    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///     let buffer = xlsx_writer.save_to_buffer()?;
+    ///
+    ///     println!("File size: {}", buffer.len());
     ///
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/excelwriter_autofit.png">
-    ///
-    pub fn set_autofit(&mut self, autofit: bool) -> &mut PolarsXlsxWriter {
-        self.options.use_autofit = autofit;
-        self
+    pub fn save_to_buffer(&mut self) -> PolarsResult<Vec<u8>> {
+        self.prepare_workbooks()?;
+
+        if !self.overflow_workbooks.is_empty() {
+            polars_bail!(
+                ComputeError:
+                "save_to_buffer() doesn't support files split by the SplitFiles overflow policy"
+            );
+        }
+
+        let buffer = self.workbook_to_buffer(0)?;
+
+        Ok(buffer)
     }
 
-    /// Set the worksheet zoom factor.
+    /// Save dataframes to separate xlsx files with the writer options.
     ///
-    /// Set the worksheet zoom factor in the range `10 <= zoom <= 400`.
+    /// Write each named dataframe to its own xlsx file, `{name}.xlsx`, in a
+    /// directory using the options set for the writer. This is useful for
+    /// pipelines that export a workbook per customer or per region with the
+    /// same formatting. The writer itself isn't changed, so it can be reused.
+    ///
+    /// The files are written with a copy of the writer options, document
+    /// properties and other workbook settings. Dataframes already written via
+    /// the writer aren't included. Any existing files with the same names are
+    /// overwritten.
+    ///
+    /// With the `parallel` feature the files are written in parallel on the
+    /// `rayon` thread pool. Otherwise they are written in order.
+    ///
+    /// The method returns the paths of the files that were written, in the
+    /// same order as the dataframes.
     ///
     /// # Parameters
     ///
-    /// * `zoom` - The worksheet zoom level. The default zoom level is 100.
+    /// * `dir` - The directory to write the files to. It must exist.
+    /// * `frames` - A slice of file names, without the `.xlsx` extension, and
+    ///   dataframes. The names must be plain file names without path
+    ///   separators or characters such as `:`, `*` or `?`.
+    ///
+    /// # Errors
+    ///
+    /// * [`PolarsError::Duplicate`] - If a file name is used more than once.
+    /// * [`PolarsError::ComputeError`] - If a file name is empty, is `.` or
+    ///   `..`, or contains `/`, `\`, control characters or characters that
+    ///   aren't valid in Windows file names. No files are written in this
+    ///   case.
+    /// * [`PolarsError::ComputeError`] - A wrapped `rust_xlsxwriter`
+    ///   [`XlsxError`](rust_xlsxwriter::XlsxError) error if a dataframe or
+    ///   file can't be written.
     ///
     /// # Examples
     ///
-    /// An example of writing a Polar Rust dataframe to an Excel file. This
-    /// demonstrates setting the worksheet zoom level.
+    /// An example of writing the rows of a Polar Rust dataframe for each
+    /// customer to a separate Excel file.
     ///
     /// ```
-    /// # // This code is available in examples/write_excel_set_zoom.rs
+    /// # // This code is available in examples/write_excel_save_partitioned.rs
     /// #
     /// # use polars::prelude::*;
     /// #
-    /// # use polars_excel_writer::PolarsXlsxWriter;
-    /// #
-    /// # fn main() -> PolarsResult<()> {
-    /// #     // Create a sample dataframe for the example.
-    /// #     let df: DataFrame = df!(
-    /// #         "String" => &["North", "South", "East", "West"],
-    /// #         "Int" => &[1, 2, 3, 4],
-    /// #         "Float" => &[1.0, 2.22, 3.333, 4.4444],
-    /// #     )?;
-    /// #
-    ///     // Write the dataframe to an Excel file.
-    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    /// use polars_excel_writer::PolarsXlsxWriter;
     ///
-    ///     // Set the worksheet zoom level.
-    ///     xlsx_writer.set_zoom(200);
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Customer" => &["Acme", "Globex", "Acme", "Initech", "Globex"],
+    ///         "Item" => &["Bolts", "Nuts", "Screws", "Washers", "Bolts"],
+    ///         "Amount" => &[120.5, 99.0, 45.25, 10.0, 310.75],
+    ///     )?;
     ///
-    ///     // Write the dataframe to Excel.
-    ///     xlsx_writer.write_dataframe(&df)?;
+    ///     // Split the dataframe into a dataframe for each customer.
+    ///     let mut frames = vec![];
+    ///     for partition in df.partition_by_stable(["Customer"], true)? {
+    ///         let customer = partition.column("Customer")?.str()?.get(0).unwrap_or_default();
+    ///         frames.push((customer.to_string(), partition));
+    ///     }
     ///
-    ///     // Save the file to disk.
-    ///     xlsx_writer.save("dataframe.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
+    ///     // Set up the writer options that are used for all of the files.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///     xlsx_writer.set_autofit(true);
+    ///     xlsx_writer.set_float_format("$#,##0.00");
     ///
-    /// Output file:
+    ///     // Write a file for each customer.
+    ///     let dir = std::env::temp_dir();
+    ///     let paths = xlsx_writer.save_partitioned(&dir, &frames)?;
     ///
-    /// <img src="https://rustxlsxwriter.github.io/images/write_excel_set_zoom.png">
+    ///     for path in paths {
+    ///         println!("Wrote {}", path.display());
+    ///     }
     ///
-    pub fn set_zoom(&mut self, zoom: u16) -> &mut PolarsXlsxWriter {
-        self.options.zoom = zoom;
-        self
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn save_partitioned<P: AsRef<Path>>(
+        &self,
+        dir: P,
+        frames: &[(String, DataFrame)],
+    ) -> PolarsResult<Vec<PathBuf>> {
+        let mut names = HashSet::new();
+        for (name, _) in frames {
+            Self::check_partition_file_name(name)?;
+
+            if !names.insert(name.as_str()) {
+                polars_bail!(Duplicate: "file name '{}' set in save_partitioned()", name);
+            }
+        }
+
+        let mut partitions: Vec<_> = frames
+            .iter()
+            .map(|(name, df)| {
+                let path = dir.as_ref().join(format!("{name}.xlsx"));
+                (self.partition_writer(), path, df)
+            })
+            .collect();
+
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+
+            partitions
+                .par_iter_mut()
+                .try_for_each(|(xlsx_writer, path, df)| {
+                    Self::save_partition(xlsx_writer, path, df)
+                })?;
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        partitions
+            .iter_mut()
+            .try_for_each(|(xlsx_writer, path, df)| Self::save_partition(xlsx_writer, path, df))?;
+
+        Ok(partitions.into_iter().map(|(_, path, _)| path).collect())
     }
 
-    /// Set the option to turn on/off the screen gridlines.
+    /// Save the Workbook as an xlsx file without blocking an async runtime.
     ///
-    /// The `set_screen_gridlines()` method is use to turn on/off gridlines on
-    /// displayed worksheet. It is on by default.
+    /// The `save_async()` method is an async version of
+    /// [`save()`](PolarsXlsxWriter::save) for use in async code such as `axum`
+    /// or `actix` web services. The assembly of the xlsx file, which is the
+    /// most expensive part of saving, is offloaded to the `tokio` blocking
+    /// thread pool and the file is then written using `tokio` async file IO.
+    ///
+    /// It requires the `async` feature and a `tokio` runtime.
     ///
     /// # Parameters
     ///
-    /// * `enable` - Turn the property on/off. It is on by default.
+    /// * `path` - The path of the new Excel file to create as a `&str` or as a
+    ///   [`std::path`] `Path` or `PathBuf` instance.
+    ///
+    /// # Errors
     ///
+    /// A [`PolarsError::ComputeError`] that wraps a `rust_xlsxwriter`
+    /// [`XlsxError`](rust_xlsxwriter::XlsxError) error, or a [`PolarsError`]
+    /// IO error if the file can't be written.
     ///
     /// # Examples
     ///
-    /// An example of writing a Polar Rust dataframe to an Excel file. This
-    /// demonstrates turning off the screen gridlines.
+    /// An example of writing a Polar Rust dataframe to an Excel file from
+    /// async code.
     ///
     /// ```
-    /// # // This code is available in examples/write_excel_set_screen_gridlines.rs
+    /// # // This code is available in examples/write_excel_save_async.rs
     /// #
     /// # use polars::prelude::*;
     /// #
-    /// # use polars_excel_writer::PolarsXlsxWriter;
-    /// #
-    /// # fn main() -> PolarsResult<()> {
-    /// #     // Create a sample dataframe for the example.
-    /// #     let df: DataFrame = df!(
-    /// #         "String" => &["North", "South", "East", "West"],
-    /// #         "Int" => &[1, 2, 3, 4],
-    /// #         "Float" => &[1.0, 2.22, 3.333, 4.4444],
-    /// #     )?;
-    /// #
-    ///     // Write the dataframe to an Excel file.
-    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    /// use polars_excel_writer::PolarsXlsxWriter;
     ///
-    ///     // Turn off the screen gridlines.
-    ///     xlsx_writer.set_screen_gridlines(false);
+    /// fn main() -> PolarsResult<()> {
+    ///     let runtime = tokio::runtime::Builder::new_current_thread().build()?;
     ///
-    ///     // Write the dataframe to Excel.
-    ///     xlsx_writer.write_dataframe(&df)?;
+    ///     runtime.block_on(write_report())
+    /// }
     ///
-    ///     // Save the file to disk.
-    ///     xlsx_writer.save("dataframe.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
+    /// async fn write_report() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "String" => &["North", "South", "East", "West"],
+    ///         "Int" => &[1, 2, 3, 4],
+    ///         "Float" => &[1.0, 2.22, 3.333, 4.4444],
+    ///     )?;
     ///
-    /// Output file:
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///     xlsx_writer.write_dataframe(&df)?;
     ///
-    /// <img src="https://rustxlsxwriter.github.io/images/write_excel_set_screen_gridlines.png">
+    ///     // Save the file to disk without blocking the runtime.
+    ///     xlsx_writer.save_async("dataframe.xlsx").await?;
     ///
-    pub fn set_screen_gridlines(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
-        self.options.screen_gridlines = enable;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    #[cfg(feature = "async")]
+    pub async fn save_async<P: AsRef<Path>>(&mut self, path: P) -> PolarsResult<()> {
+        self.prepare_workbooks()?;
 
-        self
+        let buffers = self.save_to_buffers_async().await?;
+
+        // Save the main file and any additional files for rows split by the
+        // overflow policy.
+        for (index, buffer) in buffers.into_iter().enumerate() {
+            let path = if index == 0 {
+                path.as_ref().to_path_buf()
+            } else {
+                Self::overflow_path(path.as_ref(), index + 1)
+            };
+
+            tokio::fs::write(path, buffer).await?;
+        }
+
+        Ok(())
     }
 
-    /// Freeze panes in a worksheet.
+    /// Save the Workbook as an xlsx file to an async writer.
     ///
-    /// The `set_freeze_panes()` method can be used to divide a worksheet into
-    /// horizontal or vertical regions known as panes and to “freeze” these
-    /// panes so that the splitter bars are not visible.
+    /// The `save_to_writer_async()` method is similar to
+    /// [`save_async()`](PolarsXlsxWriter::save_async) except that it writes
+    /// the xlsx file to a `tokio` [`AsyncWrite`](tokio::io::AsyncWrite)
+    /// object, such as a file, a socket or the body of an HTTP response.
     ///
-    /// As with Excel the split is to the top and left of the cell. So to freeze
-    /// the top row and leftmost column you would use `(1, 1)` (zero-indexed).
+    /// It requires the `async` feature and a `tokio` runtime.
     ///
-    /// You can set one of the row and col parameters as 0 if you do not want
-    /// either the vertical or horizontal split. For example a common
-    /// requirement is to freeze the top row which is done with the arguments
-    /// `(1, 0)` see below.
+    /// # Parameters
     ///
+    /// * `writer` - An object that implements the `tokio`
+    ///   [`AsyncWrite`](tokio::io::AsyncWrite) trait.
     ///
-    /// # Parameters
+    /// # Errors
     ///
-    /// * `row` - The zero indexed row number.
-    /// * `col` - The zero indexed column number.
+    /// A [`PolarsError::ComputeError`] that wraps a `rust_xlsxwriter`
+    /// [`XlsxError`](rust_xlsxwriter::XlsxError) error, or a [`PolarsError`]
+    /// IO error if the file can't be written. An error is also raised if the
+    /// [`OverflowPolicy::SplitFiles`] overflow policy has created additional
+    /// files, since they can't be written to the same writer.
     ///
+    #[cfg(feature = "async")]
+    pub async fn save_to_writer_async<W>(&mut self, mut writer: W) -> PolarsResult<()>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        use tokio::io::AsyncWriteExt;
+
+        self.prepare_workbooks()?;
+
+        // Check for additional files before any of the workbooks are saved.
+        if !self.overflow_workbooks.is_empty() {
+            polars_bail!(
+                ComputeError:
+                "save_to_writer_async() doesn't support files split by the SplitFiles overflow policy"
+            );
+        }
+
+        let buffers = self.save_to_buffers_async().await?;
+
+        for buffer in buffers {
+            writer.write_all(&buffer).await?;
+        }
+        writer.flush().await?;
+
+        Ok(())
+    }
+
+    /// Convert the writer to an [`ExcelWriter`] for use with the Polars
+    /// [`SerWriter`] interface.
+    ///
+    /// `ExcelWriter` implements the Polars `SerWriter` trait but its methods
+    /// only cover the most common options. This method converts a configured
+    /// `PolarsXlsxWriter`, with any of its options, to an `ExcelWriter` so
+    /// that the dataframe can be written with the idiomatic Polars
+    /// [`finish()`](SerWriter::finish) method.
+    ///
+    /// The dataframe is written to the current worksheet and the workbook is
+    /// saved to the writer, along with any other dataframes already written.
+    ///
+    /// # Parameters
+    ///
+    /// * `writer` - An object that implements the [`Write`], [`Seek`] and
+    ///   [`Send`] traits, such as a [`File`](std::fs::File).
     ///
     /// # Examples
     ///
     /// An example of writing a Polar Rust dataframe to an Excel file. This
-    /// demonstrates freezing the top row.
+    /// demonstrates using the Polars `SerWriter` interface with the
+    /// `PolarsXlsxWriter` options.
     ///
     /// ```
-    /// # // This code is available in examples/write_excel_set_freeze_panes.rs
-    /// #
-    /// # use polars::prelude::*;
-    /// #
-    /// # use polars_excel_writer::PolarsXlsxWriter;
-    /// #
-    /// # fn main() -> PolarsResult<()> {
-    /// #     // Create a sample dataframe for the example.
-    /// #     let df: DataFrame = df!(
-    /// #         "String" => &["North", "South", "East", "West"],
-    /// #         "Int" => &[1, 2, 3, 4],
-    /// #         "Float" => &[1.0, 2.22, 3.333, 4.4444],
-    /// #     )?;
+    /// # // This code is available in examples/write_excel_into_ser_writer.rs
     /// #
-    ///     // Write the dataframe to an Excel file.
-    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    /// use polars::prelude::*;
     ///
-    ///     // Freeze the top row.
-    ///     xlsx_writer.set_freeze_panes(1, 0);
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::Format;
     ///
-    ///     // Write the dataframe to Excel.
-    ///     xlsx_writer.write_dataframe(&df)?;
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let mut df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[1000.0, 2300.0, 1900.0, 2500.0],
+    ///     )?;
     ///
-    ///     // Save the file to disk.
-    ///     xlsx_writer.save("dataframe.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
+    ///     // Set up the writer with options that ExcelWriter doesn't support.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///     xlsx_writer
+    ///         .set_header_format(Format::new().set_bold().set_font_color("#1F497D"))
+    ///         .set_dtype_format(DataType::Float64, Format::new().set_num_format("#,##0.00"))
+    ///         .set_zoom(150);
     ///
-    /// Output file:
+    ///     // Create a new file object.
+    ///     let mut file = std::fs::File::create("dataframe.xlsx").unwrap();
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/write_excel_set_freeze_panes.png">
+    ///     // Write the dataframe to an Excel file using the Polars SerWriter
+    ///     // interface.
+    ///     xlsx_writer.into_ser_writer(&mut file).finish(&mut df)?;
     ///
-    pub fn set_freeze_panes(&mut self, row: u32, col: u16) -> &mut PolarsXlsxWriter {
-        self.options.freeze_cell = (row, col);
-
-        self
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn into_ser_writer<W>(self, writer: W) -> ExcelWriter<W>
+    where
+        W: Write + Seek + Send,
+    {
+        ExcelWriter::from_xlsx_writer(writer, self)
     }
 
-    /// Set the top most cell in the scrolling area of a freeze pane.
-    ///
-    /// This method is used in conjunction with the
-    /// [`PolarsXlsxWriter::set_freeze_panes()`] method to set the top most
-    /// visible cell in the scrolling range. For example you may want to freeze
-    /// the top row but have the worksheet pre-scrolled so that a cell other
-    /// than `(0, 0)` is visible in the scrolled area.
+    /// Turn on/off the dataframe header row in the Excel table. It is on by
+    /// default.
     ///
     /// # Parameters
     ///
-    /// * `row` - The zero indexed row number.
-    /// * `col` - The zero indexed column number.
-    ///
+    /// * `has_header` - Export dataframe with/without header.
     ///
     /// # Examples
     ///
     /// An example of writing a Polar Rust dataframe to an Excel file. This
-    /// demonstrates freezing the top row and setting a non-default first row
-    /// within the pane.
+    /// demonstrates saving the dataframe without a header.
     ///
     /// ```
-    /// # // This code is available in examples/write_excel_set_freeze_panes_top_cell.rs
+    /// # // This code is available in examples/write_excel_set_header.rs
     /// #
     /// # use polars::prelude::*;
     /// #
-    /// # use polars_excel_writer::PolarsXlsxWriter;
-    /// #
-    /// # fn main() -> PolarsResult<()> {
-    /// #     // Create a sample dataframe for the example.
-    /// #     let df: DataFrame = df!(
-    /// #         "String" => &["North", "South", "East", "West"],
-    /// #         "Int" => &[1, 2, 3, 4],
-    /// #         "Float" => &[1.0, 2.22, 3.333, 4.4444],
-    /// #     )?;
-    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "String" => &["North", "South", "East", "West"],
+    ///         "Int" => &[1, 2, 3, 4],
+    ///         "Float" => &[1.0, 2.22, 3.333, 4.4444],
+    ///     )
+    ///     .unwrap();
+    ///
     ///     // Write the dataframe to an Excel file.
     ///     let mut xlsx_writer = PolarsXlsxWriter::new();
     ///
-    ///     // Freeze the top row and set the first row in the range.
-    ///     xlsx_writer.set_freeze_panes(1, 0);
-    ///     xlsx_writer.set_freeze_panes_top_cell(3, 0);
+    ///     // Turn off the default header.
+    ///     xlsx_writer.set_header(false);
     ///
     ///     // Write the dataframe to Excel.
     ///     xlsx_writer.write_dataframe(&df)?;
     ///
     ///     // Save the file to disk.
     ///     xlsx_writer.save("dataframe.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
+    ///
+    ///     Ok(())
+    /// }
     /// ```
     ///
     /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/write_excel_set_freeze_panes_top_cell.png">
-    ///
-    pub fn set_freeze_panes_top_cell(&mut self, row: u32, col: u16) -> &mut PolarsXlsxWriter {
-        self.options.top_cell = (row, col);
-
-        self
-    }
-
-    /// Turn on/off the autofilter for the table header.
-    ///
-    /// By default Excel adds an autofilter to the header of a table. This
-    /// method can be used to turn it off if necessary.
-    ///
-    /// Note, you can call this method directly on a [`Table`] object which is
-    /// passed to [`PolarsXlsxWriter::set_table()`].
-    ///
-    /// # Parameters
-    ///
-    /// * `enable` - Turn the property on/off. It is on by default.
+    /// src="https://rustxlsxwriter.github.io/images/excelwriter_has_header_off.png">
     ///
-    pub fn set_autofilter(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
-        let table = self.options.table.clone().set_autofilter(enable);
+    pub fn set_header(&mut self, has_header: bool) -> &mut PolarsXlsxWriter {
+        let table = self.options.table.clone().set_header_row(has_header);
         self.options.table = table;
-
         self
     }
 
-    /// Set the worksheet table for the output dataframe.
-    ///
-    /// By default, and by convention with the Polars [`write_excel()`] method,
-    /// `PolarsXlsxWriter` adds an Excel worksheet table to each exported
-    /// dataframe.
-    ///
-    /// Tables in Excel are a way of grouping a range of cells into a single
-    /// entity that has common formatting or that can be referenced from
-    /// formulas. Tables can have column headers, autofilters, total rows,
-    /// column formulas and different formatting styles.
-    ///
-    /// The image below shows a default table in Excel with the default
-    /// properties shown in the ribbon bar.
-    ///
-    /// <img src="https://rustxlsxwriter.github.io/images/table_intro.png">
-    ///
-    /// The `set_table()` method allows you to pass a pre-configured
-    /// `rust_xlsxwriter` table and override any of the default [`Table`]
-    /// properties.
+    /// Set how the dataframe header is written.
     ///
-    /// [`write_excel()`]:
-    ///     https://pola-rs.github.io/polars/py-polars/html/reference/api/polars.DataFrame.write_excel.html#polars.DataFrame.write_excel
+    /// By default the dataframe column names are written as the header row of
+    /// an Excel worksheet table. This method sets whether the header is
+    /// written as a table header, as a normal row of cells without a table,
+    /// or not at all. See [`HeaderMode`] for the available options.
     ///
+    /// This is a combination of
+    /// [`set_header()`](PolarsXlsxWriter::set_header) and
+    /// [`set_use_table()`](PolarsXlsxWriter::set_use_table). The
+    /// `HeaderMode::None` option doesn't change whether a table is used. The
+    /// header format, if any, is applied to the written header row via
+    /// [`set_header_format()`](PolarsXlsxWriter::set_header_format).
     ///
     /// # Parameters
     ///
-    /// * `table` - A `rust_xlsxwriter` [`Table`] reference.
+    /// * `header_mode` - A [`HeaderMode`] enum value.
     ///
     /// # Examples
     ///
     /// An example of writing a Polar Rust dataframe to an Excel file. This
-    /// demonstrates setting properties of the worksheet table that wraps the
-    /// output dataframe.
+    /// demonstrates writing the header as a formatted row of cells instead of
+    /// a table header.
     ///
     /// ```
-    /// # // This code is available in examples/write_excel_set_table.rs
+    /// # // This code is available in examples/write_excel_set_header_mode.rs
     /// #
     /// # use polars::prelude::*;
     /// #
-    /// # use polars_excel_writer::PolarsXlsxWriter;
-    /// # use rust_xlsxwriter::{Table, TableStyle};
-    /// #
-    /// # fn main() -> PolarsResult<()> {
-    /// #     // Create a sample dataframe for the example.
-    /// #     let df: DataFrame = df!(
-    /// #         "String" => &["North", "South", "East", "West"],
-    /// #         "Int" => &[1, 2, 3, 4],
-    /// #         "Float" => &[1.0, 2.22, 3.333, 4.4444],
-    /// #     )?;
-    /// #
+    /// use polars_excel_writer::{HeaderMode, PolarsXlsxWriter};
+    /// use rust_xlsxwriter::Format;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[100, 200, 300, 400],
+    ///     )?;
+    ///
     ///     // Write the dataframe to an Excel file.
     ///     let mut xlsx_writer = PolarsXlsxWriter::new();
     ///
-    ///     // Add a `rust_xlsxwriter` table and set the style.
-    ///     let table = Table::new().set_style(TableStyle::Medium4);
-    ///
-    ///     // Add the table to the Excel writer.
-    ///     xlsx_writer.set_table(&table);
+    ///     // Write the header as a bold row without a table.
+    ///     xlsx_writer.set_header_mode(HeaderMode::WrittenHeaderRow);
+    ///     xlsx_writer.set_header_format(Format::new().set_bold());
     ///
     ///     // Write the dataframe to Excel.
     ///     xlsx_writer.write_dataframe(&df)?;
     ///
     ///     // Save the file to disk.
     ///     xlsx_writer.save("dataframe.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
+    ///
+    ///     Ok(())
+    /// }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/write_excel_set_table.png">
-    ///
-    pub fn set_table(&mut self, table: &Table) -> &mut PolarsXlsxWriter {
-        self.options.table = table.clone();
+    pub fn set_header_mode(&mut self, header_mode: HeaderMode) -> &mut PolarsXlsxWriter {
+        self.options.set_header_mode(header_mode);
         self
     }
 
-    /// Set the worksheet name for the output dataframe.
-    ///
-    /// Set the name of the worksheet that the dataframe is written to. If the
-    /// name isn't set then it will be the default Excel name of `Sheet1` (or
-    /// `Sheet2`, `Sheet3`, etc. if more than one worksheet is added).
+    /// Split hierarchical column names into a two row grouped header.
+    ///
+    /// For dataframes whose column names encode a hierarchy, such as `"2023|Q1"`
+    /// and `"2023|Q2"`, this method splits the names at the first `delimiter`
+    /// and writes a two row header. The top row contains the group names,
+    /// merged across adjacent columns in the same group, and the bottom row
+    /// contains the leaf names as the table header. Columns without the
+    /// delimiter have a blank group cell. If none of the column names contain
+    /// the delimiter the dataframe is written with the standard single row
+    /// header.
+    ///
+    /// The group row is written above the output table so the table, and the
+    /// range returned by the `write_dataframe*()` methods, starts one row
+    /// lower. Excel table headers must be unique so if the leaf names repeat,
+    /// for example `"Q1"` in two different years, the data is written as a
+    /// range with a header row instead of a table, see
+    /// [`HeaderMode::WrittenHeaderRow`].
+    ///
+    /// The group cells use the header format, if any, see
+    /// [`set_header_format()`](PolarsXlsxWriter::set_header_format),
+    /// otherwise a bold format. The group names are centered.
     ///
     /// # Parameters
     ///
-    /// * `name` - The worksheet name. It must follow the Excel rules, shown
-    ///   below.
+    /// * `delimiter` - The string that separates the group and leaf names.
     ///
-    ///   * The name must be less than 32 characters.
-    ///   * The name cannot be blank.
-    ///   * The name cannot contain any of the characters: `[ ] : * ? / \`.
-    ///   * The name cannot start or end with an apostrophe.
-    ///   * The name shouldn't be "History" (case-insensitive) since that is
-    ///     reserved by Excel.
-    ///   * It must not be a duplicate of another worksheet name used in the
-    ///     workbook.
+    /// # Examples
     ///
-    /// # Errors
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates splitting hierarchical column names into a grouped header.
     ///
-    /// A [`PolarsError::ComputeError`] that wraps a `rust_xlsxwriter`
-    /// [`XlsxError`](rust_xlsxwriter::XlsxError) error.
+    /// ```
+    /// # // This code is available in examples/write_excel_set_header_split.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
     ///
-    /// Excel has several rules that govern what a worksheet name can be. See
-    /// [`set_name()` errors] for more details.
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "2023|Q1" => &[100, 200, 300, 400],
+    ///         "2023|Q2" => &[110, 210, 310, 410],
+    ///         "2024|Q1" => &[120, 220, 320, 420],
+    ///         "2024|Q2" => &[130, 230, 330, 430],
+    ///     )?;
     ///
-    /// [`set_name()` errors]:
-    ///     https://docs.rs/rust_xlsxwriter/latest/rust_xlsxwriter/worksheet/struct.Worksheet.html#errors
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write the years as merged groups above the quarters.
+    ///     xlsx_writer.set_header_split("|");
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_header_split(&mut self, delimiter: impl Into<String>) -> &mut PolarsXlsxWriter {
+        self.options.header_split = Some(delimiter.into());
+        self
+    }
+
+    /// Set the Excel number format for time values.
+    ///
+    /// [Datetimes in Excel] are stored as f64 floats with a format used to
+    /// display them. The default time format used by this library is
+    /// `hh:mm:ss;@`. This method can be used to specify an alternative user
+    /// defined format.
+    ///
+    /// [Datetimes in Excel]:
+    ///     https://docs.rs/rust_xlsxwriter/latest/rust_xlsxwriter/struct.ExcelDateTime.html#datetimes-in-excel
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - A `rust_xlsxwriter` [`Format`] or an Excel number format
+    ///   string that can be converted to a [`CellFormat`].
     ///
     /// # Examples
     ///
-    /// An example of writing a Polar Rust dataframe to an Excel file. This
-    /// demonstrates setting the name for the output worksheet.
+    /// An example of writing a Polar Rust dataframe to an Excel file. This example
+    /// demonstrates how to change the default format for Polars time types.
     ///
     /// ```
-    /// # // This code is available in examples/write_excel_set_worksheet_name.rs
-    /// #
-    /// # use polars::prelude::*;
-    /// #
-    /// # use polars_excel_writer::PolarsXlsxWriter;
-    /// #
-    /// # fn main() -> PolarsResult<()> {
-    /// #     // Create a sample dataframe for the example.
-    /// #     let df: DataFrame = df!(
-    /// #         "String" => &["North", "South", "East", "West"],
-    /// #         "Int" => &[1, 2, 3, 4],
-    /// #         "Float" => &[1.0, 2.22, 3.333, 4.4444],
-    /// #     )?;
+    /// # // This code is available in examples/write_excel_time_format.rs
     /// #
+    /// use chrono::prelude::*;
+    /// use polars::prelude::*;
+    ///
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Time" => &[
+    ///             NaiveTime::from_hms_milli_opt(2, 00, 3, 456).unwrap(),
+    ///             NaiveTime::from_hms_milli_opt(2, 18, 3, 456).unwrap(),
+    ///             NaiveTime::from_hms_milli_opt(2, 37, 3, 456).unwrap(),
+    ///             NaiveTime::from_hms_milli_opt(2, 59, 3, 456).unwrap(),
+    ///         ],
+    ///     )?;
+    ///
     ///     // Write the dataframe to an Excel file.
     ///     let mut xlsx_writer = PolarsXlsxWriter::new();
     ///
-    ///     // Set the worksheet name.
-    ///     xlsx_writer.set_worksheet_name("Polars Data")?;
+    ///     // Set the time format.
+    ///     xlsx_writer.set_time_format("hh:mm");
     ///
     ///     // Write the dataframe to Excel.
     ///     xlsx_writer.write_dataframe(&df)?;
     ///
     ///     // Save the file to disk.
     ///     xlsx_writer.save("dataframe.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
+    ///
+    ///     Ok(())
+    /// }
     /// ```
     ///
     /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/write_excel_set_worksheet_name.png">
+    /// src="https://rustxlsxwriter.github.io/images/excelwriter_time_format.png">
     ///
-    pub fn set_worksheet_name(
-        &mut self,
-        name: impl Into<String>,
-    ) -> PolarsResult<&mut PolarsXlsxWriter> {
-        let worksheet = self.worksheet()?;
-        worksheet.set_name(name)?;
-        Ok(self)
+    pub fn set_time_format(&mut self, format: impl Into<CellFormat>) -> &mut PolarsXlsxWriter {
+        self.options.time_format = format.into();
+        self
     }
 
-    /// Add a new worksheet to the output workbook.
+    /// Set the Excel number format for date values.
     ///
-    /// Add a worksheet to the workbook so that dataframes can be written to
-    /// more than one worksheet. This is useful when you have several dataframes
-    /// that you wish to have on separate worksheets.
+    /// [Datetimes in Excel] are stored as f64 floats with a format used to
+    /// display them. The default date format used by this library is
+    /// `yyyy-mm-dd;@`. This method can be used to specify an alternative user
+    /// defined format.
+    ///
+    /// [Datetimes in Excel]:
+    ///     https://docs.rs/rust_xlsxwriter/latest/rust_xlsxwriter/struct.ExcelDateTime.html#datetimes-in-excel
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - A `rust_xlsxwriter` [`Format`] or an Excel number format
+    ///   string that can be converted to a [`CellFormat`].
     ///
     /// # Examples
     ///
-    /// An example of writing a Polar Rust dataframes to separate worksheets in
-    /// an Excel workbook.
+    /// An example of writing a Polar Rust dataframe to an Excel file. This example
+    /// demonstrates how to change the default format for Polars date types.
     ///
     /// ```
-    /// # // This code is available in examples/write_excel_add_worksheet.rs
+    /// # // This code is available in examples/write_excel_date_format.rs
     /// #
-    /// # use polars::prelude::*;
+    /// use chrono::prelude::*;
+    /// use polars::prelude::*;
+    ///
     /// use polars_excel_writer::PolarsXlsxWriter;
     ///
     /// fn main() -> PolarsResult<()> {
-    ///     let df1: DataFrame = df!(
-    ///         "Data 1" => &[10, 11, 12, 13, 14, 15],
-    ///     )?;
-    ///
-    ///     let df2: DataFrame = df!(
-    ///         "Data 2" => &[20, 21, 22, 23, 24, 25],
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Date" => &[
+    ///             NaiveDate::from_ymd_opt(2023, 1, 11),
+    ///             NaiveDate::from_ymd_opt(2023, 1, 12),
+    ///             NaiveDate::from_ymd_opt(2023, 1, 13),
+    ///             NaiveDate::from_ymd_opt(2023, 1, 14),
+    ///         ],
     ///     )?;
     ///
+    ///     // Create a new Excel writer.
     ///     let mut xlsx_writer = PolarsXlsxWriter::new();
     ///
-    ///     // Write the first dataframe to the first/default worksheet.
-    ///     xlsx_writer.write_dataframe(&df1)?;
+    ///     // Set the date format.
+    ///     xlsx_writer.set_date_format("mmm d yyyy");
     ///
-    ///     // Add another worksheet and write the second dataframe to it.
-    ///     xlsx_writer.add_worksheet();
-    ///     xlsx_writer.write_dataframe(&df2)?;
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
     ///
     ///     // Save the file to disk.
     ///     xlsx_writer.save("dataframe.xlsx")?;
@@ -1525,312 +2465,12088 @@ impl PolarsXlsxWriter {
     ///
     /// Output file:
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/write_excel_add_worksheet.png">
+    /// <img src="https://rustxlsxwriter.github.io/images/excelwriter_date_format.png">
     ///
-    pub fn add_worksheet(&mut self) -> &mut PolarsXlsxWriter {
-        self.workbook.add_worksheet();
-
+    pub fn set_date_format(&mut self, format: impl Into<CellFormat>) -> &mut PolarsXlsxWriter {
+        self.options.date_format = format.into();
         self
     }
 
-    /// Get the current worksheet in the workbook.
+    /// Set the Excel number format for datetime values.
     ///
-    /// Get a reference to the current/last worksheet in the workbook in order
-    /// to manipulate it with a `rust_xlsxwriter` [`Worksheet`] method. This is
-    /// occasionally useful when you need to access some feature of the
-    /// worksheet APIs that isn't supported directly by `PolarsXlsxWriter`.
+    /// [Datetimes in Excel] are stored as f64 floats with a format used to
+    /// display them. The default datetime format used by this library is
+    /// `yyyy-mm-dd hh:mm:ss`. This method can be used to specify an alternative
+    /// user defined format.
     ///
-    /// Note, it is also possible to create a [`Worksheet`] separately and then
-    /// write the Polar dataframe to it using the
-    /// [`write_dataframe_to_worksheet()`](PolarsXlsxWriter::write_dataframe_to_worksheet)
-    /// method. That latter is more useful if you need to do a lot of
-    /// manipulation of the worksheet.
+    /// [Datetimes in Excel]:
+    ///     https://docs.rs/rust_xlsxwriter/latest/rust_xlsxwriter/struct.ExcelDateTime.html#datetimes-in-excel
     ///
-    /// # Errors
+    /// # Parameters
     ///
-    /// A [`PolarsError::ComputeError`] that wraps a `rust_xlsxwriter`
-    /// [`XlsxError`](rust_xlsxwriter::XlsxError) error.
+    /// * `format` - A `rust_xlsxwriter` [`Format`] or an Excel number format
+    ///   string that can be converted to a [`CellFormat`].
     ///
     /// # Examples
     ///
-    /// An example of writing a Polar Rust dataframe to an Excel file. This
-    /// demonstrates getting a reference to the worksheet used to write the
-    /// dataframe and setting its tab color.
+    /// An example of writing a Polar Rust dataframe to an Excel file. This example
+    /// demonstrates how to change the default format for Polars datetime types.
     ///
     /// ```
-    /// # // This code is available in examples/write_excel_worksheet.rs
-    /// #
-    /// # use polars::prelude::*;
-    /// #
-    /// # use polars_excel_writer::PolarsXlsxWriter;
-    /// #
-    /// # fn main() -> PolarsResult<()> {
-    /// #     // Create a sample dataframe for the example.
-    /// #     let df: DataFrame = df!(
-    /// #         "String" => &["North", "South", "East", "West"],
-    /// #         "Int" => &[1, 2, 3, 4],
-    /// #         "Float" => &[1.0, 2.22, 3.333, 4.4444],
-    /// #     )?;
+    /// # // This code is available in examples/write_excel_datetime_format.rs
     /// #
+    /// use chrono::prelude::*;
+    /// use polars::prelude::*;
+    ///
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Datetime" => &[
+    ///             NaiveDate::from_ymd_opt(2023, 1, 11).unwrap().and_hms_opt(1, 0, 0).unwrap(),
+    ///             NaiveDate::from_ymd_opt(2023, 1, 12).unwrap().and_hms_opt(2, 0, 0).unwrap(),
+    ///             NaiveDate::from_ymd_opt(2023, 1, 13).unwrap().and_hms_opt(3, 0, 0).unwrap(),
+    ///             NaiveDate::from_ymd_opt(2023, 1, 14).unwrap().and_hms_opt(4, 0, 0).unwrap(),
+    ///         ],
+    ///     )?;
+    ///
     ///     // Write the dataframe to an Excel file.
     ///     let mut xlsx_writer = PolarsXlsxWriter::new();
     ///
-    ///     // Get the worksheet that the dataframe will be written to.
-    ///     let worksheet = xlsx_writer.worksheet()?;
-    ///
-    ///     // Set the tab color for the worksheet using a `rust_xlsxwriter` worksheet
-    ///     // method.
-    ///     worksheet.set_tab_color("#FF9900");
+    ///     // Set the datetime format.
+    ///     xlsx_writer.set_datetime_format("hh::mm - mmm d yyyy");
     ///
     ///     // Write the dataframe to Excel.
     ///     xlsx_writer.write_dataframe(&df)?;
     ///
     ///     // Save the file to disk.
     ///     xlsx_writer.save("dataframe.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
+    ///
+    ///     Ok(())
+    /// }
     /// ```
     ///
     /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/write_excel_worksheet.png">
+    /// src="https://rustxlsxwriter.github.io/images/excelwriter_datetime_format.png">
     ///
-    pub fn worksheet(&mut self) -> PolarsResult<&mut Worksheet> {
-        let mut last_index = self.workbook.worksheets().len();
+    pub fn set_datetime_format(&mut self, format: impl Into<CellFormat>) -> &mut PolarsXlsxWriter {
+        self.options.datetime_format = format.into();
+        self
+    }
 
-        // Add a worksheet if there isn't one already.
-        if last_index == 0 {
-            self.workbook.add_worksheet();
-        } else {
-            last_index -= 1;
+    /// Set a custom converter for Polars temporal values.
+    ///
+    /// Polars `Datetime`, `Date` and `Time` values are stored as integer
+    /// offsets from the Unix epoch, or from midnight, and they are converted
+    /// to `chrono` types before they are written to Excel. By default this is
+    /// done by [`DefaultTemporalConverter`].
+    ///
+    /// This method allows the conversion to be replaced by a user defined type
+    /// that implements the [`TemporalToExcel`] trait. This can be used to
+    /// adjust the values, for example to shift UTC datetimes to a local time,
+    /// or to handle the values of a Polars build with a different
+    /// representation. The trait methods have default implementations so only
+    /// the conversions that change need to be implemented.
+    ///
+    /// # Parameters
+    ///
+    /// * `converter` - A type that implements the [`TemporalToExcel`] trait.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates using a custom converter to write UTC datetimes in a local
+    /// time zone.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_temporal_converter.rs
+    /// #
+    /// use chrono::prelude::*;
+    /// use chrono::TimeDelta;
+    /// use polars::prelude::*;
+    ///
+    /// use polars_excel_writer::{DefaultTemporalConverter, PolarsXlsxWriter, TemporalToExcel};
+    ///
+    /// // A converter that shifts UTC datetimes to Central European Time.
+    /// struct CetConverter;
+    ///
+    /// impl TemporalToExcel for CetConverter {
+    ///     fn datetime(&self, value: i64, time_unit: TimeUnit) -> Option<NaiveDateTime> {
+    ///         let datetime = DefaultTemporalConverter.datetime(value, time_unit)?;
+    ///         datetime.checked_add_signed(TimeDelta::hours(1))
+    ///     }
+    /// }
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe with UTC datetimes.
+    ///     let df: DataFrame = df!(
+    ///         "Datetime" => &[
+    ///             NaiveDate::from_ymd_opt(2023, 1, 11).unwrap().and_hms_opt(1, 0, 0).unwrap(),
+    ///             NaiveDate::from_ymd_opt(2023, 1, 12).unwrap().and_hms_opt(2, 0, 0).unwrap(),
+    ///             NaiveDate::from_ymd_opt(2023, 1, 13).unwrap().and_hms_opt(3, 0, 0).unwrap(),
+    ///             NaiveDate::from_ymd_opt(2023, 1, 14).unwrap().and_hms_opt(4, 0, 0).unwrap(),
+    ///         ],
+    ///     )?;
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write the datetimes in the local time zone.
+    ///     xlsx_writer.set_temporal_converter(CetConverter);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_temporal_converter(
+        &mut self,
+        converter: impl TemporalToExcel + 'static,
+    ) -> &mut PolarsXlsxWriter {
+        self.options.temporal_converter = Arc::new(converter);
+        self
+    }
+
+    /// Set the Excel number format for floats.
+    ///
+    /// Set the Excel number format for f32/f64 float types using an Excel
+    /// number format string. These format strings can be obtained from the
+    /// `Format Cells -> Number` dialog in Excel.
+    ///
+    /// See the [Number Format Categories] section and subsequent Number Format
+    /// sections in the `rust_xlsxwriter` documentation.
+    ///
+    /// [Number Format Categories]:
+    ///     https://docs.rs/rust_xlsxwriter/latest/rust_xlsxwriter/struct.Format.html#number-format-categories
+    ///
+    /// Note, the numeric values aren't truncated in Excel, this option just
+    /// controls the display of the number.
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - A `rust_xlsxwriter` [`Format`] or an Excel number format
+    ///   string that can be converted to a [`CellFormat`].
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting an Excel number format for floats.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_float_format.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Float" => &[1000.0, 2000.22, 3000.333, 4000.4444],
+    ///     )
+    ///     .unwrap();
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Set the float format.
+    ///     xlsx_writer.set_float_format("#,##0.00");
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/excelwriter_float_format.png">
+    ///
+    pub fn set_float_format(&mut self, format: impl Into<CellFormat>) -> &mut PolarsXlsxWriter {
+        self.options.float_format = format.into();
+        self
+    }
+
+    /// Set the Excel number precision for floats.
+    ///
+    /// Set the number precision of all floats exported from the dataframe to
+    /// Excel. The precision is converted to an Excel number format (see
+    /// [`set_float_format()`](PolarsXlsxWriter::set_float_format) above), so for
+    /// example 3 is converted to the Excel format `0.000`.
+    ///
+    /// Note, the numeric values aren't truncated in Excel, this option just
+    /// controls the display of the number.
+    ///
+    /// # Parameters
+    ///
+    /// * `precision` - The floating point precision in the Excel range 1-30.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This example
+    /// demonstrates how to set the precision of the float output. Setting the
+    /// precision to 3 is equivalent to an Excel number format of `0.000`.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_float_precision.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Float" => &[1.0, 2.22, 3.333, 4.4444],
+    ///     )
+    ///     .unwrap();
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Set the float precision.
+    ///     xlsx_writer.set_float_precision(3);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/excelwriter_float_precision.png">
+    ///
+    pub fn set_float_precision(&mut self, precision: usize) -> &mut PolarsXlsxWriter {
+        if (1..=30).contains(&precision) {
+            let precision = "0".repeat(precision);
+            self.options.float_format = format!("0.{precision}").into();
+        }
+        self
+    }
+
+    /// Write large floats in scientific notation.
+    ///
+    /// Write the floats in the dataframe whose magnitude is greater than a
+    /// threshold with a scientific number format, such as `1.23E+09`, while
+    /// smaller values keep the float format. This is useful for columns with a
+    /// wide range of values where the large values would otherwise be
+    /// displayed as long strings of digits, or as `#####` in narrow columns.
+    ///
+    /// The scientific number format replaces the number format of the large
+    /// values only. Other properties of the column format, such as the font,
+    /// are kept. NaN and Infinity values aren't affected.
+    ///
+    /// Note, float columns are written value by value, rather than from the
+    /// column data, when this option is set, which is slower for large
+    /// dataframes.
+    ///
+    /// # Parameters
+    ///
+    /// * `threshold` - The magnitude above which floats are written in
+    ///   scientific notation.
+    /// * `decimals` - The number of decimal places in the scientific number
+    ///   format, in the range 0-30. For example 2 gives the Excel format
+    ///   `0.00E+00`.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates writing large floats in scientific notation.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_float_scientific.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Body" => &["Moon", "Earth", "Jupiter", "Sun"],
+    ///         "Mass (kg)" => &[7.342e22, 5.972e24, 1.898e27, 1.989e30],
+    ///         "Radius (km)" => &[1737.4, 6371.0, 69911.0, 695700.0],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write floats greater than one million in scientific notation.
+    ///     xlsx_writer.set_float_precision(1);
+    ///     xlsx_writer.set_float_scientific(1_000_000.0, 3);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_float_scientific(&mut self, threshold: f64, decimals: u8) -> &mut PolarsXlsxWriter {
+        if decimals <= 30 {
+            let decimals = "0".repeat(usize::from(decimals));
+            let num_format = if decimals.is_empty() {
+                "0E+00".to_string()
+            } else {
+                format!("0.{decimals}E+00")
+            };
+
+            self.options.float_scientific = Some((threshold, num_format));
+        }
+        self
+    }
+
+    /// Set the Excel format for a Polars data type.
+    ///
+    /// Set a `rust_xlsxwriter` [`Format`] for all the columns in a dataframe
+    /// that have the specified Polars [`DataType`]. This can be used to set a
+    /// number format, font or other cell property for a data type. For the
+    /// types that have a default format, such as floats and dates, this
+    /// overrides the format set via methods like
+    /// [`set_float_format()`](PolarsXlsxWriter::set_float_format) or
+    /// [`set_date_format()`](PolarsXlsxWriter::set_date_format). If the format
+    /// doesn't have a number format the default number format for the type is
+    /// kept, so a font color can be set for dates without losing the date
+    /// format. See [`ColumnFormat`] for the order in which formats are
+    /// combined.
+    ///
+    /// For `DataType::Categorical` and `DataType::Enum` the format applies to
+    /// all columns of that type, regardless of their categories. These types
+    /// are written to Excel as strings.
+    ///
+    /// For `DataType::Decimal` the format applies to columns with the same
+    /// precision and scale or, if `DataType::Decimal(None, None)` is used, to
+    /// all decimal columns without a more specific format. By default decimal
+    /// columns get a number format based on their scale, for example `0.00`
+    /// for a scale of 2.
+    ///
+    /// For `DataType::Duration` the format applies to columns with the same
+    /// time unit. By default duration columns are written as Excel elapsed
+    /// times with the `[h]:mm:ss` format. Note, Excel can't display negative
+    /// elapsed times with this format.
+    ///
+    /// # Parameters
+    ///
+    /// * `dtype` - A Polars [`DataType`].
+    /// * `format` - A `rust_xlsxwriter` [`Format`] or an Excel number format
+    ///   string that can be converted to a [`CellFormat`].
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting a format for the Polars categorical data type.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_dtype_format.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::Format;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe with a categorical column.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[1000, 2300, 1500, 1750],
+    ///     )?
+    ///     .lazy()
+    ///     .with_column(col("Region").cast(DataType::Categorical(None, Default::default())))
+    ///     .collect()?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Set a format for the categorical column data.
+    ///     let format = Format::new().set_bold().set_font_color("#0000FF");
+    ///     xlsx_writer.set_dtype_format(DataType::Categorical(None, Default::default()), format);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_dtype_format(
+        &mut self,
+        dtype: DataType,
+        format: impl Into<CellFormat>,
+    ) -> &mut PolarsXlsxWriter {
+        self.options.dtype_formats.insert(dtype, format.into());
+        self
+    }
+
+    /// Set the Excel format for several Polars data types.
+    ///
+    /// Set a `rust_xlsxwriter` [`Format`] for all the columns in a dataframe
+    /// that have one of the specified Polars [`DataType`]s. This is the same
+    /// as calling [`set_dtype_format()`](PolarsXlsxWriter::set_dtype_format)
+    /// for each data type and is similar to using a dtype selector with the
+    /// `column_formats` parameter of the Polars [`write_excel()`] method. For
+    /// example, it can be used to set the same number format for all the
+    /// integer types.
+    ///
+    /// [`write_excel()`]:
+    ///     https://pola-rs.github.io/polars/py-polars/html/reference/api/polars.DataFrame.write_excel.html#polars.DataFrame.write_excel
+    ///
+    /// # Parameters
+    ///
+    /// * `dtypes` - A slice of Polars [`DataType`]s.
+    /// * `format` - A `rust_xlsxwriter` [`Format`] or an Excel number format
+    ///   string that can be converted to a [`CellFormat`].
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting a format for several Polars data types.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_format_for_dtypes.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe with different integer types.
+    ///     let df: DataFrame = df!(
+    ///         "Product" => &["Apple", "Pear", "Plum"],
+    ///         "Units" => &[12500i32, 8300, 21750],
+    ///         "Revenue" => &[1562500i64, 1145400, 2175000],
+    ///     )?;
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Set a thousands separator format for the integer columns.
+    ///     xlsx_writer.set_format_for_dtypes(&[DataType::Int32, DataType::Int64], "#,##0");
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_format_for_dtypes(
+        &mut self,
+        dtypes: &[DataType],
+        format: impl Into<CellFormat>,
+    ) -> &mut PolarsXlsxWriter {
+        let format = format.into();
+        for dtype in dtypes {
+            self.options
+                .dtype_formats
+                .insert(dtype.clone(), format.clone());
+        }
+        self
+    }
+
+    /// Set the font properties for a Polars data type.
+    ///
+    /// Set the font, such as the font color or bold, for all the columns in a
+    /// dataframe that have the specified Polars [`DataType`]. Unlike
+    /// [`set_dtype_format()`](PolarsXlsxWriter::set_dtype_format) only the
+    /// properties set in the [`FontOptions`] are changed and they are combined
+    /// with the number format and the other properties of the data type
+    /// format. See [`ColumnFormat`] for the order in which formats are
+    /// combined.
+    ///
+    /// # Parameters
+    ///
+    /// * `dtype` - A Polars [`DataType`].
+    /// * `font` - A [`FontOptions`] reference.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting the font and fill for the columns of a Polars data
+    /// type.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_dtype_font.rs
+    /// #
+    /// use chrono::prelude::*;
+    /// use polars::prelude::*;
+    ///
+    /// use polars_excel_writer::{FontOptions, PolarsXlsxWriter};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Order" => &["A-100", "A-101", "A-102"],
+    ///         "Ordered" => &[
+    ///             NaiveDate::from_ymd_opt(2024, 1, 5).unwrap(),
+    ///             NaiveDate::from_ymd_opt(2024, 1, 9).unwrap(),
+    ///             NaiveDate::from_ymd_opt(2024, 2, 2).unwrap(),
+    ///         ],
+    ///         "Shipped" => &[
+    ///             NaiveDate::from_ymd_opt(2024, 1, 8).unwrap(),
+    ///             NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+    ///             NaiveDate::from_ymd_opt(2024, 2, 6).unwrap(),
+    ///         ],
+    ///         "Amount" => &[125.5, 310.0, 87.25],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Grey out the date columns, keeping the default date format.
+    ///     let font = FontOptions::new().set_italic().set_color("#595959");
+    ///     xlsx_writer.set_dtype_font(DataType::Date, &font);
+    ///     xlsx_writer.set_dtype_fill(DataType::Date, "#EDEDED");
+    ///
+    ///     // Make the amounts bold.
+    ///     xlsx_writer.set_dtype_font(DataType::Float64, &FontOptions::new().set_bold());
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_dtype_font(&mut self, dtype: DataType, font: &FontOptions) -> &mut PolarsXlsxWriter {
+        let style = self.options.dtype_styles.entry(dtype).or_default();
+        font.apply(style);
+        self
+    }
+
+    /// Set the background fill color for a Polars data type.
+    ///
+    /// Set a solid background fill for all the columns in a dataframe that
+    /// have the specified Polars [`DataType`]. The fill is combined with the
+    /// number format and the other properties of the data type format, in the
+    /// same way as
+    /// [`set_dtype_font()`](PolarsXlsxWriter::set_dtype_font).
+    ///
+    /// See [`set_dtype_font()`](PolarsXlsxWriter::set_dtype_font) for an
+    /// example.
+    ///
+    /// # Parameters
+    ///
+    /// * `dtype` - A Polars [`DataType`].
+    /// * `color` - A `rust_xlsxwriter` [`Color`] or a type that can be
+    ///   converted to a `Color`, such as an HTML style `"#RRGGBB"` string.
+    ///
+    pub fn set_dtype_fill(
+        &mut self,
+        dtype: DataType,
+        color: impl Into<Color>,
+    ) -> &mut PolarsXlsxWriter {
+        let style = self.options.dtype_styles.entry(dtype).or_default();
+        style.background_color = Some(color.into());
+        self
+    }
+
+    /// Replace Null values in the exported dataframe with string values.
+    ///
+    /// By default Null values in a dataframe aren't exported to Excel and will
+    /// appear as empty cells. If you wish you can specify a string such as
+    /// "Null", "NULL" or "N/A" as an alternative.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - A replacement string for Null values.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting a value for Null values in the dataframe. The default
+    /// is to write them as blank cells.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_null_values.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// # use polars_excel_writer::PolarsXlsxWriter;
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a dataframe with Null values.
+    /// #     let csv_string = "Foo,Bar\nNULL,B\nA,B\nA,NULL\nA,B\n";
+    /// #     let buffer = std::io::Cursor::new(csv_string);
+    /// #     let df = CsvReadOptions::default()
+    /// #         .map_parse_options(|parse_options| {
+    /// #             parse_options.with_null_values(Some(NullValues::AllColumnsSingle("NULL".into())))
+    /// #         })
+    /// #         .into_reader_with_file_handle(buffer)
+    /// #         .finish()
+    /// #         .unwrap();
+    /// #
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Set an output string value for Null.
+    ///     xlsx_writer.set_null_value("Null");
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/excelwriter_null_values.png">
+    ///
+    pub fn set_null_value(&mut self, value: impl Into<String>) -> &mut PolarsXlsxWriter {
+        self.options.null_value = Some(value.into());
+        self
+    }
+
+    /// Set the format for Null replacement values.
+    ///
+    /// By default the Null replacement string set via
+    /// [`set_null_value()`](PolarsXlsxWriter::set_null_value) is written
+    /// without a cell format. This method sets a format for the replacement
+    /// cells so that they can be styled separately from the column data, for
+    /// example in grey italic text. The format doesn't include the number
+    /// format of the column, which may not be suitable for a string such as
+    /// "N/A".
+    ///
+    /// The format has no effect if a Null replacement value isn't set.
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - A `rust_xlsxwriter` [`Format`].
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting a format for the Null replacement values.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_null_format.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::{Color, Format, FormatAlign};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe with Null values.
+    ///     let df: DataFrame = df!(
+    ///         "Item" => &["Apple", "Banana", "Cherry", "Date"],
+    ///         "Price" => &[Some(1.25), None, Some(3.5), None],
+    ///     )?;
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write Null values as "N/A" in grey, centered, italic text.
+    ///     let null_format = Format::new()
+    ///         .set_italic()
+    ///         .set_font_color(Color::Gray)
+    ///         .set_align(FormatAlign::Center);
+    ///
+    ///     xlsx_writer.set_null_value("N/A");
+    ///     xlsx_writer.set_null_format(&null_format);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_null_format(&mut self, format: impl Into<Format>) -> &mut PolarsXlsxWriter {
+        self.options.null_format = Some(format.into());
+        self
+    }
+
+    /// Replace boolean values in the exported dataframe with string values.
+    ///
+    /// By default Polars `Boolean` values are written as the Excel `TRUE` and
+    /// `FALSE` boolean values, which Excel displays in the language of the
+    /// user's version of Excel. If you wish you can specify alternative
+    /// strings such as "Yes" and "No", or localized equivalents.
+    ///
+    /// This option overrides any previous call to
+    /// [`set_boolean_as_number()`](PolarsXlsxWriter::set_boolean_as_number).
+    ///
+    /// # Parameters
+    ///
+    /// * `true_value` - A replacement string for `true` values.
+    /// * `false_value` - A replacement string for `false` values.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates writing boolean values as "Yes" and "No" strings.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_boolean_values.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// # use polars_excel_writer::PolarsXlsxWriter;
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "Region" => &["North", "South", "East", "West"],
+    /// #         "Target Met" => &[true, false, true, true],
+    /// #     )?;
+    /// #
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Set the output strings for boolean values.
+    ///     xlsx_writer.set_boolean_values("Yes", "No");
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_boolean_values(
+        &mut self,
+        true_value: impl Into<String>,
+        false_value: impl Into<String>,
+    ) -> &mut PolarsXlsxWriter {
+        self.options.boolean_values = BooleanValues::Strings(true_value.into(), false_value.into());
+        self
+    }
+
+    /// Write boolean values in the exported dataframe as 1 and 0.
+    ///
+    /// By default Polars `Boolean` values are written as the Excel `TRUE` and
+    /// `FALSE` boolean values. This option writes them as the numbers 1 and 0
+    /// instead, which can be summed or used in other calculations.
+    ///
+    /// This option overrides any previous call to
+    /// [`set_boolean_values()`](PolarsXlsxWriter::set_boolean_values).
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    pub fn set_boolean_as_number(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
+        self.options.boolean_values = if enable {
+            BooleanValues::Numbers
+        } else {
+            BooleanValues::Native
+        };
+        self
+    }
+
+    /// Set the delimiter used to join the values of Polars list types.
+    ///
+    /// Excel doesn't have a list or array type so the values of Polars
+    /// `DataType::List` columns are written as strings with the list elements
+    /// joined by a delimiter. The default delimiter is `", "`. Alternatively
+    /// the list elements can be written to separate columns using
+    /// [`set_expand_lists()`](PolarsXlsxWriter::set_expand_lists).
+    ///
+    /// # Parameters
+    ///
+    /// * `delimiter` - The string used to join the list elements.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting the delimiter for list values.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_list_delimiter.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe with a list column.
+    ///     let tags = Series::new(
+    ///         "Tags".into(),
+    ///         [
+    ///             Series::new("".into(), ["red", "green"]),
+    ///             Series::new("".into(), ["blue"]),
+    ///             Series::new("".into(), ["red", "yellow", "blue"]),
+    ///         ],
+    ///     );
+    ///     let ids = Series::new("Id".into(), [1, 2, 3]);
+    ///     let df = DataFrame::new(vec![ids.into(), tags.into()])?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Set the delimiter for the list values.
+    ///     xlsx_writer.set_list_delimiter("; ");
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_list_delimiter(&mut self, delimiter: impl Into<String>) -> &mut PolarsXlsxWriter {
+        self.options.list_delimiter = delimiter.into();
+        self
+    }
+
+    /// Write the elements of Polars list types to separate columns.
+    ///
+    /// By default the values of Polars `DataType::List` columns are written as
+    /// delimiter separated strings, see
+    /// [`set_list_delimiter()`](PolarsXlsxWriter::set_list_delimiter). This
+    /// method can be used to expand list columns so that each list element is
+    /// written to a separate worksheet column instead.
+    ///
+    /// The number of output columns is the length of the longest list in the
+    /// column. The expanded columns are named after the list column with a
+    /// numeric suffix, for example `Tags_1`, `Tags_2`, etc. Cells for missing
+    /// elements in shorter lists are left blank.
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    pub fn set_expand_lists(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
+        self.options.expand_lists = enable;
+        self
+    }
+
+    /// Set the dataframe columns to write and their order.
+    ///
+    /// By default all the columns of the dataframe are written in the
+    /// dataframe order. This method sets the columns that are written, and
+    /// the order that they are written in, without having to call
+    /// `df.select()` on the dataframe first.
+    ///
+    /// Other column methods continue to refer to the columns by their
+    /// dataframe name. Columns used as notes via
+    /// [`set_column_notes()`](PolarsXlsxWriter::set_column_notes) are kept
+    /// for the selected columns. An empty list resets the writer to write all
+    /// the columns.
+    ///
+    /// # Parameters
+    ///
+    /// * `columns` - The names of the dataframe columns to write, in order.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ColumnNotFound`] error is raised by the `write_*()`
+    /// methods if one of the column names isn't in the dataframe. The error
+    /// message lists the dataframe schema.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates writing a subset of the dataframe columns in a different
+    /// order.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_columns.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "id" => &[101, 102, 103, 104],
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Internal Code" => &["N1", "S1", "E1", "W1"],
+    ///         "Sales" => &[1000, 2300, 1900, 2500],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write the "Sales" and "Region" columns only, in that order.
+    ///     xlsx_writer.set_columns(&["Sales", "Region"]);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_columns(&mut self, columns: &[&str]) -> &mut PolarsXlsxWriter {
+        self.options.columns = columns.iter().map(|name| (*name).to_string()).collect();
+        self
+    }
+
+    /// Set a predicate to select the dataframe rows to write.
+    ///
+    /// By default all the rows of the dataframe are written. This method sets
+    /// a function that is called for each row, with the zero indexed row
+    /// number and the row values, and only the rows for which it returns
+    /// `true` are written. This avoids having to filter the dataframe before
+    /// it is written, for example to write one view of a large dataframe.
+    ///
+    /// The row values are the values of all the dataframe columns, in
+    /// dataframe order, regardless of the columns set via
+    /// [`set_columns()`](PolarsXlsxWriter::set_columns). The row numbers are
+    /// the row numbers in the dataframe.
+    ///
+    /// The selected rows are sliced from the dataframe rather than copied,
+    /// unless they are fragmented into many small runs of rows. The filter also
+    /// applies to the new dataframe in
+    /// [`write_dataframe_diff()`](PolarsXlsxWriter::write_dataframe_diff).
+    ///
+    /// Note, the dataframe values are converted to [`AnyValue`]s to call the
+    /// predicate. For a filter that can be expressed with Polars expressions it
+    /// is usually faster to filter a lazyframe and write it via
+    /// [`write_lazyframe()`](PolarsXlsxWriter::write_lazyframe).
+    ///
+    /// # Parameters
+    ///
+    /// * `filter` - A function that takes a row number and a slice of the row
+    ///   values and returns `true` if the row should be written.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates writing only the rows that match a predicate.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_row_filter.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[1000, 2300, 1900, 2500],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Only write the rows with sales of 2000 or more.
+    ///     xlsx_writer.set_row_filter(|_row, values| {
+    ///         matches!(values[1], AnyValue::Int32(sales) if sales >= 2000)
+    ///     });
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_row_filter(
+        &mut self,
+        filter: impl Fn(usize, &[AnyValue]) -> bool + Send + Sync + 'static,
+    ) -> &mut PolarsXlsxWriter {
+        self.options.row_filter = Some(Arc::new(filter));
+        self
+    }
+
+    /// Set the header text for a dataframe column.
+    ///
+    /// By default the worksheet table headers are the dataframe column names.
+    /// This method sets a different display header for a column, for example
+    /// to replace an internal `snake_case` name with a more readable one. The
+    /// dataframe itself isn't changed.
+    ///
+    /// Other column methods such as
+    /// [`set_column_width()`](PolarsXlsxWriter::set_column_width) or
+    /// [`set_column_total()`](PolarsXlsxWriter::set_column_total) continue to
+    /// refer to the column by its dataframe name. Names that aren't in the
+    /// dataframe are ignored.
+    ///
+    /// The headers of the worksheet table must be unique so the display header
+    /// shouldn't be the same as any other column header. See
+    /// [`set_duplicate_header_policy()`](PolarsXlsxWriter::set_duplicate_header_policy)
+    /// for the handling of duplicate headers.
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the dataframe column.
+    /// * `header` - The header text to display in the worksheet.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting display headers for the dataframe columns.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_column_header.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "region_name" => &["North", "South", "East", "West"],
+    ///         "total_revenue" => &[1000.0, 2300.0, 1900.0, 2500.0],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Set more readable headers for the columns.
+    ///     xlsx_writer.set_column_header("region_name", "Region");
+    ///     xlsx_writer.set_column_header("total_revenue", "Total Revenue (€)");
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_column_header(&mut self, column_name: &str, header: &str) -> &mut PolarsXlsxWriter {
+        self.options
+            .column_headers
+            .insert(column_name.to_string(), header.to_string());
+        self
+    }
+
+    /// Set the handling of duplicate table headers.
+    ///
+    /// The headers of an Excel worksheet table must be unique, ignoring case.
+    /// Polars column names are case sensitive so a dataframe can have columns
+    /// like `Price` and `price`, and headers set via
+    /// [`set_column_header()`](PolarsXlsxWriter::set_column_header) can also
+    /// repeat a header. By default these dataframes return an error but they
+    /// can also be written with renamed headers. See [`DuplicateHeaderPolicy`]
+    /// for the available options.
+    ///
+    /// The policy only applies to dataframes that are written as a worksheet
+    /// table. The first of the duplicate headers is kept and the dataframe
+    /// itself isn't changed.
+    ///
+    /// # Parameters
+    ///
+    /// * `policy` - A [`DuplicateHeaderPolicy`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates renaming duplicate table headers in the same way as Excel.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_duplicate_header_policy.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::{DuplicateHeaderPolicy, PolarsXlsxWriter};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Product" => &["Apple", "Pear", "Plum"],
+    ///         "Price" => &[1.20, 0.90, 1.50],
+    ///         "price" => &[1.10, 0.95, 1.40],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Rename the duplicate "price" header to "price2".
+    ///     xlsx_writer.set_duplicate_header_policy(DuplicateHeaderPolicy::Rename);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_duplicate_header_policy(
+        &mut self,
+        policy: DuplicateHeaderPolicy,
+    ) -> &mut PolarsXlsxWriter {
+        self.options.duplicate_header_policy = policy;
+        self
+    }
+
+    /// Set the format for the header row of the output table.
+    ///
+    /// Set a `rust_xlsxwriter` [`Format`] for the header cells of the
+    /// worksheet table. This is useful for tables with long column names
+    /// which can be displayed with wrapped or rotated text.
+    ///
+    /// If the format has wrapped text, or if the text is rotated via
+    /// [`set_header_rotation()`](PolarsXlsxWriter::set_header_rotation), then
+    /// the height of the header row is adjusted to fit the header strings,
+    /// unless a height is set explicitly via
+    /// [`set_header_row_height()`](PolarsXlsxWriter::set_header_row_height).
+    /// The height is an approximation based on the default font. A rotation
+    /// set in the `Format` itself isn't used for the row height, so it needs
+    /// an explicit height.
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - A `rust_xlsxwriter` [`Format`].
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting a wrapped format for the table header.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_header_format.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::Format;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales in the first quarter" => &[1000, 2300, 1900, 2500],
+    ///         "Sales in the second quarter" => &[1100, 2100, 2000, 2600],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Wrap the header text. The header row height is adjusted to fit.
+    ///     let header_format = Format::new().set_bold().set_text_wrap();
+    ///     xlsx_writer.set_header_format(&header_format);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_header_format(&mut self, format: impl Into<Format>) -> &mut PolarsXlsxWriter {
+        let mut format = format.into();
+        if let Some(rotation) = self.options.header_rotation {
+            format = format.set_rotation(rotation);
+        }
+
+        self.options.header_format = Some(format);
+        self
+    }
+
+    /// Set the rotation of the header row text of the output table.
+    ///
+    /// Rotate the text of the header cells, which is useful for tables with
+    /// long column names and narrow data. The rotation is added to the format
+    /// set via [`set_header_format()`](PolarsXlsxWriter::set_header_format),
+    /// if any, and the height of the header row is adjusted to fit the
+    /// rotated header strings, unless a height is set explicitly via
+    /// [`set_header_row_height()`](PolarsXlsxWriter::set_header_row_height).
+    ///
+    /// # Parameters
+    ///
+    /// * `rotation` - The rotation angle in the Excel range -90 to 90
+    ///   degrees, or 270 for stacked text with one character per line. See
+    ///   [`Format::set_rotation()`].
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates rotating the text of the table header.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_header_rotation.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::Format;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales in the first quarter" => &[1000, 2300, 1900, 2500],
+    ///         "Sales in the second quarter" => &[1100, 2100, 2000, 2600],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Rotate the bold header text. The header row height is adjusted to
+    ///     // fit.
+    ///     xlsx_writer.set_header_format(Format::new().set_bold());
+    ///     xlsx_writer.set_header_rotation(45);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_header_rotation(&mut self, rotation: i16) -> &mut PolarsXlsxWriter {
+        let format = self.options.header_format.take().unwrap_or_default();
+
+        self.options.header_format = Some(format.set_rotation(rotation));
+        self.options.header_rotation = Some(rotation);
+        self
+    }
+
+    /// Set the format for the header cell of a single column.
+    ///
+    /// Set a `rust_xlsxwriter` [`Format`] for the header cell of a named
+    /// dataframe column, for example to highlight the key columns of a table.
+    /// The format replaces the format set via
+    /// [`set_header_format()`](PolarsXlsxWriter::set_header_format) for the
+    /// column. The header row height is only adjusted for the format set via
+    /// `set_header_format()`.
+    ///
+    /// The column name is the name of the dataframe column, or of an inserted
+    /// formula, row total or sparkline column. Columns that aren't in the
+    /// written dataframe are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the dataframe column.
+    /// * `format` - A `rust_xlsxwriter` [`Format`].
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting the format of the header cells of some columns.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_column_header_format.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::{Color, Format};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Id" => &[101, 102, 103, 104],
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[1000, 2300, 1900, 2500],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Highlight the header of the key column.
+    ///     let key_format = Format::new()
+    ///         .set_bold()
+    ///         .set_font_color(Color::White)
+    ///         .set_background_color(Color::RGB(0xC00000));
+    ///
+    ///     xlsx_writer.set_column_header_format("Id", &key_format);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_column_header_format(
+        &mut self,
+        column_name: &str,
+        format: impl Into<Format>,
+    ) -> &mut PolarsXlsxWriter {
+        self.options
+            .column_header_formats
+            .insert(column_name.to_string(), format.into());
+        self
+    }
+
+    /// Set the height of the header row of the output table in pixels.
+    ///
+    /// Set the height of the header row explicitly. This overrides the height
+    /// that is calculated for wrapped header formats set via
+    /// [`set_header_format()`](PolarsXlsxWriter::set_header_format) or for
+    /// rotated headers set via
+    /// [`set_header_rotation()`](PolarsXlsxWriter::set_header_rotation).
+    ///
+    /// # Parameters
+    ///
+    /// * `height` - The row height in pixels. The default Excel row height is
+    ///   20 pixels.
+    ///
+    pub fn set_header_row_height(&mut self, height: u16) -> &mut PolarsXlsxWriter {
+        self.options.header_row_height = Some(height);
+        self
+    }
+
+    /// Set the height of a data row of the output table in pixels.
+    ///
+    /// Set the height of a data row, for example to show cells with wrapped
+    /// text. The row is the 0-indexed row of the dataframe, excluding the
+    /// header, so the height is applied relative to the position that the
+    /// dataframe is written to. Rows outside the dataframe are ignored.
+    ///
+    /// This is the equivalent of the `row_heights` parameter of the Polars
+    /// Python `write_excel()` method.
+    ///
+    /// # Parameters
+    ///
+    /// * `index` - The 0-indexed dataframe row.
+    /// * `height` - The row height in pixels. The default Excel row height is
+    ///   20 pixels.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting the height of the data rows.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_row_height.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[1000, 2300, 1900, 2500],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Set the height of all the data rows and then of a single row.
+    ///     xlsx_writer.set_row_height_all(30);
+    ///     xlsx_writer.set_row_height(2, 50);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_row_height(&mut self, index: usize, height: u16) -> &mut PolarsXlsxWriter {
+        self.options.row_heights.insert(index, height);
+        self
+    }
+
+    /// Set the height of all the data rows of the output table in pixels.
+    ///
+    /// Set the height of all the data rows. Heights set for individual rows
+    /// via [`set_row_height()`](PolarsXlsxWriter::set_row_height) take
+    /// precedence. See the example in
+    /// [`set_row_height()`](PolarsXlsxWriter::set_row_height).
+    ///
+    /// # Parameters
+    ///
+    /// * `height` - The row height in pixels. The default Excel row height is
+    ///   20 pixels.
+    ///
+    pub fn set_row_height_all(&mut self, height: u16) -> &mut PolarsXlsxWriter {
+        self.options.row_height_all = Some(height);
+        self
+    }
+
+    /// Write a string column as worksheet hyperlinks.
+    ///
+    /// By default string values that look like urls are written as plain text.
+    /// This method writes the values of the named column as Excel hyperlinks,
+    /// using the `rust_xlsxwriter` [`Worksheet::write_url()`] method, so that
+    /// they are clickable in the output file. Null and empty values are
+    /// written as standard cells.
+    ///
+    /// The urls should use a scheme supported by Excel such as `https://`,
+    /// `mailto:` or `file://`. Column names that aren't in the dataframe are
+    /// ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the dataframe column.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates writing string columns as clickable hyperlinks.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_column_as_hyperlinks.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Crate" => &["polars", "rust_xlsxwriter"],
+    ///         "Docs" => &["https://docs.rs/polars", "https://docs.rs/rust_xlsxwriter"],
+    ///         "Repository" => &[
+    ///             "https://github.com/pola-rs/polars",
+    ///             "https://github.com/jmcnamara/rust_xlsxwriter",
+    ///         ],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write the "Docs" urls as hyperlinks.
+    ///     xlsx_writer.set_column_as_hyperlinks("Docs");
+    ///
+    ///     // Write the "Crate" names as hyperlinks to the "Repository" urls.
+    ///     xlsx_writer.set_hyperlink_pair("Repository", "Crate");
+    ///     xlsx_writer.set_column_hidden("Repository");
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_column_as_hyperlinks(&mut self, column_name: &str) -> &mut PolarsXlsxWriter {
+        self.options
+            .hyperlink_columns
+            .insert(column_name.to_string());
+        self
+    }
+
+    /// Write a column as hyperlinks using the urls from another column.
+    ///
+    /// Writes the values of the `text_column` as Excel hyperlinks, with the
+    /// display text from the `text_column` and the link target from the
+    /// `url_column` in the same row. The `url_column` is written as standard
+    /// text. It can be hidden via
+    /// [`set_column_hidden()`](PolarsXlsxWriter::set_column_hidden) if it isn't
+    /// required in the output. See the example in
+    /// [`set_column_as_hyperlinks()`](PolarsXlsxWriter::set_column_as_hyperlinks).
+    ///
+    /// # Parameters
+    ///
+    /// * `url_column` - The name of the dataframe column with the urls.
+    /// * `text_column` - The name of the dataframe column to write as
+    ///   hyperlinks.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ColumnNotFound`] error is raised when the dataframe is
+    /// written if the `text_column` is in the dataframe but the `url_column`
+    /// isn't.
+    ///
+    pub fn set_hyperlink_pair(
+        &mut self,
+        url_column: &str,
+        text_column: &str,
+    ) -> &mut PolarsXlsxWriter {
+        self.options
+            .hyperlink_pairs
+            .insert(text_column.to_string(), url_column.to_string());
+        self
+    }
+
+    /// Write a column as images embedded in the worksheet cells.
+    ///
+    /// By default string values are written as plain text and binary values
+    /// aren't supported. This method writes the values of the named column as
+    /// images embedded in the cells, using the `rust_xlsxwriter`
+    /// [`Worksheet::embed_image()`] method. The column can be a `String`
+    /// column of image file paths or a `Binary` column of image data in one of
+    /// the formats supported by `rust_xlsxwriter`, such as PNG or JPEG.
+    ///
+    /// Excel scales embedded images to fit the cell, so the data rows and the
+    /// column are resized to make the images visible. The sizes can be set via
+    /// [`ImageOptions`]. Null and empty values are written as standard cells.
+    /// Column names that aren't in the dataframe are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the dataframe column.
+    /// * `image_options` - An [`ImageOptions`] struct with the image cell
+    ///   sizes.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ComputeError`] error is raised when the dataframe is
+    /// written if an image file can't be read or if the image data isn't in a
+    /// supported format.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates writing a column of image file paths as embedded images.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_column_as_images.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::{ImageOptions, PolarsXlsxWriter};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Product" => &["Red", "Blue", "Yellow"],
+    ///         "Image" => &[
+    ///             "tests/input/images/red.png",
+    ///             "tests/input/images/blue.png",
+    ///             "tests/input/images/yellow.png",
+    ///         ],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write the "Image" file paths as embedded images.
+    ///     let image_options = ImageOptions::new()
+    ///         .set_row_height(40)
+    ///         .set_column_width(40);
+    ///
+    ///     xlsx_writer.set_column_as_images("Image", &image_options);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_column_as_images(
+        &mut self,
+        column_name: &str,
+        image_options: &ImageOptions,
+    ) -> &mut PolarsXlsxWriter {
+        self.options
+            .image_columns
+            .insert(column_name.to_string(), *image_options);
+        self
+    }
+
+    /// Add Excel notes to the cells of a column from another column.
+    ///
+    /// Writes the values of the `notes_column` as Excel notes, also known as
+    /// comments, attached to the cells of the `data_column` in the same row.
+    /// Null and empty string values don't add a note. The `notes_column`
+    /// itself isn't written to the worksheet.
+    ///
+    /// This is useful for exporting annotations, such as quality control
+    /// comments, alongside the data values.
+    ///
+    /// # Parameters
+    ///
+    /// * `data_column` - The name of the dataframe column to add the notes
+    ///   to.
+    /// * `notes_column` - The name of the dataframe column with the note
+    ///   text.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ColumnNotFound`] error is raised when the dataframe is
+    /// written if the `data_column` is in the dataframe but the `notes_column`
+    /// isn't.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a dataframe with notes attached to the cells of a
+    /// column.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_column_notes.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Sample" => &["A1", "A2", "A3", "A4"],
+    ///         "Value" => &[1.25, 8.50, 1.75, 1.50],
+    ///         "QC" => &[None, Some("Outlier, retest"), None, Some("Low volume")],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Add the "QC" comments as notes on the "Value" cells.
+    ///     xlsx_writer.set_column_notes("Value", "QC");
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_column_notes(
+        &mut self,
+        data_column: &str,
+        notes_column: &str,
+    ) -> &mut PolarsXlsxWriter {
+        self.options
+            .column_notes
+            .insert(data_column.to_string(), notes_column.to_string());
+        self
+    }
+
+    /// Set the handling of Polars struct types.
+    ///
+    /// Excel doesn't have an equivalent of the Polars `DataType::Struct` type
+    /// so by default writing a dataframe with a struct column returns an
+    /// error. This method allows struct columns to be flattened into a column
+    /// per struct field or to be written as a JSON string per cell. See
+    /// [`StructHandling`] for the available options.
+    ///
+    /// Flattened columns are named after the struct column and field, for
+    /// example `Point.x` and `Point.y`. Nested structs are flattened
+    /// recursively. These names can be used with other column methods such as
+    /// [`set_column_width()`](PolarsXlsxWriter::set_column_width).
+    ///
+    /// # Parameters
+    ///
+    /// * `handling` - A [`StructHandling`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates flattening a struct column into a column per field.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_struct_handling.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::{PolarsXlsxWriter, StructHandling};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe with a struct column.
+    ///     let x = Series::new("x".into(), [1.5, 2.0, 3.25]);
+    ///     let y = Series::new("y".into(), [4.0, 5.5, 6.75]);
+    ///     let point = StructChunked::from_series("Point".into(), 3, [x, y].iter())?;
+    ///
+    ///     let ids = Series::new("Id".into(), [1, 2, 3]);
+    ///     let df = DataFrame::new(vec![ids.into(), point.into_series().into()])?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write the struct fields to the "Point.x" and "Point.y" columns.
+    ///     xlsx_writer.set_struct_handling(StructHandling::Flatten);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_struct_handling(&mut self, handling: StructHandling) -> &mut PolarsXlsxWriter {
+        self.options.struct_handling = handling;
+        self
+    }
+
+    /// Set the handling of Polars binary types.
+    ///
+    /// Excel doesn't have an equivalent of the Polars `DataType::Binary` type
+    /// so by default writing a dataframe with a binary column returns an
+    /// error. This method allows binary columns, such as hashes or small
+    /// blobs, to be written as Base64 or hexadecimal strings, or to be left
+    /// out of the output. See [`BinaryHandling`] for the available options.
+    ///
+    /// Binary columns written as images via
+    /// [`set_column_as_images()`](PolarsXlsxWriter::set_column_as_images)
+    /// aren't affected by this option.
+    ///
+    /// # Parameters
+    ///
+    /// * `handling` - A [`BinaryHandling`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates writing a binary column as hexadecimal strings.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_binary_handling.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::{BinaryHandling, PolarsXlsxWriter};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "File" => &["a.txt", "b.txt", "c.txt"],
+    ///         "Checksum" => &[
+    ///             &[0xde_u8, 0xad, 0xbe, 0xef][..],
+    ///             &[0xca, 0xfe, 0xba, 0xbe],
+    ///             &[0x0b, 0xad, 0xf0, 0x0d],
+    ///         ],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write the binary values as hex strings like "deadbeef".
+    ///     xlsx_writer.set_binary_handling(BinaryHandling::Hex);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_binary_handling(&mut self, handling: BinaryHandling) -> &mut PolarsXlsxWriter {
+        self.options.binary_handling = handling;
+        self
+    }
+
+    /// Set the handling of Polars object types.
+    ///
+    /// Excel doesn't have an equivalent of the Polars `DataType::Object` type,
+    /// which holds custom Rust objects, so by default writing a dataframe with
+    /// an object column returns an error. This method allows object columns
+    /// to be written as the `Display` strings of the objects, or to be left out
+    /// of the output, so that a dataframe from a mixed pipeline can still be
+    /// exported. See [`ObjectHandling`] for the available options.
+    ///
+    /// This method requires the `object` feature, which also enables the
+    /// Polars `object` feature.
+    ///
+    /// # Parameters
+    ///
+    /// * `handling` - An [`ObjectHandling`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates writing an object column as strings.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_object_handling.rs
+    /// #
+    /// # use std::fmt;
+    /// # use std::hash::{Hash, Hasher};
+    /// #
+    /// # use polars::prelude::*;
+    /// # use polars_utils::total_ord::{TotalEq, TotalHash};
+    /// #
+    /// use polars_excel_writer::{ObjectHandling, PolarsXlsxWriter};
+    ///
+    /// // A custom type stored in a Polars object column. Polars objects also
+    /// // require the `TotalEq` and `TotalHash` traits.
+    /// #[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+    /// struct Version {
+    ///     major: u32,
+    ///     minor: u32,
+    /// }
+    ///
+    /// impl fmt::Display for Version {
+    ///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    ///         write!(f, "v{}.{}", self.major, self.minor)
+    ///     }
+    /// }
+    ///
+    /// # impl TotalEq for Version {
+    /// #     fn tot_eq(&self, other: &Self) -> bool {
+    /// #         self == other
+    /// #     }
+    /// # }
+    /// #
+    /// # impl TotalHash for Version {
+    /// #     fn tot_hash<H: Hasher>(&self, state: &mut H) {
+    /// #         self.hash(state);
+    /// #     }
+    /// # }
+    /// #
+    /// impl PolarsObject for Version {
+    ///     fn type_name() -> &'static str {
+    ///         "version"
+    ///     }
+    /// }
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe with an object column.
+    ///     let versions = vec![
+    ///         Version { major: 1, minor: 2 },
+    ///         Version { major: 2, minor: 0 },
+    ///         Version { major: 2, minor: 1 },
+    ///     ];
+    ///
+    ///     let df = DataFrame::new(vec![
+    ///         Column::new("Package".into(), &["core", "io", "cli"]),
+    ///         ObjectChunked::new_from_vec("Version".into(), versions)
+    ///             .into_series()
+    ///             .into_column(),
+    ///     ])?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write the object values as strings like "v1.2".
+    ///     xlsx_writer.set_object_handling(ObjectHandling::DisplayString);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    #[cfg(feature = "object")]
+    pub fn set_object_handling(&mut self, handling: ObjectHandling) -> &mut PolarsXlsxWriter {
+        self.options.object_handling = handling;
+        self
+    }
+
+    /// Replace NaN values in the exported dataframe with string values.
+    ///
+    /// By default [`f64::NAN`] values in a dataframe are exported as the string
+    /// "NAN" since Excel does not support NaN values.
+    ///
+    /// This method can be used to supply an alternative string value. See the
+    /// example below.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - A replacement string for Null values.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates handling NaN and Infinity values with custom string
+    /// representations.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_nan_value.rs
+    /// #
+    /// use polars::prelude::*;
+    ///
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Default" => &["NAN", "INF", "-INF"],
+    ///         "Custom" => &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Set custom values for NaN, Infinity, and -Infinity.
+    ///     xlsx_writer.set_nan_value("NaN");
+    ///     xlsx_writer.set_infinity_value("Infinity");
+    ///     xlsx_writer.set_neg_infinity_value("-Infinity");
+    ///
+    ///     // Autofit the output data, for clarity.
+    ///     xlsx_writer.set_autofit(true);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/write_excel_set_nan_value.png">
+    ///
+    pub fn set_nan_value(&mut self, value: impl Into<String>) -> &mut PolarsXlsxWriter {
+        self.options.nan_value = Some(value.into());
+        self
+    }
+
+    /// Replace Infinity values in the exported dataframe with string values.
+    ///
+    /// By default [`f64::INFINITY`] values in a dataframe are exported as the
+    /// string "INF" since Excel does not support Infinity values.
+    ///
+    /// This method can be used to supply an alternative string value. See the
+    /// `set_nan_value()` example above.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - A replacement string for Null values.
+    ///
+    pub fn set_infinity_value(&mut self, value: impl Into<String>) -> &mut PolarsXlsxWriter {
+        self.options.infinity_value = Some(value.into());
+        self
+    }
+
+    /// Replace Negative Infinity values in the exported dataframe with string
+    /// values.
+    ///
+    /// By default [`f64::NEG_INFINITY`] values in a dataframe are exported as
+    /// the string "-INF" since Excel does not support Infinity values.
+    ///
+    /// This method can be used to supply an alternative string value. See the
+    /// `set_nan_value()` example above.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - A replacement string for Null values.
+    ///
+    pub fn set_neg_infinity_value(&mut self, value: impl Into<String>) -> &mut PolarsXlsxWriter {
+        self.options.neg_infinity_value = Some(value.into());
+        self
+    }
+
+    /// Set the handling of NaN and Infinity values.
+    ///
+    /// Excel doesn't support NaN or Infinity numbers so by default they are
+    /// written as the strings "NAN", "INF" and "-INF", or the strings set via
+    /// [`set_nan_value()`](PolarsXlsxWriter::set_nan_value) and the related
+    /// methods. Excel functions such as `SUM()` ignore these strings but they
+    /// can cause errors in formulas that refer to the cells directly.
+    ///
+    /// This method allows the values to be written as a single user defined
+    /// string, as empty cells or as the Excel `#N/A` error value. See
+    /// [`NanHandling`] for the available options.
+    ///
+    /// # Parameters
+    ///
+    /// * `handling` - A [`NanHandling`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates writing NaN and Infinity values as Excel `#N/A` errors.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_nan_handling.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// # use polars_excel_writer::{NanHandling, PolarsXlsxWriter};
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "Sensor" => &["A", "B", "C", "D"],
+    /// #         "Reading" => &[1.5, f64::NAN, 2.25, f64::INFINITY],
+    /// #     )?;
+    /// #
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write the NaN and Infinity values as #N/A errors.
+    ///     xlsx_writer.set_nan_handling(NanHandling::ErrorValue);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_nan_handling(&mut self, handling: NanHandling) -> &mut PolarsXlsxWriter {
+        self.options.nan_handling = handling;
+        self
+    }
+
+    /// Set the policy for values that can't be represented in Excel.
+    ///
+    /// Excel stores numbers as 64 bit floats and dates as a number of days
+    /// from 1900. This means that some Polars values can't be represented
+    /// exactly in Excel:
+    ///
+    /// - `Int64` and `UInt64` values larger than 2^53 lose precision.
+    /// - `Date` and `Datetime` values before 1900-01-01 or after 9999-12-31
+    ///   aren't valid Excel dates.
+    ///
+    /// By default these values are written as the closest Excel value, with a
+    /// [`WarningKind::CoercedValue`] warning for each affected column that can
+    /// be read via [`warnings()`](PolarsXlsxWriter::warnings). This method
+    /// allows them to be written as strings instead or to return an error.
+    /// See [`ErrorPolicy`] for the available options.
+    ///
+    /// # Parameters
+    ///
+    /// * `policy` - An [`ErrorPolicy`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates writing values that can't be represented in Excel as
+    /// strings.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_value_error_policy.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::{ErrorPolicy, PolarsXlsxWriter};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe with an integer that is too large to be
+    ///     // stored exactly in Excel.
+    ///     let df: DataFrame = df!(
+    ///         "Id" => &[1_i64, 9_007_199_254_740_993],
+    ///         "Name" => &["Alpha", "Beta"],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write values that can't be represented in Excel as strings.
+    ///     xlsx_writer.set_value_error_policy(ErrorPolicy::WriteAsString);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_value_error_policy(&mut self, policy: ErrorPolicy) -> &mut PolarsXlsxWriter {
+        self.options.value_error_policy = policy;
+        self
+    }
+
+    /// Set the handling of 64 bit integers that don't fit exactly in Excel.
+    ///
+    /// Excel stores numbers as 64 bit floats so `Int64` and `UInt64` values
+    /// larger than 2^53, such as database or "snowflake" IDs, lose precision
+    /// when they are written as numbers. This method allows these values to be
+    /// written as text cells that preserve all the digits. See
+    /// [`LargeIntHandling`] for the available options.
+    ///
+    /// Only the values larger than 2^53 are affected. Other values in the
+    /// column are written as numbers. This setting takes precedence over
+    /// [`set_value_error_policy()`](PolarsXlsxWriter::set_value_error_policy)
+    /// for integer values.
+    ///
+    /// # Parameters
+    ///
+    /// * `handling` - A [`LargeIntHandling`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates writing large integer IDs as text cells.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_large_int_handling.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::{LargeIntHandling, PolarsXlsxWriter};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe with IDs that are too large to be stored
+    ///     // exactly in Excel.
+    ///     let df: DataFrame = df!(
+    ///         "Id" => &[1_541_815_603_606_036_480_u64, 1_541_815_603_606_036_481],
+    ///         "Name" => &["Alpha", "Beta"],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write the large integers as text cells to preserve all the digits.
+    ///     xlsx_writer.set_large_int_handling(LargeIntHandling::TextFormat);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_large_int_handling(&mut self, handling: LargeIntHandling) -> &mut PolarsXlsxWriter {
+        self.options.large_int_handling = Some(handling);
+        self
+    }
+
+    /// Simulate autofit for columns in the dataframe output.
+    ///
+    /// Use a simulated autofit to adjust dataframe columns to the maximum
+    /// string or number widths.
+    ///
+    /// **Note**: There are several limitations to this autofit method, see the
+    /// `rust_xlsxwriter` docs on [`Worksheet::autofit()`] for details.
+    ///
+    /// [`Worksheet::autofit()`]:
+    ///     https://docs.rs/rust_xlsxwriter/latest/rust_xlsxwriter/worksheet/struct.Worksheet.html#method.autofit
+    ///
+    /// # Parameters
+    ///
+    /// * `autofit` - Turn autofit on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This example
+    /// demonstrates autofitting column widths in the output worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_autofit.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Col 1" => &["A", "B", "C", "D"],
+    ///         "Column 2" => &["A", "B", "C", "D"],
+    ///         "Column 3" => &["Hello", "World", "Hello, world", "Ciao"],
+    ///         "Column 4" => &[1234567, 12345678, 123456789, 1234567],
+    ///     )?;
+    ///
+    ///     // Create a new Excel writer.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Autofit the output data.
+    ///     xlsx_writer.set_autofit(true);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/excelwriter_autofit.png">
+    ///
+    pub fn set_autofit(&mut self, autofit: bool) -> &mut PolarsXlsxWriter {
+        self.options.use_autofit = autofit;
+        self
+    }
+
+    /// Autofit numeric and date columns using the width of the formatted
+    /// values.
+    ///
+    /// The standard [`set_autofit()`](PolarsXlsxWriter::set_autofit) option
+    /// measures numbers by their unformatted digits and uses a fixed width for
+    /// all dates and times. This can give columns that are too wide for floats
+    /// with a short number format, such as `0.00`, or too narrow for formats
+    /// with thousands separators, currency symbols or long datetimes. Excel
+    /// displays `#####` for numbers that don't fit in a column.
+    ///
+    /// This option estimates the width of each numeric, decimal, date,
+    /// datetime, time and duration column from its values as they would be
+    /// rendered with the column number format, either the default format for
+    /// the type or a format set via
+    /// [`set_dtype_format()`](PolarsXlsxWriter::set_dtype_format). Other
+    /// columns, and columns with a `rust_xlsxwriter` [`Format`] whose number
+    /// format isn't known (see [`CellFormat`]), use the standard autofit.
+    ///
+    /// The option only applies when autofit is turned on and, like autofit,
+    /// it isn't supported in constant memory mode. The formatted widths are
+    /// limited by
+    /// [`set_autofit_max_width()`](PolarsXlsxWriter::set_autofit_max_width),
+    /// if it is set.
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the format aware autofit on/off. It is off by
+    ///   default.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates autofitting columns using the width of the formatted
+    /// values and a maximum column width.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_autofit_with_formats.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// # use chrono::prelude::*;
+    /// #
+    /// # use polars_excel_writer::PolarsXlsxWriter;
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "Region" => &["North", "South", "East", "West"],
+    /// #         "Sales" => &[1234567.891, 22345678.5, 345.25, 4567890.0],
+    /// #         "Updated" => &[
+    /// #             NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(9, 30, 0).unwrap(),
+    /// #             NaiveDate::from_ymd_opt(2024, 1, 2).unwrap().and_hms_opt(10, 0, 0).unwrap(),
+    /// #             NaiveDate::from_ymd_opt(2024, 1, 3).unwrap().and_hms_opt(11, 15, 0).unwrap(),
+    /// #             NaiveDate::from_ymd_opt(2024, 1, 4).unwrap().and_hms_opt(12, 45, 0).unwrap(),
+    /// #         ],
+    /// #         "Notes" => &[
+    /// #             "A short note",
+    /// #             "A much longer note that would make the column very wide if it was autofitted",
+    /// #             "",
+    /// #             "Another note",
+    /// #         ],
+    /// #     )?;
+    /// #
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Autofit the columns using the formatted values, with a maximum
+    ///     // width of 30 characters.
+    ///     xlsx_writer
+    ///         .set_dtype_format(DataType::Float64, "#,##0.00 [$€-407]")
+    ///         .set_autofit(true)
+    ///         .set_autofit_with_formats(true)
+    ///         .set_autofit_max_width(30.0);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_autofit_with_formats(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
+        self.options.autofit_with_formats = enable;
+        self
+    }
+
+    /// Set the maximum width of autofitted columns.
+    ///
+    /// Excel autofits columns with long strings up to a width of 255
+    /// characters, which is usually too wide to view on a screen. This option
+    /// sets a lower maximum width for columns that are adjusted via
+    /// [`set_autofit()`](PolarsXlsxWriter::set_autofit). Widths set via
+    /// [`set_column_width()`](PolarsXlsxWriter::set_column_width) aren't
+    /// limited. The widths of formatted numbers via
+    /// [`set_autofit_with_formats()`](PolarsXlsxWriter::set_autofit_with_formats)
+    /// are also limited.
+    ///
+    /// See [`set_autofit_with_formats()`](PolarsXlsxWriter::set_autofit_with_formats)
+    /// for an example.
+    ///
+    /// # Parameters
+    ///
+    /// * `width` - The maximum column width in character units.
+    ///
+    pub fn set_autofit_max_width(&mut self, width: f64) -> &mut PolarsXlsxWriter {
+        self.options.autofit_max_width = Some(width);
+        self
+    }
+
+    /// Exclude dataframe columns from autofit.
+    ///
+    /// When [`set_autofit()`](PolarsXlsxWriter::set_autofit) is on all the
+    /// worksheet columns are adjusted to the width of their data. This option
+    /// excludes the named columns so that they keep the default Excel column
+    /// width. This is useful for columns of long text, such as notes or
+    /// descriptions, that would otherwise make the column very wide.
+    ///
+    /// Columns with a width set via
+    /// [`set_column_width()`](PolarsXlsxWriter::set_column_width) are also
+    /// excluded from autofit. Both types of column keep their width if the
+    /// worksheet is autofitted again for other dataframes written to it.
+    ///
+    /// # Parameters
+    ///
+    /// * `columns` - The names of the dataframe columns to exclude. Column
+    ///   names that aren't in the dataframe are ignored.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates excluding a column from autofit.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_autofit_exclude.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// # use polars_excel_writer::PolarsXlsxWriter;
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "Region" => &["North", "South", "East", "West"],
+    /// #         "Sales" => &[1000, 2300, 1900, 2100],
+    /// #         "Notes" => &[
+    /// #             "Sales were in line with the forecast for the quarter",
+    /// #             "A new sales office opened at the start of the quarter",
+    /// #             "",
+    /// #             "Sales were lower than expected due to supply issues",
+    /// #         ],
+    /// #     )?;
+    /// #
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Autofit the columns apart from the "Notes" column.
+    ///     xlsx_writer
+    ///         .set_autofit(true)
+    ///         .set_autofit_exclude(&["Notes"]);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_autofit_exclude(&mut self, columns: &[&str]) -> &mut PolarsXlsxWriter {
+        self.options.autofit_exclude = columns.iter().map(|name| (*name).to_string()).collect();
+        self
+    }
+
+    /// Turn on/off the default width of date and datetime columns.
+    ///
+    /// By default the columns of date and datetime data are set to a width of
+    /// 10 and 18 characters respectively so that the values with the default
+    /// formats aren't displayed by Excel as `#####`. This option turns off
+    /// the default widths, for example to keep the column widths of a
+    /// worksheet passed to
+    /// [`write_dataframe_to_worksheet()`](PolarsXlsxWriter::write_dataframe_to_worksheet).
+    ///
+    /// The default widths are set before the data is written so that widths
+    /// set via [`set_column_width()`](PolarsXlsxWriter::set_column_width)
+    /// take precedence, as does autofit via
+    /// [`set_autofit()`](PolarsXlsxWriter::set_autofit). The standard autofit
+    /// only widens the default widths since it uses a fixed width for all
+    /// dates, but the default widths aren't used with
+    /// [`set_autofit_with_formats()`](PolarsXlsxWriter::set_autofit_with_formats),
+    /// which measures the formatted values.
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the default widths on/off. They are on by default.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates turning off the default width of date columns.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_auto_datetime_width.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// # use chrono::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Date" => &[
+    ///             NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+    ///             NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+    ///             NaiveDate::from_ymd_opt(2024, 1, 3).unwrap(),
+    ///         ],
+    ///         "Sales" => &[1000, 2300, 1900],
+    ///     )?;
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Use a short date format that fits in the default column width.
+    ///     xlsx_writer
+    ///         .set_date_format("d/m")
+    ///         .set_auto_datetime_width(false);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_auto_datetime_width(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
+        self.options.auto_datetime_width = enable;
+        self
+    }
+
+    /// Set the worksheet zoom factor.
+    ///
+    /// Set the worksheet zoom factor in the range `10 <= zoom <= 400`.
+    ///
+    /// # Parameters
+    ///
+    /// * `zoom` - The worksheet zoom level. The default zoom level is 100.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting the worksheet zoom level.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_zoom.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// # use polars_excel_writer::PolarsXlsxWriter;
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "String" => &["North", "South", "East", "West"],
+    /// #         "Int" => &[1, 2, 3, 4],
+    /// #         "Float" => &[1.0, 2.22, 3.333, 4.4444],
+    /// #     )?;
+    /// #
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Set the worksheet zoom level.
+    ///     xlsx_writer.set_zoom(200);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/write_excel_set_zoom.png">
+    ///
+    pub fn set_zoom(&mut self, zoom: u16) -> &mut PolarsXlsxWriter {
+        self.options.zoom = zoom;
+        self
+    }
+
+    /// Set the option to turn on/off the screen gridlines.
+    ///
+    /// The `set_screen_gridlines()` method is use to turn on/off gridlines on
+    /// displayed worksheet. It is on by default.
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is on by default.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates turning off the screen gridlines.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_screen_gridlines.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// # use polars_excel_writer::PolarsXlsxWriter;
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "String" => &["North", "South", "East", "West"],
+    /// #         "Int" => &[1, 2, 3, 4],
+    /// #         "Float" => &[1.0, 2.22, 3.333, 4.4444],
+    /// #     )?;
+    /// #
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Turn off the screen gridlines.
+    ///     xlsx_writer.set_screen_gridlines(false);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/write_excel_set_screen_gridlines.png">
+    ///
+    pub fn set_screen_gridlines(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
+        self.options.screen_gridlines = enable;
+
+        self
+    }
+
+    /// Set the color of the worksheet tab.
+    ///
+    /// Set the tab color of the worksheets written by the writer. This is
+    /// useful to distinguish the worksheets of a multi-sheet workbook created
+    /// via [`add_worksheet()`](PolarsXlsxWriter::add_worksheet), without having
+    /// to get a reference to each worksheet.
+    ///
+    /// Like other writer options the color applies to any subsequent
+    /// worksheets that are written until it is changed.
+    ///
+    /// # Parameters
+    ///
+    /// * `color` - The tab color property defined by a `rust_xlsxwriter`
+    ///   [`Color`] enum value or a type that converts to a color such as a
+    ///   html style `"#RRGGBB"` string.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing Polar Rust dataframes to an Excel file. This
+    /// demonstrates setting the colors of the worksheet tabs.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_tab_color.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::Color;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create some sample dataframes for the example.
+    ///     let df1: DataFrame = df!("Data 1" => &[10, 20, 15, 25, 30, 20])?;
+    ///     let df2: DataFrame = df!("Data 2" => &[1.23, 2.34, 3.56])?;
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write the first dataframe with a red tab.
+    ///     xlsx_writer.set_tab_color(Color::Red);
+    ///     xlsx_writer.write_dataframe(&df1)?;
+    ///
+    ///     // Add another worksheet and write the second dataframe with a green tab.
+    ///     xlsx_writer.add_worksheet();
+    ///     xlsx_writer.set_tab_color(Color::Green);
+    ///     xlsx_writer.write_dataframe(&df2)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_tab_color(&mut self, color: impl Into<Color>) -> &mut PolarsXlsxWriter {
+        self.options.tab_color = Some(color.into());
+        self
+    }
+
+    /// Set the option to hide the worksheet.
+    ///
+    /// Hide the worksheets written by the writer. This is useful for
+    /// worksheets with lookup data, or the data of charts, that doesn't need
+    /// to be displayed. The worksheets can be shown again by the user in
+    /// Excel.
+    ///
+    /// If the first worksheet of the workbook is hidden when the file is
+    /// saved then the first visible worksheet is made the active worksheet. At
+    /// least one worksheet in the workbook must be visible.
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing Polar Rust dataframes to an Excel file. This
+    /// demonstrates hiding a worksheet with lookup data.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_sheet_hidden.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create some sample dataframes for the example.
+    ///     let sales: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[100, 120, 140, 130],
+    ///     )?;
+    ///     let regions: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Manager" => &["Ann", "Bob", "Cy", "Di"],
+    ///     )?;
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write the sales data to the first worksheet.
+    ///     xlsx_writer.write_dataframe(&sales)?;
+    ///
+    ///     // Write the lookup data to a hidden worksheet.
+    ///     xlsx_writer.add_worksheet();
+    ///     xlsx_writer.set_sheet_hidden(true);
+    ///     xlsx_writer.write_dataframe(&regions)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_sheet_hidden(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
+        self.options.sheet_hidden = enable;
+        self
+    }
+
+    /// Set the position of the worksheet in the workbook.
+    ///
+    /// Worksheets are added to the end of the workbook by default. This method
+    /// moves the worksheets written by the writer to a zero indexed position
+    /// in the workbook, such as `0` for the first worksheet. This allows a
+    /// summary worksheet to be written after the data that it summarizes but
+    /// displayed before it.
+    ///
+    /// The worksheets are moved when the workbook is saved, in the order that
+    /// they were written. A position past the end of the workbook moves the
+    /// worksheet to the end. Like other writer options the position applies
+    /// to any subsequent worksheets that are written until it is changed.
+    ///
+    /// # Parameters
+    ///
+    /// * `index` - The zero indexed position of the worksheet in the workbook.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing Polar Rust dataframes to an Excel file. This
+    /// demonstrates moving a summary worksheet to the start of the workbook.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_sheet_position.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create some sample dataframes for the example.
+    ///     let sales: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[100, 120, 140, 130],
+    ///     )?;
+    ///     let summary: DataFrame = df!("Total" => &[490])?;
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write the sales data to the first worksheet.
+    ///     xlsx_writer.set_worksheet_name("Sales")?;
+    ///     xlsx_writer.write_dataframe(&sales)?;
+    ///
+    ///     // Write the summary to a worksheet at the start of the workbook.
+    ///     xlsx_writer.add_worksheet();
+    ///     xlsx_writer.set_worksheet_name("Summary")?;
+    ///     xlsx_writer.set_sheet_position(0);
+    ///     xlsx_writer.write_dataframe(&summary)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_sheet_position(&mut self, index: usize) -> &mut PolarsXlsxWriter {
+        self.options.sheet_position = Some(index);
+        self
+    }
+
+    /// Set the option to make the worksheet the active worksheet.
+    ///
+    /// The active worksheet is the worksheet that is displayed when the
+    /// workbook is opened in Excel. By default this is the first worksheet.
+    /// This method makes the worksheet written by the writer the active
+    /// worksheet instead, for example to open a report on a summary
+    /// worksheet that isn't the first worksheet.
+    ///
+    /// Only one worksheet can be active. If the option is set for more than one
+    /// worksheet the last worksheet written with it is the active worksheet.
+    /// The active worksheet can't be hidden so this option takes precedence
+    /// over [`set_sheet_hidden()`](PolarsXlsxWriter::set_sheet_hidden).
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing Polar Rust dataframes to an Excel file. This
+    /// demonstrates opening the workbook on a worksheet other than the first
+    /// one, with a selected cell.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_sheet_active.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create some sample dataframes for the example.
+    ///     let sales: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[100, 120, 140, 130],
+    ///     )?;
+    ///     let summary: DataFrame = df!("Total" => &[490])?;
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write the sales data to the first worksheet.
+    ///     xlsx_writer.set_worksheet_name("Sales")?;
+    ///     xlsx_writer.write_dataframe(&sales)?;
+    ///
+    ///     // Write the summary to the active worksheet with the total selected.
+    ///     xlsx_writer.add_worksheet();
+    ///     xlsx_writer.set_worksheet_name("Summary")?;
+    ///     xlsx_writer.set_sheet_active(true);
+    ///     xlsx_writer.set_selection(1, 0);
+    ///     xlsx_writer.write_dataframe(&summary)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_sheet_active(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
+        self.options.sheet_active = enable;
+        self
+    }
+
+    /// Set the option to display the worksheet from right to left.
+    ///
+    /// Set the worksheets written by the writer to display from right to left,
+    /// with the first column on the right hand side. This is the default
+    /// display direction in Excel for right-to-left languages such as Hebrew
+    /// and Arabic.
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates displaying the worksheet from right to left.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_right_to_left.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe with Hebrew headers and data.
+    ///     let df: DataFrame = df!(
+    ///         "אזור" => &["צפון", "דרום", "מזרח", "מערב"],
+    ///         "מכירות" => &[100, 120, 140, 130],
+    ///     )?;
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Display the worksheet from right to left.
+    ///     xlsx_writer.set_right_to_left(true);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_right_to_left(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
+        self.options.right_to_left = enable;
+        self
+    }
+
+    /// Protect the worksheets from modification.
+    ///
+    /// The `set_worksheet_protected()` method protects the worksheets that
+    /// dataframes are written to so that end users can read but not modify
+    /// the data. An optional password can be used to prevent the user from
+    /// turning off the protection in Excel.
+    ///
+    /// The protection is applied to every worksheet written by the
+    /// `PolarsXlsxWriter`, including worksheets added for rows that overflow
+    /// the first worksheet. See also
+    /// [`set_read_only_recommended()`](PolarsXlsxWriter::set_read_only_recommended)
+    /// for a workbook level option.
+    ///
+    /// Note, the password isn't encrypted and only provides a weak level of
+    /// protection against accidental changes to the worksheet.
+    ///
+    /// # Parameters
+    ///
+    /// * `password` - An optional password to unprotect the worksheet.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates protecting the worksheet with a password.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_worksheet_protected.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// # use polars_excel_writer::PolarsXlsxWriter;
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "String" => &["North", "South", "East", "West"],
+    /// #         "Int" => &[1, 2, 3, 4],
+    /// #         "Float" => &[1.0, 2.22, 3.333, 4.4444],
+    /// #     )?;
+    /// #
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Protect the worksheet with a password.
+    ///     xlsx_writer.set_worksheet_protected(Some("abc123"));
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_worksheet_protected(&mut self, password: Option<&str>) -> &mut PolarsXlsxWriter {
+        self.options.worksheet_protected = true;
+        self.options.worksheet_password = password.map(str::to_string);
+
+        self
+    }
+
+    /// Set the page orientation to landscape for printing.
+    ///
+    /// The default page orientation when printing a worksheet is portrait.
+    /// This method sets it to landscape which is usually better suited to
+    /// dataframes with a large number of columns.
+    ///
+    /// This and the other page setup methods such as
+    /// [`set_paper_size()`](PolarsXlsxWriter::set_paper_size),
+    /// [`set_print_area_to_table()`](PolarsXlsxWriter::set_print_area_to_table),
+    /// [`set_repeat_header_row()`](PolarsXlsxWriter::set_repeat_header_row)
+    /// and [`set_fit_to_pages()`](PolarsXlsxWriter::set_fit_to_pages) only
+    /// affect the printed, or PDF, output of the worksheet.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting the page setup options for printing the
+    /// worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_page_setup.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Q1" => &[100, 110, 120, 130],
+    ///         "Q2" => &[200, 210, 220, 230],
+    ///         "Q3" => &[300, 310, 320, 330],
+    ///         "Q4" => &[400, 410, 420, 430],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Print the table in landscape on A4 paper, one page wide, with
+    ///     // the header row repeated on each page.
+    ///     xlsx_writer.set_landscape();
+    ///     xlsx_writer.set_paper_size(9);
+    ///     xlsx_writer.set_print_area_to_table(true);
+    ///     xlsx_writer.set_repeat_header_row(true);
+    ///     xlsx_writer.set_fit_to_pages(1, 0);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_landscape(&mut self) -> &mut PolarsXlsxWriter {
+        self.options.landscape = true;
+
+        self
+    }
+
+    /// Set the paper type/size for printing.
+    ///
+    /// Set the Excel paper format index, such as 1 for US Letter or 9 for A4.
+    /// See [`Worksheet::set_paper_size()`] for the full list of paper sizes.
+    /// The default, 0, is the printer's default paper size. See the example in
+    /// [`set_landscape()`](PolarsXlsxWriter::set_landscape).
+    ///
+    /// # Parameters
+    ///
+    /// * `paper_size` - The Excel paper format index.
+    ///
+    pub fn set_paper_size(&mut self, paper_size: u8) -> &mut PolarsXlsxWriter {
+        self.options.paper_size = paper_size;
+
+        self
+    }
+
+    /// Set the print area to the output table.
+    ///
+    /// Set the printed area of the worksheet to the cell range of the output
+    /// table, including the header and any total row, so that other data in
+    /// the worksheet isn't printed. See the example in
+    /// [`set_landscape()`](PolarsXlsxWriter::set_landscape).
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    pub fn set_print_area_to_table(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
+        self.options.print_area_to_table = enable;
+
+        self
+    }
+
+    /// Repeat the table header row at the top of each printed page.
+    ///
+    /// This is useful for dataframes that span several printed pages. It has
+    /// no effect if the dataframe is written without a header. See the example
+    /// in [`set_landscape()`](PolarsXlsxWriter::set_landscape).
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    pub fn set_repeat_header_row(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
+        self.options.repeat_header_row = enable;
+
+        self
+    }
+
+    /// Fit the printed area to a number of pages wide and high.
+    ///
+    /// Scale the printed worksheet to fit within the given number of pages. A
+    /// value of 0 for either the width or height means that the number of
+    /// pages in that direction isn't limited. For example `(1, 0)` fits the
+    /// columns to the width of one page with as many pages in height as
+    /// required. See the example in
+    /// [`set_landscape()`](PolarsXlsxWriter::set_landscape).
+    ///
+    /// # Parameters
+    ///
+    /// * `width` - The number of pages horizontally.
+    /// * `height` - The number of pages vertically.
+    ///
+    pub fn set_fit_to_pages(&mut self, width: u16, height: u16) -> &mut PolarsXlsxWriter {
+        self.options.fit_to_pages = Some((width, height));
+
+        self
+    }
+
+    /// Set the printed page header for the worksheets.
+    ///
+    /// Set the header text that is shown at the top of each printed page of
+    /// the worksheets written by the writer. The text can contain the Excel
+    /// control sequences such as `&L`, `&C` and `&R` for the left, center and
+    /// right sections and `&P` and `&N` for the page number and number of
+    /// pages. See [`Worksheet::set_header()`] for the full list of control
+    /// sequences.
+    ///
+    /// # Parameters
+    ///
+    /// * `header` - The header string with optional control sequences.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting a header and footer for the printed pages.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_print_header.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// # use polars_excel_writer::PolarsXlsxWriter;
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "String" => &["North", "South", "East", "West"],
+    /// #         "Int" => &[1, 2, 3, 4],
+    /// #         "Float" => &[1.0, 2.22, 3.333, 4.4444],
+    /// #     )?;
+    /// #
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Set the header and footer for the printed pages.
+    ///     xlsx_writer.set_print_header("&LSales Report&RConfidential");
+    ///     xlsx_writer.set_print_footer("&CPage &P of &N");
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_print_header(&mut self, header: impl Into<String>) -> &mut PolarsXlsxWriter {
+        self.options.print_header = Some(header.into());
+
+        self
+    }
+
+    /// Set the printed page footer for the worksheets.
+    ///
+    /// Set the footer text that is shown at the bottom of each printed page of
+    /// the worksheets written by the writer. It supports the same control
+    /// sequences as [`set_print_header()`](PolarsXlsxWriter::set_print_header),
+    /// which also has an example.
+    ///
+    /// # Parameters
+    ///
+    /// * `footer` - The footer string with optional control sequences.
+    ///
+    pub fn set_print_footer(&mut self, footer: impl Into<String>) -> &mut PolarsXlsxWriter {
+        self.options.print_footer = Some(footer.into());
+
+        self
+    }
+
+    /// Freeze panes in a worksheet.
+    ///
+    /// The `set_freeze_panes()` method can be used to divide a worksheet into
+    /// horizontal or vertical regions known as panes and to “freeze” these
+    /// panes so that the splitter bars are not visible.
+    ///
+    /// As with Excel the split is to the top and left of the cell. So to freeze
+    /// the top row and leftmost column you would use `(1, 1)` (zero-indexed).
+    ///
+    /// You can set one of the row and col parameters as 0 if you do not want
+    /// either the vertical or horizontal split. For example a common
+    /// requirement is to freeze the top row which is done with the arguments
+    /// `(1, 0)` see below.
+    ///
+    ///
+    /// # Parameters
+    ///
+    /// * `row` - The zero indexed row number.
+    /// * `col` - The zero indexed column number.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates freezing the top row.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_freeze_panes.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// # use polars_excel_writer::PolarsXlsxWriter;
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "String" => &["North", "South", "East", "West"],
+    /// #         "Int" => &[1, 2, 3, 4],
+    /// #         "Float" => &[1.0, 2.22, 3.333, 4.4444],
+    /// #     )?;
+    /// #
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Freeze the top row.
+    ///     xlsx_writer.set_freeze_panes(1, 0);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/write_excel_set_freeze_panes.png">
+    ///
+    pub fn set_freeze_panes(&mut self, row: u32, col: u16) -> &mut PolarsXlsxWriter {
+        self.options.freeze_cell = (row, col);
+
+        self
+    }
+
+    /// Freeze panes in a worksheet using an Excel A1 style cell reference.
+    ///
+    /// This is the same as [`set_freeze_panes()`](PolarsXlsxWriter::set_freeze_panes)
+    /// except that the cell is set using Excel's A1 notation, such as `"A2"`
+    /// to freeze the top row. This is the same as the string form of the
+    /// `freeze_panes` parameter of the Polars [`write_excel()`] method.
+    ///
+    /// The Polars `(cell, row, col)` tuple form, such as `("C2", 9, 4)`, is
+    /// equivalent to calling this method with the cell followed by
+    /// [`set_freeze_panes_top_cell()`](PolarsXlsxWriter::set_freeze_panes_top_cell)
+    /// with the row and column.
+    ///
+    /// [`write_excel()`]:
+    ///     https://pola-rs.github.io/polars/py-polars/html/reference/api/polars.DataFrame.write_excel.html#polars.DataFrame.write_excel
+    ///
+    /// # Parameters
+    ///
+    /// * `cell` - The A1 style cell reference. Absolute references such as
+    ///   `"$A$2"` are also supported.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ComputeError`] error if the cell reference isn't valid.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates freezing the top row using a cell reference.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_freeze_panes_cell.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// # use polars_excel_writer::PolarsXlsxWriter;
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "String" => &["North", "South", "East", "West"],
+    /// #         "Int" => &[1, 2, 3, 4],
+    /// #         "Float" => &[1.0, 2.22, 3.333, 4.4444],
+    /// #     )?;
+    /// #
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Freeze the top row.
+    ///     xlsx_writer.set_freeze_panes_cell("A2")?;
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_freeze_panes_cell(&mut self, cell: &str) -> PolarsResult<&mut PolarsXlsxWriter> {
+        self.options.freeze_cell = Self::parse_cell_reference(cell)?;
+
+        Ok(self)
+    }
+
+    /// Set the top most cell in the scrolling area of a freeze pane.
+    ///
+    /// This method is used in conjunction with the
+    /// [`PolarsXlsxWriter::set_freeze_panes()`] method to set the top most
+    /// visible cell in the scrolling range. For example you may want to freeze
+    /// the top row but have the worksheet pre-scrolled so that a cell other
+    /// than `(0, 0)` is visible in the scrolled area.
+    ///
+    /// # Parameters
+    ///
+    /// * `row` - The zero indexed row number.
+    /// * `col` - The zero indexed column number.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates freezing the top row and setting a non-default first row
+    /// within the pane.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_freeze_panes_top_cell.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// # use polars_excel_writer::PolarsXlsxWriter;
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "String" => &["North", "South", "East", "West"],
+    /// #         "Int" => &[1, 2, 3, 4],
+    /// #         "Float" => &[1.0, 2.22, 3.333, 4.4444],
+    /// #     )?;
+    /// #
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Freeze the top row and set the first row in the range.
+    ///     xlsx_writer.set_freeze_panes(1, 0);
+    ///     xlsx_writer.set_freeze_panes_top_cell(3, 0);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/write_excel_set_freeze_panes_top_cell.png">
+    ///
+    pub fn set_freeze_panes_top_cell(&mut self, row: u32, col: u16) -> &mut PolarsXlsxWriter {
+        self.options.top_cell = (row, col);
+
+        self
+    }
+
+    /// Set the top most cell in the scrolling area of a freeze pane using an
+    /// Excel A1 style cell reference.
+    ///
+    /// This is the same as
+    /// [`set_freeze_panes_top_cell()`](PolarsXlsxWriter::set_freeze_panes_top_cell)
+    /// except that the cell is set using Excel's A1 notation, such as `"A4"`.
+    /// See also
+    /// [`set_freeze_panes_cell()`](PolarsXlsxWriter::set_freeze_panes_cell).
+    ///
+    /// # Parameters
+    ///
+    /// * `cell` - The A1 style cell reference.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ComputeError`] error if the cell reference isn't valid.
+    ///
+    pub fn set_freeze_panes_top_cell_ref(
+        &mut self,
+        cell: &str,
+    ) -> PolarsResult<&mut PolarsXlsxWriter> {
+        self.options.top_cell = Self::parse_cell_reference(cell)?;
+
+        Ok(self)
+    }
+
+    /// Set the selected cell in the worksheet.
+    ///
+    /// Set the cell that is selected when the worksheet is opened in Excel.
+    /// By default this is cell `A1`. This is useful to direct the user to a
+    /// particular cell, such as a total, in a generated report. The selection
+    /// is set for the worksheets written by the writer.
+    ///
+    /// See [`set_sheet_active()`](PolarsXlsxWriter::set_sheet_active) for an
+    /// example.
+    ///
+    /// # Parameters
+    ///
+    /// * `row` - The zero indexed row number.
+    /// * `col` - The zero indexed column number.
+    ///
+    pub fn set_selection(&mut self, row: u32, col: u16) -> &mut PolarsXlsxWriter {
+        self.options.selection = Some((row, col));
+        self
+    }
+
+    /// Turn on/off the autofilter for the table header.
+    ///
+    /// By default Excel adds an autofilter to the header of a table. This
+    /// method can be used to turn it off if necessary.
+    ///
+    /// This method also controls the worksheet autofilter that is added when
+    /// the dataframe is written as a plain range via
+    /// [`set_use_table()`](PolarsXlsxWriter::set_use_table).
+    ///
+    /// Note, you can call this method directly on a [`Table`] object which is
+    /// passed to [`PolarsXlsxWriter::set_table()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is on by default.
+    ///
+    pub fn set_autofilter(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
+        let table = self.options.table.clone().set_autofilter(enable);
+        self.options.table = table;
+        self.options.use_autofilter = enable;
+
+        self
+    }
+
+    /// Set a filter condition on a column of the output table.
+    ///
+    /// Apply a `rust_xlsxwriter` [`FilterCondition`] to the autofilter of a
+    /// named dataframe column so that the worksheet opens with the data
+    /// already filtered. Rows that don't match the filter conditions are
+    /// hidden. This is similar to the `rust_xlsxwriter`
+    /// [`Worksheet::filter_column()`] method but the column is set by name.
+    ///
+    /// Since `rust_xlsxwriter` doesn't support filter conditions on table
+    /// autofilters the table autofilter is replaced by a worksheet autofilter,
+    /// with the same range, when filter conditions are set. Column names that
+    /// aren't in the dataframe are ignored.
+    ///
+    /// Note, rows can't be hidden in the constant memory worksheets used by
+    /// [`write_dataframe_chunked()`](PolarsXlsxWriter::write_dataframe_chunked)
+    /// so in that case the filter is shown as applied but the rows aren't
+    /// hidden until the filter is reapplied in Excel.
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the column to filter.
+    /// * `condition` - A `rust_xlsxwriter` [`FilterCondition`].
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates filtering the output table by the values in a column.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_filter.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::FilterCondition;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West", "West", "East"],
+    ///         "Sales" => &[1000, 2300, 1900, 2500, 1500, 1700],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Only show the rows for the "West" region.
+    ///     let condition = FilterCondition::new().add_list_filter("West");
+    ///     xlsx_writer.set_filter("Region", &condition);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_filter(
+        &mut self,
+        column_name: &str,
+        condition: &FilterCondition,
+    ) -> &mut PolarsXlsxWriter {
+        self.options
+            .filter_conditions
+            .push((column_name.to_string(), condition.clone()));
+
+        self
+    }
+
+    /// Set the style of the worksheet table.
+    ///
+    /// Excel supports 61 different styles for tables divided into Light, Medium
+    /// and Dark categories. This method sets the table style without having to
+    /// create and set a full [`Table`] object. It is similar to the
+    /// `table_style` parameter of the Polars [`write_excel()`] method.
+    ///
+    /// Note, you can call this method directly on a [`Table`] object which is
+    /// passed to [`PolarsXlsxWriter::set_table()`].
+    ///
+    /// [`write_excel()`]:
+    ///     https://pola-rs.github.io/polars/py-polars/html/reference/api/polars.DataFrame.write_excel.html#polars.DataFrame.write_excel
+    ///
+    /// # Parameters
+    ///
+    /// * `style` - A `rust_xlsxwriter` [`TableStyle`] enum value. The default
+    ///   table style in Excel is equivalent to [`TableStyle::Medium9`].
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting the style and banding of the worksheet table.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_table_style.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// # use polars_excel_writer::PolarsXlsxWriter;
+    /// # use rust_xlsxwriter::TableStyle;
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "String" => &["North", "South", "East", "West"],
+    /// #         "Int" => &[1, 2, 3, 4],
+    /// #         "Float" => &[1.0, 2.22, 3.333, 4.4444],
+    /// #     )?;
+    /// #
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Set the table style and banding.
+    ///     xlsx_writer
+    ///         .set_table_style(TableStyle::Medium4)
+    ///         .set_table_banded_rows(false)
+    ///         .set_table_banded_columns(true)
+    ///         .set_table_first_column(true);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_table_style(&mut self, style: TableStyle) -> &mut PolarsXlsxWriter {
+        let table = self.options.table.clone().set_style(style);
+        self.options.table = table;
+
+        self
+    }
+
+    /// Turn on/off banded rows in the worksheet table.
+    ///
+    /// By default Excel uses banded rows of alternating colors in a table to
+    /// distinguish each data row. This method can be used to turn them off.
+    ///
+    /// Note, you can call this method directly on a [`Table`] object which is
+    /// passed to [`PolarsXlsxWriter::set_table()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is on by default.
+    ///
+    pub fn set_table_banded_rows(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
+        let table = self.options.table.clone().set_banded_rows(enable);
+        self.options.table = table;
+
+        self
+    }
+
+    /// Turn on/off banded columns in the worksheet table.
+    ///
+    /// Banded columns are alternating colors in a table that distinguish each
+    /// data column. They are off by default.
+    ///
+    /// Note, you can call this method directly on a [`Table`] object which is
+    /// passed to [`PolarsXlsxWriter::set_table()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    pub fn set_table_banded_columns(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
+        let table = self.options.table.clone().set_banded_columns(enable);
+        self.options.table = table;
+
+        self
+    }
+
+    /// Turn on/off the first column highlighting in the worksheet table.
+    ///
+    /// The first column of a table can be highlighted with a different format
+    /// to the other columns. This is generally bold text in the default style.
+    ///
+    /// Note, you can call this method directly on a [`Table`] object which is
+    /// passed to [`PolarsXlsxWriter::set_table()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    pub fn set_table_first_column(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
+        let table = self.options.table.clone().set_first_column(enable);
+        self.options.table = table;
+
+        self
+    }
+
+    /// Turn on/off the last column highlighting in the worksheet table.
+    ///
+    /// The last column of a table can be highlighted with a different format
+    /// to the other columns. This is generally bold text in the default style.
+    ///
+    /// Note, you can call this method directly on a [`Table`] object which is
+    /// passed to [`PolarsXlsxWriter::set_table()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    pub fn set_table_last_column(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
+        let table = self.options.table.clone().set_last_column(enable);
+        self.options.table = table;
+
+        self
+    }
+
+    /// Set alternating formats for the data rows.
+    ///
+    /// Apply alternating "zebra stripe" formats to the data rows of the
+    /// output. This is useful for plain ranges written with
+    /// [`set_use_table(false)`](PolarsXlsxWriter::set_use_table) or for
+    /// banding formats that can't be expressed with a table style.
+    ///
+    /// The formats are [`ColumnFormat`]s so only the properties that are set,
+    /// such as the fill color, are combined with the format of each cell. The
+    /// number format and the other properties of the column are kept. Rows are
+    /// counted from 0, like the dataframe rows, so the first data row uses the
+    /// `even` format. Null cells are written as blank cells with the row
+    /// format so that the bands are continuous.
+    ///
+    /// The formats apply to the cells of the dataframe columns. The cells of
+    /// inserted formula, row total and sparkline columns aren't banded. The
+    /// formats returned by a cell style callback, see
+    /// [`set_cell_style_callback()`](PolarsXlsxWriter::set_cell_style_callback),
+    /// take precedence.
+    ///
+    /// When used with a worksheet table you will generally also want to turn
+    /// off the table banding with
+    /// [`set_table_banded_rows(false)`](PolarsXlsxWriter::set_table_banded_rows).
+    ///
+    /// # Parameters
+    ///
+    /// * `even` - The [`ColumnFormat`] properties for the even data rows.
+    /// * `odd` - The [`ColumnFormat`] properties for the odd data rows.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting alternating formats for the data rows of a plain
+    /// range.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_banded_row_formats.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::{ColumnFormat, PolarsXlsxWriter};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West", "Central"],
+    ///         "Sales" => &[1000, 2300, 1900, 2500, 1700],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write the data as a plain range with alternating row colors.
+    ///     xlsx_writer.set_use_table(false);
+    ///     xlsx_writer.set_banded_row_formats(
+    ///         &ColumnFormat::new().set_background_color("DDEBF7"),
+    ///         &ColumnFormat::new().set_background_color("FFFFFF"),
+    ///     );
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_banded_row_formats(
+        &mut self,
+        even: &ColumnFormat,
+        odd: &ColumnFormat,
+    ) -> &mut PolarsXlsxWriter {
+        self.options.banded_row_formats = Some((even.clone(), odd.clone()));
+        self
+    }
+
+    /// Set the worksheet table for the output dataframe.
+    ///
+    /// By default, and by convention with the Polars [`write_excel()`] method,
+    /// `PolarsXlsxWriter` adds an Excel worksheet table to each exported
+    /// dataframe.
+    ///
+    /// Tables in Excel are a way of grouping a range of cells into a single
+    /// entity that has common formatting or that can be referenced from
+    /// formulas. Tables can have column headers, autofilters, total rows,
+    /// column formulas and different formatting styles.
+    ///
+    /// The image below shows a default table in Excel with the default
+    /// properties shown in the ribbon bar.
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/table_intro.png">
+    ///
+    /// The `set_table()` method allows you to pass a pre-configured
+    /// `rust_xlsxwriter` table and override any of the default [`Table`]
+    /// properties.
+    ///
+    /// [`write_excel()`]:
+    ///     https://pola-rs.github.io/polars/py-polars/html/reference/api/polars.DataFrame.write_excel.html#polars.DataFrame.write_excel
+    ///
+    ///
+    /// # Parameters
+    ///
+    /// * `table` - A `rust_xlsxwriter` [`Table`] reference.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting properties of the worksheet table that wraps the
+    /// output dataframe.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_table.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// # use polars_excel_writer::PolarsXlsxWriter;
+    /// # use rust_xlsxwriter::{Table, TableStyle};
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "String" => &["North", "South", "East", "West"],
+    /// #         "Int" => &[1, 2, 3, 4],
+    /// #         "Float" => &[1.0, 2.22, 3.333, 4.4444],
+    /// #     )?;
+    /// #
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Add a `rust_xlsxwriter` table and set the style.
+    ///     let table = Table::new().set_style(TableStyle::Medium4);
+    ///
+    ///     // Add the table to the Excel writer.
+    ///     xlsx_writer.set_table(&table);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/write_excel_set_table.png">
+    ///
+    pub fn set_table(&mut self, table: &Table) -> &mut PolarsXlsxWriter {
+        self.options.table = table.clone();
+        self
+    }
+
+    /// Set the properties of a worksheet table column.
+    ///
+    /// Set a `rust_xlsxwriter` [`TableColumn`] for the named dataframe column,
+    /// for example to add a column format or a total function that isn't
+    /// otherwise supported by `PolarsXlsxWriter`.
+    ///
+    /// Columns set on a [`Table`] passed to
+    /// [`set_table()`](PolarsXlsxWriter::set_table) are replaced when the
+    /// table columns are configured from other options, such as
+    /// [`set_column_total()`](PolarsXlsxWriter::set_column_total) or
+    /// [`set_header_format()`](PolarsXlsxWriter::set_header_format), since the
+    /// properties of a `Table` can't be read back. Columns set via this method
+    /// are combined with those options instead. The other options take
+    /// precedence for the properties that they set. Note, a total function
+    /// set in a `TableColumn` also requires a table total row, which is added
+    /// by the total options or via [`Table::set_total_row()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the dataframe column.
+    /// * `column` - A `rust_xlsxwriter` [`TableColumn`] reference.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates combining a user defined table column with a total row.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_table_column.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::{Format, TableColumn, TableFunction};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[1000, 2300, 1500, 1750],
+    ///         "Units" => &[10, 23, 15, 17],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Add a table column with a maximum total and a currency format.
+    ///     let column = TableColumn::new()
+    ///         .set_total_function(TableFunction::Max)
+    ///         .set_format(Format::new().set_num_format("$#,##0"));
+    ///     xlsx_writer.set_table_column("Sales", &column);
+    ///
+    ///     // Add a total for another column.
+    ///     xlsx_writer.set_column_total("Units", TableFunction::Sum);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_table_column(
+        &mut self,
+        column_name: &str,
+        column: &TableColumn,
+    ) -> &mut PolarsXlsxWriter {
+        self.options
+            .table_columns
+            .insert(column_name.to_string(), column.clone());
+        self
+    }
+
+    /// Turn on/off writing the dataframe as an Excel table.
+    ///
+    /// By default the dataframe is written as an Excel worksheet table. This
+    /// method can be used to write it as a plain range of cells instead, for
+    /// compatibility with applications that don't support tables.
+    ///
+    /// In plain range mode the header row is written as standard cells with
+    /// the format from
+    /// [`set_header_format()`](PolarsXlsxWriter::set_header_format), if any,
+    /// and a worksheet autofilter is added to the range. The autofilter can be
+    /// turned off with [`set_autofilter()`](PolarsXlsxWriter::set_autofilter).
+    ///
+    /// The table properties such as the style, name and total row don't apply
+    /// in plain range mode and are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is on by default.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ComputeError`] error is raised when the dataframe is
+    /// written if formula columns have been added via
+    /// [`add_formula_column()`](PolarsXlsxWriter::add_formula_column) since
+    /// they require a table.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates writing the dataframe as a plain range of cells instead of
+    /// a worksheet table.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_use_table.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::Format;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[100, 200, 300, 400],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write the dataframe as a plain range with a bold header.
+    ///     xlsx_writer.set_use_table(false);
+    ///     xlsx_writer.set_header_format(Format::new().set_bold());
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_use_table(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
+        self.options.use_table = enable;
+        self
+    }
+
+    /// Set the name of the worksheet table for the output dataframe.
+    ///
+    /// By default Excel names worksheet tables `Table1`, `Table2`, etc. This
+    /// method sets a user defined name for the table so that it can be
+    /// referenced in formulas and charts. This is similar to the `table_name`
+    /// parameter of the Polars [`write_excel()`] method.
+    ///
+    /// Table names must be unique within a workbook so the name should be
+    /// changed, or cleared, before writing another dataframe. If the rows of a
+    /// dataframe are split by
+    /// [`set_overflow_policy()`](PolarsXlsxWriter::set_overflow_policy) then
+    /// the tables in the additional worksheets are named with a `_2`, `_3`,
+    /// etc., suffix.
+    ///
+    /// [`write_excel()`]:
+    ///     https://pola-rs.github.io/polars/py-polars/html/reference/api/polars.DataFrame.write_excel.html#polars.DataFrame.write_excel
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The table name. It must follow the Excel rules, shown below.
+    ///
+    ///   * The name must be less than 256 characters.
+    ///   * The name must start with a letter, underscore or backslash.
+    ///   * The remaining characters can only be letters, digits, underscores,
+    ///     periods or backslashes. Spaces aren't allowed.
+    ///   * The name cannot be a cell reference such as `A1` or `R1C1`.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ComputeError`] error if the name doesn't follow the
+    /// Excel rules. The write methods also return an error if the name has
+    /// already been used for another table in the workbook.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting the name of the output table and referencing it in
+    /// a formula.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_table_name.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// # use polars_excel_writer::PolarsXlsxWriter;
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "Region" => &["North", "South", "East", "West"],
+    /// #         "Sales" => &[100, 200, 300, 400],
+    /// #     )?;
+    /// #
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Set the name of the output table.
+    ///     xlsx_writer.set_table_name("RegionSales")?;
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Reference the table in a formula.
+    ///     let worksheet = xlsx_writer.worksheet()?;
+    ///     worksheet.write_formula(0, 3, "=SUM(RegionSales[Sales])")?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_table_name(
+        &mut self,
+        name: impl Into<String>,
+    ) -> PolarsResult<&mut PolarsXlsxWriter> {
+        let name = name.into();
+        Self::validate_table_name(&name)?;
+
+        self.options.table_name = Some(name);
+        Ok(self)
+    }
+
+    /// Add a workbook defined name for the data range of each column.
+    ///
+    /// Excel defined names, also known as named ranges, can be used in place
+    /// of cell ranges in formulas and charts. This method adds a defined name
+    /// for the data cells of each output column, excluding the header and any
+    /// total row, with the name `{prefix}_{column}`. For example a `Revenue`
+    /// column with the prefix `Sales` is named `Sales_Revenue`. If the prefix
+    /// is empty the column name is used on its own.
+    ///
+    /// Characters that aren't allowed in Excel names, such as spaces, are
+    /// replaced with underscores. Names that start with a digit or that are
+    /// cell references, such as `A1`, are prefixed with an underscore.
+    ///
+    /// The names apply to the data in the first worksheet if the rows are split
+    /// by [`set_overflow_policy()`](PolarsXlsxWriter::set_overflow_policy).
+    /// They aren't added for
+    /// [`write_dataframe_to_worksheet()`](PolarsXlsxWriter::write_dataframe_to_worksheet)
+    /// since the workbook of the worksheet isn't known.
+    ///
+    /// # Parameters
+    ///
+    /// * `prefix` - The prefix for the defined names.
+    ///
+    /// # Errors
+    ///
+    /// The write methods return a [`PolarsError::ComputeError`] error if a
+    /// defined name has already been used in the workbook, for example when
+    /// two dataframes are written with the same prefix.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates adding defined names for the columns and referencing them
+    /// in a formula.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_column_defined_names.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// # use polars_excel_writer::PolarsXlsxWriter;
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "Region" => &["North", "South", "East", "West"],
+    /// #         "Revenue" => &[100, 200, 300, 400],
+    /// #     )?;
+    /// #
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Add defined names such as "Sales_Revenue" for the columns.
+    ///     xlsx_writer.set_column_defined_names("Sales");
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Reference a defined name in a formula.
+    ///     let worksheet = xlsx_writer.worksheet()?;
+    ///     worksheet.write_formula(0, 3, "=SUM(Sales_Revenue)")?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_column_defined_names(&mut self, prefix: impl Into<String>) -> &mut PolarsXlsxWriter {
+        self.options.defined_names_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Set a total function for a column in the table total row.
+    ///
+    /// Add a total row to the worksheet table and set the Excel subtotal
+    /// function, such as `Sum` or `Average`, for the named dataframe column.
+    /// This is similar to the `column_totals` parameter of the Polars
+    /// [`write_excel()`] method.
+    ///
+    /// The table columns are configured automatically from the dataframe
+    /// schema when it is written so you don't need to create a list of
+    /// [`TableColumn`]s and keep it in sync with the dataframe. Columns
+    /// without a total function are left blank in the total row. Note, this
+    /// replaces any columns set via a [`Table`] passed to
+    /// [`set_table()`](PolarsXlsxWriter::set_table). Use
+    /// [`set_table_column()`](PolarsXlsxWriter::set_table_column) to set
+    /// other table column properties.
+    ///
+    /// [`write_excel()`]:
+    ///     https://pola-rs.github.io/polars/py-polars/html/reference/api/polars.DataFrame.write_excel.html#polars.DataFrame.write_excel
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the dataframe column.
+    /// * `function` - A `rust_xlsxwriter` [`TableFunction`] value.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates adding a total row with totals for some of the columns.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_column_total.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::TableFunction;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[1000, 2300, 1500, 1750],
+    ///         "Units" => &[10, 23, 15, 17],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Add totals for the numeric columns.
+    ///     xlsx_writer.set_column_total("Sales", TableFunction::Sum);
+    ///     xlsx_writer.set_column_total("Units", TableFunction::Average);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_column_total(
+        &mut self,
+        column_name: &str,
+        function: TableFunction,
+    ) -> &mut PolarsXlsxWriter {
+        self.options
+            .column_totals
+            .insert(column_name.to_string(), function);
+        self
+    }
+
+    /// Set a total function for all the columns in the table total row.
+    ///
+    /// Add a total row to the worksheet table and set the Excel subtotal
+    /// function for every column in the dataframe. Totals set for individual
+    /// columns via [`set_column_total()`](PolarsXlsxWriter::set_column_total),
+    /// [`set_total_row_formula()`](PolarsXlsxWriter::set_total_row_formula)
+    /// or [`set_total_row_label()`](PolarsXlsxWriter::set_total_row_label)
+    /// take precedence over this setting.
+    ///
+    /// # Parameters
+    ///
+    /// * `function` - A `rust_xlsxwriter` [`TableFunction`] value.
+    ///
+    pub fn set_column_totals_all(&mut self, function: TableFunction) -> &mut PolarsXlsxWriter {
+        self.options.column_totals_all = Some(function);
+        self
+    }
+
+    /// Set a label for a column in the table total row.
+    ///
+    /// Add a total row to the worksheet table and set a text label, such as
+    /// "Total", for the named dataframe column instead of a subtotal function.
+    /// This is typically used for the leftmost column of the table. The label
+    /// takes precedence over any total function for the column.
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the dataframe column.
+    /// * `label` - The total row label.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates adding a total row with a label and a custom formula.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_total_row_label.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::TableFunction;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[1000, 2300, 1500, 1750],
+    ///         "Units" => &[10, 23, 15, 17],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Add a total row with a label, a sum, and the sales per unit.
+    ///     xlsx_writer.set_total_row_label("Region", "Total");
+    ///     xlsx_writer.set_column_total("Sales", TableFunction::Sum);
+    ///     xlsx_writer.set_total_row_formula("Units", "SUM([Sales])/SUM([Units])");
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_total_row_label(
+        &mut self,
+        column_name: &str,
+        label: impl Into<String>,
+    ) -> &mut PolarsXlsxWriter {
+        self.options
+            .column_total_labels
+            .insert(column_name.to_string(), label.into());
+        self
+    }
+
+    /// Set a custom formula for a column in the table total row.
+    ///
+    /// Add a total row to the worksheet table and set a user defined formula
+    /// for the named dataframe column, for cases that aren't covered by the
+    /// standard subtotal functions of
+    /// [`set_column_total()`](PolarsXlsxWriter::set_column_total). The formula
+    /// can use [Structured References] to refer to the table columns, such as
+    /// `SUM([Sales])/SUM([Units])`. This is equivalent to
+    /// `set_column_total()` with a [`TableFunction::Custom`] value.
+    ///
+    /// [Structured References]:
+    ///     https://support.microsoft.com/en-us/office/using-structured-references-with-excel-tables-f5ed2452-2337-4f71-bed3-c8ae6d2b276e
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the dataframe column.
+    /// * `formula` - The total row formula as a string or a `rust_xlsxwriter`
+    ///   [`Formula`].
+    ///
+    /// # Examples
+    ///
+    /// See the example in
+    /// [`set_total_row_label()`](PolarsXlsxWriter::set_total_row_label).
+    ///
+    pub fn set_total_row_formula(
+        &mut self,
+        column_name: &str,
+        formula: impl Into<Formula>,
+    ) -> &mut PolarsXlsxWriter {
+        self.options.column_totals.insert(
+            column_name.to_string(),
+            TableFunction::Custom(formula.into()),
+        );
+        self
+    }
+
+    /// Set the width of a dataframe column in the output worksheet.
+    ///
+    /// Set the width of the worksheet column that a named dataframe column is
+    /// written to. This is similar to the `column_widths` parameter of the
+    /// Polars [`write_excel()`] method. The column position is worked out when
+    /// the dataframe is written, including any offset from
+    /// [`write_dataframe_to_cell()`](PolarsXlsxWriter::write_dataframe_to_cell),
+    /// so there is no need to calculate it manually.
+    ///
+    /// The width is in Excel character units. See
+    /// [`set_column_width_pixels()`](PolarsXlsxWriter::set_column_width_pixels)
+    /// to set the width in pixels. User defined widths take precedence over
+    /// [`set_autofit()`](PolarsXlsxWriter::set_autofit). Column names that
+    /// aren't in the dataframe are ignored.
+    ///
+    /// [`write_excel()`]:
+    ///     https://pola-rs.github.io/polars/py-polars/html/reference/api/polars.DataFrame.write_excel.html#polars.DataFrame.write_excel
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the dataframe column.
+    /// * `width` - The column width in character units.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting the width of dataframe columns.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_column_width.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// # use polars_excel_writer::PolarsXlsxWriter;
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "String" => &["North", "South", "East", "West"],
+    /// #         "Int" => &[1, 2, 3, 4],
+    /// #         "Float" => &[1.0, 2.22, 3.333, 4.4444],
+    /// #     )?;
+    /// #
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Set the width of some of the columns.
+    ///     xlsx_writer
+    ///         .set_column_width("String", 20.0)
+    ///         .set_column_width_pixels("Float", 100);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe_to_cell(&df, 1, 1)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_column_width(&mut self, column_name: &str, width: f64) -> &mut PolarsXlsxWriter {
+        self.options
+            .column_widths
+            .insert(column_name.to_string(), ColumnWidth::Characters(width));
+        self
+    }
+
+    /// Set the width of a dataframe column in the output worksheet, in pixels.
+    ///
+    /// This method is the same as
+    /// [`set_column_width()`](PolarsXlsxWriter::set_column_width) except that
+    /// the width is in pixels.
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the dataframe column.
+    /// * `width` - The column width in pixels.
+    ///
+    pub fn set_column_width_pixels(
+        &mut self,
+        column_name: &str,
+        width: u16,
+    ) -> &mut PolarsXlsxWriter {
+        self.options
+            .column_widths
+            .insert(column_name.to_string(), ColumnWidth::Pixels(width));
+        self
+    }
+
+    /// Hide a dataframe column in the output worksheet.
+    ///
+    /// Hide the worksheet column that a named dataframe column is written to.
+    /// This is similar to the `hidden_columns` parameter of the Polars
+    /// [`write_excel()`] method. The column position is worked out each time a
+    /// dataframe is written so it applies to dataframes written at different
+    /// positions in the same worksheet. Column names that aren't in the
+    /// dataframe are ignored.
+    ///
+    /// [`write_excel()`]:
+    ///     https://pola-rs.github.io/polars/py-polars/html/reference/api/polars.DataFrame.write_excel.html#polars.DataFrame.write_excel
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the dataframe column.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates hiding a dataframe column in the output worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_column_hidden.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// # use polars_excel_writer::PolarsXlsxWriter;
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "String" => &["North", "South", "East", "West"],
+    /// #         "Id" => &[1, 2, 3, 4],
+    /// #         "Float" => &[1.0, 2.22, 3.333, 4.4444],
+    /// #     )?;
+    /// #
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Hide the "Id" column.
+    ///     xlsx_writer.set_column_hidden("Id");
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_column_hidden(&mut self, column_name: &str) -> &mut PolarsXlsxWriter {
+        self.options.hidden_columns.insert(column_name.to_string());
+        self
+    }
+
+    /// Merge repeated values in a dataframe column.
+    ///
+    /// Merge the vertically adjacent cells of a column that contain the same
+    /// value, for a classic report style view of grouped or sorted data. The
+    /// merged cells use the format of the column data, including any alignment
+    /// set via
+    /// [`set_column_alignment()`](PolarsXlsxWriter::set_column_alignment), such
+    /// as `FormatAlign::Top` or `FormatAlign::VerticalCenter`. Null values
+    /// aren't merged.
+    ///
+    /// Excel tables can't contain merged cells so the data is written as a
+    /// range with a header row instead of a table, see
+    /// [`HeaderMode::WrittenHeaderRow`]. The merged cells aren't supported in
+    /// the constant memory worksheets used by
+    /// [`write_dataframe_chunked()`](PolarsXlsxWriter::write_dataframe_chunked)
+    /// and [`set_constant_memory()`](PolarsXlsxWriter::set_constant_memory)
+    /// since the rows can't be changed once they are written. The option is
+    /// ignored for those worksheets with a [`WarningKind::UnsupportedOption`]
+    /// warning, see [`warnings()`](PolarsXlsxWriter::warnings). Column names
+    /// that aren't in the dataframe are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the dataframe column.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates merging the repeated values in a key column.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_merge_repeated.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::FormatAlign;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "North", "North", "South", "South", "East"],
+    ///         "Product" => &["Apple", "Pear", "Plum", "Apple", "Pear", "Apple"],
+    ///         "Sales" => &[100, 200, 150, 300, 250, 400],
+    ///     )?;
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Merge the repeated regions and align them at the top of the cells.
+    ///     xlsx_writer.set_merge_repeated("Region");
+    ///     xlsx_writer.set_column_alignment("Region", FormatAlign::Top);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_merge_repeated(&mut self, column_name: &str) -> &mut PolarsXlsxWriter {
+        self.options
+            .merge_repeated_columns
+            .insert(column_name.to_string());
+        self
+    }
+
+    /// Group the data rows by a key column into collapsible outlines.
+    ///
+    /// Add Excel outline groups to the data rows so that the rows with the
+    /// same value in a key column, such as the transactions of an account, can
+    /// be expanded and collapsed in Excel. The data should be sorted by the key
+    /// column since only vertically adjacent rows with the same value are
+    /// grouped.
+    ///
+    /// The first row of each key is the summary row of the group and stays
+    /// visible. The other rows of the key are the detail rows, at the first
+    /// outline level. The outline buttons are shown above the detail rows,
+    /// next to the summary row. Keys with a single row, and null values, aren't grouped.
+    ///
+    /// `rust_xlsxwriter` doesn't support row outlines so they are added to the
+    /// worksheets when the file is saved by the writer. They aren't included
+    /// if the workbook is saved via
+    /// [`into_workbook()`](PolarsXlsxWriter::into_workbook) or to a user
+    /// supplied worksheet that isn't in the writer's workbook.
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the key column.
+    /// * `collapsed` - Collapse the groups, which hides the detail rows, when
+    ///   the file is opened.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ColumnNotFound`] error is raised when the dataframe is
+    /// written if the key column isn't in the written dataframe.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates grouping the transactions of each account into collapsed
+    /// outline groups.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_row_grouping.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Account" => &["A-100", "A-100", "A-100", "B-200", "B-200", "C-300"],
+    ///         "Date" => &["Jan", "Feb", "Mar", "Jan", "Feb", "Jan"],
+    ///         "Amount" => &[100, 200, 150, 300, 250, 400],
+    ///     )?;
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Group the rows of each account and collapse the groups.
+    ///     xlsx_writer.set_row_grouping("Account", true);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_row_grouping(
+        &mut self,
+        column_name: &str,
+        collapsed: bool,
+    ) -> &mut PolarsXlsxWriter {
+        self.options.row_grouping = Some((column_name.to_string(), collapsed));
+        self
+    }
+
+    /// Set the alignment of a dataframe column in the output worksheet.
+    ///
+    /// Set the horizontal or vertical alignment of the data cells of a column
+    /// without having to create a [`Format`] for it. The alignment is merged
+    /// into the format that applies to the column data, so a date column keeps
+    /// its date number format, or any format set via
+    /// [`set_dtype_format()`](PolarsXlsxWriter::set_dtype_format).
+    ///
+    /// The method can be called more than once for the same column to set
+    /// both a horizontal and a vertical alignment. The header cell isn't
+    /// changed. Column names that aren't in the dataframe are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the dataframe column.
+    /// * `align` - A `rust_xlsxwriter` [`FormatAlign`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting the alignment of dataframe columns.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_column_alignment.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// # use rust_xlsxwriter::FormatAlign;
+    /// #
+    /// # use polars_excel_writer::PolarsXlsxWriter;
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "Order" => &[1001, 1002, 1003, 1004],
+    /// #         "Status" => &["Shipped", "Pending", "Shipped", "Cancelled"],
+    /// #         "Total" => &[120.5, 34.25, 99.0, 15.75],
+    /// #     )?;
+    /// #
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Center the order numbers and the status values.
+    ///     xlsx_writer
+    ///         .set_column_alignment("Order", FormatAlign::Center)
+    ///         .set_column_alignment("Status", FormatAlign::Center)
+    ///         .set_float_format("0.00");
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_column_alignment(
+        &mut self,
+        column_name: &str,
+        align: FormatAlign,
+    ) -> &mut PolarsXlsxWriter {
+        self.options
+            .column_alignments
+            .entry(column_name.to_string())
+            .or_default()
+            .push(align);
+        self
+    }
+
+    /// Set format properties, such as the font or fill, for a dataframe column.
+    ///
+    /// Set the properties in a [`ColumnFormat`] for the data cells of a
+    /// column. Only the properties that are set in the `ColumnFormat` are
+    /// changed and they are combined with the format for the column data type.
+    /// For example a font color can be set for a date column and it keeps its
+    /// date number format. See [`ColumnFormat`] for the order in which the
+    /// formats are combined.
+    ///
+    /// The header cell isn't changed. Column names that aren't in the
+    /// dataframe are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the dataframe column.
+    /// * `format` - A [`ColumnFormat`] reference.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting format properties for dataframe columns.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_column_format.rs
+    /// #
+    /// # use chrono::prelude::*;
+    /// # use polars::prelude::*;
+    /// # use rust_xlsxwriter::Color;
+    /// #
+    /// # use polars_excel_writer::{ColumnFormat, PolarsXlsxWriter};
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "Due" => &[
+    /// #             NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+    /// #             NaiveDate::from_ymd_opt(2024, 2, 15).unwrap(),
+    /// #             NaiveDate::from_ymd_opt(2024, 3, 15).unwrap(),
+    /// #         ],
+    /// #         "Amount" => &[1250.0, 830.5, 99.99],
+    /// #     )?;
+    /// #
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Set a number format for all the float columns.
+    ///     xlsx_writer.set_dtype_format(DataType::Float64, "#,##0.00");
+    ///
+    ///     // Highlight the due dates. They keep the default date format.
+    ///     let highlight = ColumnFormat::new()
+    ///         .set_bold()
+    ///         .set_font_color(Color::Red);
+    ///
+    ///     xlsx_writer.set_column_format("Due", &highlight);
+    ///
+    ///     // Add a fill to the amounts. They keep the float number format.
+    ///     let fill = ColumnFormat::new().set_background_color("#E2EFDA");
+    ///
+    ///     xlsx_writer.set_column_format("Amount", &fill);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_column_format(
+        &mut self,
+        column_name: &str,
+        format: &ColumnFormat,
+    ) -> &mut PolarsXlsxWriter {
+        self.options
+            .column_formats
+            .insert(column_name.to_string(), format.clone());
+        self
+    }
+
+    /// Set the Excel format for the columns with names that match a pattern.
+    ///
+    /// Set a `rust_xlsxwriter` [`Format`] for all the columns in a dataframe
+    /// with names that match a regular expression. This is similar to using a
+    /// column name selector with the `column_formats` parameter of the Polars
+    /// [`write_excel()`] method. For example, the pattern `_pct$` matches all
+    /// the columns with names that end in `_pct`.
+    ///
+    /// The pattern can match any part of the column name, use `^` and `$` to
+    /// match the full name. The format takes precedence over any format for
+    /// the column data type, such as the format set via
+    /// [`set_dtype_format()`](PolarsXlsxWriter::set_dtype_format), and if a
+    /// column matches more than one pattern the most recently added pattern is
+    /// used. As with data type formats, the default number format for the type
+    /// is kept if the format doesn't have a number format. See
+    /// [`ColumnFormat`] for the order in which formats are combined.
+    ///
+    /// [`write_excel()`]:
+    ///     https://pola-rs.github.io/polars/py-polars/html/reference/api/polars.DataFrame.write_excel.html#polars.DataFrame.write_excel
+    ///
+    /// # Parameters
+    ///
+    /// * `pattern` - A regular expression in the syntax of the [`regex`] crate.
+    /// * `format` - A `rust_xlsxwriter` [`Format`] or an Excel number format
+    ///   string that can be converted to a [`CellFormat`].
+    ///
+    /// [`regex`]: https://docs.rs/regex/latest/regex/#syntax
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ComputeError`] error if the pattern isn't a valid
+    /// regular expression.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting a format for the columns with names that match a
+    /// pattern.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_format_matching.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[1000.0, 2300.0, 1900.0, 2500.0],
+    ///         "Growth_pct" => &[0.051, 0.124, -0.032, 0.087],
+    ///         "Margin_pct" => &[0.25, 0.31, 0.22, 0.28],
+    ///     )?;
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Set a percentage format for the columns ending in "_pct".
+    ///     xlsx_writer.set_format_matching("_pct$", "0.0%")?;
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_format_matching(
+        &mut self,
+        pattern: &str,
+        format: impl Into<CellFormat>,
+    ) -> PolarsResult<&mut PolarsXlsxWriter> {
+        let regex = Regex::new(pattern).map_err(
+            |error| polars_err!(ComputeError: "invalid column name pattern '{}': {}", pattern, error),
+        )?;
+
+        self.options.pattern_formats.push((regex, format.into()));
+        Ok(self)
+    }
+
+    /// Set a currency number format for a dataframe column.
+    ///
+    /// Set the number format of a column to an Excel currency format from a
+    /// [`CurrencyPreset`], such as US Dollars or Euros. The formats have a
+    /// thousands separator, two decimal places and display negative numbers in
+    /// red. This is a shortcut for setting the number format of the column via
+    /// [`set_column_format()`](PolarsXlsxWriter::set_column_format).
+    ///
+    /// Any other properties of a [`ColumnFormat`] set for the column, before
+    /// this method is called, are kept.
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the dataframe column.
+    /// * `currency` - A [`CurrencyPreset`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting currency formats for dataframe columns.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_column_currency.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::{CurrencyPreset, PolarsXlsxWriter};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Item" => &["Widget", "Gadget", "Refund"],
+    ///         "US" => &[1250.0, 830.5, -99.99],
+    ///         "Europe" => &[1120.0, 745.25, -89.5],
+    ///         "Swiss" => &[1180.0, 790.0, -95.0],
+    ///     )?;
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Set the currency formats for the sales columns.
+    ///     xlsx_writer
+    ///         .set_column_currency("US", CurrencyPreset::USD)
+    ///         .set_column_currency("Europe", CurrencyPreset::EUR)
+    ///         .set_column_currency("Swiss", CurrencyPreset::Custom("CHF".to_string()));
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_column_currency(
+        &mut self,
+        column_name: &str,
+        currency: CurrencyPreset,
+    ) -> &mut PolarsXlsxWriter {
+        self.options
+            .column_formats
+            .entry(column_name.to_string())
+            .or_default()
+            .num_format = Some(currency.num_format());
+        self
+    }
+
+    /// Set a percentage number format for a dataframe column.
+    ///
+    /// Set the number format of a column to an Excel percentage format, such
+    /// as `0.0%`, with the given number of decimal places. Excel displays a
+    /// percentage as the cell value multiplied by 100 so a value of `0.25` is
+    /// displayed as `25.0%`.
+    ///
+    /// If the column values are already fractions, like `0.25`, then they are
+    /// written unchanged. If they are percentages, like `25.0`, they are
+    /// divided by 100 when they are written so that they are displayed
+    /// correctly. The source dataframe isn't changed.
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the dataframe column.
+    /// * `decimals` - The number of decimal places displayed.
+    /// * `already_fractional` - Set to `true` if the values are fractions
+    ///   such as `0.25`, or `false` if they are percentages such as `25.0`.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates formatting columns as percentages.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_column_percentage.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe with fractional and percentage values.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Share" => &[0.25, 0.35, 0.15, 0.25],
+    ///         "Growth" => &[5.5, -2.25, 12.0, 3.75],
+    ///     )?;
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Format the fractional and percentage values as percentages.
+    ///     xlsx_writer
+    ///         .set_column_percentage("Share", 0, true)
+    ///         .set_column_percentage("Growth", 1, false);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_column_percentage(
+        &mut self,
+        column_name: &str,
+        decimals: u8,
+        already_fractional: bool,
+    ) -> &mut PolarsXlsxWriter {
+        let num_format = match decimals {
+            0 => "0%".to_string(),
+            _ => format!("0.{}%", "0".repeat(usize::from(decimals))),
+        };
+
+        self.options
+            .column_formats
+            .entry(column_name.to_string())
+            .or_default()
+            .num_format = Some(num_format);
+
+        if !already_fractional {
+            self.options.column_transforms.insert(
+                column_name.to_string(),
+                Arc::new(Self::percentage_to_fraction),
+            );
+        }
+
+        self
+    }
+
+    /// Set a function to transform the values of a dataframe column.
+    ///
+    /// Set a function, or closure, that is applied to each value of a column
+    /// before it is mapped to an Excel type and written to the worksheet. This
+    /// can be used for unit conversion, scaling, truncation or masking of
+    /// values without creating a modified copy of the dataframe.
+    ///
+    /// The function takes a Polars [`AnyValue`] and returns an `AnyValue`,
+    /// which can be of a different type. New string values should be returned
+    /// as `AnyValue::StringOwned`. Null values are also passed to the function.
+    ///
+    /// The transform isn't applied to hyperlink or image columns, or to the
+    /// values used in formulas or conditional formats that refer to the cells.
+    /// A column can only have one transform and a later call for the same
+    /// column, or [`set_column_percentage()`], replaces it.
+    ///
+    /// [`set_column_percentage()`]: PolarsXlsxWriter::set_column_percentage
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the dataframe column.
+    /// * `transform` - A function or closure that takes an [`AnyValue`] and
+    ///   returns an `AnyValue`.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates transforming the values of columns when they are written.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_column_transform.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::{CurrencyPreset, PolarsXlsxWriter};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Product" => &["Widget", "Gadget", "Extended warranty"],
+    ///         "Price" => &[1250, 830, 9999],
+    ///     )?;
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Convert the prices from cents to dollars.
+    ///     xlsx_writer.set_column_transform("Price", |value| match value {
+    ///         AnyValue::Int32(cents) => AnyValue::Float64(f64::from(cents) / 100.0),
+    ///         value => value,
+    ///     });
+    ///
+    ///     // Truncate the product names to 8 characters.
+    ///     xlsx_writer.set_column_transform("Product", |value| match value {
+    ///         AnyValue::String(name) if name.chars().count() > 8 => {
+    ///             let name: String = name.chars().take(8).collect();
+    ///             AnyValue::StringOwned(format!("{name}...").into())
+    ///         }
+    ///         value => value,
+    ///     });
+    ///
+    ///     // Set a currency format for the converted prices.
+    ///     xlsx_writer.set_column_currency("Price", CurrencyPreset::USD);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_column_transform(
+        &mut self,
+        column_name: &str,
+        transform: impl Fn(AnyValue) -> AnyValue + Send + Sync + 'static,
+    ) -> &mut PolarsXlsxWriter {
+        self.options
+            .column_transforms
+            .insert(column_name.to_string(), Arc::new(transform));
+        self
+    }
+
+    /// Mask the values of a sensitive dataframe column.
+    ///
+    /// Obfuscate the values of a column with personal or sensitive data, such
+    /// as names, account numbers or email addresses, when they are written.
+    /// The values can be replaced by a hash, partially masked to keep the last
+    /// few characters, or replaced with a fixed string. See [`MaskStyle`] for
+    /// details. The source dataframe isn't changed.
+    ///
+    /// Values are masked using their string representation and are written as
+    /// strings. Null values aren't masked. This method uses the same
+    /// mechanism as
+    /// [`set_column_transform()`](PolarsXlsxWriter::set_column_transform) and
+    /// it replaces any transform set for the column. Masked columns shouldn't
+    /// also be written as hyperlinks since the hyperlink text isn't masked.
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the dataframe column.
+    /// * `style` - A [`MaskStyle`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates masking the values of sensitive columns.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_column_masked.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::{MaskStyle, PolarsXlsxWriter};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe with sensitive data.
+    ///     let df: DataFrame = df!(
+    ///         "Customer" => &["Alice Smith", "Bob Jones", "Carol White"],
+    ///         "Email" => &["alice@example.com", "bob@example.com", "carol@example.com"],
+    ///         "Card" => &["4111222233334444", "5500111122223333", "340011112222333"],
+    ///         "Amount" => &[120.5, 89.99, 240.0],
+    ///     )?;
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Mask the sensitive columns.
+    ///     xlsx_writer
+    ///         .set_column_masked("Customer", MaskStyle::Hash)
+    ///         .set_column_masked("Email", MaskStyle::Replace("REDACTED".to_string()))
+    ///         .set_column_masked("Card", MaskStyle::Partial(4));
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_column_masked(
+        &mut self,
+        column_name: &str,
+        style: MaskStyle,
+    ) -> &mut PolarsXlsxWriter {
+        self.set_column_transform(column_name, move |value| {
+            if value.is_null() {
+                return value;
+            }
+
+            let masked = match value.get_str() {
+                Some(text) => style.mask(text),
+                None => style.mask(&value.to_string()),
+            };
+
+            AnyValue::StringOwned(masked.into())
+        })
+    }
+
+    /// Set a function to compute the format of the cells in a column.
+    ///
+    /// Set a function or closure that is called with the value of each cell
+    /// in a dataframe column and returns a [`Format`] for the cell, or `None`
+    /// to use the column format. This can be used to style individual cells
+    /// based on their values, such as a red fill for negative numbers, when
+    /// the rule is easier to express in Rust than as an Excel conditional
+    /// format. See also
+    /// [`set_conditional_format()`](PolarsXlsxWriter::set_conditional_format).
+    ///
+    /// The function is called with the dataframe value, before any
+    /// [`set_column_transform()`](PolarsXlsxWriter::set_column_transform)
+    /// transformation, and null values are also passed to it. The returned
+    /// format replaces the column format for the cell. However, formats
+    /// without a number format keep the number format of the column. Equal
+    /// formats returned for different cells are resolved once and shared.
+    ///
+    /// The function isn't applied to hyperlink or image columns. A column can
+    /// only have one callback and a later call for the same column replaces
+    /// it.
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the dataframe column.
+    /// * `callback` - A function or closure that takes a reference to an
+    ///   [`AnyValue`] and returns an `Option<Format>`.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting the format of cells based on their values.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_cell_style_callback.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::{Color, Format};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Account" => &["Rent", "Sales", "Refunds", "Services"],
+    ///         "Balance" => &[-1200.0, 3450.5, -230.25, 870.0],
+    ///     )?;
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Set a red fill for negative balances.
+    ///     let negative_format = Format::new().set_background_color(Color::RGB(0xFFC7CE));
+    ///
+    ///     xlsx_writer.set_cell_style_callback("Balance", move |value| match value {
+    ///         AnyValue::Float64(balance) if *balance < 0.0 => Some(negative_format.clone()),
+    ///         _ => None,
+    ///     });
+    ///
+    ///     // Set a number format for the column. It is kept for the styled cells.
+    ///     xlsx_writer.set_float_format("#,##0.00");
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_cell_style_callback(
+        &mut self,
+        column_name: &str,
+        callback: impl Fn(&AnyValue) -> Option<Format> + Send + Sync + 'static,
+    ) -> &mut PolarsXlsxWriter {
+        self.options
+            .cell_styles
+            .insert(column_name.to_string(), Arc::new(callback));
+        self
+    }
+
+    /// Add a conditional format to a dataframe column or cell range.
+    ///
+    /// Add a `rust_xlsxwriter` conditional format, such as a data bar, color
+    /// scale or cell rule, to the data in a named dataframe column or to an
+    /// explicit worksheet cell range. This is similar to the
+    /// `conditional_formats` parameter of the Polars [`write_excel()`] method.
+    ///
+    /// If `target` is the name of a dataframe column the cell range of the
+    /// conditional format is calculated from the position of the column in
+    /// the output worksheet when the dataframe is written. It covers the data
+    /// cells of the column and excludes the header and total rows. Otherwise
+    /// `target` is used as an A1 style cell range such as `"B2:D10"` or
+    /// `"$B$2:$D$10"`. Column names take precedence over cell ranges.
+    ///
+    /// The method can be called more than once to add several conditional
+    /// formats to the same or different columns. See the `rust_xlsxwriter`
+    /// [Working with Conditional Formats] docs for details on the available
+    /// conditional formats.
+    ///
+    /// [`write_excel()`]:
+    ///     https://pola-rs.github.io/polars/py-polars/html/reference/api/polars.DataFrame.write_excel.html#polars.DataFrame.write_excel
+    /// [Working with Conditional Formats]:
+    ///     https://docs.rs/rust_xlsxwriter/latest/rust_xlsxwriter/conditional_format/index.html
+    ///
+    /// # Parameters
+    ///
+    /// * `target` - The name of the dataframe column or an A1 style cell
+    ///   range.
+    /// * `conditional_format` - A `rust_xlsxwriter` conditional format such as
+    ///   [`ConditionalFormatDataBar`] or [`ConditionalFormat3ColorScale`]. See
+    ///   [`ColumnConditionalFormat`] for the supported types.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ColumnNotFound`] error is raised when the dataframe is
+    /// written if `target` isn't a column in the dataframe or a valid cell
+    /// range.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates adding conditional formats to dataframe columns.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_conditional_format.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::{ConditionalFormat3ColorScale, ConditionalFormatDataBar};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[1000, 2300, 1500, 1750],
+    ///         "Growth" => &[0.5, -0.25, 0.1, 0.3],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Add a data bar to the "Sales" column and a color scale to the
+    ///     // "Growth" column.
+    ///     xlsx_writer.set_conditional_format("Sales", &ConditionalFormatDataBar::new());
+    ///     xlsx_writer.set_conditional_format("Growth", &ConditionalFormat3ColorScale::new());
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_conditional_format<T>(
+        &mut self,
+        target: &str,
+        conditional_format: &T,
+    ) -> &mut PolarsXlsxWriter
+    where
+        T: Clone + Into<ColumnConditionalFormat>,
+    {
+        self.options
+            .conditional_formats
+            .push((target.to_string(), conditional_format.clone().into()));
+        self
+    }
+
+    /// Add a color scale heatmap across several dataframe columns.
+    ///
+    /// Add a single 2 or 3 color scale conditional format that covers the data
+    /// cells of all the named columns. Unlike adding a color scale to each
+    /// column via
+    /// [`set_conditional_format()`](PolarsXlsxWriter::set_conditional_format)
+    /// the colors are scaled relative to the values of all the columns, which
+    /// gives a heatmap of the data. This is similar to the heatmap example in
+    /// the Polars [`write_excel()`] docs.
+    ///
+    /// The cell ranges are calculated from the position of the columns in the
+    /// output worksheet and exclude the header and total rows. The columns
+    /// don't need to be adjacent. Columns that aren't in the written dataframe
+    /// are ignored.
+    ///
+    /// [`write_excel()`]:
+    ///     https://pola-rs.github.io/polars/py-polars/html/reference/api/polars.DataFrame.write_excel.html#polars.DataFrame.write_excel
+    ///
+    /// # Parameters
+    ///
+    /// * `column_names` - The names of the dataframe columns.
+    /// * `color_scale` - A [`ColorScale`] or a `rust_xlsxwriter`
+    ///   [`ConditionalFormat2ColorScale`] or [`ConditionalFormat3ColorScale`].
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates adding a heatmap across several columns.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_heatmap.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::ConditionalFormat3ColorScale;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Q1" => &[100, 250, 130, 90],
+    ///         "Q2" => &[120, 210, 160, 110],
+    ///         "Q3" => &[150, 190, 180, 140],
+    ///         "Q4" => &[170, 160, 200, 180],
+    ///     )?;
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Add a heatmap across the quarterly columns.
+    ///     xlsx_writer.set_heatmap(
+    ///         &["Q1", "Q2", "Q3", "Q4"],
+    ///         ConditionalFormat3ColorScale::new(),
+    ///     );
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_heatmap(
+        &mut self,
+        column_names: &[&str],
+        color_scale: impl Into<ColorScale>,
+    ) -> &mut PolarsXlsxWriter {
+        let column_names = column_names.iter().map(ToString::to_string).collect();
+        self.options
+            .heatmaps
+            .push((column_names, color_scale.into()));
+        self
+    }
+
+    /// Add a data validation to a dataframe column.
+    ///
+    /// Add a `rust_xlsxwriter` [`DataValidation`], such as a dropdown list or
+    /// a range of numbers or dates, to the data cells of a named dataframe
+    /// column. The validation restricts the values that a user can enter in
+    /// the cells in Excel.
+    ///
+    /// The cell range is calculated from the position of the column in the
+    /// output worksheet and excludes the header and total rows. Columns that
+    /// aren't in the written dataframe are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the dataframe column.
+    /// * `validation` - A `rust_xlsxwriter` [`DataValidation`].
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates adding data validations to dataframe columns.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_column_validation.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::{DataValidation, DataValidationRule};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Status" => &["Open", "Closed", "Open", "Pending"],
+    ///         "Units" => &[10, 25, 5, 40],
+    ///     )?;
+    ///     let df = df
+    ///         .lazy()
+    ///         .with_column(col("Status").cast(DataType::Categorical(None, Default::default())))
+    ///         .collect()?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Restrict the "Units" to whole numbers between 0 and 100.
+    ///     let validation = DataValidation::new().allow_whole_number(DataValidationRule::Between(0, 100));
+    ///     xlsx_writer.set_column_validation("Units", &validation);
+    ///
+    ///     // Add a dropdown list of the "Status" categories.
+    ///     xlsx_writer.set_column_dropdown("Status");
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_column_validation(
+        &mut self,
+        column_name: &str,
+        validation: &DataValidation,
+    ) -> &mut PolarsXlsxWriter {
+        self.options.column_validations.push((
+            column_name.to_string(),
+            ColumnValidation::Rule(validation.clone()),
+        ));
+        self
+    }
+
+    /// Add a dropdown list of the categories of a Categorical column.
+    ///
+    /// Add a list data validation to the data cells of a Polars `Categorical`
+    /// or `Enum` column with the categories of the column as the dropdown
+    /// values. The list is created when the dataframe is written. See the
+    /// example in
+    /// [`set_column_validation()`](PolarsXlsxWriter::set_column_validation).
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the dataframe column.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ComputeError`] error is raised when the dataframe is
+    /// written if the column isn't a `Categorical` or `Enum` column or if the
+    /// combined length of the categories exceeds Excel's limit of 255
+    /// characters for a list validation.
+    ///
+    pub fn set_column_dropdown(&mut self, column_name: &str) -> &mut PolarsXlsxWriter {
+        self.options
+            .column_validations
+            .push((column_name.to_string(), ColumnValidation::Categories));
+        self
+    }
+
+    /// Set the format used to highlight changed cells in a dataframe diff.
+    ///
+    /// Set the format of the cells that differ between the dataframes written
+    /// by [`write_dataframe_diff()`](PolarsXlsxWriter::write_dataframe_diff).
+    /// The default is the Excel "Neutral" style of dark yellow text on a light
+    /// yellow background.
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - A `rust_xlsxwriter` [`Format`].
+    ///
+    pub fn set_diff_format(&mut self, format: impl Into<Format>) -> &mut PolarsXlsxWriter {
+        self.options.diff_format = format.into();
+        self
+    }
+
+    /// Set a key column to match the rows of a dataframe diff.
+    ///
+    /// By default the rows of the dataframes compared by
+    /// [`write_dataframe_diff()`](PolarsXlsxWriter::write_dataframe_diff) are
+    /// matched by position. This method matches them by the values of a key
+    /// column, such as an id, instead. If a key value occurs more than once in
+    /// the old dataframe the first row is used. Null keys don't match.
+    ///
+    /// # Parameters
+    ///
+    /// * `column_name` - The name of the key column in both dataframes.
+    ///
+    pub fn set_diff_key_column(&mut self, column_name: &str) -> &mut PolarsXlsxWriter {
+        self.options.diff_key_column = Some(column_name.to_string());
+        self
+    }
+
+    /// Add a column of sparklines to the output table.
+    ///
+    /// Sparklines are small charts that fit in a single cell and are used to
+    /// show trends in data. This method inserts a new column into the worksheet
+    /// table with a sparkline in each row that plots the data in the source
+    /// columns of that row. This is similar to the `sparklines` parameter of
+    /// the Polars [`write_excel()`] method.
+    ///
+    /// The data range of each sparkline is calculated from the position of the
+    /// source columns in the output worksheet when the dataframe is written.
+    /// The range spans from the first to the last of the source columns, so
+    /// they should normally be adjacent in the dataframe.
+    ///
+    /// [`write_excel()`]:
+    ///     https://pola-rs.github.io/polars/py-polars/html/reference/api/polars.DataFrame.write_excel.html#polars.DataFrame.write_excel
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The header name of the inserted sparkline column.
+    /// * `source_columns` - The names of the dataframe columns to plot.
+    /// * `sparkline` - A `rust_xlsxwriter` [`Sparkline`] used to set the
+    ///   type and style of the sparklines. The data range is set by the writer.
+    /// * `position` - A [`ColumnPosition`] value to set where the column is
+    ///   inserted.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ColumnNotFound`] error is raised when the dataframe is
+    /// written if a source or position column isn't found in the dataframe.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates adding a column of sparklines to the output table.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_add_sparkline.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::{ColumnPosition, PolarsXlsxWriter};
+    /// use rust_xlsxwriter::{Sparkline, SparklineType};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Q1" => &[100, 230, 150, 175],
+    ///         "Q2" => &[120, 210, 160, 185],
+    ///         "Q3" => &[150, 190, 170, 160],
+    ///         "Q4" => &[170, 250, 140, 190],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Add a column of sparklines after the "Region" column.
+    ///     let sparkline = Sparkline::new().set_type(SparklineType::Column);
+    ///     xlsx_writer.add_sparkline(
+    ///         "Trend",
+    ///         &["Q1", "Q2", "Q3", "Q4"],
+    ///         &sparkline,
+    ///         ColumnPosition::After("Region".to_string()),
+    ///     );
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn add_sparkline(
+        &mut self,
+        name: &str,
+        source_columns: &[&str],
+        sparkline: &Sparkline,
+        position: ColumnPosition,
+    ) -> &mut PolarsXlsxWriter {
+        self.options.sparkline_columns.push(SparklineColumn {
+            name: name.to_string(),
+            source_columns: source_columns.iter().map(ToString::to_string).collect(),
+            sparkline: sparkline.clone(),
+            position,
+        });
+        self
+    }
+
+    /// Add a column of formulas to the output table.
+    ///
+    /// This method inserts a new column into the worksheet table with the
+    /// same formula in each data row. This is similar to the `formulas`
+    /// parameter of the Polars [`write_excel()`] method. The formula is
+    /// normally a structured reference to other columns in the same table
+    /// row, such as `[@[Price]]*[@[Quantity]]`, so that it doesn't depend on
+    /// the table name or the position of the columns. The Excel 2007 style
+    /// `[#This Row]` and Excel 2010 style `@` structured references are both
+    /// supported.
+    ///
+    /// The formula column is included in the table range and, like the other
+    /// table columns, in the autofit calculation. It can also have a table
+    /// total via [`set_column_total()`](PolarsXlsxWriter::set_column_total)
+    /// using the `name` of the column.
+    ///
+    /// [`write_excel()`]:
+    ///     https://pola-rs.github.io/polars/py-polars/html/reference/api/polars.DataFrame.write_excel.html#polars.DataFrame.write_excel
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The header name of the inserted formula column.
+    /// * `formula` - The formula to add to each row of the column, as a
+    ///   string or a `rust_xlsxwriter` [`Formula`].
+    /// * `position` - A [`ColumnPosition`] value to set where the column is
+    ///   inserted.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ColumnNotFound`] error is raised when the dataframe is
+    /// written if the position column isn't found in the dataframe.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates adding a column of formulas to the output table.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_add_formula_column.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::{ColumnPosition, PolarsXlsxWriter};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Product" => &["Apple", "Pear", "Plum", "Kiwi"],
+    ///         "Price" => &[0.50, 0.75, 0.30, 0.45],
+    ///         "Quantity" => &[100, 80, 150, 120],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Add a column with the cost of each row at the end of the table.
+    ///     xlsx_writer.add_formula_column(
+    ///         "Cost",
+    ///         "[@[Price]]*[@[Quantity]]",
+    ///         ColumnPosition::End,
+    ///     );
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn add_formula_column(
+        &mut self,
+        name: &str,
+        formula: impl Into<Formula>,
+        position: ColumnPosition,
+    ) -> &mut PolarsXlsxWriter {
+        self.options.formula_columns.push(FormulaColumn {
+            name: name.to_string(),
+            formula: formula.into(),
+            position,
+        });
+        self
+    }
+
+    /// Add a column with the total of each row to the output table.
+    ///
+    /// Appends a column at the right of the worksheet table with a formula
+    /// that sums the source columns in each row. This is similar to the
+    /// `row_totals` parameter of the Polars [`write_excel()`] method. The
+    /// formula is a structured reference to the source columns in the same
+    /// table row, such as `=SUM(Table1[@[Q1]:[Q4]])`. The table name is
+    /// omitted unless it is set via
+    /// [`set_table_name()`](PolarsXlsxWriter::set_table_name). If the output
+    /// isn't a table with a header row the formula uses cell references, such
+    /// as `=SUM(B2:E2)`.
+    ///
+    /// The row total values are formatted with the format of the source
+    /// columns, such as a format set via
+    /// [`set_dtype_format()`](PolarsXlsxWriter::set_dtype_format). If the
+    /// source columns have different formats the format for the
+    /// `DataType::Float64` type, or the
+    /// [`set_float_format()`](PolarsXlsxWriter::set_float_format) format, is
+    /// used.
+    ///
+    /// The column can also have a table total via
+    /// [`set_column_total()`](PolarsXlsxWriter::set_column_total) using the
+    /// `name` of the column.
+    ///
+    /// [`write_excel()`]:
+    ///     https://pola-rs.github.io/polars/py-polars/html/reference/api/polars.DataFrame.write_excel.html#polars.DataFrame.write_excel
+    ///
+    /// # Parameters
+    ///
+    /// * `columns` - The names of the dataframe columns to sum. If the slice is
+    ///   empty all of the numeric dataframe columns are summed.
+    /// * `name` - The header name of the row total column.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ColumnNotFound`] error is raised when the dataframe is
+    /// written if a source column isn't found in the dataframe.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates adding a column with the total of each row.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_row_totals.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Q1" => &[100, 120, 140, 130],
+    ///         "Q2" => &[110, 125, 135, 150],
+    ///         "Q3" => &[105, 130, 145, 140],
+    ///         "Q4" => &[120, 135, 150, 160],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Add a column with the total of the quarterly values.
+    ///     xlsx_writer.set_row_totals(&["Q1", "Q2", "Q3", "Q4"], "Total");
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_row_totals(&mut self, columns: &[&str], name: &str) -> &mut PolarsXlsxWriter {
+        self.options.row_total_column = Some(RowTotalColumn {
+            name: name.to_string(),
+            source_columns: columns.iter().map(ToString::to_string).collect(),
+        });
+        self
+    }
+
+    /// Add a column of row numbers to the left of the output table.
+    ///
+    /// Pandas writes the dataframe index to Excel as the first column. Polars
+    /// dataframes don't have an index but it is often useful to have a row
+    /// number for each row of data. This method prepends an automatically
+    /// numbered column to the output table without modifying the source
+    /// dataframe.
+    ///
+    /// The row numbers are continuous across worksheets when a dataframe is
+    /// split via [`set_overflow_policy()`](PolarsXlsxWriter::set_overflow_policy)
+    /// or written via
+    /// [`append_dataframe()`](PolarsXlsxWriter::append_dataframe). The column
+    /// can be formatted, or given a total, like other columns using
+    /// `header_name` as the column name.
+    ///
+    /// # Parameters
+    ///
+    /// * `enabled` - Turn the row number column on/off. It is off by default.
+    /// * `header_name` - The header name of the row number column.
+    /// * `start_at` - The number of the first row, typically 0 or 1.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::Duplicate`] error is raised when the dataframe is
+    /// written if it already has a column called `header_name`.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates adding a column of row numbers to the left of the data.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_row_numbers.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[100, 120, 140, 130],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Add a column of row numbers starting at 1.
+    ///     xlsx_writer.set_row_numbers(true, "No.", 1);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_row_numbers(
+        &mut self,
+        enabled: bool,
+        header_name: &str,
+        start_at: u32,
+    ) -> &mut PolarsXlsxWriter {
+        self.options.row_numbers = enabled.then(|| (header_name.to_string(), start_at));
+        self
+    }
+
+    /// Write all dataframes using constant memory mode.
+    ///
+    /// Turn on the `rust_xlsxwriter` [constant memory mode] for all the
+    /// `write_*()` methods, in the same way as
+    /// [`write_dataframe_chunked()`](PolarsXlsxWriter::write_dataframe_chunked).
+    /// The dataframes are written row by row, in chunks of 10,000 rows, to
+    /// temporary file backed worksheets so that very large exports don't hold
+    /// the whole worksheet in memory.
+    ///
+    /// Worksheets added while constant memory mode is on are constant memory
+    /// worksheets. Turning it on also changes the current worksheet, and any
+    /// other worksheets that haven't been used yet, to constant memory
+    /// worksheets with the same name. Changes made to the worksheets via
+    /// [`worksheet()`](PolarsXlsxWriter::worksheet) after that are kept. A
+    /// worksheet that has already been changed or written to can't be changed
+    /// to a constant memory worksheet and writing a dataframe to it returns an
+    /// error.
+    ///
+    /// Additional dataframes can be written to the same worksheet but, since
+    /// rows are written in strictly increasing order, they must start below
+    /// the previous data, for example with [`Layout::Vertical`]. A dataframe
+    /// that starts in or above a row that has already been written returns an
+    /// error.
+    ///
+    /// Worksheets passed to
+    /// [`write_dataframe_to_worksheet()`](PolarsXlsxWriter::write_dataframe_to_worksheet)
+    /// aren't affected by this setting.
+    ///
+    /// Options that need the rows after they are written, such as
+    /// [`set_merge_repeated()`](PolarsXlsxWriter::set_merge_repeated) and
+    /// [`set_autofit()`](PolarsXlsxWriter::set_autofit), aren't supported in
+    /// constant memory mode. They are ignored with a
+    /// [`WarningKind::UnsupportedOption`] warning, see
+    /// [`warnings()`](PolarsXlsxWriter::warnings).
+    ///
+    /// [constant memory mode]:
+    ///     https://docs.rs/rust_xlsxwriter/latest/rust_xlsxwriter/performance/index.html
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates writing large dataframes in constant memory mode.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_constant_memory.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create some sample dataframes for the example.
+    ///     let df1: DataFrame = df!(
+    ///         "Id" => (0..50_000).collect::<Vec<i32>>(),
+    ///         "Value" => (0..50_000).map(|i| f64::from(i) * 1.5).collect::<Vec<f64>>(),
+    ///     )?;
+    ///
+    ///     let df2: DataFrame = df!(
+    ///         "Id" => (0..50_000).collect::<Vec<i32>>(),
+    ///         "Value" => (0..50_000).map(|i| f64::from(i) * 2.5).collect::<Vec<f64>>(),
+    ///     )?;
+    ///
+    ///     // Write the dataframes to an Excel file in constant memory mode.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///     xlsx_writer.set_constant_memory(true);
+    ///
+    ///     xlsx_writer.write_dataframe(&df1)?;
+    ///
+    ///     xlsx_writer.add_worksheet();
+    ///     xlsx_writer.write_dataframe(&df2)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_constant_memory(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
+        self.options.constant_memory = enable;
+
+        if enable {
+            self.replace_blank_worksheets();
+        }
+
+        self
+    }
+
+    /// Set the order in which the dataframe cells are written.
+    ///
+    /// By default the dataframe is written column by column, which allows
+    /// numeric columns to be written directly from their underlying arrays.
+    /// This method allows the dataframe to be written row by row instead, with
+    /// each row completed before the next one is started. See [`WriteOrder`]
+    /// for the available options.
+    ///
+    /// The worksheet data is the same for both write orders. Row order is always
+    /// used for dataframes written in [constant memory
+    /// mode](PolarsXlsxWriter::set_constant_memory).
+    ///
+    /// # Parameters
+    ///
+    /// * `order` - A [`WriteOrder`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates writing the dataframe row by row.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_write_order.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::{PolarsXlsxWriter, WriteOrder};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "String" => &["North", "South", "East", "West"],
+    ///         "Int" => &[1, 2, 3, 4],
+    ///         "Float" => &[1.0, 2.22, 3.333, 4.4444],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write the data row by row.
+    ///     xlsx_writer.set_write_order(WriteOrder::RowMajor);
+    ///
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_write_order(&mut self, order: WriteOrder) -> &mut PolarsXlsxWriter {
+        self.options.write_order = order;
+        self
+    }
+
+    /// Write the dataframe columns in parallel.
+    ///
+    /// By default the cells of each column are written to the worksheet one
+    /// column after another. This method turns on writing the column values
+    /// to separate in-memory column buffers in parallel, on the `rayon` thread
+    /// pool, which are then copied to the worksheet in column order. On a
+    /// multi-core machine this can reduce the time taken to write wide
+    /// dataframes, particularly ones with string, temporal or styled columns
+    /// that need a conversion for each value. The copy to the worksheet is
+    /// sequential so there is no benefit for narrow dataframes. The worksheet
+    /// data is the same as for a sequential write.
+    ///
+    /// The column buffers hold a copy of the column values so this uses more
+    /// memory while each dataframe is written. It only applies to the default
+    /// [`WriteOrder::ColumnMajor`] write order and is ignored for dataframes
+    /// written row by row, for example in [constant memory
+    /// mode](PolarsXlsxWriter::set_constant_memory). Hyperlink and image
+    /// columns are always written sequentially.
+    ///
+    /// This method requires the `parallel` feature.
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates writing the dataframe columns in parallel.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_parallel.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => (0..10_000).map(|i| format!("Region {}", i % 7)).collect::<Vec<String>>(),
+    ///         "Units" => (0..10_000).collect::<Vec<i32>>(),
+    ///         "Price" => (0..10_000).map(|i| f64::from(i) * 0.25).collect::<Vec<f64>>(),
+    ///         "Active" => (0..10_000).map(|i| i % 3 == 0).collect::<Vec<bool>>(),
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write the columns in parallel.
+    ///     xlsx_writer.set_parallel(true);
+    ///
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    #[cfg(feature = "parallel")]
+    pub fn set_parallel(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
+        self.options.parallel = enable;
+        self
+    }
+
+    /// Set the policy for dataframes that exceed the worksheet row limit.
+    ///
+    /// Excel worksheets are limited to 1,048,576 rows, including the header
+    /// and any total row. By default, writing a dataframe that doesn't fit in
+    /// the remaining rows of the worksheet returns an error. This method allows
+    /// the remaining rows to be ignored or to be continued in additional
+    /// worksheets or files. See [`OverflowPolicy`] for the available options.
+    ///
+    /// When the rows are split, each additional worksheet gets its own table
+    /// and a repeated header row, at the same position as the first worksheet.
+    ///
+    /// The `SplitWorksheets` and `SplitFiles` policies aren't supported with
+    /// [`write_dataframe_to_worksheet()`](PolarsXlsxWriter::write_dataframe_to_worksheet)
+    /// since the parent workbook of the worksheet isn't available.
+    ///
+    /// # Parameters
+    ///
+    /// * `policy` - An [`OverflowPolicy`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates splitting a dataframe that exceeds the Excel row limit
+    /// across several worksheets.
+    ///
+    /// ```no_run
+    /// # // This code is available in examples/write_excel_set_overflow_policy.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::{OverflowPolicy, PolarsXlsxWriter};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe with more rows than fit in a worksheet.
+    ///     let df: DataFrame = df!(
+    ///         "Id" => (0..1_500_000).collect::<Vec<i32>>(),
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Continue any rows beyond the row limit on another worksheet.
+    ///     xlsx_writer.set_overflow_policy(OverflowPolicy::SplitWorksheets);
+    ///
+    ///     xlsx_writer.write_dataframe_chunked(&df, 100_000)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_overflow_policy(&mut self, policy: OverflowPolicy) -> &mut PolarsXlsxWriter {
+        self.options.overflow_policy = policy;
+        self
+    }
+
+    /// Set the worksheet name for the output dataframe.
+    ///
+    /// Set the name of the worksheet that the dataframe is written to. If the
+    /// name isn't set then it will be the default Excel name of `Sheet1` (or
+    /// `Sheet2`, `Sheet3`, etc. if more than one worksheet is added).
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The worksheet name. It must follow the Excel rules, shown
+    ///   below.
+    ///
+    ///   * The name must be less than 32 characters.
+    ///   * The name cannot be blank.
+    ///   * The name cannot contain any of the characters: `[ ] : * ? / \`.
+    ///   * The name cannot start or end with an apostrophe.
+    ///   * The name shouldn't be "History" (case-insensitive) since that is
+    ///     reserved by Excel.
+    ///   * It must not be a duplicate of another worksheet name used in the
+    ///     workbook.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ComputeError`] that wraps a `rust_xlsxwriter`
+    /// [`XlsxError`](rust_xlsxwriter::XlsxError) error.
+    ///
+    /// Excel has several rules that govern what a worksheet name can be. See
+    /// [`set_name()` errors] for more details.
+    ///
+    /// [`set_name()` errors]:
+    ///     https://docs.rs/rust_xlsxwriter/latest/rust_xlsxwriter/worksheet/struct.Worksheet.html#errors
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting the name for the output worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_worksheet_name.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// # use polars_excel_writer::PolarsXlsxWriter;
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "String" => &["North", "South", "East", "West"],
+    /// #         "Int" => &[1, 2, 3, 4],
+    /// #         "Float" => &[1.0, 2.22, 3.333, 4.4444],
+    /// #     )?;
+    /// #
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Set the worksheet name.
+    ///     xlsx_writer.set_worksheet_name("Polars Data")?;
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/write_excel_set_worksheet_name.png">
+    ///
+    pub fn set_worksheet_name(
+        &mut self,
+        name: impl Into<String>,
+    ) -> PolarsResult<&mut PolarsXlsxWriter> {
+        let worksheet = self.current_worksheet()?;
+        worksheet.set_name(name)?;
+        Ok(self)
+    }
+
+    /// Set the worksheet name for the output dataframe, adjusting it to follow
+    /// the Excel rules.
+    ///
+    /// This is a version of
+    /// [`set_worksheet_name()`](PolarsXlsxWriter::set_worksheet_name) that
+    /// changes an invalid name into a valid one instead of returning an error.
+    /// This is useful when the worksheet names come from the data, for example
+    /// a customer name.
+    ///
+    /// The name is adjusted as follows:
+    ///
+    /// * The invalid characters `[ ] : * ? / \` are replaced with underscores.
+    /// * Leading and trailing apostrophes are removed.
+    /// * A blank name is replaced with an underscore.
+    /// * The name is truncated to 31 characters.
+    /// * A name that is the same as another worksheet in the workbook, ignoring
+    ///   case, or the reserved name "History" is given a suffix such as ` (2)`.
+    ///
+    /// These are the same rules that are used for the worksheet names of
+    /// [`write_dataframe_partitioned()`](PolarsXlsxWriter::write_dataframe_partitioned).
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The worksheet name.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ComputeError`] that wraps a `rust_xlsxwriter`
+    /// [`XlsxError`](rust_xlsxwriter::XlsxError) error.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing Polar Rust dataframes to an Excel file. This
+    /// demonstrates setting worksheet names that come from the data.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_worksheet_name_lossy.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Sample customer names and sales for the example.
+    ///     let customers = [
+    ///         ("Acme: North/South", [1000, 1200]),
+    ///         ("ACME: NORTH/SOUTH", [800, 900]),
+    ///         ("Smith & Sons [Wholesale Division]", [2300, 2100]),
+    ///     ];
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     for (index, (customer, sales)) in customers.iter().enumerate() {
+    ///         let df: DataFrame = df!(
+    ///             "Quarter" => &["Q1", "Q2"],
+    ///             "Sales" => sales,
+    ///         )?;
+    ///
+    ///         if index > 0 {
+    ///             xlsx_writer.add_worksheet();
+    ///         }
+    ///
+    ///         // Use the customer name as the worksheet name. The names are
+    ///         // changed to "Acme_ North_South", "ACME_ NORTH_SOUTH (2)" and
+    ///         // "Smith & Sons _Wholesale Divisio".
+    ///         xlsx_writer.set_worksheet_name_lossy(customer)?;
+    ///
+    ///         xlsx_writer.write_dataframe(&df)?;
+    ///     }
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_worksheet_name_lossy(&mut self, name: &str) -> PolarsResult<&mut PolarsXlsxWriter> {
+        // The current worksheet is the last one, so its name can be reused.
+        let num_worksheets = self.workbook.worksheets().len();
+        let used_names: HashSet<String> = self
+            .workbook
+            .worksheets()
+            .iter()
+            .take(num_worksheets.saturating_sub(1))
+            .map(|worksheet| worksheet.name().to_lowercase())
+            .collect();
+
+        let sheet_name = Self::lossy_sheet_name(name, &used_names);
+
+        self.set_worksheet_name(sheet_name)
+    }
+
+    /// Set the document properties of the output workbook.
+    ///
+    /// Set the document metadata properties, such as the title, author and
+    /// custom properties, of the output Excel file. These are displayed by
+    /// Excel under `File > Info` and in the file properties dialog. See the
+    /// `rust_xlsxwriter` [`DocProperties`] documentation for the properties
+    /// that can be set.
+    ///
+    /// # Parameters
+    ///
+    /// * `properties` - A `rust_xlsxwriter` [`DocProperties`] object.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates setting the document properties of the Excel file.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_properties.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::DocProperties;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[1000, 2300, 1900, 2500],
+    ///     )?;
+    ///
+    ///     // Create the document properties for the report.
+    ///     let properties = DocProperties::new()
+    ///         .set_title("Regional Sales")
+    ///         .set_author("Sales Team")
+    ///         .set_custom_property("Quarter", "Q1");
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///     xlsx_writer.set_properties(&properties);
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_properties(&mut self, properties: &DocProperties) -> &mut PolarsXlsxWriter {
+        self.workbook.set_properties(properties);
+        self.properties = Some(properties.clone());
+
+        self
+    }
+
+    /// Set the output workbook to open as read only recommended.
+    ///
+    /// Set the workbook so that Excel displays a dialog, when the file is
+    /// opened, recommending that the user opens it in read only mode. The user
+    /// can still choose to open the file for editing. Use this in conjunction
+    /// with [`set_worksheet_protected()`](PolarsXlsxWriter::set_worksheet_protected)
+    /// to make a report read only for end users.
+    ///
+    /// Note, `rust_xlsxwriter` doesn't support protecting the workbook
+    /// structure, so this is the available workbook level option.
+    ///
+    pub fn set_read_only_recommended(&mut self) -> &mut PolarsXlsxWriter {
+        self.workbook.read_only_recommended();
+
+        self
+    }
+
+    /// Turn on/off deterministic output.
+    ///
+    /// Excel files contain the time that they were created in the document
+    /// properties, so the same dataframe written at different times produces
+    /// different files. With deterministic output the creation time is set to
+    /// a fixed value, 1980-01-01T00:00:00Z, so that writing the same data with
+    /// the same options produces byte identical files. This is useful for
+    /// snapshot tests and for build pipelines that compare output files.
+    ///
+    /// The fixed time overrides any creation time set via
+    /// [`set_properties()`](PolarsXlsxWriter::set_properties). The other
+    /// document properties are kept. It is off by default.
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates writing identical files for the same dataframe.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_deterministic.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[1000, 2300, 1900, 2500],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Use a fixed creation time so the output is the same for every run.
+    ///     xlsx_writer.set_deterministic(true);
+    ///
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_deterministic(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
+        self.is_deterministic = enable;
+        self
+    }
+
+    /// Turn on/off zip large file/ZIP64 extensions.
+    ///
+    /// The size limit for a standard zip file, which is the container format
+    /// for xlsx files, is 4GB for the overall file or for any of the
+    /// uncompressed files within it. In practice this would apply to
+    /// worksheets with approximately 150 million cells, or more. Saving a
+    /// larger dataframe will fail with an I/O error unless ZIP64 extensions are
+    /// turned on.
+    ///
+    /// Excel supports ZIP64 extensions but other applications may not, so
+    /// only turn this on for very large exports. It is applied to the main
+    /// workbook and to any files added by the
+    /// [`OverflowPolicy::SplitFiles`] overflow policy. See also the
+    /// `rust_xlsxwriter` [`Workbook::use_zip_large_file()`] docs.
+    ///
+    /// Note, the zip compression level can't be changed. `rust_xlsxwriter`
+    /// always uses the default Deflate compression level. The `zlib` feature
+    /// can be used to select a faster compression backend.
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates turning on zip large file support.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_set_zip_large_file.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[1000, 2300, 1900, 2500],
+    ///     )?;
+    ///
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Allow the output file to exceed the standard 4GB zip limit.
+    ///     xlsx_writer.set_zip_large_file(true);
+    ///
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn set_zip_large_file(&mut self, enable: bool) -> &mut PolarsXlsxWriter {
+        self.workbook.use_zip_large_file(enable);
+        self.use_zip_large_file = enable;
+
+        self
+    }
+
+    /// Add a new worksheet to the output workbook.
+    ///
+    /// Add a worksheet to the workbook so that dataframes can be written to
+    /// more than one worksheet. This is useful when you have several dataframes
+    /// that you wish to have on separate worksheets.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframes to separate worksheets in
+    /// an Excel workbook.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_add_worksheet.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     let df1: DataFrame = df!(
+    ///         "Data 1" => &[10, 11, 12, 13, 14, 15],
+    ///     )?;
+    ///
+    ///     let df2: DataFrame = df!(
+    ///         "Data 2" => &[20, 21, 22, 23, 24, 25],
+    ///     )?;
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Write the first dataframe to the first/default worksheet.
+    ///     xlsx_writer.write_dataframe(&df1)?;
+    ///
+    ///     // Add another worksheet and write the second dataframe to it.
+    ///     xlsx_writer.add_worksheet();
+    ///     xlsx_writer.write_dataframe(&df2)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/write_excel_add_worksheet.png">
+    ///
+    pub fn add_worksheet(&mut self) -> &mut PolarsXlsxWriter {
+        self.add_new_worksheet();
+
+        self
+    }
+
+    /// Add a table of contents worksheet to the workbook.
+    ///
+    /// Add a worksheet with a row for each dataframe written to the other
+    /// worksheets of the workbook. Each row has a hyperlink to the dataframe,
+    /// its cell range and the number of data rows and columns. This is useful
+    /// as an index for workbooks with several worksheets.
+    ///
+    /// The table of contents is added as the first worksheet when the file is
+    /// saved so it includes all the dataframes written by `PolarsXlsxWriter`,
+    /// in worksheet order. Dataframes written to another workbook by the
+    /// [`OverflowPolicy::SplitFiles`] overflow policy aren't included.
+    ///
+    /// # Parameters
+    ///
+    /// * `sheet_name` - The name of the table of contents worksheet. It must
+    ///   follow the Excel rules for worksheet names, see
+    ///   [`set_worksheet_name()`](PolarsXlsxWriter::set_worksheet_name), and
+    ///   it must not be the same as another worksheet name.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ComputeError`] that wraps a `rust_xlsxwriter`
+    /// [`XlsxError`](rust_xlsxwriter::XlsxError) error for an invalid
+    /// worksheet name.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing Polar Rust dataframes to an Excel file. This
+    /// demonstrates adding a table of contents worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_add_table_of_contents.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create some sample dataframes for the example.
+    ///     let df1: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[1000, 2300, 1900, 2500],
+    ///     )?;
+    ///
+    ///     let df2: DataFrame = df!(
+    ///         "Product" => &["Apple", "Pear"],
+    ///         "Units" => &[120, 80],
+    ///         "Price" => &[1.2, 0.9],
+    ///     )?;
+    ///
+    ///     // Write the dataframes to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Add a table of contents with links to the dataframes.
+    ///     xlsx_writer.add_table_of_contents("Contents")?;
+    ///
+    ///     xlsx_writer.set_worksheet_name("Sales")?;
+    ///     xlsx_writer.write_dataframe(&df1)?;
+    ///
+    ///     xlsx_writer.add_worksheet();
+    ///     xlsx_writer.set_worksheet_name("Products")?;
+    ///     xlsx_writer.write_dataframe(&df2)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn add_table_of_contents(
+        &mut self,
+        sheet_name: &str,
+    ) -> PolarsResult<&mut PolarsXlsxWriter> {
+        // Check that the name is valid before the worksheet is added.
+        Worksheet::new().set_name(sheet_name)?;
+
+        self.table_of_contents = Some(sheet_name.to_string());
+        Ok(self)
+    }
+
+    /// Add a dataframe to be written to a named worksheet with its own options.
+    ///
+    /// The options set on `PolarsXlsxWriter`, such as the float format or the
+    /// table style, apply to all the dataframes that it writes. This method
+    /// adds a [`SheetSpec`] that holds a dataframe and a copy of the current
+    /// writer options that can be changed for that worksheet only via the
+    /// `SheetSpec` `with_*()` methods. This allows several dataframes with
+    /// different options to be written to the same workbook.
+    ///
+    /// The worksheets are added to the workbook, in the order they were
+    /// specified, when the workbook is saved. If the initial default worksheet
+    /// hasn't been used by then it is removed from the workbook.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The worksheet name. It must follow the Excel rules for
+    ///   worksheet names. See
+    ///   [`set_worksheet_name()`](PolarsXlsxWriter::set_worksheet_name).
+    /// * `df` - A Polars dataframe.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing Polar Rust dataframes to separate worksheets in an
+    /// Excel workbook with different options for each worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_add_sheet.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::TableStyle;
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     let df1: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[1000.0, 2000.0, 3000.0, 4000.0],
+    ///     )?;
+    ///
+    ///     let df2: DataFrame = df!(
+    ///         "Item" => &["Rent", "Wages", "Marketing"],
+    ///         "Cost" => &[500.0, 1500.0, 250.0],
+    ///     )?;
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Add each dataframe with its own worksheet options.
+    ///     xlsx_writer
+    ///         .add_sheet("Sales", &df1)
+    ///         .with_autofit(true)
+    ///         .with_table_style(TableStyle::Medium4);
+    ///
+    ///     xlsx_writer
+    ///         .add_sheet("Costs", &df2)
+    ///         .with_float_format("$#,##0.00")
+    ///         .with_table_style(TableStyle::Light9);
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn add_sheet(&mut self, name: &str, df: &DataFrame) -> &mut SheetSpec {
+        self.sheet_specs
+            .push(SheetSpec::new(name, df, &self.options));
+
+        self.sheet_specs.last_mut().unwrap()
+    }
+
+    /// Get the current worksheet in the workbook.
+    ///
+    /// Get a reference to the current/last worksheet in the workbook in order
+    /// to manipulate it with a `rust_xlsxwriter` [`Worksheet`] method. This is
+    /// occasionally useful when you need to access some feature of the
+    /// worksheet APIs that isn't supported directly by `PolarsXlsxWriter`.
+    ///
+    /// Note, it is also possible to create a [`Worksheet`] separately and then
+    /// write the Polar dataframe to it using the
+    /// [`write_dataframe_to_worksheet()`](PolarsXlsxWriter::write_dataframe_to_worksheet)
+    /// method. That latter is more useful if you need to do a lot of
+    /// manipulation of the worksheet.
+    ///
+    /// # Errors
+    ///
+    /// A [`PolarsError::ComputeError`] that wraps a `rust_xlsxwriter`
+    /// [`XlsxError`](rust_xlsxwriter::XlsxError) error.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates getting a reference to the worksheet used to write the
+    /// dataframe and setting its tab color.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_worksheet.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// # use polars_excel_writer::PolarsXlsxWriter;
+    /// #
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "String" => &["North", "South", "East", "West"],
+    /// #         "Int" => &[1, 2, 3, 4],
+    /// #         "Float" => &[1.0, 2.22, 3.333, 4.4444],
+    /// #     )?;
+    /// #
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Get the worksheet that the dataframe will be written to.
+    ///     let worksheet = xlsx_writer.worksheet()?;
+    ///
+    ///     // Set the tab color for the worksheet using a `rust_xlsxwriter` worksheet
+    ///     // method.
+    ///     worksheet.set_tab_color("#FF9900");
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/write_excel_worksheet.png">
+    ///
+    pub fn worksheet(&mut self) -> PolarsResult<&mut Worksheet> {
+        self.current_worksheet()?;
+
+        // The worksheet may be changed by the caller so it can't be replaced
+        // by a constant memory worksheet.
+        let index = self.current_worksheet_index();
+        self.blank_sheets.remove(&index);
+
+        Ok(self.workbook.worksheet_from_index(index)?)
+    }
+
+    /// Get the underlying workbook.
+    ///
+    /// Get a reference to the `rust_xlsxwriter` [`Workbook`] that the
+    /// dataframes are written to. This is an advanced escape hatch for
+    /// workbook level features that aren't supported directly by
+    /// `PolarsXlsxWriter` such as defined names, document properties, VBA
+    /// macros or the order and visibility of worksheets.
+    ///
+    /// Note, changes to the workbook structure, such as deleting or reordering
+    /// worksheets, may conflict with the worksheet handling in
+    /// `PolarsXlsxWriter`. Dataframes are written to the last worksheet in
+    /// the workbook. Overflow files written with
+    /// [`OverflowPolicy::SplitFiles`](crate::OverflowPolicy::SplitFiles) use
+    /// separate workbooks and aren't affected.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates getting a reference to the underlying workbook and setting
+    /// some document properties.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_workbook.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// # use polars_excel_writer::PolarsXlsxWriter;
+    /// use rust_xlsxwriter::DocProperties;
+    ///
+    /// # fn main() -> PolarsResult<()> {
+    /// #     // Create a sample dataframe for the example.
+    /// #     let df: DataFrame = df!(
+    /// #         "String" => &["North", "South", "East", "West"],
+    /// #         "Int" => &[1, 2, 3, 4],
+    /// #         "Float" => &[1.0, 2.22, 3.333, 4.4444],
+    /// #     )?;
+    /// #
+    ///     // Write the dataframe to an Excel file.
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Get the underlying workbook and set the document properties using
+    ///     // a `rust_xlsxwriter` workbook method.
+    ///     let properties = DocProperties::new()
+    ///         .set_title("Regional Sales")
+    ///         .set_author("Sales Team");
+    ///
+    ///     xlsx_writer.workbook().set_properties(&properties);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn workbook(&mut self) -> &mut Workbook {
+        self.blank_sheets.clear();
+        &mut self.workbook
+    }
+
+    /// Get the warnings for the dataframes written by the writer.
+    ///
+    /// Some writer options that don't match the written data are ignored
+    /// instead of raising an error. For example a format set via
+    /// [`set_column_format()`](PolarsXlsxWriter::set_column_format) for a
+    /// column name that isn't in the dataframe. This method returns a
+    /// warning for each of these cases so that configuration mistakes can be
+    /// reported. See [`WarningKind`] for the types of warning.
+    ///
+    /// The warnings are added when each dataframe is written, or for
+    /// dataframes added via
+    /// [`append_dataframe()`](PolarsXlsxWriter::append_dataframe) or
+    /// [`add_sheet()`](PolarsXlsxWriter::add_sheet), when the file is saved.
+    /// Repeated warnings are only stored once.
+    ///
+    /// # Examples
+    ///
+    /// An example of writing a Polar Rust dataframe to an Excel file. This
+    /// demonstrates getting the warnings for writer options that were
+    /// ignored.
+    ///
+    /// ```
+    /// # // This code is available in examples/write_excel_warnings.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// #
+    /// use polars_excel_writer::{ColumnFormat, PolarsXlsxWriter};
+    ///
+    /// fn main() -> PolarsResult<()> {
+    ///     // Create a sample dataframe for the example.
+    ///     let df: DataFrame = df!(
+    ///         "Region" => &["North", "South", "East", "West"],
+    ///         "Sales" => &[1000, 2300, 1900, 2500],
+    ///     )?;
+    ///
+    ///     let mut xlsx_writer = PolarsXlsxWriter::new();
+    ///
+    ///     // Set a format for a misspelled column name.
+    ///     let currency = ColumnFormat::new().set_num_format("$#,##0");
+    ///
+    ///     xlsx_writer.set_column_format("Sale", &currency);
+    ///
+    ///     // Write the dataframe to Excel.
+    ///     xlsx_writer.write_dataframe(&df)?;
+    ///
+    ///     // Save the file to disk.
+    ///     xlsx_writer.save("dataframe.xlsx")?;
+    ///
+    ///     // Report any options that were ignored.
+    ///     for warning in xlsx_writer.warnings() {
+    ///         eprintln!("Warning: {}", warning.message);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn warnings(&self) -> &[ExportWarning] {
+        &self.warnings
+    }
+
+    // -----------------------------------------------------------------------
+    // Internal functions/methods.
+    // -----------------------------------------------------------------------
+
+    // Write any pending dataframes and worksheets and apply the workbook
+    // level settings before the workbooks are saved.
+    fn prepare_workbooks(&mut self) -> PolarsResult<()> {
+        self.write_appended_frame()?;
+        self.write_sheet_specs()?;
+        self.write_table_of_contents()?;
+        self.arrange_worksheets();
+
+        // Use a fixed creation time for deterministic output. This is the
+        // same as the timestamp of the files in the xlsx zip container.
+        if self.is_deterministic {
+            let creation_time = ExcelDateTime::from_ymd(1980, 1, 1)?;
+
+            let properties = self
+                .properties
+                .clone()
+                .unwrap_or_default()
+                .set_creation_datetime(&creation_time);
+            self.workbook.set_properties(&properties);
+
+            let properties = DocProperties::new().set_creation_datetime(&creation_time);
+            for workbook in &mut self.overflow_workbooks {
+                workbook.set_properties(&properties);
+            }
+        }
+
+        Ok(())
+    }
+
+    // Method to support writing to ExcelWriter writer<W>.
+    pub(crate) fn save_to_writer<W>(&mut self, df: &DataFrame, writer: W) -> PolarsResult<()>
+    where
+        W: Write + Seek + Send,
+    {
+        self.write_dataframe_with_overflow(df, 0, 0, None)?;
+        self.prepare_workbooks()?;
+
+        if !self.overflow_workbooks.is_empty() {
+            polars_bail!(
+                ComputeError:
+                "ExcelWriter doesn't support files split by the SplitFiles overflow policy"
+            );
+        }
+
+        if self.has_row_groups(0) {
+            let mut writer = writer;
+            writer.write_all(&self.workbook_to_buffer(0)?)?;
+        } else {
+            self.workbook.save_to_writer(writer)?;
+        }
+
+        Ok(())
+    }
+
+    // Save the prepared workbook, and any overflow workbooks, to buffers on
+    // the `tokio` blocking thread pool. The workbooks are shared with the pool
+    // and restored afterwards, even if the task fails, so that the writer can
+    // still be used.
+    #[cfg(feature = "async")]
+    async fn save_to_buffers_async(&mut self) -> PolarsResult<Vec<Vec<u8>>> {
+        use std::sync::{Mutex, PoisonError};
+
+        let mut workbooks = vec![std::mem::replace(&mut self.workbook, Workbook::new())];
+        workbooks.append(&mut self.overflow_workbooks);
+
+        let workbooks = Arc::new(Mutex::new(workbooks));
+        let task_workbooks = Arc::clone(&workbooks);
+        let row_groups = self.row_groups.clone();
+        let use_zip_large_file = self.use_zip_large_file;
+
+        let result = tokio::task::spawn_blocking(move || {
+            let mut workbooks = task_workbooks
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+
+            workbooks
+                .iter_mut()
+                .enumerate()
+                .map(|(index, workbook)| {
+                    Self::save_workbook_to_buffer(workbook, &row_groups, index, use_zip_large_file)
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })
+        .await;
+
+        // The task has finished, or won't run, so the workbooks can be taken
+        // back from the shared vector.
+        let mut workbooks =
+            std::mem::take(&mut *workbooks.lock().unwrap_or_else(PoisonError::into_inner));
+
+        if !workbooks.is_empty() {
+            self.overflow_workbooks = workbooks.split_off(1);
+            self.workbook = workbooks.remove(0);
+        }
+
+        result.map_err(|error| polars_err!(ComputeError: "{}", error))?
+    }
+
+    // Write the dataframe to a `rust_xlsxwriter` Worksheet. It is structured as
+    // an associated method to allow it to handle external worksheets.
+    fn write_dataframe_internal(
+        df: &DataFrame,
+        worksheet: &mut Worksheet,
+        row_offset: u32,
+        col_offset: u16,
+        options: &WriterOptions,
+    ) -> Result<(), PolarsError> {
+        let header_offset = u32::from(options.table.has_header_row());
+
+        // Set NaN and Infinity values, if required.
+        Self::set_special_float_values(worksheet, options);
+
+        // Get the output table columns, including any inserted columns.
+        let output_columns = Self::output_columns(df, options)?;
+
+        // Set the height of the header row, if required.
+        Self::write_header_row_height(worksheet, &output_columns, row_offset, options)?;
+
+        // Set the default width of date and datetime columns, if required.
+        Self::set_datetime_column_widths(worksheet, &output_columns, col_offset, options)?;
+
+        // Write the column values to in-memory buffers in parallel, if required.
+        #[cfg(feature = "parallel")]
+        let mut column_buffers = Self::write_column_buffers(
+            df,
+            &output_columns,
+            header_offset + row_offset,
+            col_offset,
+            options,
+        )?;
+
+        // Iterate through the output columns.
+        for (index, output_column) in output_columns.iter().enumerate() {
+            let col_num = col_offset + index as u16;
+
+            // Store the column names for use as table headers.
+            if options.table.has_header_row() {
+                Self::write_header(worksheet, row_offset, col_num, output_column, options)?;
+            }
+
+            // Write the formulas for the row total column.
+            if let OutputColumn::RowTotal(row_total_column) = output_column {
+                let row_total = Self::row_total(
+                    &output_columns,
+                    row_total_column,
+                    col_num,
+                    col_offset,
+                    options,
+                )?;
+
+                for row_num in 0..df.height() as u32 {
+                    let row_num = header_offset + row_offset + row_num;
+                    Self::write_row_total(worksheet, row_num, &row_total)?;
+                }
+                continue;
+            }
+
+            // Inserted columns don't have any dataframe data to write.
+            let Some(column) = output_column.column() else {
+                continue;
+            };
+
+            // Add any notes to the column cells.
+            let first_row = header_offset + row_offset;
+            Self::write_column_notes(worksheet, df, output_column, first_row, col_num, options)?;
+
+            // Copy the values written to a column buffer to the worksheet.
+            #[cfg(feature = "parallel")]
+            if let Some(column_buffer) = column_buffers[index].take() {
+                column_buffer.write_to_worksheet(worksheet)?;
+                continue;
+            }
+
+            // Check for values that can't be represented in Excel.
+            let has_invalid_values = Self::check_column_values(column, options)?;
+
+            // Get the user defined format for the column data, and the formats
+            // for banded rows, if any.
+            let column_format = output_column.column_format(options);
+            let dtype_format = column_format.as_deref();
+            let banded_formats = output_column.banded_formats(options);
+
+            // Write hyperlink columns as worksheet urls.
+            if let Some(url_column) = Self::hyperlink_url_column(df, output_column, options)? {
+                let urls = url_column.contiguous_series();
+                let texts = column.contiguous_series();
+
+                for (row_index, (url, text)) in urls.iter().zip(texts.iter()).enumerate() {
+                    let row_num = header_offset + row_offset + row_index as u32;
+                    let format = Self::row_format(dtype_format, banded_formats.as_ref(), row_index);
+
+                    Self::write_hyperlink(worksheet, row_num, col_num, url, text, format, options)?;
+                }
+                continue;
+            }
+
+            // Write image columns as embedded cell images.
+            if Self::image_column_options(output_column, options).is_some() {
+                let series = column.contiguous_series();
+                for (row_index, data) in series.iter().enumerate() {
+                    let row_num = header_offset + row_offset + row_index as u32;
+                    let format = Self::row_format(dtype_format, banded_formats.as_ref(), row_index);
+
+                    Self::write_image(worksheet, row_num, col_num, data, format, options)?;
+                }
+                continue;
+            }
+
+            // Write the row data for the column.
+            Self::write_column_values(
+                worksheet,
+                output_column,
+                first_row,
+                col_num,
+                dtype_format,
+                has_invalid_values,
+                options,
+            )?;
+        }
+
+        // Add the table and any column features that depend on it.
+        Self::write_table(
+            worksheet,
+            &output_columns,
+            row_offset,
+            col_offset,
+            df.height(),
+            options,
+        )?;
+
+        // Autofit the columns.
+        Self::autofit_columns(worksheet, &output_columns, col_offset, options)?;
+
+        // Set any user defined column properties. These override autofit.
+        Self::set_column_properties(worksheet, &output_columns, col_offset, options)?;
+
+        // Set the worksheet level options.
+        Self::set_worksheet_options(worksheet, options)?;
+
+        // Set the page setup options.
+        Self::set_page_setup(
+            worksheet,
+            row_offset,
+            col_offset,
+            df.height(),
+            output_columns.len(),
+            options,
+        )?;
+
+        Ok(())
+    }
+
+    // Write the values of a dataframe output column to the worksheet cells, or
+    // to a column buffer.
+    fn write_column_values(
+        worksheet: &mut impl CellSink,
+        output_column: &OutputColumn,
+        first_row: u32,
+        col_num: u16,
+        dtype_format: Option<&Format>,
+        has_invalid_values: bool,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        let Some(column) = output_column.column() else {
+            return Ok(());
+        };
+
+        // Write numeric data directly from the column chunks, if possible.
+        // Columns with values that are written as strings are excluded.
+        let write_as_string =
+            has_invalid_values && Self::is_written_as_string(column.dtype(), options);
+
+        // Columns with a value transformation, cell style or banded rows are
+        // also excluded.
+        let is_transformed = options.column_transforms.contains_key(output_column.name())
+            || options.cell_styles.contains_key(output_column.name());
+        let banded_formats = output_column.banded_formats(options);
+
+        if let (OutputColumn::Data(_), false, false, None) = (
+            output_column,
+            write_as_string,
+            is_transformed,
+            &banded_formats,
+        ) {
+            let series = column.series();
+
+            if Self::write_numeric_column(
+                worksheet,
+                first_row,
+                col_num,
+                series,
+                dtype_format,
+                options,
+            )? {
+                return Ok(());
+            }
+        }
+
+        // Write the row data for each column/type. The AnyValue iterator
+        // requires the series to be contiguous.
+        let series = column.contiguous_series();
+        for (row_index, data) in series.iter().enumerate() {
+            let row_num = first_row + row_index as u32;
+            let format = Self::row_format(dtype_format, banded_formats.as_ref(), row_index);
+
+            if Self::write_banded_null(
+                worksheet,
+                row_num,
+                col_num,
+                output_column,
+                &data,
+                format,
+                options,
+            )? {
+                continue;
+            }
+
+            Self::write_output_value(
+                worksheet,
+                row_num,
+                col_num,
+                output_column,
+                data,
+                format,
+                options,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    // Get the format for a row of column data. This is the format for the row
+    // band, if banded row formats are set, or the column format.
+    fn row_format<'b>(
+        column_format: Option<&'b Format>,
+        banded_formats: Option<&'b [Format; 2]>,
+        row_index: usize,
+    ) -> Option<&'b Format> {
+        match banded_formats {
+            Some(formats) => Some(&formats[row_index % 2]),
+            None => column_format,
+        }
+    }
+
+    // Write a null value as a blank cell with the row band format so that the
+    // bands are continuous. Returns false if the value isn't a null that is
+    // written as a blank cell. Nulls in columns with a value transformation or
+    // cell style are written as usual.
+    fn write_banded_null(
+        worksheet: &mut impl CellSink,
+        row: u32,
+        col: u16,
+        output_column: &OutputColumn,
+        data: &AnyValue,
+        format: Option<&Format>,
+        options: &WriterOptions,
+    ) -> Result<bool, XlsxError> {
+        if options.column_transforms.contains_key(output_column.name())
+            || options.cell_styles.contains_key(output_column.name())
+        {
+            return Ok(false);
+        }
+
+        match (
+            data,
+            format,
+            &options.banded_row_formats,
+            &options.null_value,
+        ) {
+            (AnyValue::Null, Some(format), Some(_), None) => {
+                worksheet.write_blank_cell(row, col, format)?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    // Write the values of the dataframe columns to in-memory column buffers in
+    // parallel, if enabled via `set_parallel()`. Returns the buffer, if any,
+    // for each output column. Hyperlink and image columns, and the columns
+    // inserted by the writer, aren't buffered.
+    #[cfg(feature = "parallel")]
+    fn write_column_buffers(
+        df: &DataFrame,
+        output_columns: &[OutputColumn],
+        first_row: u32,
+        col_offset: u16,
+        options: &WriterOptions,
+    ) -> PolarsResult<Vec<Option<ColumnBuffer>>> {
+        use rayon::prelude::*;
+
+        if !options.parallel {
+            return Ok(output_columns.iter().map(|_| None).collect());
+        }
+
+        output_columns
+            .par_iter()
+            .enumerate()
+            .map(|(index, output_column)| {
+                let Some(column) = output_column.column() else {
+                    return Ok(None);
+                };
+
+                if Self::hyperlink_url_column(df, output_column, options)?.is_some()
+                    || Self::image_column_options(output_column, options).is_some()
+                {
+                    return Ok(None);
+                }
+
+                let has_invalid_values = Self::check_column_values(column, options)?;
+                let column_format = output_column.column_format(options);
+
+                let mut column_buffer = ColumnBuffer::default();
+                Self::write_column_values(
+                    &mut column_buffer,
+                    output_column,
+                    first_row,
+                    col_offset + index as u16,
+                    column_format.as_deref(),
+                    has_invalid_values,
+                    options,
+                )?;
+
+                Ok(Some(column_buffer))
+            })
+            .collect()
+    }
+
+    // Write the dataframes added via `add_sheet()` to their own worksheets
+    // using the options stored with each of them.
+    fn write_sheet_specs(&mut self) -> PolarsResult<()> {
+        let sheet_specs = std::mem::take(&mut self.sheet_specs);
+        if sheet_specs.is_empty() {
+            return Ok(());
+        }
+
+        // Remove the initial default worksheet if it hasn't been used.
+        if !self.is_worksheet_used && self.workbook.worksheets().len() == 1 {
+            self.remove_default_worksheet();
+        }
+
+        // Swap in the options for each worksheet and restore the writer
+        // options afterwards, even if there is an error.
+        let writer_options = self.options.clone();
+        let mut result = Ok(());
+
+        for sheet_spec in sheet_specs {
+            self.options = sheet_spec.options;
+            result = self.write_sheet_spec(&sheet_spec.name, &sheet_spec.df);
+
+            if result.is_err() {
+                break;
+            }
+        }
+
+        self.options = writer_options;
+
+        result
+    }
+
+    // Write a dataframe to a new named worksheet with the current options.
+    fn write_sheet_spec(&mut self, name: &str, df: &DataFrame) -> PolarsResult<()> {
+        let worksheet = self.add_new_worksheet();
+        worksheet.set_name(name)?;
+
+        self.write_dataframe(df)?;
+
+        Ok(())
+    }
+
+    // Create a new writer for a file written via `save_partitioned()` with a
+    // copy of the writer options and workbook settings.
+    fn partition_writer(&self) -> PolarsXlsxWriter {
+        let mut xlsx_writer = PolarsXlsxWriter::new();
+        xlsx_writer.options = self.options.clone();
+        xlsx_writer.set_deterministic(self.is_deterministic);
+        xlsx_writer.set_zip_large_file(self.use_zip_large_file);
+
+        if let Some(properties) = &self.properties {
+            xlsx_writer.set_properties(properties);
+        }
+
+        xlsx_writer
+    }
+
+    // Write a dataframe to a file for `save_partitioned()`.
+    fn save_partition(
+        xlsx_writer: &mut PolarsXlsxWriter,
+        path: &Path,
+        df: &DataFrame,
+    ) -> PolarsResult<()> {
+        xlsx_writer.write_dataframe(df)?;
+        xlsx_writer.save(path)
+    }
+
+    // Check that a `save_partitioned()` name is a plain file name so that the
+    // file can't be written outside the target directory. Names with path
+    // separators, relative path components or characters that are invalid in
+    // Windows file names are rejected rather than adjusted, since adjusted
+    // names could clash.
+    fn check_partition_file_name(name: &str) -> PolarsResult<()> {
+        let is_invalid = name.is_empty()
+            || name == "."
+            || name == ".."
+            || name.chars().any(|c| {
+                c.is_control() || matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|')
+            });
+
+        if is_invalid {
+            polars_bail!(
+                ComputeError:
+                "file name '{}' set in save_partitioned() isn't a valid file name", name.escape_debug()
+            );
+        }
+
+        Ok(())
+    }
+
+    // Write the dataframes of an `ExcelExport` layout to a new named
+    // worksheet. The tables are written without autofilters and any autofit is
+    // deferred until the last dataframe so that it is only applied once.
+    pub(crate) fn write_sheet_layout(&mut self, layout: &SheetLayout) -> PolarsResult<()> {
+        // Remove the initial default worksheet if it hasn't been used.
+        if !self.is_worksheet_used && self.workbook.worksheets().len() == 1 {
+            self.remove_default_worksheet();
+        }
+
+        let worksheet = self.add_new_worksheet();
+        worksheet.set_name(&layout.name)?;
+        self.is_worksheet_used = true;
+
+        // Swap in the layout options and restore the writer options
+        // afterwards, even if there is an error.
+        let writer_options = self.options.clone();
+        self.options.table = self.options.table.clone().set_autofilter(false);
+        self.options.use_autofilter = false;
+
+        let mut result = Ok(());
+        let mut previous_range: Option<WrittenRange> = None;
+
+        for (index, frame) in layout.frames.iter().enumerate() {
+            self.options.is_autofit_deferred = index + 1 < layout.frames.len();
+
+            // Dataframes without a position go below the previous dataframe.
+            let (row, col) = match (frame.position, &previous_range) {
+                (Some(position), _) => position,
+                (None, Some(range)) => (range.last_row.saturating_add(2), range.first_col),
+                (None, None) => (0, 0),
+            };
+
+            match self.write_dataframe_to_cell(&frame.df, row, col) {
+                Ok(range) => previous_range = Some(range),
+                Err(error) => {
+                    result = Err(error);
+                    break;
+                }
+            }
+        }
+
+        self.options = writer_options;
+
+        result
+    }
+
+    // Write the table of dataframes added via `append_dataframe()`, if any,
+    // using the options stored with them. If other worksheets have been added
+    // since then they are moved aside temporarily so that the table's
+    // worksheet is the current worksheet.
+    fn write_appended_frame(&mut self) -> PolarsResult<()> {
+        let Some(appended) = self.appended_frame.take() else {
+            return Ok(());
+        };
+
+        let later_worksheets = self
+            .workbook
+            .worksheets_mut()
+            .split_off(appended.sheet_index + 1);
+
+        let writer_options = std::mem::replace(&mut self.options, appended.options);
+        let result =
+            self.write_dataframe_with_overflow(&appended.df, appended.row, appended.col, None);
+        self.options = writer_options;
+
+        self.workbook.worksheets_mut().extend(later_worksheets);
+
+        result.map(|_| ())
+    }
+
+    // Store the position and visibility of a worksheet in the main workbook so
+    // that they can be applied when the workbook is saved. The position only
+    // applies to the first worksheet of a split dataframe.
+    fn record_sheet_arrangement(
+        &mut self,
+        sheet_name: &str,
+        is_first_sheet: bool,
+        options: &WriterOptions,
+    ) {
+        if let (Some(position), true) = (options.sheet_position, is_first_sheet) {
+            self.sheet_positions
+                .retain(|(name, _)| name.as_str() != sheet_name);
+            self.sheet_positions
+                .push((sheet_name.to_string(), position));
+        }
+
+        if options.sheet_hidden {
+            self.hidden_sheets.insert(sheet_name.to_string());
+        }
+
+        if options.sheet_active && is_first_sheet {
+            self.active_sheet = Some(sheet_name.to_string());
+        }
+    }
+
+    // Add the table of contents worksheet, if required, as the first worksheet
+    // in the workbook. It has a row with a link and the size of each dataframe
+    // written to the other worksheets, in worksheet order.
+    fn write_table_of_contents(&mut self) -> PolarsResult<()> {
+        let Some(contents_name) = self.table_of_contents.take() else {
+            return Ok(());
+        };
+
+        let mut contents = Worksheet::new();
+        contents.set_name(contents_name)?;
+
+        let header_format = Format::new().set_bold();
+        for (col_num, header) in ["Sheet", "Range", "Rows", "Columns"].iter().enumerate() {
+            contents.write_with_format(0, col_num as u16, *header, &header_format)?;
+        }
+
+        let mut row_num = 1;
+        for worksheet in self.workbook.worksheets() {
+            let Some(ranges) = self.written_ranges.get(&worksheet.name()) else {
+                continue;
+            };
+
+            for (range, num_rows) in ranges {
+                let first_cell = utility::row_col_to_cell(range.first_row, range.first_col);
+                let link = format!(
+                    "internal:{}!{first_cell}",
+                    Self::quote_sheet_name(&range.sheet_name)
+                );
+                let cell_range = utility::cell_range(
+                    range.first_row,
+                    range.first_col,
+                    range.last_row,
+                    range.last_col,
+                );
+                let num_cols = range.last_col - range.first_col + 1;
+
+                contents.write_url(row_num, 0, Url::new(link).set_text(&range.sheet_name))?;
+                contents.write(row_num, 1, cell_range)?;
+                contents.write(row_num, 2, *num_rows as u32)?;
+                contents.write(row_num, 3, num_cols)?;
+
+                row_num += 1;
+            }
+        }
+
+        contents.autofit();
+        self.workbook.worksheets_mut().insert(0, contents);
+
+        Ok(())
+    }
+
+    // Move any worksheets with a position set via `set_sheet_position()` to
+    // that position, in the order they were written. Then set the active
+    // worksheet set via `set_sheet_active()`, if any. Otherwise, if the first
+    // worksheet is hidden the first visible worksheet is made the active
+    // worksheet since Excel doesn't allow a hidden worksheet to be active.
+    fn arrange_worksheets(&mut self) {
+        let worksheets = self.workbook.worksheets_mut();
+
+        for (sheet_name, position) in std::mem::take(&mut self.sheet_positions) {
+            if let Some(index) = worksheets.iter().position(|ws| ws.name() == sheet_name) {
+                let worksheet = worksheets.remove(index);
+                worksheets.insert(position.min(worksheets.len()), worksheet);
+            }
+        }
+
+        if let Some(sheet_name) = self.active_sheet.take() {
+            if let Some(worksheet) = worksheets.iter_mut().find(|ws| ws.name() == sheet_name) {
+                worksheet.set_active(true);
+                return;
+            }
+        }
+
+        let is_first_hidden = worksheets
+            .first()
+            .is_some_and(|worksheet| self.hidden_sheets.contains(&worksheet.name()));
+
+        if is_first_hidden {
+            if let Some(worksheet) = worksheets
+                .iter_mut()
+                .find(|worksheet| !self.hidden_sheets.contains(&worksheet.name()))
+            {
+                worksheet.set_active(true);
+            }
+        }
+    }
+
+    // Write the dataframe to the current worksheet and, if required by the
+    // overflow policy, to additional worksheets or workbooks for any rows
+    // that don't fit in the worksheet. If a chunk size is specified, or
+    // constant memory mode is on, the dataframe is written to constant memory
+    // worksheets. Returns the range written to the current worksheet.
+    #[allow(clippy::too_many_lines)]
+    fn write_dataframe_with_overflow(
+        &mut self,
+        df: &DataFrame,
+        row_offset: u32,
+        col_offset: u16,
+        chunk_size: Option<usize>,
+    ) -> PolarsResult<WrittenRange> {
+        self.write_appended_frame()?;
+
+        let mut options = self.options.clone();
+        // Use constant memory worksheets for all writes, if required. Writes
+        // to an existing constant memory worksheet also need to use them.
+        let sheet_index = self.current_worksheet_index();
+        let chunk_size = match chunk_size {
+            None if options.constant_memory
+                || self.constant_memory_sheets.contains(&sheet_index) =>
+            {
+                Some(CONSTANT_MEMORY_CHUNK_SIZE)
+            }
+            chunk_size => chunk_size,
+        };
+        let constant_memory = chunk_size.is_some();
+
+        // Constant memory worksheets must be written in row order.
+        let chunk_size = match (chunk_size, options.write_order) {
+            (None, WriteOrder::RowMajor) => Some(CONSTANT_MEMORY_CHUNK_SIZE),
+            (chunk_size, _) => chunk_size,
+        };
+
+        let df = Self::filter_rows(df, &options)?;
+        let df = Self::select_columns(df.as_ref(), &options)?;
+        let df = Self::convert_struct_columns(df.as_ref(), &options)?;
+        let df = Self::convert_binary_columns(df.as_ref(), &options)?;
+        #[cfg(feature = "object")]
+        let df = Self::convert_object_columns(df, &options)?;
+        let df = Self::add_row_numbers(df, &options)?;
+        let df = df.as_ref();
+
+        // Write any grouped header row above the table, which moves the table
+        // down by one row.
+        let header_groups = Self::split_headers(df, &mut options)?;
+        let group_row = row_offset;
+        let row_offset = row_offset + u32::from(header_groups.is_some());
+
+        Self::set_merge_range_mode(df, &mut options);
+        Self::set_empty_dataframe_mode(df, &mut options);
+        let renamed_headers = Self::set_unique_headers(df, &mut options)?;
+
+        let segments = Self::row_segments(df.height(), row_offset, &options)?;
+        let sheet_name = self.current_worksheet()?.name();
+        self.add_warnings(&sheet_name, df, &renamed_headers, &options)?;
+        if constant_memory {
+            self.check_constant_memory_rows(&sheet_name, group_row)?;
+            self.add_constant_memory_warnings(&sheet_name, df, &options)?;
+        }
+        let table_name = options.output_table_name().map(str::to_string);
+        let range = Self::written_range(&sheet_name, df, row_offset, col_offset, &options)?;
+
+        // Check that the dataframe doesn't overlap a previous dataframe in the
+        // worksheet. This includes any grouped header row.
+        self.add_written_range(
+            WrittenRange {
+                first_row: group_row,
+                ..range.clone()
+            },
+            segments[0].1,
+        )?;
+
+        // Store the layout of the output table for use with charts.
+        self.last_layout = Some(WrittenLayout {
+            range: range.clone(),
+            column_names: Self::output_columns(df, &options)?
+                .iter()
+                .map(|output_column| output_column.name().to_string())
+                .collect(),
+            has_header: options.table.has_header_row(),
+            has_total_row: Self::has_total_row(&options),
+        });
+
+        for (index, (first_row, num_rows)) in segments.into_iter().enumerate() {
+            let df = df.slice_rows(first_row, num_rows);
+            let row_groups = Self::row_groups(&df, row_offset, &options)?;
+
+            // Set a unique table name for each part of a split dataframe.
+            if let Some(table_name) = &table_name {
+                let table_name = if index == 0 {
+                    table_name.clone()
+                } else {
+                    format!("{table_name}_{}", index + 1)
+                };
+
+                self.add_table_name(&table_name)?;
+                options.table_name = Some(table_name);
+            }
+
+            let worksheet = if index == 0 {
+                if constant_memory {
+                    self.replace_with_constant_memory_worksheet()?;
+                }
+                self.blank_sheets.remove(&sheet_index);
+                self.current_worksheet()?
+            } else {
+                self.overflow_worksheet(&sheet_name, index, constant_memory)?
+            };
+            let worksheet_name = worksheet.name();
+
+            if let Some(header_groups) = &header_groups {
+                Self::write_header_groups(
+                    worksheet,
+                    group_row,
+                    col_offset,
+                    header_groups,
+                    &options,
+                )?;
+            }
+
+            Self::write_data_row_heights(
+                worksheet, &df, first_row, num_rows, row_offset, &options,
+            )?;
+
+            match chunk_size {
+                Some(chunk_size) => Self::write_dataframe_chunked_internal(
+                    &df,
+                    worksheet,
+                    row_offset,
+                    col_offset,
+                    chunk_size,
+                    constant_memory,
+                    &options,
+                )?,
+                None => {
+                    Self::write_dataframe_internal(
+                        &df, worksheet, row_offset, col_offset, &options,
+                    )?;
+                }
+            }
+
+            // Constant memory rows can't be changed once they are written. A
+            // warning is added for the ignored columns.
+            if !constant_memory {
+                Self::merge_repeated_values(worksheet, &df, row_offset, col_offset, &options)?;
+            }
+
+            // Files split by the overflow policy are in the overflow workbooks.
+            let workbook_index = match options.overflow_policy {
+                OverflowPolicy::SplitFiles => index,
+                _ => 0,
+            };
+            self.add_row_groups(workbook_index, &worksheet_name, row_groups);
+
+            // Keep the fixed column widths of worksheets in the main workbook
+            // for any later dataframes written to them.
+            if index == 0 || options.overflow_policy != OverflowPolicy::SplitFiles {
+                self.record_sheet_arrangement(&worksheet_name, index == 0, &options);
+
+                // Store the range of the overflow worksheets, which are new.
+                if index > 0 {
+                    let range = Self::written_range(
+                        &worksheet_name,
+                        &df,
+                        row_offset,
+                        col_offset,
+                        &options,
+                    )?;
+                    self.add_written_range(
+                        WrittenRange {
+                            first_row: group_row,
+                            ..range
+                        },
+                        num_rows,
+                    )?;
+                }
+
+                let worksheet = self.workbook.worksheet_from_name(&worksheet_name)?;
+                let fixed_widths = self.fixed_column_widths.entry(worksheet_name).or_default();
+
+                Self::set_fixed_column_widths(worksheet, fixed_widths, &df, col_offset, &options)?;
+            }
+        }
+
+        // Add the defined names for the column data ranges, if required.
+        if let Some(prefix) = &options.defined_names_prefix {
+            self.add_column_defined_names(prefix, &range)?;
+        }
+
+        Ok(range)
+    }
+
+    // Add a workbook defined name for the data range of each column in the
+    // last written table. Defined names are case insensitive and must be
+    // unique in the workbook.
+    fn add_column_defined_names(&mut self, prefix: &str, range: &WrittenRange) -> PolarsResult<()> {
+        let Some(layout) = &self.last_layout else {
+            return Ok(());
+        };
+
+        let first_row = range.first_row + u32::from(layout.has_header);
+        let last_row = range.last_row - u32::from(layout.has_total_row);
+        if first_row > last_row {
+            return Ok(());
+        }
+
+        let sheet_name = Self::quote_sheet_name(&range.sheet_name);
+
+        for (col_num, column_name) in layout.column_names.iter().enumerate() {
+            let name = Self::column_defined_name(prefix, column_name);
+
+            if !self.defined_names.insert(name.to_lowercase()) {
+                polars_bail!(
+                    ComputeError:
+                    "defined name '{}' has already been used in the workbook",
+                    name
+                );
+            }
+
+            let col = range.first_col + col_num as u16;
+            let formula = format!(
+                "={sheet_name}!{}",
+                utility::cell_range_absolute(first_row, col, last_row, col)
+            );
+
+            self.workbook.define_name(name, &formula)?;
+        }
+
+        Ok(())
+    }
+
+    // Get the defined name for a column, replacing any characters that aren't
+    // allowed in Excel names.
+    fn column_defined_name(prefix: &str, column_name: &str) -> String {
+        let name = if prefix.is_empty() {
+            column_name.to_string()
+        } else {
+            format!("{prefix}_{column_name}")
+        };
+
+        let name: String = name
+            .chars()
+            .map(|c| {
+                if c.is_alphanumeric() || matches!(c, '_' | '.' | '\\') {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+
+        let starts_with_letter = name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '\\');
+
+        if !starts_with_letter || Self::is_cell_reference(&name) {
+            format!("_{name}")
+        } else {
+            name
+        }
+    }
+
+    // Quote a worksheet name for use in a formula, if required. Names with
+    // special characters, or that could be read as a cell reference, such as
+    // "A1", "R1C1" or "C", need to be quoted. Excel also reads names that
+    // start with an R1C1 reference, such as "R1Data", as a reference.
+    fn quote_sheet_name(sheet_name: &str) -> String {
+        let name = sheet_name.to_ascii_uppercase();
+        let starts_with_rc_reference = ["RC", "R", "C"].iter().any(|prefix| {
+            name.strip_prefix(prefix)
+                .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+        });
+
+        if sheet_name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+            && !sheet_name.starts_with(|c: char| c.is_ascii_digit() || c == '.')
+            && !Self::is_cell_reference(sheet_name)
+            && !starts_with_rc_reference
+        {
+            sheet_name.to_string()
+        } else {
+            format!("'{}'", sheet_name.replace('\'', "''"))
+        }
+    }
+
+    // Get a valid and unique worksheet name from a name that may not follow
+    // the Excel rules, such as a partition key. Invalid characters are
+    // replaced and the name is truncated to the Excel limit of 31 characters,
+    // including any deduplication suffix. The used names are lowercase.
+    fn lossy_sheet_name(name: &str, used_names: &HashSet<String>) -> String {
+        let name: String = name
+            .chars()
+            .map(|c| match c {
+                '*' | '?' | ':' | '[' | ']' | '\\' | '/' => '_',
+                _ => c,
+            })
+            .collect();
+
+        let name = name.trim_matches('\'');
+        let name = if name.is_empty() { "_" } else { name };
+
+        let truncate = |name: &str, max_len: usize| -> String {
+            name.chars()
+                .take(max_len)
+                .collect::<String>()
+                .trim_end_matches('\'')
+                .to_string()
+        };
+
+        // The "History" name is reserved by Excel.
+        let is_used = |sheet_name: &str| {
+            let sheet_name = sheet_name.to_lowercase();
+            sheet_name == "history" || used_names.contains(&sheet_name)
+        };
+
+        let mut sheet_name = truncate(name, 31);
+        let mut index = 2;
+        while is_used(&sheet_name) {
+            let suffix = format!(" ({index})");
+            sheet_name = format!("{}{suffix}", truncate(name, 31 - suffix.len()));
+            index += 1;
+        }
+
+        sheet_name
+    }
+
+    // Get the row in the old dataframe, if any, that matches each row of the
+    // new dataframe in a diff. Rows are matched by position or by key value.
+    fn diff_matching_rows(
+        df_old: &DataFrame,
+        df_new: &DataFrame,
+        options: &WriterOptions,
+    ) -> PolarsResult<Vec<Option<usize>>> {
+        let Some(key_column) = &options.diff_key_column else {
+            return Ok((0..df_new.height())
+                .map(|row| (row < df_old.height()).then_some(row))
+                .collect());
+        };
+
+        let old_keys = df_old.contiguous_series(key_column)?;
+        let new_keys = df_new.contiguous_series(key_column)?;
+
+        let mut old_rows = HashMap::new();
+        for (row, key) in old_keys.iter().enumerate() {
+            if !key.is_null() {
+                old_rows.entry(key.to_string()).or_insert(row);
+            }
+        }
+
+        Ok(new_keys
+            .iter()
+            .map(|key| {
+                if key.is_null() {
+                    None
+                } else {
+                    old_rows.get(&key.to_string()).copied()
+                }
+            })
+            .collect())
+    }
+
+    // Highlight the cells of the last written dataframe that differ from the
+    // matching cells of the old dataframe in a diff. The cells are highlighted
+    // with a single conditional format over the ranges of changed cells.
+    fn add_diff_highlights(
+        &mut self,
+        df_old: &DataFrame,
+        df_new: &DataFrame,
+        old_rows: &[Option<usize>],
+        range: &WrittenRange,
+    ) -> PolarsResult<()> {
+        let Some(layout) = &self.last_layout else {
+            return Ok(());
+        };
+
+        let first_row = range.first_row + u32::from(layout.has_header);
+        let last_row = range.last_row - u32::from(layout.has_total_row);
+
+        // Get the ranges of changed cells in each column.
+        let mut cell_ranges = vec![];
+        for (col_num, column_name) in layout.column_names.iter().enumerate() {
+            let Ok(new_column) = df_new.column(column_name) else {
+                continue;
+            };
+
+            let new_values = new_column.contiguous_series();
+            let old_values = df_old
+                .column(column_name)
+                .ok()
+                .map(ColumnAdapter::contiguous_series);
+
+            let col = range.first_col + col_num as u16;
+            let mut first_changed_row = None;
+
+            for (row_num, new_value) in new_values.iter().enumerate() {
+                let row = first_row + row_num as u32;
+                if row > last_row {
+                    break;
+                }
+
+                let is_changed = match (&old_values, old_rows[row_num]) {
+                    (Some(old_values), Some(old_row)) => old_values.get(old_row)? != new_value,
+                    _ => true,
+                };
+
+                match (is_changed, first_changed_row) {
+                    (true, None) => first_changed_row = Some(row),
+                    (false, Some(first)) => {
+                        cell_ranges.push((first, col, row - 1));
+                        first_changed_row = None;
+                    }
+                    _ => {}
+                }
+            }
+
+            if let Some(first) = first_changed_row {
+                let last = last_row.min(first_row + new_values.len() as u32 - 1);
+                cell_ranges.push((first, col, last));
+            }
+        }
+
+        let Some(&(first, col, last)) = cell_ranges.first() else {
+            return Ok(());
+        };
+
+        let multi_range = cell_ranges
+            .iter()
+            .map(|&(first, col, last)| utility::cell_range(first, col, last, col))
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        let conditional_format = ConditionalFormatFormula::new()
+            .set_rule("TRUE")
+            .set_format(&self.options.diff_format)
+            .set_multi_range(multi_range);
+
+        let worksheet = self.workbook.worksheet_from_name(&range.sheet_name)?;
+        worksheet.add_conditional_format(first, col, last, col, &conditional_format)?;
+
+        Ok(())
+    }
+
+    // Get the current worksheet, which is the last worksheet in the workbook.
+    // A worksheet is added if there isn't one already.
+    fn current_worksheet(&mut self) -> PolarsResult<&mut Worksheet> {
+        self.is_worksheet_used = true;
+
+        if self.workbook.worksheets().is_empty() {
+            self.add_new_worksheet();
+        }
+
+        let index = self.current_worksheet_index();
+        let worksheet = self.workbook.worksheet_from_index(index)?;
+
+        Ok(worksheet)
+    }
+
+    // Get the index of the current worksheet.
+    fn current_worksheet_index(&mut self) -> usize {
+        self.workbook.worksheets().len().saturating_sub(1)
+    }
+
+    // Add a worksheet to the workbook. In constant memory mode this is a
+    // constant memory worksheet so that any changes made to it before a
+    // dataframe is written are kept. Other worksheets are blank until they are
+    // used and can be replaced by a constant memory worksheet.
+    fn add_new_worksheet(&mut self) -> &mut Worksheet {
+        let index = self.workbook.worksheets().len();
+
+        if self.options.constant_memory {
+            self.constant_memory_sheets.insert(index);
+            self.workbook.add_worksheet_with_constant_memory()
+        } else {
+            self.blank_sheets.insert(index);
+            self.workbook.add_worksheet()
+        }
+    }
+
+    // Remove the initial default worksheet, and the record of its type.
+    fn remove_default_worksheet(&mut self) {
+        self.workbook.worksheets_mut().clear();
+        self.constant_memory_sheets.clear();
+        self.blank_sheets.clear();
+    }
+
+    // Update the recorded worksheet indices for a sheet inserted at an index.
+    fn shift_sheet_indices(&mut self, inserted_index: usize) {
+        for sheets in [&mut self.constant_memory_sheets, &mut self.blank_sheets] {
+            *sheets = sheets
+                .drain()
+                .map(|index| index + usize::from(index >= inserted_index))
+                .collect();
+        }
+    }
+
+    // Replace the current worksheet with a constant memory worksheet of the
+    // same name, if it isn't one already. Only blank worksheets can be
+    // replaced since the changes made to a worksheet would be lost.
+    fn replace_with_constant_memory_worksheet(&mut self) -> PolarsResult<()> {
+        let sheet_name = self.current_worksheet()?.name();
+        let sheet_index = self.current_worksheet_index();
+
+        if self.constant_memory_sheets.contains(&sheet_index) {
+            return Ok(());
+        }
+
+        if !self.blank_sheets.remove(&sheet_index) {
+            polars_bail!(
+                ComputeError:
+                "worksheet '{}' has already been used and can't be changed to a constant memory worksheet. \
+                 Call set_constant_memory() before the worksheet is added or changed",
+                sheet_name
+            );
+        }
+
+        let mut worksheet = self.workbook.new_worksheet_with_constant_memory();
+        worksheet.set_name(&sheet_name)?;
+        self.workbook.worksheets_mut()[sheet_index] = worksheet;
+        self.constant_memory_sheets.insert(sheet_index);
+
+        Ok(())
+    }
+
+    // Replace the blank worksheets with constant memory worksheets of the same
+    // name.
+    fn replace_blank_worksheets(&mut self) {
+        for sheet_index in std::mem::take(&mut self.blank_sheets) {
+            let sheet_name = self.workbook.worksheets()[sheet_index].name();
+
+            let mut worksheet = self.workbook.new_worksheet_with_constant_memory();
+            // The name was validated when it was set on the blank worksheet.
+            let _ = worksheet.set_name(&sheet_name);
+            self.workbook.worksheets_mut()[sheet_index] = worksheet;
+            self.constant_memory_sheets.insert(sheet_index);
+        }
+    }
+
+    // Check that a dataframe written to a constant memory worksheet starts
+    // below any previous dataframes in the worksheet. The earlier rows have
+    // already been written to disk and any new data in them would be lost.
+    fn check_constant_memory_rows(&self, sheet_name: &str, first_row: u32) -> PolarsResult<()> {
+        let last_row = self
+            .written_ranges
+            .get(sheet_name)
+            .and_then(|ranges| ranges.iter().map(|(range, _)| range.last_row).max());
+
+        match last_row {
+            Some(last_row) if first_row <= last_row => polars_bail!(
+                ComputeError:
+                "dataframe in row {} of constant memory worksheet '{}' must start below the previous data in row {}. \
+                 Constant memory rows are written in order, for example with Layout::Vertical",
+                first_row + 1,
+                sheet_name,
+                last_row + 1
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    // Add a worksheet for rows that overflow the first worksheet. For
+    // `SplitWorksheets` this is added to the workbook with a `_2`, `_3`, etc.,
+    // suffix. For `SplitFiles` it is added to an additional workbook, with the
+    // same worksheet name, that is saved alongside the main file.
+    fn overflow_worksheet(
+        &mut self,
+        sheet_name: &str,
+        index: usize,
+        constant_memory: bool,
+    ) -> PolarsResult<&mut Worksheet> {
+        let (workbook, name) = if self.options.overflow_policy == OverflowPolicy::SplitFiles {
+            if self.overflow_workbooks.len() < index {
+                let mut workbook = Workbook::new();
+                workbook.use_zip_large_file(self.use_zip_large_file);
+                self.overflow_workbooks.push(workbook);
+            }
+            (
+                &mut self.overflow_workbooks[index - 1],
+                sheet_name.to_string(),
+            )
+        } else {
+            (&mut self.workbook, format!("{sheet_name}_{}", index + 1))
+        };
+
+        let worksheet = if constant_memory {
+            workbook.add_worksheet_with_constant_memory()
+        } else {
+            workbook.add_worksheet()
+        };
+        worksheet.set_name(name)?;
+
+        Ok(worksheet)
+    }
+
+    // Split the dataframe rows into segments, as (first row, number of rows),
+    // that fit in a worksheet according to the overflow policy.
+    fn row_segments(
+        height: usize,
+        row_offset: u32,
+        options: &WriterOptions,
+    ) -> PolarsResult<Vec<(usize, usize)>> {
+        let header_rows = usize::from(options.table.has_header_row());
+        let total_rows = usize::from(Self::has_total_row(options));
+        let max_rows =
+            EXCEL_MAX_ROWS.saturating_sub(row_offset as usize + header_rows + total_rows);
+
+        if height <= max_rows {
+            return Ok(vec![(0, height)]);
+        }
+
+        match options.overflow_policy {
+            OverflowPolicy::Error => polars_bail!(
+                ComputeError:
+                "dataframe has {} rows which exceeds the {} rows available in the worksheet",
+                height, max_rows
+            ),
+            OverflowPolicy::Truncate => Ok(vec![(0, max_rows)]),
+            OverflowPolicy::SplitWorksheets | OverflowPolicy::SplitFiles => {
+                if max_rows == 0 {
+                    polars_bail!(ComputeError: "no rows available in the worksheet to split dataframe");
+                }
+
+                Ok((0..height)
+                    .step_by(max_rows)
+                    .map(|first_row| (first_row, max_rows.min(height - first_row)))
+                    .collect())
+            }
+        }
+    }
+
+    // Get the cell range of the output table for a dataframe written at the
+    // given cell. This includes the header, any total row and any inserted or
+    // expanded columns. For dataframes split by the overflow policy it is the
+    // range in the first worksheet.
+    fn written_range(
+        sheet_name: &str,
+        df: &DataFrame,
+        row: u32,
+        col: u16,
+        options: &WriterOptions,
+    ) -> PolarsResult<WrittenRange> {
+        let num_cols = Self::output_columns(df, options)?.len();
+        let segments = Self::row_segments(df.height(), row, options)?;
+
+        let num_rows = segments[0].1
+            + usize::from(options.table.has_header_row())
+            + usize::from(Self::has_total_row(options));
+
+        let last_row = row.saturating_add(num_rows.max(1) as u32 - 1);
+        let last_col = col.saturating_add(num_cols.max(1) as u16 - 1);
+
+        Ok(WrittenRange {
+            sheet_name: sheet_name.to_string(),
+            first_row: row,
+            first_col: col,
+            last_row,
+            last_col,
+            table_name: options.output_table_name().map(str::to_string),
+        })
+    }
+
+    // Store the warnings for writer options that are ignored for a dataframe:
+    // column names that aren't in the output columns, column widths that are
+    // larger than the Excel maximum, duplicate table headers that were renamed
+    // and columns with values that are coerced by the value error policy.
+    // Repeated warnings are only stored once.
+    #[allow(clippy::too_many_lines)]
+    fn add_warnings(
+        &mut self,
+        sheet_name: &str,
+        df: &DataFrame,
+        renamed_headers: &[RenamedHeader],
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        let output_columns = Self::output_columns(df, options)?;
+        let column_names: HashSet<&str> = output_columns.iter().map(OutputColumn::name).collect();
+
+        let configured_columns: Vec<(&str, Vec<&String>)> = vec![
+            (
+                "set_column_header()",
+                options.column_headers.keys().collect(),
+            ),
+            (
+                "set_column_header_format()",
+                options.column_header_formats.keys().collect(),
+            ),
+            ("set_table_column()", options.table_columns.keys().collect()),
+            (
+                "set_column_as_hyperlinks()",
+                options.hyperlink_columns.iter().collect(),
+            ),
+            (
+                "set_column_as_images()",
+                options.image_columns.keys().collect(),
+            ),
+            (
+                "set_autofit_exclude()",
+                options.autofit_exclude.iter().collect(),
+            ),
+            (
+                "set_filter()",
+                options
+                    .filter_conditions
+                    .iter()
+                    .map(|(name, _)| name)
+                    .collect(),
+            ),
+            ("set_column_width()", options.column_widths.keys().collect()),
+            (
+                "set_column_hidden()",
+                options.hidden_columns.iter().collect(),
+            ),
+            (
+                "set_merge_repeated()",
+                options.merge_repeated_columns.iter().collect(),
+            ),
+            (
+                "set_column_alignment()",
+                options.column_alignments.keys().collect(),
+            ),
+            (
+                "set_column_format()",
+                options.column_formats.keys().collect(),
+            ),
+            (
+                "set_column_transform()",
+                options.column_transforms.keys().collect(),
+            ),
+            (
+                "set_cell_style_callback()",
+                options.cell_styles.keys().collect(),
+            ),
+            (
+                "set_heatmap()",
+                options
+                    .heatmaps
+                    .iter()
+                    .flat_map(|(names, _)| names)
+                    .collect(),
+            ),
+            (
+                "set_column_validation()",
+                options
+                    .column_validations
+                    .iter()
+                    .map(|(name, _)| name)
+                    .collect(),
+            ),
+        ];
+
+        let mut warnings = vec![];
+        for (method, mut names) in configured_columns {
+            names.sort();
+            names.dedup();
+
+            for name in names {
+                if !column_names.contains(name.as_str()) {
+                    warnings.push(ExportWarning {
+                        kind: WarningKind::UnknownColumn,
+                        sheet_name: sheet_name.to_string(),
+                        column_name: name.clone(),
+                        message: format!(
+                            "column '{name}' set via {method} isn't in the dataframe written to worksheet '{sheet_name}'"
+                        ),
+                    });
+                }
+            }
+        }
+
+        for (pattern, _) in &options.pattern_formats {
+            if !column_names.iter().any(|name| pattern.is_match(name)) {
+                warnings.push(ExportWarning {
+                    kind: WarningKind::UnknownColumn,
+                    sheet_name: sheet_name.to_string(),
+                    column_name: pattern.to_string(),
+                    message: format!(
+                        "pattern '{pattern}' set via set_format_matching() doesn't match any column in the dataframe written to worksheet '{sheet_name}'"
+                    ),
+                });
+            }
+        }
+
+        let mut column_widths: Vec<_> = options.column_widths.iter().collect();
+        column_widths.sort_by_key(|(name, _)| *name);
+
+        for (name, width) in column_widths {
+            let is_clipped = match width {
+                ColumnWidth::Characters(width) => *width > EXCEL_MAX_COLUMN_WIDTH,
+                ColumnWidth::Pixels(width) => *width > EXCEL_MAX_COLUMN_WIDTH_PIXELS,
+            };
+
+            if is_clipped && column_names.contains(name.as_str()) {
+                warnings.push(ExportWarning {
+                    kind: WarningKind::ClippedWidth,
+                    sheet_name: sheet_name.to_string(),
+                    column_name: name.clone(),
+                    message: format!(
+                        "width of column '{name}' is larger than the Excel maximum of 255 characters and is clipped"
+                    ),
+                });
+            }
+        }
+
+        for (name, header, new_header) in renamed_headers {
+            warnings.push(ExportWarning {
+                kind: WarningKind::RenamedHeader,
+                sheet_name: sheet_name.to_string(),
+                column_name: name.clone(),
+                message: format!(
+                    "duplicate table header '{header}' of column '{name}' is renamed to '{new_header}'"
+                ),
+            });
+        }
+
+        if options.value_error_policy == ErrorPolicy::CoerceWithWarning {
+            for column in output_columns.iter().filter_map(OutputColumn::column) {
+                if Self::is_large_int_type(column.dtype()) && options.large_int_handling.is_some() {
+                    continue;
+                }
+
+                if let Some(description) = Self::invalid_values_description(column)? {
+                    let name = column.name();
+                    warnings.push(ExportWarning {
+                        kind: WarningKind::CoercedValue,
+                        sheet_name: sheet_name.to_string(),
+                        column_name: name.to_string(),
+                        message: format!(
+                            "column '{name}' contains {description} which can't be represented exactly in Excel"
+                        ),
+                    });
+                }
+            }
+        }
+
+        for warning in warnings {
+            if !self.warnings.contains(&warning) {
+                self.warnings.push(warning);
+            }
+        }
+
+        Ok(())
+    }
+
+    // Add warnings for the column options that are ignored for constant
+    // memory worksheets, since their rows can't be changed after they are
+    // written.
+    fn add_constant_memory_warnings(
+        &mut self,
+        sheet_name: &str,
+        df: &DataFrame,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        let output_columns = Self::output_columns(df, options)?;
+
+        let mut names: Vec<&String> = options.merge_repeated_columns.iter().collect();
+        names.sort();
+
+        for name in names {
+            if Self::column_position(&output_columns, name).is_none() {
+                continue;
+            }
+
+            let warning = ExportWarning {
+                kind: WarningKind::UnsupportedOption,
+                sheet_name: sheet_name.to_string(),
+                column_name: name.clone(),
+                message: format!(
+                    "set_merge_repeated() isn't supported for constant memory or chunked writes and is ignored for column '{name}'"
+                ),
+            };
+
+            if !self.warnings.contains(&warning) {
+                self.warnings.push(warning);
+            }
+        }
+
+        // Autofit needs the written data, which isn't retained.
+        if options.use_autofit {
+            let warning = ExportWarning {
+                kind: WarningKind::UnsupportedOption,
+                sheet_name: sheet_name.to_string(),
+                column_name: String::new(),
+                message: "set_autofit() isn't supported for constant memory or chunked writes and is ignored"
+                    .to_string(),
+            };
+
+            if !self.warnings.contains(&warning) {
+                self.warnings.push(warning);
+            }
+        }
+
+        Ok(())
+    }
+
+    // Store a table name and check that it hasn't been used for a previous
+    // table. Excel table names are case insensitive.
+    fn add_table_name(&mut self, table_name: &str) -> PolarsResult<()> {
+        if !self.table_names.insert(table_name.to_lowercase()) {
+            polars_bail!(
+                ComputeError:
+                "table name '{}' has already been used in the workbook",
+                table_name
+            );
+        }
+
+        Ok(())
+    }
+
+    // Store the cell range of a dataframe written to a worksheet and check that
+    // it doesn't overlap the range of a previous dataframe in the worksheet.
+    // Otherwise the data is overwritten or, for tables, the file fails to save.
+    // The number of data rows is stored for the table of contents.
+    fn add_written_range(&mut self, range: WrittenRange, num_rows: usize) -> PolarsResult<()> {
+        let ranges = self
+            .written_ranges
+            .entry(range.sheet_name.clone())
+            .or_default();
+
+        if let Some((previous, _)) = ranges.iter().find(|(previous, _)| {
+            range.first_row <= previous.last_row
+                && previous.first_row <= range.last_row
+                && range.first_col <= previous.last_col
+                && previous.first_col <= range.last_col
+        }) {
+            let sheet_name = Self::quote_sheet_name(&range.sheet_name);
+            polars_bail!(
+                ComputeError:
+                "dataframe range {}!{} overlaps the range {}!{} of a previous dataframe",
+                sheet_name,
+                utility::cell_range(range.first_row, range.first_col, range.last_row, range.last_col),
+                sheet_name,
+                utility::cell_range(
+                    previous.first_row,
+                    previous.first_col,
+                    previous.last_row,
+                    previous.last_col
+                )
+            );
+        }
+
+        ranges.push((range, num_rows));
+
+        Ok(())
+    }
+
+    // Check that a table name follows the Excel rules.
+    fn validate_table_name(name: &str) -> PolarsResult<()> {
+        let mut chars = name.chars();
+
+        let Some(first_char) = chars.next() else {
+            polars_bail!(ComputeError: "table name cannot be blank");
+        };
+
+        if name.chars().count() > 255 {
+            polars_bail!(
+                ComputeError:
+                "table name '{}' exceeds Excel's limit of 255 characters",
+                name
+            );
+        }
+
+        if !(first_char.is_alphabetic() || first_char == '_' || first_char == '\\') {
+            polars_bail!(
+                ComputeError:
+                "table name '{}' must start with a letter, underscore or backslash",
+                name
+            );
+        }
+
+        if let Some(invalid_char) =
+            chars.find(|c| !(c.is_alphanumeric() || matches!(c, '_' | '.' | '\\')))
+        {
+            polars_bail!(
+                ComputeError:
+                "table name '{}' contains invalid character '{}'",
+                name, invalid_char
+            );
+        }
+
+        if Self::is_cell_reference(name) {
+            polars_bail!(
+                ComputeError:
+                "table name '{}' cannot be a cell reference",
+                name
+            );
+        }
+
+        Ok(())
+    }
+
+    // Convert an A1 style cell reference, such as "B3" or "$B$3", to a zero
+    // indexed (row, col) pair.
+    fn parse_cell_reference(cell: &str) -> PolarsResult<(u32, u16)> {
+        let reference = cell.to_ascii_uppercase().replace('$', "");
+        let num_letters = reference
+            .chars()
+            .take_while(char::is_ascii_uppercase)
+            .count();
+        let (column, row) = reference.split_at(num_letters);
+
+        if !(1..=3).contains(&num_letters)
+            || row.is_empty()
+            || !row.chars().all(|c| c.is_ascii_digit())
+        {
+            polars_bail!(ComputeError: "invalid cell reference '{}'", cell);
+        }
+
+        let row = row.parse::<u32>().unwrap_or(0);
+        let col = utility::column_name_to_number(column);
+
+        if row == 0 || row as usize > EXCEL_MAX_ROWS || col >= EXCEL_MAX_COLS {
+            polars_bail!(ComputeError: "cell reference '{}' is outside the worksheet", cell);
+        }
+
+        Ok((row - 1, col))
+    }
+
+    // Convert an A1 style cell range, such as "B2:C5" or "B2", to a zero
+    // indexed (first_row, first_col, last_row, last_col) tuple. Targets that
+    // aren't a valid range are treated as unknown column names.
+    fn parse_cell_range(range: &str) -> PolarsResult<(u32, u16, u32, u16)> {
+        let (first, last) = range.split_once(':').unwrap_or((range, range));
+
+        match (
+            Self::parse_cell_reference(first),
+            Self::parse_cell_reference(last),
+        ) {
+            (Ok((row1, col1)), Ok((row2, col2))) => Ok((
+                row1.min(row2),
+                col1.min(col2),
+                row1.max(row2),
+                col1.max(col2),
+            )),
+            _ => polars_bail!(ColumnNotFound: "{}", range),
+        }
+    }
+
+    // Check if a name is an A1 or R1C1 style cell reference, which Excel
+    // doesn't allow as a table name.
+    fn is_cell_reference(name: &str) -> bool {
+        let name = name.to_ascii_uppercase();
+        let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+
+        // A1 style references such as "A1" or "XFD1048576".
+        let num_letters = name.chars().take_while(char::is_ascii_uppercase).count();
+        let row = &name[num_letters..];
+        if (1..=3).contains(&num_letters) && !row.is_empty() && is_digits(row) {
+            return true;
+        }
+
+        // R1C1 style references such as "R", "C", "RC" or "R1C1".
+        if name == "C" || (name.starts_with('C') && is_digits(&name[1..])) {
+            return true;
+        }
+        if let Some(rest) = name.strip_prefix('R') {
+            let (row, col) = rest.split_once('C').unwrap_or((rest, ""));
+            if is_digits(row) && is_digits(col) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    // Get the path for an additional file created by the `SplitFiles`
+    // overflow policy, for example `dataframe.xlsx` -> `dataframe_2.xlsx`.
+    fn overflow_path(path: &Path, file_num: usize) -> PathBuf {
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+
+        let file_name = match path.extension() {
+            Some(extension) => format!("{stem}_{file_num}.{}", extension.to_string_lossy()),
+            None => format!("{stem}_{file_num}"),
+        };
+
+        path.with_file_name(file_name)
+    }
+
+    // Write the dataframe in row order, in chunks of rows. This is required for
+    // constant memory worksheets since they flush each row to disk once a
+    // subsequent row is written. It is also used for `WriteOrder::RowMajor`.
+    #[allow(clippy::too_many_lines)]
+    fn write_dataframe_chunked_internal(
+        df: &DataFrame,
+        worksheet: &mut Worksheet,
+        row_offset: u32,
+        col_offset: u16,
+        chunk_size: usize,
+        constant_memory: bool,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        let header_offset = u32::from(options.table.has_header_row());
+
+        // Set NaN and Infinity values, if required.
+        Self::set_special_float_values(worksheet, options);
+
+        // Get the output table columns, including any inserted columns.
+        let output_columns = Self::output_columns(df, options)?;
+
+        // Set the height of the header row, if required.
+        Self::write_header_row_height(worksheet, &output_columns, row_offset, options)?;
+
+        // Set the default width of date and datetime columns, if required.
+        Self::set_datetime_column_widths(worksheet, &output_columns, col_offset, options)?;
+
+        // Write the column names for use as table headers.
+        if options.table.has_header_row() {
+            for (col_num, output_column) in output_columns.iter().enumerate() {
+                let col_num = col_offset + col_num as u16;
+                Self::write_header(worksheet, row_offset, col_num, output_column, options)?;
+            }
+        }
+
+        // Add the table before the data rows so that the header row is still
+        // available to it.
+        Self::write_table(
+            worksheet,
+            &output_columns,
+            row_offset,
+            col_offset,
+            df.height(),
+            options,
+        )?;
+
+        // Get the source columns for the row total column, if any.
+        let mut row_total = None;
+        for (col_num, output_column) in output_columns.iter().enumerate() {
+            if let OutputColumn::RowTotal(row_total_column) = output_column {
+                row_total = Some(Self::row_total(
+                    &output_columns,
+                    row_total_column,
+                    col_offset + col_num as u16,
+                    col_offset,
+                    options,
+                )?);
+            }
+        }
+
+        // Check for values that can't be represented in Excel.
+        for output_column in &output_columns {
+            if let Some(column) = output_column.column() {
+                Self::check_column_values(column, options)?;
+            }
+        }
+
+        // Get the user defined format for the data of each column, and the
+        // formats for banded rows, if any.
+        let column_formats: Vec<_> = output_columns
+            .iter()
+            .map(|output_column| output_column.column_format(options))
+            .collect();
+        let banded_formats: Vec<_> = output_columns
+            .iter()
+            .map(|output_column| output_column.banded_formats(options))
+            .collect();
+
+        // Write the data rows for each chunk.
+        let mut chunk_offset = 0;
+        while chunk_offset < df.height() {
+            let chunk_rows = chunk_size.min(df.height() - chunk_offset);
+            let chunk = df.slice_rows(chunk_offset, chunk_rows);
+
+            // Get the chunk data for each output column as a contiguous series
+            // so that it can be iterated over by row.
+            let mut chunk_columns = vec![];
+            for output_column in &output_columns {
+                let series = match output_column.column() {
+                    Some(column) => Some(chunk.contiguous_series(column.column_name())?),
+                    None => None,
+                };
+                chunk_columns.push(series);
+            }
+
+            // Get the url data for any hyperlink columns.
+            let mut chunk_urls = vec![];
+            for output_column in &output_columns {
+                let series = match Self::hyperlink_url_column(df, output_column, options)? {
+                    Some(url_column) => Some(chunk.contiguous_series(url_column.column_name())?),
+                    None => None,
+                };
+                chunk_urls.push(series);
+            }
+
+            let mut chunk_values: Vec<_> = chunk_columns
+                .iter()
+                .map(|series| series.as_ref().map(Series::iter))
+                .collect();
+
+            let mut url_values: Vec<_> = chunk_urls
+                .iter()
+                .map(|series| series.as_ref().map(Series::iter))
+                .collect();
+
+            for row_num in 0..chunk_rows {
+                let row_index = chunk_offset + row_num;
+                let row_num = header_offset + row_offset + row_index as u32;
+
+                for (col_num, output_column) in output_columns.iter().enumerate() {
+                    let Some(Some(data)) = chunk_values[col_num].as_mut().map(Iterator::next)
+                    else {
+                        continue;
+                    };
+
+                    let col = col_offset + col_num as u16;
+                    let format = Self::row_format(
+                        column_formats[col_num].as_deref(),
+                        banded_formats[col_num].as_ref(),
+                        row_index,
+                    );
+
+                    if let Some(Some(url)) = url_values[col_num].as_mut().map(Iterator::next) {
+                        Self::write_hyperlink(worksheet, row_num, col, url, data, format, options)?;
+                        continue;
+                    }
+
+                    if Self::image_column_options(output_column, options).is_some() {
+                        Self::write_image(worksheet, row_num, col, data, format, options)?;
+                        continue;
+                    }
+
+                    if Self::write_banded_null(
+                        worksheet,
+                        row_num,
+                        col,
+                        output_column,
+                        &data,
+                        format,
+                        options,
+                    )? {
+                        continue;
+                    }
+
+                    Self::write_output_value(
+                        worksheet,
+                        row_num,
+                        col,
+                        output_column,
+                        data,
+                        format,
+                        options,
+                    )?;
+                }
+
+                if let Some(row_total) = &row_total {
+                    Self::write_row_total(worksheet, row_num, row_total)?;
+                }
+            }
+
+            chunk_offset += chunk_rows;
+        }
+
+        // Add any notes to the column cells. Notes aren't written with the
+        // row data so they can be added after the rows are flushed.
+        for (col_num, output_column) in output_columns.iter().enumerate() {
+            Self::write_column_notes(
+                worksheet,
+                df,
+                output_column,
+                header_offset + row_offset,
+                col_offset + col_num as u16,
+                options,
+            )?;
+        }
+
+        // Autofit the columns. This isn't supported for constant memory
+        // worksheets since they don't retain the written data.
+        if !constant_memory {
+            Self::autofit_columns(worksheet, &output_columns, col_offset, options)?;
+        }
+
+        // Set any user defined column properties. These override autofit.
+        Self::set_column_properties(worksheet, &output_columns, col_offset, options)?;
+
+        // Set the worksheet level options.
+        Self::set_worksheet_options(worksheet, options)?;
+
+        // Set the page setup options.
+        Self::set_page_setup(
+            worksheet,
+            row_offset,
+            col_offset,
+            df.height(),
+            output_columns.len(),
+            options,
+        )?;
+
+        Ok(())
+    }
+
+    // Add the worksheet table for the output columns, or an autofilter for a
+    // plain range, along with any conditional formats and sparklines.
+    #[allow(clippy::too_many_lines)]
+    fn write_table(
+        worksheet: &mut Worksheet,
+        output_columns: &[OutputColumn],
+        row_offset: u32,
+        col_offset: u16,
+        num_rows: usize,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        let header_offset = u32::from(options.table.has_header_row());
+
+        // There is nothing to add for a range without any columns.
+        if output_columns.is_empty() {
+            return Ok(());
+        }
+
+        // Add the table, or the autofilter for a plain range with a header.
+        if options.use_table {
+            Self::add_worksheet_table(
+                worksheet,
+                output_columns,
+                row_offset,
+                col_offset,
+                num_rows,
+                options,
+            )?;
+        } else if options.table.has_header_row()
+            && (options.use_autofilter || !options.filter_conditions.is_empty())
+        {
+            Self::add_worksheet_autofilter(
+                worksheet,
+                output_columns,
+                row_offset,
+                col_offset,
+                num_rows,
+                options,
+            )?;
+        }
+
+        // Add any conditional formats to the data range of the named columns
+        // or to the user supplied cell ranges.
+        for (target, conditional_format) in &options.conditional_formats {
+            match Self::column_position(output_columns, target) {
+                Some(col_num) if num_rows > 0 => {
+                    let first_row = row_offset + header_offset;
+                    let last_row = first_row + num_rows as u32 - 1;
+                    let col_num = col_offset + col_num as u16;
+                    conditional_format
+                        .add_to_worksheet(worksheet, first_row, col_num, last_row, col_num)?;
+                }
+                Some(_) => {}
+                None => {
+                    let (first_row, first_col, last_row, last_col) =
+                        Self::parse_cell_range(target)?;
+                    conditional_format
+                        .add_to_worksheet(worksheet, first_row, first_col, last_row, last_col)?;
+                }
+            }
+        }
+
+        if num_rows > 0 {
+            let first_row = row_offset + header_offset;
+            let last_row = first_row + num_rows as u32 - 1;
+
+            // Add any heatmaps across the data range of several columns.
+            for (column_names, color_scale) in &options.heatmaps {
+                let mut cols: Vec<u16> = column_names
+                    .iter()
+                    .filter_map(|name| Self::column_position(output_columns, name))
+                    .map(|col_num| col_offset + col_num as u16)
+                    .collect();
+                cols.sort_unstable();
+                cols.dedup();
+
+                Self::add_heatmap(worksheet, first_row, last_row, &cols, color_scale)?;
+            }
+
+            // Add any data validations to the data range of the named columns.
+            for (column_name, validation) in &options.column_validations {
+                if let Some(col_num) = Self::column_position(output_columns, column_name) {
+                    let validation = match validation {
+                        ColumnValidation::Rule(validation) => validation.clone(),
+                        ColumnValidation::Categories => {
+                            Self::category_validation(&output_columns[col_num])?
+                        }
+                    };
+
+                    let col_num = col_offset + col_num as u16;
+                    worksheet.add_data_validation(
+                        first_row,
+                        col_num,
+                        last_row,
+                        col_num,
+                        &validation,
+                    )?;
+                }
+            }
+        }
+
+        // Add the sparklines for any sparkline columns. Each sparkline plots
+        // the data in the source columns of the same row.
+        for (col_num, output_column) in output_columns.iter().enumerate() {
+            let OutputColumn::Sparkline(sparkline_column) = output_column else {
+                continue;
+            };
+
+            let col_num = col_offset + col_num as u16;
+            let mut first_col = u16::MAX;
+            let mut last_col = 0;
+
+            for column_name in &sparkline_column.source_columns {
+                let Some(source_col) = Self::column_position(output_columns, column_name) else {
+                    polars_bail!(
+                        ColumnNotFound:
+                        "sparkline source column '{}' not found in dataframe",
+                        column_name
+                    );
+                };
+
+                let source_col = col_offset + source_col as u16;
+                first_col = first_col.min(source_col);
+                last_col = last_col.max(source_col);
+            }
+
+            let sheet_name = worksheet.name();
+            for row_num in 0..num_rows as u32 {
+                let row_num = header_offset + row_offset + row_num;
+                let sparkline = sparkline_column.sparkline.clone().set_range((
+                    sheet_name.as_str(),
+                    row_num,
+                    first_col,
+                    row_num,
+                    last_col,
+                ));
+
+                worksheet.add_sparkline(row_num, col_num, &sparkline)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Create a list data validation from the categories of a Categorical or
+    // Enum output column.
+    fn category_validation(output_column: &OutputColumn) -> PolarsResult<DataValidation> {
+        let categorical = match output_column {
+            OutputColumn::Data(column) => column.categorical().ok(),
+            _ => None,
+        };
+
+        let Some(categorical) = categorical else {
+            polars_bail!(
+                ComputeError:
+                "dropdown column '{}' isn't a Categorical or Enum column",
+                output_column.name()
+            );
+        };
+
+        let categories: Vec<&str> = categorical
+            .get_rev_map()
+            .get_categories()
+            .values_iter()
+            .collect();
+
+        Ok(DataValidation::new().allow_list_strings(&categories)?)
+    }
+
+    // Set the worksheet NaN and Infinity values, if required.
+    fn set_special_float_values(worksheet: &mut Worksheet, options: &WriterOptions) {
+        if let NanHandling::String(value) = &options.nan_handling {
+            worksheet
+                .set_nan_value(value)
+                .set_infinity_value(value)
+                .set_neg_infinity_value(value);
+            return;
+        }
+
+        if let Some(nan_value) = &options.nan_value {
+            worksheet.set_nan_value(nan_value);
+        }
+        if let Some(infinity_value) = &options.infinity_value {
+            worksheet.set_infinity_value(infinity_value);
+        }
+        if let Some(neg_infinity_value) = &options.neg_infinity_value {
+            worksheet.set_neg_infinity_value(neg_infinity_value);
+        }
+    }
+
+    // Set the user defined properties, such as the width, of the named output
+    // columns. Names that aren't in the output columns are ignored.
+    fn set_column_properties(
+        worksheet: &mut Worksheet,
+        output_columns: &[OutputColumn],
+        col_offset: u16,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        // Set the width of image columns. User defined widths take precedence.
+        for (column_name, image_options) in &options.image_columns {
+            if options.column_widths.contains_key(column_name) {
+                continue;
+            }
+
+            if let (Some(col_num), Some(width)) = (
+                Self::column_position(output_columns, column_name),
+                image_options.column_width,
+            ) {
+                worksheet.set_column_width_pixels(col_offset + col_num as u16, width)?;
+            }
+        }
+
+        for (column_name, width) in &options.column_widths {
+            if let Some(col_num) = Self::column_position(output_columns, column_name) {
+                let col_num = col_offset + col_num as u16;
+
+                match width {
+                    ColumnWidth::Characters(width) => {
+                        worksheet.set_column_width(col_num, *width)?;
+                    }
+                    ColumnWidth::Pixels(width) => {
+                        worksheet.set_column_width_pixels(col_num, *width)?;
+                    }
+                }
+            }
+        }
+
+        for column_name in &options.hidden_columns {
+            if let Some(col_num) = Self::column_position(output_columns, column_name) {
+                worksheet.set_column_hidden(col_offset + col_num as u16)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Set the heights of the data rows, if required. The heights are set
+    // before the data is written so that they also apply in constant memory
+    // mode. The row indices are relative to the full dataframe, which may be
+    // split over several worksheets.
+    fn write_data_row_heights(
+        worksheet: &mut Worksheet,
+        df: &DataFrame,
+        first_df_row: usize,
+        num_rows: usize,
+        row_offset: u32,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        let first_row = row_offset + u32::from(options.table.has_header_row());
+
+        // Use the largest of the image column heights and the user defined
+        // height for all rows.
+        let image_height = options
+            .image_columns
+            .iter()
+            .filter(|(column_name, _)| df.get_column_index(column_name).is_some())
+            .filter_map(|(_, image_options)| image_options.row_height)
+            .max();
+
+        if let Some(height) = image_height.max(options.row_height_all) {
+            for row_num in 0..num_rows as u32 {
+                worksheet.set_row_height_pixels(first_row + row_num, height)?;
+            }
+        }
+
+        for (index, height) in &options.row_heights {
+            if (first_df_row..first_df_row + num_rows).contains(index) {
+                let row_num = (index - first_df_row) as u32;
+                worksheet.set_row_height_pixels(first_row + row_num, *height)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Set the height of the header row. This is the user defined height, if
+    // any, or a height calculated from the header strings for header formats
+    // with rotated or wrapped text.
+    fn write_header_row_height(
+        worksheet: &mut Worksheet,
+        output_columns: &[OutputColumn],
+        row_offset: u32,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        // The default Excel row height and column width in pixels.
+        const ROW_HEIGHT: f64 = 20.0;
+        const COLUMN_WIDTH: f64 = 64.0;
+
+        if !options.table.has_header_row() {
+            return Ok(());
+        }
+
+        if let Some(height) = options.header_row_height {
+            worksheet.set_row_height_pixels(row_offset, height)?;
+            return Ok(());
+        }
+
+        let Some(format) = &options.header_format else {
+            return Ok(());
+        };
+
+        let headers: Vec<&str> = output_columns
+            .iter()
+            .map(|output_column| output_column.header(options))
+            .collect();
+
+        let max_width = headers
+            .iter()
+            .flat_map(|header| header.lines())
+            .map(|line| f64::from(utility::cell_autofit_width(line)))
+            .fold(0.0, f64::max);
+
+        // The format properties aren't public so the rotation is the value
+        // stored by set_header_rotation(). A rotation set in the format is
+        // ignored.
+        let rotation = options.header_rotation.filter(|rotation| *rotation != 0);
+
+        let height = match rotation {
+            // Stacked text, with one character per line.
+            Some(270) => {
+                let max_chars = headers
+                    .iter()
+                    .map(|header| header.chars().count() as u32)
+                    .max()
+                    .unwrap_or(0);
+
+                ROW_HEIGHT * f64::from(max_chars)
+            }
+            Some(rotation) => {
+                let angle = f64::from(rotation).abs().to_radians();
+                max_width * angle.sin() + ROW_HEIGHT * angle.cos()
+            }
+            None if *format == format.clone().set_text_wrap() => {
+                // Get the number of lines of the longest wrapped header.
+                let max_lines = output_columns
+                    .iter()
+                    .zip(&headers)
+                    .map(|(output_column, header)| {
+                        let column_width = match options.column_widths.get(output_column.name()) {
+                            Some(ColumnWidth::Characters(width)) => width * 7.0 + 5.0,
+                            Some(ColumnWidth::Pixels(width)) => f64::from(*width),
+                            None if options.use_autofit
+                                && !options.autofit_exclude.contains(output_column.name()) =>
+                            {
+                                f64::MAX
+                            }
+                            None => COLUMN_WIDTH,
+                        };
+
+                        header
+                            .lines()
+                            .map(|line| {
+                                let width = f64::from(utility::cell_autofit_width(line));
+                                (width / column_width).ceil().max(1.0)
+                            })
+                            .sum::<f64>()
+                    })
+                    .fold(1.0, f64::max);
+
+                ROW_HEIGHT * max_lines
+            }
+            None => return Ok(()),
+        };
+
+        // Allow truncation of the approximate height in pixels.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let height = height.ceil().min(f64::from(u16::MAX)) as u16;
+
+        if height > ROW_HEIGHT as u16 {
+            worksheet.set_row_height_pixels(row_offset, height)?;
+        }
+
+        Ok(())
+    }
+
+    // Set the worksheet level options such as zoom and freeze panes.
+    fn set_worksheet_options(
+        worksheet: &mut Worksheet,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        // Set the zoom level.
+        worksheet.set_zoom(options.zoom);
+
+        // Set the screen gridlines.
+        worksheet.set_screen_gridlines(options.screen_gridlines);
+
+        // Display the worksheet from right to left, if required.
+        if options.right_to_left {
+            worksheet.set_right_to_left(true);
+        }
+
+        // Set the worksheet tab color.
+        if let Some(color) = options.tab_color {
+            worksheet.set_tab_color(color);
+        }
+
+        // Hide the worksheet, if required.
+        if options.sheet_hidden {
+            worksheet.set_hidden(true);
+        }
+
+        // Set the worksheet panes.
+        worksheet.set_freeze_panes(options.freeze_cell.0, options.freeze_cell.1)?;
+        worksheet.set_freeze_panes_top_cell(options.top_cell.0, options.top_cell.1)?;
+
+        // Set the selected cell.
+        if let Some((row, col)) = options.selection {
+            worksheet.set_selection(row, col, row, col)?;
+        }
+
+        // Set the worksheet protection.
+        if options.worksheet_protected {
+            match &options.worksheet_password {
+                Some(password) => worksheet.protect_with_password(password),
+                None => worksheet.protect(),
+            };
+        }
+
+        Ok(())
+    }
+
+    // Set the page setup options for printing the worksheet. The print area and
+    // repeated rows are relative to the output table.
+    fn set_page_setup(
+        worksheet: &mut Worksheet,
+        row_offset: u32,
+        col_offset: u16,
+        num_rows: usize,
+        num_cols: usize,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        if options.landscape {
+            worksheet.set_landscape();
+        }
+
+        if options.paper_size != 0 {
+            worksheet.set_paper_size(options.paper_size);
+        }
+
+        if let Some((width, height)) = options.fit_to_pages {
+            worksheet.set_print_fit_to_pages(width, height);
+        }
+
+        if let Some(header) = &options.print_header {
+            worksheet.set_header(header);
+        }
+
+        if let Some(footer) = &options.print_footer {
+            worksheet.set_footer(footer);
+        }
+
+        let has_header = options.table.has_header_row();
+        if options.repeat_header_row && has_header {
+            worksheet.set_repeat_rows(row_offset, row_offset)?;
+        }
+
+        if options.print_area_to_table {
+            let num_rows =
+                num_rows + usize::from(has_header) + usize::from(Self::has_total_row(options));
+            let last_row = row_offset + num_rows.max(1) as u32 - 1;
+            let last_col = col_offset + num_cols.max(1) as u16 - 1;
+
+            worksheet.set_print_area(row_offset, col_offset, last_row, last_col)?;
+        }
+
+        Ok(())
+    }
+
+    // Split the output column headers into group and leaf names for a grouped
+    // header, if required. The leaf names are set as the column headers and
+    // the group names are returned. The table is turned off if the leaf names
+    // aren't unique since Excel table headers must be unique.
+    fn split_headers(
+        df: &DataFrame,
+        options: &mut WriterOptions,
+    ) -> PolarsResult<Option<Vec<String>>> {
+        let Some(delimiter) = options.header_split.clone() else {
+            return Ok(None);
+        };
+
+        if delimiter.is_empty() || !options.table.has_header_row() {
+            return Ok(None);
+        }
+
+        let mut groups = vec![];
+        let mut leaf_headers = vec![];
+
+        for output_column in Self::output_columns(df, options)? {
+            let header = output_column.header(options);
+            let (group, leaf) = header.split_once(&delimiter).unwrap_or(("", header));
+
+            groups.push(group.to_string());
+            leaf_headers.push((output_column.name().to_string(), leaf.to_string()));
+        }
+
+        // Keep the original layout if none of the headers are split.
+        if groups.iter().all(String::is_empty) {
+            return Ok(None);
+        }
+
+        let mut unique_leaves = HashSet::new();
+        if !leaf_headers
+            .iter()
+            .all(|(_, leaf)| unique_leaves.insert(leaf.to_lowercase()))
+        {
+            options.use_table = false;
+        }
+
+        options.column_headers.extend(leaf_headers);
+
+        Ok(Some(groups))
+    }
+
+    // Rename any duplicate table headers according to the duplicate header
+    // policy, since Excel table headers must be unique, ignoring case. The new
+    // headers are set as the column headers.
+    fn set_unique_headers(
+        df: &DataFrame,
+        options: &mut WriterOptions,
+    ) -> PolarsResult<Vec<RenamedHeader>> {
+        if !options.use_table || !options.table.has_header_row() {
+            return Ok(vec![]);
+        }
+
+        let headers: Vec<(String, String)> = Self::output_columns(df, options)?
+            .iter()
+            .map(|output_column| {
+                (
+                    output_column.name().to_string(),
+                    output_column.header(options).to_string(),
+                )
+            })
+            .collect();
+
+        // The renamed headers must not match any of the original headers.
+        let mut used_headers: HashSet<String> = headers
+            .iter()
+            .map(|(_, header)| header.to_lowercase())
+            .collect();
+        let mut seen_headers: HashMap<String, &str> = HashMap::new();
+        let mut new_headers = vec![];
+
+        for (column_name, header) in &headers {
+            let Some(previous_column) = seen_headers.get(&header.to_lowercase()) else {
+                seen_headers.insert(header.to_lowercase(), column_name);
+                continue;
+            };
+
+            let suffix = match &options.duplicate_header_policy {
+                DuplicateHeaderPolicy::Error => polars_bail!(
+                    Duplicate:
+                    "columns '{}' and '{}' have the same table header '{}'. Excel table headers must be unique, ignoring case",
+                    previous_column, column_name, header
+                ),
+                DuplicateHeaderPolicy::Rename => "",
+                DuplicateHeaderPolicy::Suffix(suffix) => suffix.as_str(),
+            };
+
+            let mut number = 2;
+            let mut new_header = format!("{header}{suffix}{number}");
+            while used_headers.contains(&new_header.to_lowercase()) {
+                number += 1;
+                new_header = format!("{header}{suffix}{number}");
+            }
+
+            used_headers.insert(new_header.to_lowercase());
+            new_headers.push((column_name.clone(), header.clone(), new_header));
+        }
+
+        options.column_headers.extend(
+            new_headers
+                .iter()
+                .map(|(column_name, _, new_header)| (column_name.clone(), new_header.clone())),
+        );
+
+        Ok(new_headers)
+    }
+
+    // Write the group names of a grouped header. Adjacent columns in the same
+    // group are merged.
+    fn write_header_groups(
+        worksheet: &mut Worksheet,
+        row: u32,
+        col_offset: u16,
+        groups: &[String],
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        let format = options
+            .header_format
+            .clone()
+            .unwrap_or_else(|| Format::new().set_bold())
+            .set_align(FormatAlign::Center);
+
+        let mut first_col = col_offset;
+        for run in groups.chunk_by(|a, b| a == b) {
+            let last_col = first_col + run.len() as u16 - 1;
+            let group = run[0].as_str();
+
+            if !group.is_empty() {
+                if first_col == last_col {
+                    worksheet.write_string_with_format(row, first_col, group, &format)?;
+                } else {
+                    worksheet.merge_range(row, first_col, row, last_col, group, &format)?;
+                }
+            }
+
+            first_col = last_col + 1;
+        }
+
+        Ok(())
+    }
+
+    // Turn off the table if there are columns with merged repeated values,
+    // since Excel tables can't contain merged cells.
+    fn set_merge_range_mode(df: &DataFrame, options: &mut WriterOptions) {
+        if options
+            .merge_repeated_columns
+            .iter()
+            .any(|column_name| df.column(column_name).is_ok())
+        {
+            options.use_table = false;
+        }
+    }
+
+    // Turn off the table for a dataframe without any rows or columns, since an
+    // Excel table needs at least one data row and one column. A dataframe with
+    // columns but no rows is written as a header row only, if there is one.
+    fn set_empty_dataframe_mode(df: &DataFrame, options: &mut WriterOptions) {
+        if df.height() == 0 || df.width() == 0 {
+            options.use_table = false;
+        }
+    }
+
+    // Merge the vertically adjacent cells with the same value in the columns
+    // set via `set_merge_repeated()`. The value is rewritten to the first cell
+    // of each merged range since `merge_range()` only writes strings.
+    fn merge_repeated_values(
+        worksheet: &mut Worksheet,
+        df: &DataFrame,
+        row_offset: u32,
+        col_offset: u16,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        if options.merge_repeated_columns.is_empty() {
+            return Ok(());
+        }
+
+        let first_row = row_offset + u32::from(options.table.has_header_row());
+
+        for (col_num, output_column) in Self::output_columns(df, options)?.iter().enumerate() {
+            let OutputColumn::Data(column) = output_column else {
+                continue;
+            };
+
+            if !options
+                .merge_repeated_columns
+                .contains(output_column.name())
+            {
+                continue;
+            }
+
+            let col = col_offset + col_num as u16;
+            let format = output_column
+                .column_format(options)
+                .map(Cow::into_owned)
+                .unwrap_or_default();
+
+            let mut start = 0;
+            for end in 1..=column.len() {
+                let value = column.get(start)?;
+
+                if end < column.len() && !value.is_null() && column.get(end)? == value {
+                    continue;
+                }
+
+                if end - start > 1 {
+                    let first = first_row + start as u32;
+                    let last = first_row + end as u32 - 1;
+
+                    worksheet.merge_range(first, col, last, col, "", &format)?;
+                    Self::write_output_value(
+                        worksheet,
+                        first,
+                        col,
+                        output_column,
+                        value,
+                        Some(&format),
+                        options,
+                    )?;
+                }
+
+                start = end;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Add a color scale conditional format across the data rows of a set of
+    // sorted columns. Non adjacent columns are added as a multi range.
+    fn add_heatmap(
+        worksheet: &mut Worksheet,
+        first_row: u32,
+        last_row: u32,
+        cols: &[u16],
+        color_scale: &ColorScale,
+    ) -> PolarsResult<()> {
+        let ranges: Vec<(u16, u16)> = cols
+            .chunk_by(|a, b| a + 1 == *b)
+            .map(|run| (run[0], run[run.len() - 1]))
+            .collect();
+
+        let Some(&(first_col, last_col)) = ranges.first() else {
+            return Ok(());
+        };
+
+        let multi_range = ranges
+            .iter()
+            .map(|&(first, last)| utility::cell_range(first_row, first, last_row, last))
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        match color_scale {
+            ColorScale::TwoColor(color_scale) => worksheet.add_conditional_format(
+                first_row,
+                first_col,
+                last_row,
+                last_col,
+                &color_scale.clone().set_multi_range(multi_range),
+            )?,
+            ColorScale::ThreeColor(color_scale) => worksheet.add_conditional_format(
+                first_row,
+                first_col,
+                last_row,
+                last_col,
+                &color_scale.clone().set_multi_range(multi_range),
+            )?,
+        };
+
+        Ok(())
+    }
+
+    // Write the header for an output column. The header format is applied by
+    // the table, if any, otherwise it is applied to the cell.
+    fn write_header(
+        worksheet: &mut Worksheet,
+        row: u32,
+        col: u16,
+        output_column: &OutputColumn,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        let header = output_column.header(options);
+
+        match Self::header_format(output_column, options) {
+            Some(format) if !options.use_table => {
+                worksheet.write_with_format(row, col, header, format)?;
+            }
+            _ => {
+                worksheet.write(row, col, header)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Get the header format for an output column. A format set for the column
+    // takes precedence over the format for the header row.
+    pub(crate) fn header_format<'a>(
+        output_column: &OutputColumn,
+        options: &'a WriterOptions,
+    ) -> Option<&'a Format> {
+        options
+            .column_header_formats
+            .get(output_column.name())
+            .or(options.header_format.as_ref())
+    }
+
+    // Write a dataframe value for an output column. For expanded list columns
+    // this is the list element at the column index.
+    fn write_output_value(
+        worksheet: &mut impl CellSink,
+        row: u32,
+        col: u16,
+        output_column: &OutputColumn,
+        data: AnyValue,
+        dtype_format: Option<&Format>,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        match output_column {
+            OutputColumn::Data(column) => {
+                let cell_format = Self::cell_style(output_column, column, &data, options);
+                let format = cell_format.as_ref().or(dtype_format);
+                let data = Self::transform_value(column, data, options);
+                Self::write_any_value(worksheet, row, col, data, format, options)?;
+            }
+            OutputColumn::ListElement(column, index, _) => {
+                if let AnyValue::List(series) = data {
+                    if *index < series.len() {
+                        let data = series.get(*index)?;
+                        let cell_format = Self::cell_style(output_column, column, &data, options);
+                        let format = cell_format.as_ref().or(dtype_format);
+                        let data = Self::transform_value(column, data, options);
+                        Self::write_any_value(worksheet, row, col, data, format, options)?;
+                    }
+                }
+            }
+            OutputColumn::Sparkline(_) | OutputColumn::Formula(_) | OutputColumn::RowTotal(_) => {}
+        }
+
+        Ok(())
+    }
+
+    // Apply the value transformation for a column, if any.
+    fn transform_value<'a>(
+        column: &Column,
+        data: AnyValue<'a>,
+        options: &WriterOptions,
+    ) -> AnyValue<'a> {
+        match options.column_transforms.get(column.column_name()) {
+            Some(transform) => transform(data),
+            None => data,
+        }
+    }
+
+    // Get the format computed by the cell style function for a column value,
+    // if any. Formats without a number format get the number format of the
+    // column.
+    fn cell_style(
+        output_column: &OutputColumn,
+        column: &Column,
+        data: &AnyValue,
+        options: &WriterOptions,
+    ) -> Option<Format> {
+        let cell_style = options.cell_styles.get(column.column_name())?;
+        let style = cell_style(data)?;
+
+        if Self::has_num_format(&style) {
+            return Some(style);
+        }
+
+        match output_column.num_format(options) {
+            Some(num_format) if !num_format.is_empty() => Some(style.set_num_format(num_format)),
+            _ => Some(style),
+        }
+    }
+
+    // Get the dataframe column that contains the urls for a hyperlink output
+    // column. This is the column itself or, for a hyperlink pair, the url
+    // column. Returns None if the output column isn't a hyperlink column.
+    fn hyperlink_url_column<'a>(
+        df: &'a DataFrame,
+        output_column: &OutputColumn<'a>,
+        options: &WriterOptions,
+    ) -> PolarsResult<Option<&'a Column>> {
+        let OutputColumn::Data(column) = output_column else {
+            return Ok(None);
+        };
+
+        if let Some(url_column_name) = options.hyperlink_pairs.get(column.column_name()) {
+            return Ok(Some(df.column(url_column_name)?));
+        }
+
+        if options.hyperlink_columns.contains(column.column_name()) {
+            return Ok(Some(column));
+        }
+
+        Ok(None)
+    }
+
+    // Check if a dataframe column is used as the notes for another column in
+    // the dataframe, in which case it isn't written to the worksheet.
+    fn is_notes_column(df: &DataFrame, column: &Column, options: &WriterOptions) -> bool {
+        options
+            .column_notes
+            .iter()
+            .any(|(data_column, notes_column)| {
+                notes_column == column.column_name()
+                    && data_column != notes_column
+                    && df.get_column_index(data_column).is_some()
+            })
+    }
+
+    // Add the values of a notes column as worksheet notes on the cells of an
+    // output column. Null and empty values don't add a note.
+    fn write_column_notes(
+        worksheet: &mut Worksheet,
+        df: &DataFrame,
+        output_column: &OutputColumn,
+        first_row: u32,
+        col: u16,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        let OutputColumn::Data(column) = output_column else {
+            return Ok(());
+        };
+
+        let Some(notes_column_name) = options.column_notes.get(column.column_name()) else {
+            return Ok(());
+        };
+
+        let notes = df.contiguous_series(notes_column_name)?;
+
+        for (row_num, value) in notes.iter().enumerate() {
+            let text = match value {
+                AnyValue::Null => continue,
+                value => value
+                    .get_str()
+                    .map_or_else(|| value.to_string(), str::to_string),
+            };
+
+            if text.is_empty() {
+                continue;
+            }
+
+            let note = Note::new(text);
+            worksheet.insert_note(first_row + row_num as u32, col, &note)?;
+        }
+
+        Ok(())
+    }
+
+    // Write a url to a worksheet cell with the display text from the column
+    // value. Null urls are written as a standard cell value.
+    fn write_hyperlink(
+        worksheet: &mut Worksheet,
+        row: u32,
+        col: u16,
+        url: AnyValue,
+        text: AnyValue,
+        dtype_format: Option<&Format>,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        let url = match url.get_str() {
+            Some(url) if !url.is_empty() => url.to_string(),
+            _ => return Self::write_any_value(worksheet, row, col, text, dtype_format, options),
+        };
+
+        let text = match &text {
+            AnyValue::Null => url.clone(),
+            text => text
+                .get_str()
+                .map_or_else(|| text.to_string(), ToString::to_string),
+        };
+
+        let mut link = Url::new(url.as_str());
+        if text != url {
+            link = link.set_text(text);
+        }
+
+        match dtype_format {
+            Some(format) => worksheet.write_url_with_format(row, col, link, format)?,
+            None => worksheet.write_url(row, col, link)?,
+        };
+
+        Ok(())
+    }
+
+    // Get the image options for an output column. Returns None if the output
+    // column isn't an image column.
+    fn image_column_options<'b>(
+        output_column: &OutputColumn,
+        options: &'b WriterOptions,
+    ) -> Option<&'b ImageOptions> {
+        let OutputColumn::Data(column) = output_column else {
+            return None;
+        };
+
+        options.image_columns.get(column.column_name())
+    }
+
+    // Write an image file path or image data to a worksheet cell as an
+    // embedded image. Null and empty values are written as standard cells.
+    fn write_image(
+        worksheet: &mut Worksheet,
+        row: u32,
+        col: u16,
+        data: AnyValue,
+        dtype_format: Option<&Format>,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        let image = match &data {
+            AnyValue::String(path) if !path.is_empty() => Image::new(path)?,
+            AnyValue::StringOwned(path) if !path.is_empty() => Image::new(path.as_str())?,
+            AnyValue::Binary(buffer) if !buffer.is_empty() => Image::new_from_buffer(buffer)?,
+            AnyValue::BinaryOwned(buffer) if !buffer.is_empty() => Image::new_from_buffer(buffer)?,
+            AnyValue::Binary(_) | AnyValue::BinaryOwned(_) => return Ok(()),
+            _ => return Self::write_any_value(worksheet, row, col, data, dtype_format, options),
+        };
+
+        match dtype_format {
+            Some(format) => worksheet.embed_image_with_format(row, col, &image, format)?,
+            None => worksheet.embed_image(row, col, &image)?,
+        };
+
+        Ok(())
+    }
+
+    // Write a numeric column directly from its Arrow chunks using the typed
+    // values, which avoids converting each value to an AnyValue. Returns false
+    // if the column isn't a numeric type.
+    #[allow(clippy::too_many_lines)]
+    fn write_numeric_column(
+        worksheet: &mut impl CellSink,
+        first_row: u32,
+        col: u16,
+        series: &Series,
+        dtype_format: Option<&Format>,
+        options: &WriterOptions,
+    ) -> PolarsResult<bool> {
+        let float_format = Some(dtype_format.unwrap_or(&options.float_format.format));
+
+        // Allow u64 conversion within Excel's limits.
+        #[allow(clippy::cast_precision_loss)]
+        match series.dtype() {
+            // Floats with a scientific threshold need a format for each value.
+            DataType::Float32 | DataType::Float64 if options.float_scientific.is_some() => {
+                return Ok(false);
+            }
+            DataType::Int8 => {
+                let ca = series.i8()?;
+                Self::write_numeric_chunks(
+                    worksheet,
+                    first_row,
+                    col,
+                    ca,
+                    f64::from,
+                    dtype_format,
+                    options,
+                )?;
+            }
+            DataType::UInt8 => {
+                let ca = series.u8()?;
+                Self::write_numeric_chunks(
+                    worksheet,
+                    first_row,
+                    col,
+                    ca,
+                    f64::from,
+                    dtype_format,
+                    options,
+                )?;
+            }
+            DataType::Int16 => {
+                let ca = series.i16()?;
+                Self::write_numeric_chunks(
+                    worksheet,
+                    first_row,
+                    col,
+                    ca,
+                    f64::from,
+                    dtype_format,
+                    options,
+                )?;
+            }
+            DataType::UInt16 => {
+                let ca = series.u16()?;
+                Self::write_numeric_chunks(
+                    worksheet,
+                    first_row,
+                    col,
+                    ca,
+                    f64::from,
+                    dtype_format,
+                    options,
+                )?;
+            }
+            DataType::Int32 => {
+                let ca = series.i32()?;
+                Self::write_numeric_chunks(
+                    worksheet,
+                    first_row,
+                    col,
+                    ca,
+                    f64::from,
+                    dtype_format,
+                    options,
+                )?;
+            }
+            DataType::UInt32 => {
+                let ca = series.u32()?;
+                Self::write_numeric_chunks(
+                    worksheet,
+                    first_row,
+                    col,
+                    ca,
+                    f64::from,
+                    dtype_format,
+                    options,
+                )?;
+            }
+            DataType::Int64 => {
+                let ca = series.i64()?;
+                Self::write_numeric_chunks(
+                    worksheet,
+                    first_row,
+                    col,
+                    ca,
+                    |value| value as f64,
+                    dtype_format,
+                    options,
+                )?;
+            }
+            DataType::UInt64 => {
+                let ca = series.u64()?;
+                Self::write_numeric_chunks(
+                    worksheet,
+                    first_row,
+                    col,
+                    ca,
+                    |value| value as f64,
+                    dtype_format,
+                    options,
+                )?;
+            }
+            DataType::Float32 => {
+                let ca = series.f32()?;
+                Self::write_numeric_chunks(
+                    worksheet,
+                    first_row,
+                    col,
+                    ca,
+                    f64::from,
+                    float_format,
+                    options,
+                )?;
+            }
+            DataType::Float64 => {
+                let ca = series.f64()?;
+                Self::write_numeric_chunks(
+                    worksheet,
+                    first_row,
+                    col,
+                    ca,
+                    |value| value,
+                    float_format,
+                    options,
+                )?;
+            }
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
+    // Write the values in each chunk of a numeric ChunkedArray to a column of
+    // cells. Chunks without a validity bitmap are written directly from the
+    // values slice.
+    fn write_numeric_chunks<T>(
+        worksheet: &mut impl CellSink,
+        first_row: u32,
+        col: u16,
+        ca: &ChunkedArray<T>,
+        to_f64: impl Fn(T::Native) -> f64,
+        format: Option<&Format>,
+        options: &WriterOptions,
+    ) -> PolarsResult<()>
+    where
+        T: PolarsNumericType,
+    {
+        let mut row = first_row;
+
+        for array in ca.downcast_iter() {
+            if array.validity().is_none() {
+                for value in array.values().iter() {
+                    Self::write_number(worksheet, row, col, to_f64(*value), format, options)?;
+                    row += 1;
+                }
+            } else {
+                for value in array {
+                    match value {
+                        Some(value) => {
+                            Self::write_number(
+                                worksheet,
+                                row,
+                                col,
+                                to_f64(*value),
+                                format,
+                                options,
+                            )?;
+                        }
+                        None => Self::write_null(worksheet, row, col, options)?,
+                    }
+                    row += 1;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Write the replacement string for a Null value, if any, with the optional
+    // user defined Null format.
+    fn write_null(
+        worksheet: &mut impl CellSink,
+        row: u32,
+        col: u16,
+        options: &WriterOptions,
+    ) -> Result<(), XlsxError> {
+        if let Some(null_string) = &options.null_value {
+            worksheet.write_string_cell(row, col, null_string, options.null_format.as_ref())?;
+        }
+
+        Ok(())
+    }
+
+    // Write a number to a worksheet cell with an optional user defined format.
+    // NaN and Infinity values are written according to the NaN handling.
+    fn write_number(
+        worksheet: &mut impl CellSink,
+        row: u32,
+        col: u16,
+        value: f64,
+        format: Option<&Format>,
+        options: &WriterOptions,
+    ) -> Result<(), XlsxError> {
+        if !value.is_finite() {
+            match options.nan_handling {
+                NanHandling::Blank => {
+                    if let Some(format) = format {
+                        worksheet.write_blank_cell(row, col, format)?;
+                    }
+                    return Ok(());
+                }
+                NanHandling::ErrorValue => {
+                    let formula = Formula::new("=NA()").set_result("#N/A");
+                    worksheet.write_formula_cell(row, col, formula, format)?;
+                    return Ok(());
+                }
+                NanHandling::Strings | NanHandling::String(_) => {}
+            }
+        }
+
+        worksheet.write_number_cell(row, col, value, format)
+    }
+
+    // Write a float to a worksheet cell with the float format. Values with a
+    // magnitude greater than the scientific threshold, if any, are written
+    // with a scientific number format instead.
+    fn write_float(
+        worksheet: &mut impl CellSink,
+        row: u32,
+        col: u16,
+        value: f64,
+        dtype_format: Option<&Format>,
+        options: &WriterOptions,
+    ) -> Result<(), XlsxError> {
+        let format = dtype_format.unwrap_or(&options.float_format.format);
+
+        match &options.float_scientific {
+            Some((threshold, num_format)) if value.is_finite() && value.abs() > *threshold => {
+                let format = format.clone().set_num_format(num_format);
+                Self::write_number(worksheet, row, col, value, Some(&format), options)
+            }
+            _ => Self::write_number(worksheet, row, col, value, Some(format), options),
+        }
+    }
+
+    // Write a Polars AnyValue to a worksheet cell by mapping it to the
+    // equivalent Excel/rust_xlsxwriter type.
+    #[allow(clippy::too_many_lines)]
+    fn write_any_value(
+        worksheet: &mut impl CellSink,
+        row: u32,
+        col: u16,
+        data: AnyValue,
+        dtype_format: Option<&Format>,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        // Write values that can't be represented in Excel as strings, if
+        // required.
+        if !Self::is_excel_value(&data) && Self::is_written_as_string(&data.dtype(), options) {
+            let text_format;
+            let format = if Self::is_large_int_type(&data.dtype())
+                && options.large_int_handling == Some(LargeIntHandling::TextFormat)
+            {
+                text_format = dtype_format
+                    .cloned()
+                    .unwrap_or_default()
+                    .set_num_format("@");
+                Some(&text_format)
+            } else {
+                dtype_format
+            };
+
+            worksheet.write_string_cell(row, col, &data.to_string(), format)?;
+            return Ok(());
+        }
+
+        match data {
+            AnyValue::Int8(value) => {
+                worksheet.write_number_cell(row, col, f64::from(value), dtype_format)?;
+            }
+            AnyValue::UInt8(value) => {
+                worksheet.write_number_cell(row, col, f64::from(value), dtype_format)?;
+            }
+            AnyValue::Int16(value) => {
+                worksheet.write_number_cell(row, col, f64::from(value), dtype_format)?;
+            }
+            AnyValue::UInt16(value) => {
+                worksheet.write_number_cell(row, col, f64::from(value), dtype_format)?;
+            }
+            AnyValue::Int32(value) => {
+                worksheet.write_number_cell(row, col, f64::from(value), dtype_format)?;
+            }
+            AnyValue::UInt32(value) => {
+                worksheet.write_number_cell(row, col, f64::from(value), dtype_format)?;
+            }
+            AnyValue::Int64(value) => {
+                // Allow u64 conversion within Excel's limits.
+                #[allow(clippy::cast_precision_loss)]
+                let value = value as f64;
+                worksheet.write_number_cell(row, col, value, dtype_format)?;
+            }
+            AnyValue::UInt64(value) => {
+                // Allow u64 conversion within Excel's limits.
+                #[allow(clippy::cast_precision_loss)]
+                let value = value as f64;
+                worksheet.write_number_cell(row, col, value, dtype_format)?;
+            }
+            AnyValue::Float32(value) => {
+                Self::write_float(worksheet, row, col, f64::from(value), dtype_format, options)?;
+            }
+            AnyValue::Float64(value) => {
+                Self::write_float(worksheet, row, col, value, dtype_format, options)?;
+            }
+            AnyValue::String(value) => {
+                worksheet.write_string_cell(row, col, value, dtype_format)?;
+            }
+            AnyValue::StringOwned(value) => {
+                worksheet.write_string_cell(row, col, value.as_str(), dtype_format)?;
+            }
+            AnyValue::Categorical(..)
+            | AnyValue::CategoricalOwned(..)
+            | AnyValue::Enum(..)
+            | AnyValue::EnumOwned(..) => {
+                // Write categorical values as their string representation.
+                let value = data.get_str().unwrap_or_default();
+                worksheet.write_string_cell(row, col, value, dtype_format)?;
+            }
+            AnyValue::Boolean(value) => match &options.boolean_values {
+                BooleanValues::Native => {
+                    worksheet.write_boolean_cell(row, col, value, dtype_format)?;
+                }
+                BooleanValues::Numbers => {
+                    let value = f64::from(u8::from(value));
+                    worksheet.write_number_cell(row, col, value, dtype_format)?;
+                }
+                BooleanValues::Strings(true_value, false_value) => {
+                    let value = if value { true_value } else { false_value };
+                    worksheet.write_string_cell(row, col, value, dtype_format)?;
+                }
+            },
+            AnyValue::Null => Self::write_null(worksheet, row, col, options)?,
+            AnyValue::Datetime(value, time_units, _) => {
+                let datetime = options
+                    .temporal_converter
+                    .datetime(value, time_units)
+                    .ok_or_else(
+                        || polars_err!(ComputeError: "datetime value out of range: {value}"),
+                    )?;
+                worksheet.write_temporal_cell(
+                    row,
+                    col,
+                    TemporalValue::DateTime(datetime),
+                    dtype_format.unwrap_or(&options.datetime_format.format),
+                )?;
+            }
+            AnyValue::Date(value) => {
+                let excel_date = options
+                    .temporal_converter
+                    .date(value)
+                    .ok_or_else(|| polars_err!(ComputeError: "date value out of range: {value}"))?;
+                worksheet.write_temporal_cell(
+                    row,
+                    col,
+                    TemporalValue::Date(excel_date),
+                    dtype_format.unwrap_or(&options.date_format.format),
+                )?;
+            }
+            AnyValue::Time(value) => {
+                let time = options
+                    .temporal_converter
+                    .time(value)
+                    .ok_or_else(|| polars_err!(ComputeError: "time value out of range: {value}"))?;
+                worksheet.write_temporal_cell(
+                    row,
+                    col,
+                    TemporalValue::Time(time),
+                    dtype_format.unwrap_or(&options.time_format.format),
+                )?;
+            }
+            AnyValue::Decimal(value, scale) => {
+                Self::write_decimal(worksheet, row, col, value, scale, dtype_format)?;
+            }
+            AnyValue::Duration(value, time_units) => {
+                Self::write_duration(
+                    worksheet,
+                    row,
+                    col,
+                    value,
+                    time_units,
+                    dtype_format,
+                    options,
+                )?;
+            }
+            AnyValue::List(series) => {
+                // Write list values as a delimiter separated string.
+                let value = Self::join_list_values(&series, options);
+                worksheet.write_string_cell(row, col, &value, dtype_format)?;
+            }
+            _ => {
+                polars_bail!(
+                    ComputeError:
+                    "Polars AnyValue data type '{}' is not supported by Excel",
+                    data.dtype()
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    // Check a column for values that can't be represented in Excel and apply
+    // the value error policy. Returns true if the column contains any of these
+    // values.
+    fn check_column_values(column: &Column, options: &WriterOptions) -> PolarsResult<bool> {
+        let Some(description) = Self::invalid_values_description(column)? else {
+            return Ok(false);
+        };
+
+        // Large integers are handled separately, if required.
+        if Self::is_large_int_type(column.dtype()) && options.large_int_handling.is_some() {
+            return Ok(true);
+        }
+
+        match options.value_error_policy {
+            ErrorPolicy::Error => polars_bail!(
+                ComputeError:
+                "column '{}' contains {} which can't be represented in Excel. See set_value_error_policy()",
+                column.name(),
+                description
+            ),
+            // The warning is added, with the other warnings, before the
+            // dataframe is written.
+            ErrorPolicy::CoerceWithWarning | ErrorPolicy::WriteAsString => {}
+        }
+
+        Ok(true)
+    }
+
+    // Get a description of the values in a column that can't be represented
+    // in Excel, if there are any. Only the column minimum and maximum need to
+    // be checked.
+    fn invalid_values_description(column: &Column) -> PolarsResult<Option<&'static str>> {
+        let series = column.series();
+
+        let (is_valid, description) = match series.dtype() {
+            DataType::Int64 => {
+                let ca = series.i64()?;
+                let is_valid = [ca.min(), ca.max()]
+                    .into_iter()
+                    .flatten()
+                    .all(|value| Self::is_excel_integer(i128::from(value)));
+
+                (is_valid, "integers larger than 2^53")
+            }
+            DataType::UInt64 => {
+                let is_valid = series
+                    .u64()?
+                    .max()
+                    .is_none_or(|value| Self::is_excel_integer(i128::from(value)));
+
+                (is_valid, "integers larger than 2^53")
+            }
+            DataType::Date => {
+                let ca = series.date()?.physical();
+                let is_valid = [ca.min(), ca.max()]
+                    .into_iter()
+                    .flatten()
+                    .all(|value| Self::is_excel_date(i64::from(value)));
+
+                (is_valid, "dates before 1900 or after 9999")
+            }
+            DataType::Datetime(time_units, _) => {
+                let ca = series.datetime()?.physical();
+                let is_valid = [ca.min(), ca.max()]
+                    .into_iter()
+                    .flatten()
+                    .all(|value| Self::is_excel_date(Self::datetime_days(value, *time_units)));
+
+                (is_valid, "dates before 1900 or after 9999")
+            }
+            _ => (true, ""),
+        };
+
+        Ok((!is_valid).then_some(description))
+    }
+
+    // Check if values of a data type that can't be represented in Excel are
+    // written as strings, according to the large integer handling or the value
+    // error policy.
+    fn is_written_as_string(dtype: &DataType, options: &WriterOptions) -> bool {
+        match options.large_int_handling {
+            Some(handling) if Self::is_large_int_type(dtype) => handling != LargeIntHandling::Float,
+            _ => options.value_error_policy == ErrorPolicy::WriteAsString,
+        }
+    }
+
+    // Check if a data type can contain integers larger than 2^53.
+    pub(crate) fn is_large_int_type(dtype: &DataType) -> bool {
+        matches!(dtype, DataType::Int64 | DataType::UInt64)
+    }
+
+    // Check if a value can be represented in Excel. Values that aren't checked
+    // are assumed to be valid.
+    fn is_excel_value(data: &AnyValue) -> bool {
+        match data {
+            AnyValue::Int64(value) => Self::is_excel_integer(i128::from(*value)),
+            AnyValue::UInt64(value) => Self::is_excel_integer(i128::from(*value)),
+            AnyValue::Date(value) => Self::is_excel_date(i64::from(*value)),
+            AnyValue::Datetime(value, time_units, _) => {
+                Self::is_excel_date(Self::datetime_days(*value, *time_units))
+            }
+            _ => true,
+        }
+    }
+
+    // Check if an integer can be stored exactly as an Excel f64 number.
+    fn is_excel_integer(value: i128) -> bool {
+        value.unsigned_abs() <= u128::from(EXCEL_MAX_INTEGER)
+    }
+
+    // Check if a number of days from the Unix epoch is a valid Excel date.
+    fn is_excel_date(days: i64) -> bool {
+        (EXCEL_MIN_DATE..=EXCEL_MAX_DATE).contains(&days)
+    }
+
+    // Convert a Polars datetime value to a number of days from the Unix epoch.
+    fn datetime_days(value: i64, time_units: TimeUnit) -> i64 {
+        let units_per_day = match time_units {
+            TimeUnit::Nanoseconds => 86_400_000_000_000,
+            TimeUnit::Microseconds => 86_400_000_000,
+            TimeUnit::Milliseconds => 86_400_000,
+        };
+
+        value.div_euclid(units_per_day)
+    }
+
+    // Select the columns set via set_columns(), in the given order. Notes
+    // columns for the selected columns are also kept since they aren't written
+    // as data.
+    fn select_columns<'a>(
+        df: &'a DataFrame,
+        options: &WriterOptions,
+    ) -> PolarsResult<Cow<'a, DataFrame>> {
+        if options.columns.is_empty() {
+            return Ok(Cow::Borrowed(df));
+        }
+
+        let mut names: Vec<&str> = vec![];
+        for name in &options.columns {
+            if df.column(name).is_err() {
+                let schema = df.schema_description();
+
+                polars_bail!(
+                    ColumnNotFound:
+                    "column '{name}' set via set_columns(). Dataframe schema: [{schema}]"
+                );
+            }
+
+            names.push(name);
+        }
+
+        for name in &options.columns {
+            if let Some(notes_name) = options.column_notes.get(name) {
+                if !names.contains(&notes_name.as_str()) && df.column(notes_name).is_ok() {
+                    names.push(notes_name);
+                }
+            }
+        }
+
+        Ok(Cow::Owned(df.select(names)?))
+    }
+
+    // Select the dataframe rows for which the row filter, if any, returns true.
+    // The filter is called with the values of all the dataframe columns.
+    fn filter_rows<'a>(
+        df: &'a DataFrame,
+        options: &WriterOptions,
+    ) -> PolarsResult<Cow<'a, DataFrame>> {
+        match Self::selected_row_runs(df, options) {
+            Some(runs) => Ok(Cow::Owned(Self::take_row_runs(df, &runs)?)),
+            None => Ok(Cow::Borrowed(df)),
+        }
+    }
+
+    // Get the runs of consecutive rows, as (first_row, num_rows), that are
+    // selected by the row filter. Returns None if all the rows are selected.
+    // The filter is evaluated over windows of the dataframe so that only one
+    // window of the columns is made contiguous at a time.
+    fn selected_row_runs(df: &DataFrame, options: &WriterOptions) -> Option<Vec<(usize, usize)>> {
+        let Some(row_filter) = &options.row_filter else {
+            return None;
+        };
+
+        if df.height() == 0 {
+            return None;
+        }
+
+        let mut runs: Vec<(usize, usize)> = vec![];
+
+        for window_start in (0..df.height()).step_by(CONSTANT_MEMORY_CHUNK_SIZE) {
+            let window = df.slice_rows(window_start, CONSTANT_MEMORY_CHUNK_SIZE);
+            let series: Vec<Series> = window
+                .data_columns()
+                .iter()
+                .map(ColumnAdapter::contiguous_series)
+                .collect();
+            let mut columns: Vec<_> = series.iter().map(Series::iter).collect();
+            let mut values = Vec::with_capacity(columns.len());
+
+            for row_num in window_start..window_start + window.height() {
+                values.clear();
+                values.extend(columns.iter_mut().filter_map(Iterator::next));
+
+                if !row_filter(row_num, &values) {
+                    continue;
+                }
+
+                match runs.last_mut() {
+                    Some((first_row, num_rows)) if *first_row + *num_rows == row_num => {
+                        *num_rows += 1;
+                    }
+                    _ => runs.push((row_num, 1)),
+                }
+            }
+        }
+
+        if runs == [(0, df.height())] {
+            return None;
+        }
+
+        Some(runs)
+    }
+
+    // Build a dataframe from runs of rows of another dataframe. Long runs are
+    // sliced, which shares the column buffers with the source dataframe.
+    // Fragmented selections are gathered instead, since a large number of
+    // small slices is slower to write than a copy of the selected rows.
+    fn take_row_runs(df: &DataFrame, runs: &[(usize, usize)]) -> PolarsResult<DataFrame> {
+        let num_rows: usize = runs.iter().map(|(_, num_rows)| num_rows).sum();
+
+        if runs.is_empty() || num_rows / runs.len() < MIN_SLICED_ROW_RUN {
+            let indices: Vec<IdxSize> = runs
+                .iter()
+                .flat_map(|(first_row, num_rows)| *first_row..first_row + num_rows)
+                .map(|row_num| row_num as IdxSize)
+                .collect();
+            let indices = IdxCa::from_vec("indices".into(), indices);
+
+            return df.take(&indices);
+        }
+
+        let mut filtered = df.clear();
+        for (first_row, num_rows) in runs {
+            filtered.vstack_mut(&df.slice_rows(*first_row, *num_rows))?;
+        }
+
+        Ok(filtered)
+    }
+
+    // Convert a numeric percentage value, such as 25.0, to a fraction, such as
+    // 0.25. Other values are returned unchanged.
+    fn percentage_to_fraction(value: AnyValue) -> AnyValue {
+        if value.is_primitive_numeric() {
+            if let Some(number) = value.extract::<f64>() {
+                return AnyValue::Float64(number / 100.0);
+            }
+        }
+
+        value
+    }
+
+    // Prepend a column of row numbers to the dataframe, if required.
+    fn add_row_numbers<'a>(
+        df: Cow<'a, DataFrame>,
+        options: &WriterOptions,
+    ) -> PolarsResult<Cow<'a, DataFrame>> {
+        match &options.row_numbers {
+            Some((name, start_at)) => Ok(Cow::Owned(
+                df.with_row_index(name.as_str().into(), Some(*start_at))?,
+            )),
+            None => Ok(df),
+        }
+    }
+
+    // Write a Polars duration value as an Excel elapsed time, which is a
+    // number of days, with an elapsed time format such as `[h]:mm:ss`.
+    fn write_duration(
+        worksheet: &mut impl CellSink,
+        row: u32,
+        col: u16,
+        value: i64,
+        time_units: TimeUnit,
+        dtype_format: Option<&Format>,
+        options: &WriterOptions,
+    ) -> PolarsResult<()> {
+        let units_per_day = match time_units {
+            TimeUnit::Nanoseconds => 86_400_000_000_000_f64,
+            TimeUnit::Microseconds => 86_400_000_000_f64,
+            TimeUnit::Milliseconds => 86_400_000_f64,
+        };
+
+        // Allow precision loss for durations beyond the exact f64 range.
+        #[allow(clippy::cast_precision_loss)]
+        let days = value as f64 / units_per_day;
+
+        worksheet.write_number_cell(
+            row,
+            col,
+            days,
+            Some(dtype_format.unwrap_or(&options.duration_format.format)),
+        )?;
+
+        Ok(())
+    }
+
+    // Get the default format for a data type, which is used if there isn't a
+    // user defined format for the type.
+    pub(crate) fn default_dtype_format(dtype: &DataType, options: &WriterOptions) -> Format {
+        match dtype {
+            DataType::Float32 | DataType::Float64 => options.float_format.format.clone(),
+            DataType::Date => options.date_format.format.clone(),
+            DataType::Datetime(_, _) => options.datetime_format.format.clone(),
+            DataType::Time => options.time_format.format.clone(),
+            DataType::Duration(_) => options.duration_format.format.clone(),
+            DataType::Decimal(_, scale) => {
+                Format::new().set_num_format(Self::decimal_num_format(scale.unwrap_or(0)))
+            }
+            _ => Format::new(),
+        }
+    }
+
+    // Get the number format of the default format for a data type. An empty
+    // string is the Excel General format. Returns `None` if the number format
+    // isn't known, see `CellFormat`.
+    fn default_num_format(dtype: &DataType, options: &WriterOptions) -> Option<String> {
+        match dtype {
+            DataType::Float32 | DataType::Float64 => options.float_format.num_format.clone(),
+            DataType::Date => options.date_format.num_format.clone(),
+            DataType::Datetime(_, _) => options.datetime_format.num_format.clone(),
+            DataType::Time => options.time_format.num_format.clone(),
+            DataType::Duration(_) => options.duration_format.num_format.clone(),
+            DataType::Decimal(_, scale) => Some(Self::decimal_num_format(scale.unwrap_or(0))),
+            _ => Some(String::new()),
+        }
+    }
+
+    // Check if a format has a number format. The properties of a format can't
+    // be read but a format without a number format is unchanged by setting an
+    // empty number format.
+    pub(crate) fn has_num_format(format: &Format) -> bool {
+        format.clone().set_num_format("") != *format
+    }
+
+    // Join the values of a list into a delimiter separated string.
+    fn join_list_values(series: &Series, options: &WriterOptions) -> String {
+        series
+            .iter()
+            .map(|value| match value {
+                AnyValue::Null => options.null_value.clone().unwrap_or_default(),
+                _ => match value.get_str() {
+                    Some(string) => string.to_string(),
+                    None => value.to_string(),
+                },
+            })
+            .collect::<Vec<String>>()
+            .join(&options.list_delimiter)
+    }
+
+    // Get the columns of the output table in worksheet order. These are the
+    // dataframe columns plus any columns inserted by the writer.
+    pub(crate) fn output_columns<'a>(
+        df: &'a DataFrame,
+        options: &'a WriterOptions,
+    ) -> PolarsResult<Vec<OutputColumn<'a>>> {
+        let mut output_columns: Vec<OutputColumn> = vec![];
+
+        for column in df.data_columns() {
+            // Columns used as notes for other columns aren't written.
+            if Self::is_notes_column(df, column, options) {
+                continue;
+            }
+
+            // Expand list columns into a column per list element, if required.
+            if options.expand_lists && matches!(column.dtype(), DataType::List(_)) {
+                let max_len = column
+                    .contiguous_series()
+                    .iter()
+                    .map(|value| match value {
+                        AnyValue::List(series) => series.len(),
+                        _ => 0,
+                    })
+                    .max()
+                    .unwrap_or(0);
+
+                for index in 0..max_len.max(1) {
+                    let name = format!("{}_{}", column.name(), index + 1);
+                    output_columns.push(OutputColumn::ListElement(column, index, name));
+                }
+            } else {
+                output_columns.push(OutputColumn::Data(column));
+            }
+        }
+
+        for sparkline_column in &options.sparkline_columns {
+            let position =
+                Self::inserted_column_position(&output_columns, &sparkline_column.position);
+
+            let Some(position) = position else {
+                polars_bail!(
+                    ColumnNotFound:
+                    "sparkline column position not found in dataframe: {:?}",
+                    sparkline_column.position
+                );
+            };
+
+            output_columns.insert(position, OutputColumn::Sparkline(sparkline_column));
+        }
+
+        if !options.use_table && !options.formula_columns.is_empty() {
+            polars_bail!(ComputeError: "formula columns require a worksheet table");
+        }
+
+        for formula_column in &options.formula_columns {
+            let position =
+                Self::inserted_column_position(&output_columns, &formula_column.position);
+
+            let Some(position) = position else {
+                polars_bail!(
+                    ColumnNotFound:
+                    "formula column position not found in dataframe: {:?}",
+                    formula_column.position
+                );
+            };
+
+            output_columns.insert(position, OutputColumn::Formula(formula_column));
+        }
+
+        // Add the row total column at the end of the table.
+        if let Some(row_total_column) = &options.row_total_column {
+            output_columns.push(OutputColumn::RowTotal(row_total_column));
+        }
+
+        Ok(output_columns)
+    }
+
+    // Get the zero indexed position at which to insert a column into the output
+    // columns, or `None` if the position column isn't found.
+    fn inserted_column_position(
+        output_columns: &[OutputColumn],
+        position: &ColumnPosition,
+    ) -> Option<usize> {
+        match position {
+            ColumnPosition::End => Some(output_columns.len()),
+            ColumnPosition::Before(name) => Self::column_position(output_columns, name),
+            ColumnPosition::After(name) => {
+                Self::column_position(output_columns, name).map(|index| index + 1)
+            }
+        }
+    }
+
+    // Get the zero indexed position of a named column in the output columns.
+    pub(crate) fn column_position(output_columns: &[OutputColumn], name: &str) -> Option<usize> {
+        output_columns
+            .iter()
+            .position(|output_column| output_column.name() == name)
+    }
+}
+
+/// The position of a column inserted into the output table.
+///
+/// Used with methods such as [`PolarsXlsxWriter::add_sparkline()`] to set the
+/// position of an inserted column relative to the dataframe columns.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ColumnPosition {
+    /// Insert the column before the named dataframe column.
+    Before(String),
+
+    /// Insert the column after the named dataframe column.
+    After(String),
+
+    /// Insert the column at the end of the table. This is the default.
+    #[default]
+    End,
+}
+
+/// The policy for handling dataframes with more rows than fit in a worksheet.
+///
+/// Used with [`PolarsXlsxWriter::set_overflow_policy()`] to control what
+/// happens when a dataframe exceeds the Excel limit of 1,048,576 rows per
+/// worksheet, including the header and any total row.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Return an error if the dataframe doesn't fit in the worksheet. This is
+    /// the default.
+    #[default]
+    Error,
+
+    /// Write the rows that fit in the worksheet and ignore the remaining rows.
+    Truncate,
+
+    /// Continue writing the remaining rows to additional worksheets named after
+    /// the first worksheet with a `_2`, `_3`, etc., suffix. For example
+    /// `Sheet1_2`.
+    SplitWorksheets,
+
+    /// Continue writing the remaining rows to additional files that are saved
+    /// alongside the main file with a `_2`, `_3`, etc., suffix. For example
+    /// `dataframe_2.xlsx`.
+    SplitFiles,
+}
+
+/// The order in which dataframe cells are written to the worksheet.
+///
+/// Used with [`PolarsXlsxWriter::set_write_order()`] to control whether the
+/// dataframe is written column by column or row by row.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WriteOrder {
+    /// Write the data column by column. This is the fastest option since
+    /// numeric columns can be written directly from their underlying arrays.
+    /// This is the default.
+    #[default]
+    ColumnMajor,
+
+    /// Write the data row by row, with each row completed before the next one
+    /// is started. This is the order used for constant memory worksheets.
+    RowMajor,
+}
+
+/// The layout of dataframes written to the same worksheet.
+///
+/// Used with [`PolarsXlsxWriter::write_dataframes()`] to set the direction in
+/// which the dataframes are positioned and the gap between them.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Layout {
+    /// Write the dataframes side by side, from left to right, with a gap of
+    /// `gap` columns between them.
+    Horizontal {
+        /// The number of blank columns between the dataframes.
+        gap: u16,
+    },
+
+    /// Write the dataframes one below the other with a gap of `gap` rows
+    /// between them.
+    Vertical {
+        /// The number of blank rows between the dataframes.
+        gap: u32,
+    },
+}
+
+/// The way that the dataframe header is written.
+///
+/// Used with [`PolarsXlsxWriter::set_header_mode()`] to control whether the
+/// dataframe column names are written as a table header, as a normal row of
+/// cells or not at all.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum HeaderMode {
+    /// Write the column names as the header row of an Excel worksheet table.
+    /// This is the default.
+    #[default]
+    TableHeader,
+
+    /// Write the column names as a normal row of cells, with the header format
+    /// if any, and write the data as a plain range without a table.
+    WrittenHeaderRow,
+
+    /// Don't write the column names. The data starts in the first row of the
+    /// output range.
+    None,
+}
+
+/// The handling of duplicate worksheet table headers.
+///
+/// Used with [`PolarsXlsxWriter::set_duplicate_header_policy()`] to control
+/// how the writer handles table headers that are the same, ignoring case,
+/// which isn't allowed by Excel.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum DuplicateHeaderPolicy {
+    /// Return an error that names the columns with the duplicate header. This
+    /// is the default.
+    #[default]
+    Error,
+
+    /// Rename the duplicate headers by adding the lowest number, starting from
+    /// 2, that makes the header unique, like Excel. For example `Price` and
+    /// `price` are written as `Price` and `price2`.
+    Rename,
+
+    /// Rename the duplicate headers by adding a suffix and the lowest number,
+    /// starting from 2, that makes the header unique. For example, with a `_`
+    /// suffix, `Price` and `price` are written as `Price` and `price_2`.
+    Suffix(String),
+}
+
+/// The handling of Polars struct columns.
+///
+/// Used with [`PolarsXlsxWriter::set_struct_handling()`] to control how
+/// `DataType::Struct` columns are written to the worksheet.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StructHandling {
+    /// Return an error if the dataframe contains a struct column. This is the
+    /// default.
+    #[default]
+    Error,
+
+    /// Flatten the struct fields into separate columns named after the struct
+    /// column and field, for example `Point.x`. Nested structs are flattened
+    /// recursively.
+    Flatten,
+
+    /// Write each struct value as a JSON string, for example
+    /// `{"x":1.5,"y":4}`. Null struct values are written as null cells.
+    JsonString,
+}
+
+/// The handling of Polars binary columns.
+///
+/// Used with [`PolarsXlsxWriter::set_binary_handling()`] to control how
+/// `DataType::Binary` columns are written to the worksheet.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BinaryHandling {
+    /// Return an error if the dataframe contains a binary column. This is the
+    /// default.
+    #[default]
+    Error,
+
+    /// Write each binary value as a standard Base64 string with padding, for
+    /// example `3q2+7w==`.
+    Base64,
+
+    /// Write each binary value as a lowercase hexadecimal string, for example
+    /// `deadbeef`.
+    Hex,
+
+    /// Leave binary columns out of the output.
+    Skip,
+}
+
+/// The handling of Polars object columns.
+///
+/// Used with [`PolarsXlsxWriter::set_object_handling()`] to control how
+/// `DataType::Object` columns are written to the worksheet. This requires the
+/// `object` feature.
+///
+#[cfg(feature = "object")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ObjectHandling {
+    /// Return an error if the dataframe contains an object column. This is the
+    /// default.
+    #[default]
+    Error,
+
+    /// Write each object value as the string from its `Display`
+    /// implementation. Null values are written as null cells.
+    DisplayString,
+
+    /// Leave object columns out of the output.
+    Skip,
+}
+
+/// The type of an [`ExportWarning`].
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WarningKind {
+    /// A column name set via a writer method, such as
+    /// [`PolarsXlsxWriter::set_column_format()`], isn't in the written
+    /// dataframe so the option is ignored for it. This also applies to a
+    /// pattern set via [`PolarsXlsxWriter::set_format_matching()`] that
+    /// doesn't match any column.
+    UnknownColumn,
+
+    /// A column width set via [`PolarsXlsxWriter::set_column_width()`] is
+    /// larger than the Excel maximum of 255 characters and is clipped.
+    ClippedWidth,
+
+    /// A duplicate table header is renamed according to the
+    /// [`DuplicateHeaderPolicy`].
+    RenamedHeader,
+
+    /// A column contains values that can't be represented exactly in Excel,
+    /// such as integers larger than 2^53 or dates before 1900, and they are
+    /// written as the closest Excel value according to
+    /// [`ErrorPolicy::CoerceWithWarning`].
+    CoercedValue,
+
+    /// A column option, such as [`PolarsXlsxWriter::set_merge_repeated()`],
+    /// isn't supported in [constant memory
+    /// mode](PolarsXlsxWriter::set_constant_memory) or by
+    /// [`PolarsXlsxWriter::write_dataframe_chunked()`] since the rows can't be
+    /// changed once they are written. The option is ignored for the column.
+    UnsupportedOption,
+}
+
+/// The handling of NaN and Infinity values, which aren't supported by Excel.
+///
+/// Used with [`PolarsXlsxWriter::set_nan_handling()`] to control how `NaN`,
+/// `Infinity` and `-Infinity` float values are written to the worksheet.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum NanHandling {
+    /// Write the values as the strings "NAN", "INF" and "-INF", or the strings
+    /// set via [`PolarsXlsxWriter::set_nan_value()`],
+    /// [`PolarsXlsxWriter::set_infinity_value()`] and
+    /// [`PolarsXlsxWriter::set_neg_infinity_value()`]. This is the default.
+    #[default]
+    Strings,
+
+    /// Write all the values as the same user defined string, such as "N/A".
+    String(String),
+
+    /// Write the values as empty cells. Cells with a format, such as the
+    /// float format, keep the format.
+    Blank,
+
+    /// Write the values as the Excel `#N/A` error value, via the `=NA()`
+    /// formula. Excel aggregate functions such as `SUM()` return an error for
+    /// ranges that contain `#N/A` so that missing data isn't hidden.
+    ErrorValue,
+}
+
+/// The policy for handling values that can't be represented in Excel.
+///
+/// Used with [`PolarsXlsxWriter::set_value_error_policy()`] to control how
+/// values such as integers larger than 2^53 or dates before 1900 are written
+/// to the worksheet.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Return an error if a column contains values that can't be represented
+    /// in Excel.
+    Error,
+
+    /// Write the values as the closest Excel value, which may be lossy or
+    /// display as an invalid date, and add a warning for each affected column
+    /// to [`PolarsXlsxWriter::warnings()`]. This is the default.
+    #[default]
+    CoerceWithWarning,
+
+    /// Write the values that can't be represented in Excel as strings. Other
+    /// values in the column are written normally.
+    WriteAsString,
+}
+
+/// The handling of 64 bit integers that don't fit exactly in Excel.
+///
+/// Used with [`PolarsXlsxWriter::set_large_int_handling()`] to control how
+/// `Int64` and `UInt64` values larger than 2^53 are written to the worksheet.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LargeIntHandling {
+    /// Write the values as string cells.
+    String,
+
+    /// Write the values as string cells with the Excel text number format,
+    /// `@`. This is combined with any user defined format for the column data
+    /// type.
+    TextFormat,
+
+    /// Write the values as Excel numbers. This loses precision but doesn't
+    /// raise an error or warning.
+    Float,
+}
+
+/// The color scale of a heatmap.
+///
+/// Used with [`PolarsXlsxWriter::set_heatmap()`] to set the `rust_xlsxwriter`
+/// color scale conditional format that is applied across several columns.
+/// The `rust_xlsxwriter` types can also be passed directly to `set_heatmap()`
+/// since they convert into this type.
+///
+#[derive(Clone)]
+pub enum ColorScale {
+    /// A 2 color scale, such as white to green.
+    TwoColor(ConditionalFormat2ColorScale),
+
+    /// A 3 color scale, such as red to yellow to green.
+    ThreeColor(ConditionalFormat3ColorScale),
+}
+
+impl From<ConditionalFormat2ColorScale> for ColorScale {
+    fn from(color_scale: ConditionalFormat2ColorScale) -> ColorScale {
+        ColorScale::TwoColor(color_scale)
+    }
+}
+
+impl From<ConditionalFormat3ColorScale> for ColorScale {
+    fn from(color_scale: ConditionalFormat3ColorScale) -> ColorScale {
+        ColorScale::ThreeColor(color_scale)
+    }
+}
+
+/// A conditional format for a dataframe column or cell range.
+///
+/// Used with [`PolarsXlsxWriter::set_conditional_format()`] to store the
+/// `rust_xlsxwriter` conditional format that is added to the worksheet. The
+/// `rust_xlsxwriter` types can also be passed directly to
+/// `set_conditional_format()` since they convert into this type.
+///
+#[derive(Clone)]
+pub enum ColumnConditionalFormat {
+    /// A cell value rule, such as greater than a value.
+    Cell(ConditionalFormatCell),
+
+    /// A rule for blank, or non-blank, cells.
+    Blank(ConditionalFormatBlank),
+
+    /// A rule for error, or non-error, cells.
+    Error(ConditionalFormatError),
+
+    /// A rule for duplicate, or unique, values.
+    Duplicate(ConditionalFormatDuplicate),
+
+    /// A rule based on a formula.
+    Formula(ConditionalFormatFormula),
+
+    /// A rule for values above or below the average.
+    Average(ConditionalFormatAverage),
+
+    /// A rule for the top or bottom values.
+    Top(ConditionalFormatTop),
+
+    /// A rule for cells that contain, or begin or end with, a text string.
+    Text(ConditionalFormatText),
+
+    /// A rule for dates relative to the current date.
+    Date(ConditionalFormatDate),
+
+    /// A 2 color scale.
+    TwoColorScale(ConditionalFormat2ColorScale),
+
+    /// A 3 color scale.
+    ThreeColorScale(ConditionalFormat3ColorScale),
+
+    /// A data bar.
+    DataBar(ConditionalFormatDataBar),
+
+    /// An icon set.
+    IconSet(ConditionalFormatIconSet),
+}
+
+impl ColumnConditionalFormat {
+    // Add the conditional format to a cell range of a worksheet.
+    #[allow(clippy::too_many_lines)]
+    pub(crate) fn add_to_worksheet(
+        &self,
+        worksheet: &mut Worksheet,
+        first_row: u32,
+        first_col: u16,
+        last_row: u32,
+        last_col: u16,
+    ) -> Result<(), XlsxError> {
+        match self {
+            ColumnConditionalFormat::Cell(conditional_format) => worksheet.add_conditional_format(
+                first_row,
+                first_col,
+                last_row,
+                last_col,
+                conditional_format,
+            )?,
+            ColumnConditionalFormat::Blank(conditional_format) => worksheet
+                .add_conditional_format(
+                    first_row,
+                    first_col,
+                    last_row,
+                    last_col,
+                    conditional_format,
+                )?,
+            ColumnConditionalFormat::Error(conditional_format) => worksheet
+                .add_conditional_format(
+                    first_row,
+                    first_col,
+                    last_row,
+                    last_col,
+                    conditional_format,
+                )?,
+            ColumnConditionalFormat::Duplicate(conditional_format) => worksheet
+                .add_conditional_format(
+                    first_row,
+                    first_col,
+                    last_row,
+                    last_col,
+                    conditional_format,
+                )?,
+            ColumnConditionalFormat::Formula(conditional_format) => worksheet
+                .add_conditional_format(
+                    first_row,
+                    first_col,
+                    last_row,
+                    last_col,
+                    conditional_format,
+                )?,
+            ColumnConditionalFormat::Average(conditional_format) => worksheet
+                .add_conditional_format(
+                    first_row,
+                    first_col,
+                    last_row,
+                    last_col,
+                    conditional_format,
+                )?,
+            ColumnConditionalFormat::Top(conditional_format) => worksheet.add_conditional_format(
+                first_row,
+                first_col,
+                last_row,
+                last_col,
+                conditional_format,
+            )?,
+            ColumnConditionalFormat::Text(conditional_format) => worksheet.add_conditional_format(
+                first_row,
+                first_col,
+                last_row,
+                last_col,
+                conditional_format,
+            )?,
+            ColumnConditionalFormat::Date(conditional_format) => worksheet.add_conditional_format(
+                first_row,
+                first_col,
+                last_row,
+                last_col,
+                conditional_format,
+            )?,
+            ColumnConditionalFormat::TwoColorScale(conditional_format) => worksheet
+                .add_conditional_format(
+                    first_row,
+                    first_col,
+                    last_row,
+                    last_col,
+                    conditional_format,
+                )?,
+            ColumnConditionalFormat::ThreeColorScale(conditional_format) => worksheet
+                .add_conditional_format(
+                    first_row,
+                    first_col,
+                    last_row,
+                    last_col,
+                    conditional_format,
+                )?,
+            ColumnConditionalFormat::DataBar(conditional_format) => worksheet
+                .add_conditional_format(
+                    first_row,
+                    first_col,
+                    last_row,
+                    last_col,
+                    conditional_format,
+                )?,
+            ColumnConditionalFormat::IconSet(conditional_format) => worksheet
+                .add_conditional_format(
+                    first_row,
+                    first_col,
+                    last_row,
+                    last_col,
+                    conditional_format,
+                )?,
+        };
+
+        Ok(())
+    }
+}
+
+impl From<ConditionalFormatCell> for ColumnConditionalFormat {
+    fn from(conditional_format: ConditionalFormatCell) -> ColumnConditionalFormat {
+        ColumnConditionalFormat::Cell(conditional_format)
+    }
+}
+
+impl From<ConditionalFormatBlank> for ColumnConditionalFormat {
+    fn from(conditional_format: ConditionalFormatBlank) -> ColumnConditionalFormat {
+        ColumnConditionalFormat::Blank(conditional_format)
+    }
+}
+
+impl From<ConditionalFormatError> for ColumnConditionalFormat {
+    fn from(conditional_format: ConditionalFormatError) -> ColumnConditionalFormat {
+        ColumnConditionalFormat::Error(conditional_format)
+    }
+}
+
+impl From<ConditionalFormatDuplicate> for ColumnConditionalFormat {
+    fn from(conditional_format: ConditionalFormatDuplicate) -> ColumnConditionalFormat {
+        ColumnConditionalFormat::Duplicate(conditional_format)
+    }
+}
+
+impl From<ConditionalFormatFormula> for ColumnConditionalFormat {
+    fn from(conditional_format: ConditionalFormatFormula) -> ColumnConditionalFormat {
+        ColumnConditionalFormat::Formula(conditional_format)
+    }
+}
+
+impl From<ConditionalFormatAverage> for ColumnConditionalFormat {
+    fn from(conditional_format: ConditionalFormatAverage) -> ColumnConditionalFormat {
+        ColumnConditionalFormat::Average(conditional_format)
+    }
+}
+
+impl From<ConditionalFormatTop> for ColumnConditionalFormat {
+    fn from(conditional_format: ConditionalFormatTop) -> ColumnConditionalFormat {
+        ColumnConditionalFormat::Top(conditional_format)
+    }
+}
+
+impl From<ConditionalFormatText> for ColumnConditionalFormat {
+    fn from(conditional_format: ConditionalFormatText) -> ColumnConditionalFormat {
+        ColumnConditionalFormat::Text(conditional_format)
+    }
+}
+
+impl From<ConditionalFormatDate> for ColumnConditionalFormat {
+    fn from(conditional_format: ConditionalFormatDate) -> ColumnConditionalFormat {
+        ColumnConditionalFormat::Date(conditional_format)
+    }
+}
+
+impl From<ConditionalFormat2ColorScale> for ColumnConditionalFormat {
+    fn from(conditional_format: ConditionalFormat2ColorScale) -> ColumnConditionalFormat {
+        ColumnConditionalFormat::TwoColorScale(conditional_format)
+    }
+}
+
+impl From<ConditionalFormat3ColorScale> for ColumnConditionalFormat {
+    fn from(conditional_format: ConditionalFormat3ColorScale) -> ColumnConditionalFormat {
+        ColumnConditionalFormat::ThreeColorScale(conditional_format)
+    }
+}
+
+impl From<ConditionalFormatDataBar> for ColumnConditionalFormat {
+    fn from(conditional_format: ConditionalFormatDataBar) -> ColumnConditionalFormat {
+        ColumnConditionalFormat::DataBar(conditional_format)
+    }
+}
+
+impl From<ConditionalFormatIconSet> for ColumnConditionalFormat {
+    fn from(conditional_format: ConditionalFormatIconSet) -> ColumnConditionalFormat {
+        ColumnConditionalFormat::IconSet(conditional_format)
+    }
+}
+
+/// A currency number format preset.
+///
+/// Used with [`PolarsXlsxWriter::set_column_currency()`] to set an Excel
+/// currency number format for a column. The formats have a thousands
+/// separator, two decimal places and show negative numbers in red with a
+/// minus sign, like the Excel "Currency" number format category.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CurrencyPreset {
+    /// US Dollar, displayed as `$1,234.56`.
+    USD,
+
+    /// Euro, displayed as `€ 1,234.56`.
+    EUR,
+
+    /// British Pound, displayed as `£1,234.56`.
+    GBP,
+
+    /// A custom currency symbol, such as `"CHF"` or `"¥"`, displayed before
+    /// the number like `CHF 1,234.56`.
+    Custom(String),
+}
+
+impl CurrencyPreset {
+    // Get the Excel number format for the currency.
+    pub(crate) fn num_format(&self) -> String {
+        let symbol = match self {
+            CurrencyPreset::USD => "[$$-409]".to_string(),
+            CurrencyPreset::EUR => "[$€-2]\\ ".to_string(),
+            CurrencyPreset::GBP => "[$£-809]".to_string(),
+            CurrencyPreset::Custom(symbol) => {
+                format!("\"{}\"\\ ", symbol.replace('"', ""))
+            }
+        };
+
+        format!("{symbol}#,##0.00;[Red]\\-{symbol}#,##0.00")
+    }
+}
+
+/// The style used to mask the values of a sensitive column.
+///
+/// Used with [`PolarsXlsxWriter::set_column_masked()`] to obfuscate the
+/// values of columns with personal or sensitive data when they are written.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MaskStyle {
+    /// Replace each value with a 16 character hexadecimal hash of the value.
+    /// Equal values have the same hash so the masked column can still be used
+    /// to group or match rows. The hash is a non-cryptographic FNV-1a hash so
+    /// it shouldn't be used for values that could be recovered by guessing,
+    /// such as short numbers.
+    Hash,
+
+    /// Keep the last `n` characters of each value and replace the other
+    /// characters with `*`. For example `Partial(4)` masks `"4111222233334444"`
+    /// as `"************4444"`.
+    Partial(usize),
+
+    /// Replace each value with a fixed string, such as `"REDACTED"`.
+    Replace(String),
+}
+
+impl MaskStyle {
+    // Mask the string representation of a value.
+    pub(crate) fn mask(&self, text: &str) -> String {
+        match self {
+            MaskStyle::Hash => {
+                let hash = text.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+                    (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+                });
+
+                format!("{hash:016x}")
+            }
+            MaskStyle::Partial(keep) => {
+                let num_chars = text.chars().count();
+                let num_masked = num_chars.saturating_sub(*keep);
+
+                text.chars()
+                    .enumerate()
+                    .map(|(index, char)| if index < num_masked { '*' } else { char })
+                    .collect()
+            }
+            MaskStyle::Replace(replacement) => replacement.clone(),
+        }
+    }
+}
+
+/// The cell sizes for a column of images.
+///
+/// Used with [`PolarsXlsxWriter::set_column_as_images()`] to set the height of
+/// the data rows and the width of the column that images are embedded in.
+/// Excel scales embedded images to fit the cell, maintaining the aspect ratio,
+/// so these sizes set the displayed size of the images.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ImageOptions {
+    pub(crate) row_height: Option<u16>,
+    pub(crate) column_width: Option<u16>,
+}
+
+impl Default for ImageOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ImageOptions {
+    /// Create a new `ImageOptions` struct.
+    ///
+    /// The default row height and column width are both 60 pixels.
+    ///
+    pub fn new() -> ImageOptions {
+        ImageOptions {
+            row_height: Some(60),
+            column_width: Some(60),
+        }
+    }
+
+    /// Set the height of the data rows in pixels.
+    ///
+    /// The height is applied to all the data rows of the output table. Heights
+    /// set via [`PolarsXlsxWriter::set_row_height()`] take precedence and
+    /// heights set via [`PolarsXlsxWriter::set_row_height_all()`] are only
+    /// overridden if they are smaller.
+    ///
+    /// # Parameters
+    ///
+    /// * `height` - The row height in pixels.
+    ///
+    pub fn set_row_height(mut self, height: u16) -> ImageOptions {
+        self.row_height = Some(height);
+        self
+    }
+
+    /// Set the width of the image column in pixels.
+    ///
+    /// Widths set via [`PolarsXlsxWriter::set_column_width()`] take
+    /// precedence.
+    ///
+    /// # Parameters
+    ///
+    /// * `width` - The column width in pixels.
+    ///
+    pub fn set_column_width(mut self, width: u16) -> ImageOptions {
+        self.column_width = Some(width);
+        self
+    }
+
+    /// Keep the existing row heights and column width.
+    ///
+    /// Turn off the resizing of the data rows and the image column so that the
+    /// images are scaled to the default or user defined cell sizes.
+    ///
+    pub fn set_default_cell_size(mut self) -> ImageOptions {
+        self.row_height = None;
+        self.column_width = None;
+        self
+    }
+}
+
+/// A set of format properties that are combined with the format of a column.
+///
+/// Used with [`PolarsXlsxWriter::set_column_format()`] to set properties such
+/// as the font or fill of a dataframe column. Unlike a `rust_xlsxwriter`
+/// [`Format`], only the properties that are set are applied, and they are
+/// combined with the other formats that apply to the column data.
+///
+/// The formats for the column data are combined in the following order, with
+/// the properties of later formats taking precedence:
+///
+/// 1. The default format for the data type, such as the format set via
+///    [`PolarsXlsxWriter::set_date_format()`].
+/// 2. The format for the data type set via
+///    [`PolarsXlsxWriter::set_dtype_format()`] or
+///    [`PolarsXlsxWriter::set_format_for_dtypes()`], or the format for a
+///    matching column name set via [`PolarsXlsxWriter::set_format_matching()`]
+///    which takes precedence. This replaces the default format, apart from
+///    the number format which is kept if the data type format doesn't have
+///    one.
+/// 3. The font and fill for the data type set via
+///    [`PolarsXlsxWriter::set_dtype_font()`] and
+///    [`PolarsXlsxWriter::set_dtype_fill()`].
+/// 4. The `ColumnFormat` for the column.
+/// 5. The alignment set via [`PolarsXlsxWriter::set_column_alignment()`].
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ColumnFormat {
+    pub(crate) num_format: Option<String>,
+    pub(crate) bold: bool,
+    pub(crate) italic: bool,
+    pub(crate) text_wrap: bool,
+    pub(crate) font_name: Option<String>,
+    pub(crate) font_size: Option<f64>,
+    pub(crate) font_color: Option<Color>,
+    pub(crate) background_color: Option<Color>,
+}
+
+impl ColumnFormat {
+    /// Create a new `ColumnFormat` struct without any properties.
+    ///
+    pub fn new() -> ColumnFormat {
+        ColumnFormat::default()
+    }
+
+    /// Set the number format, such as `"0.00"` or `"dd/mm/yyyy"`.
+    ///
+    /// # Parameters
+    ///
+    /// * `num_format` - An Excel number format string.
+    ///
+    pub fn set_num_format(mut self, num_format: impl Into<String>) -> ColumnFormat {
+        self.num_format = Some(num_format.into());
+        self
+    }
+
+    /// Set the bold property of the font.
+    ///
+    pub fn set_bold(mut self) -> ColumnFormat {
+        self.bold = true;
+        self
+    }
+
+    /// Set the italic property of the font.
+    ///
+    pub fn set_italic(mut self) -> ColumnFormat {
+        self.italic = true;
+        self
+    }
+
+    /// Turn on text wrapping for the cells.
+    ///
+    pub fn set_text_wrap(mut self) -> ColumnFormat {
+        self.text_wrap = true;
+        self
+    }
+
+    /// Set the font name, such as `"Arial"`.
+    ///
+    /// # Parameters
+    ///
+    /// * `font_name` - The font name.
+    ///
+    pub fn set_font_name(mut self, font_name: impl Into<String>) -> ColumnFormat {
+        self.font_name = Some(font_name.into());
+        self
+    }
+
+    /// Set the font size.
+    ///
+    /// # Parameters
+    ///
+    /// * `font_size` - The font size in points.
+    ///
+    pub fn set_font_size(mut self, font_size: impl Into<f64>) -> ColumnFormat {
+        self.font_size = Some(font_size.into());
+        self
+    }
+
+    /// Set the font color.
+    ///
+    /// # Parameters
+    ///
+    /// * `color` - A `rust_xlsxwriter` [`Color`] or a type that can be
+    ///   converted to a `Color`, such as an HTML style `"#RRGGBB"` string.
+    ///
+    pub fn set_font_color(mut self, color: impl Into<Color>) -> ColumnFormat {
+        self.font_color = Some(color.into());
+        self
+    }
+
+    /// Set the background color of a solid cell fill.
+    ///
+    /// # Parameters
+    ///
+    /// * `color` - A `rust_xlsxwriter` [`Color`] or a type that can be
+    ///   converted to a `Color`, such as an HTML style `"#RRGGBB"` string.
+    ///
+    pub fn set_background_color(mut self, color: impl Into<Color>) -> ColumnFormat {
+        self.background_color = Some(color.into());
+        self
+    }
+
+    // Apply the properties that have been set to a format.
+    pub(crate) fn apply(&self, mut format: Format) -> Format {
+        if let Some(num_format) = &self.num_format {
+            format = format.set_num_format(num_format);
+        }
+        if self.bold {
+            format = format.set_bold();
+        }
+        if self.italic {
+            format = format.set_italic();
+        }
+        if self.text_wrap {
+            format = format.set_text_wrap();
+        }
+        if let Some(font_name) = &self.font_name {
+            format = format.set_font_name(font_name);
+        }
+        if let Some(font_size) = self.font_size {
+            format = format.set_font_size(font_size);
+        }
+        if let Some(color) = self.font_color {
+            format = format.set_font_color(color);
+        }
+        if let Some(color) = self.background_color {
+            format = format.set_background_color(color);
+        }
+
+        format
+    }
+}
+
+/// The format for the data of a Polars data type or of a group of columns.
+///
+/// `CellFormat` is used by the methods that set a format for the data of
+/// several columns, such as [`PolarsXlsxWriter::set_float_format()`] or
+/// [`PolarsXlsxWriter::set_dtype_format()`]. It is usually created implicitly
+/// from an Excel number format string, such as `"#,##0.00"`, or from a
+/// `rust_xlsxwriter` [`Format`].
+///
+/// The properties of a `rust_xlsxwriter` [`Format`] can't be read so the
+/// number format is stored alongside the format, if it is known. This is the
+/// case for number format strings, for formats created with
+/// [`CellFormat::new()`] and for a `Format` without a number format. The
+/// number format of any other `Format` isn't known, so it isn't used to
+/// estimate column widths via
+/// [`PolarsXlsxWriter::set_autofit_with_formats()`] or inherited by formats
+/// that don't have a number format. Use [`CellFormat::new()`] to set other
+/// format properties together with a number format.
+///
+/// # Examples
+///
+/// ```
+/// # use polars::prelude::*;
+/// # use polars_excel_writer::{CellFormat, PolarsXlsxWriter};
+/// # use rust_xlsxwriter::Format;
+/// #
+/// let mut xlsx_writer = PolarsXlsxWriter::new();
+///
+/// // A number format string.
+/// xlsx_writer.set_float_format("#,##0.00");
+///
+/// // A format with other properties and a number format.
+/// let format = CellFormat::new(&Format::new().set_bold(), "yyyy-mm-dd");
+/// xlsx_writer.set_date_format(format);
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct CellFormat {
+    pub(crate) format: Format,
+    pub(crate) num_format: Option<String>,
+}
+
+impl CellFormat {
+    /// Create a new `CellFormat` from a `rust_xlsxwriter` [`Format`] and an
+    /// Excel number format.
+    ///
+    /// The number format replaces any number format set in the `Format`.
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - A `rust_xlsxwriter` [`Format`] reference.
+    /// * `num_format` - An Excel number format string, such as `"#,##0.00"`.
+    ///
+    pub fn new(format: &Format, num_format: impl Into<String>) -> CellFormat {
+        let num_format = num_format.into();
+
+        CellFormat {
+            format: format.clone().set_num_format(&num_format),
+            num_format: Some(num_format),
         }
+    }
+}
 
-        let worksheet = self.workbook.worksheet_from_index(last_index)?;
+impl Default for CellFormat {
+    fn default() -> CellFormat {
+        CellFormat::from(Format::new())
+    }
+}
 
-        Ok(worksheet)
+impl From<Format> for CellFormat {
+    fn from(format: Format) -> CellFormat {
+        let num_format = if PolarsXlsxWriter::has_num_format(&format) {
+            None
+        } else {
+            Some(String::new())
+        };
+
+        CellFormat { format, num_format }
     }
+}
 
-    // -----------------------------------------------------------------------
-    // Internal functions/methods.
-    // -----------------------------------------------------------------------
+impl From<&Format> for CellFormat {
+    fn from(format: &Format) -> CellFormat {
+        CellFormat::from(format.clone())
+    }
+}
 
-    // Method to support writing to ExcelWriter writer<W>.
-    pub(crate) fn save_to_writer<W>(&mut self, df: &DataFrame, writer: W) -> PolarsResult<()>
-    where
-        W: Write + Seek + Send,
-    {
-        let options = self.options.clone();
-        let worksheet = self.worksheet()?;
+impl From<&str> for CellFormat {
+    fn from(num_format: &str) -> CellFormat {
+        CellFormat::new(&Format::new(), num_format)
+    }
+}
 
-        Self::write_dataframe_internal(df, worksheet, 0, 0, &options)?;
+impl From<&String> for CellFormat {
+    fn from(num_format: &String) -> CellFormat {
+        CellFormat::new(&Format::new(), num_format)
+    }
+}
 
-        self.workbook.save_to_writer(writer)?;
+impl From<String> for CellFormat {
+    fn from(num_format: String) -> CellFormat {
+        CellFormat::new(&Format::new(), num_format)
+    }
+}
 
-        Ok(())
+/// A set of font properties for the columns of a data type.
+///
+/// Used with [`PolarsXlsxWriter::set_dtype_font()`] to set the font of all the
+/// columns with a Polars data type. Only the properties that are set are
+/// applied, in the same way as a [`ColumnFormat`].
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FontOptions {
+    pub(crate) bold: bool,
+    pub(crate) italic: bool,
+    pub(crate) name: Option<String>,
+    pub(crate) size: Option<f64>,
+    pub(crate) color: Option<Color>,
+}
+
+impl FontOptions {
+    /// Create a new `FontOptions` struct without any properties.
+    ///
+    pub fn new() -> FontOptions {
+        FontOptions::default()
     }
 
-    // Write the dataframe to a `rust_xlsxwriter` Worksheet. It is structured as
-    // an associated method to allow it to handle external worksheets.
-    #[allow(clippy::too_many_lines)]
-    fn write_dataframe_internal(
-        df: &DataFrame,
-        worksheet: &mut Worksheet,
-        row_offset: u32,
-        col_offset: u16,
-        options: &WriterOptions,
-    ) -> Result<(), PolarsError> {
-        let header_offset = u32::from(options.table.has_header_row());
+    /// Set the bold property of the font.
+    ///
+    pub fn set_bold(mut self) -> FontOptions {
+        self.bold = true;
+        self
+    }
 
-        // Set NaN and Infinity values, if required.
-        if let Some(nan_value) = &options.nan_value {
-            worksheet.set_nan_value(nan_value);
-        }
-        if let Some(infinity_value) = &options.infinity_value {
-            worksheet.set_infinity_value(infinity_value);
-        }
-        if let Some(neg_infinity_value) = &options.neg_infinity_value {
-            worksheet.set_neg_infinity_value(neg_infinity_value);
-        }
+    /// Set the italic property of the font.
+    ///
+    pub fn set_italic(mut self) -> FontOptions {
+        self.italic = true;
+        self
+    }
 
-        // Iterate through the dataframe column by column.
-        for (col_num, column) in df.get_columns().iter().enumerate() {
-            let col_num = col_offset + col_num as u16;
+    /// Set the font name, such as `"Arial"`.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The font name.
+    ///
+    pub fn set_name(mut self, name: impl Into<String>) -> FontOptions {
+        self.name = Some(name.into());
+        self
+    }
 
-            // Store the column names for use as table headers.
-            if options.table.has_header_row() {
-                worksheet.write(row_offset, col_num, column.name().as_str())?;
-            }
+    /// Set the font size.
+    ///
+    /// # Parameters
+    ///
+    /// * `size` - The font size in points.
+    ///
+    pub fn set_size(mut self, size: impl Into<f64>) -> FontOptions {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Set the font color.
+    ///
+    /// # Parameters
+    ///
+    /// * `color` - A `rust_xlsxwriter` [`Color`] or a type that can be
+    ///   converted to a `Color`, such as an HTML style `"#RRGGBB"` string.
+    ///
+    pub fn set_color(mut self, color: impl Into<Color>) -> FontOptions {
+        self.color = Some(color.into());
+        self
+    }
 
-            // Write the row data for each column/type.
-            for (row_num, data) in column.as_materialized_series().iter().enumerate() {
-                let row_num = header_offset + row_offset + row_num as u32;
+    // Add the font properties that have been set to a column format.
+    pub(crate) fn apply(&self, style: &mut ColumnFormat) {
+        style.bold |= self.bold;
+        style.italic |= self.italic;
 
-                // Map the Polars Series AnyValue types to Excel/rust_xlsxwriter
-                // types.
-                match data {
-                    AnyValue::Int8(value) => {
-                        worksheet.write_number(row_num, col_num, value)?;
-                    }
-                    AnyValue::UInt8(value) => {
-                        worksheet.write_number(row_num, col_num, value)?;
-                    }
-                    AnyValue::Int16(value) => {
-                        worksheet.write_number(row_num, col_num, value)?;
-                    }
-                    AnyValue::UInt16(value) => {
-                        worksheet.write_number(row_num, col_num, value)?;
-                    }
-                    AnyValue::Int32(value) => {
-                        worksheet.write_number(row_num, col_num, value)?;
-                    }
-                    AnyValue::UInt32(value) => {
-                        worksheet.write_number(row_num, col_num, value)?;
-                    }
-                    AnyValue::Int64(value) => {
-                        // Allow u64 conversion within Excel's limits.
-                        #[allow(clippy::cast_precision_loss)]
-                        worksheet.write_number(row_num, col_num, value as f64)?;
-                    }
-                    AnyValue::UInt64(value) => {
-                        // Allow u64 conversion within Excel's limits.
-                        #[allow(clippy::cast_precision_loss)]
-                        worksheet.write_number(row_num, col_num, value as f64)?;
-                    }
-                    AnyValue::Float32(value) => {
-                        worksheet.write_number_with_format(
-                            row_num,
-                            col_num,
-                            value,
-                            &options.float_format,
-                        )?;
-                    }
-                    AnyValue::Float64(value) => {
-                        worksheet.write_number_with_format(
-                            row_num,
-                            col_num,
-                            value,
-                            &options.float_format,
-                        )?;
-                    }
-                    AnyValue::String(value) => {
-                        worksheet.write_string(row_num, col_num, value)?;
-                    }
-                    AnyValue::StringOwned(value) => {
-                        worksheet.write_string(row_num, col_num, value.as_str())?;
-                    }
-                    AnyValue::Boolean(value) => {
-                        worksheet.write_boolean(row_num, col_num, value)?;
-                    }
-                    AnyValue::Null => {
-                        if let Some(null_string) = &options.null_value {
-                            worksheet.write_string(row_num, col_num, null_string)?;
-                        }
-                    }
-                    AnyValue::Datetime(value, time_units, _) => {
-                        let datetime = match time_units {
-                            TimeUnit::Nanoseconds => timestamp_ns_to_datetime(value),
-                            TimeUnit::Microseconds => timestamp_us_to_datetime(value),
-                            TimeUnit::Milliseconds => timestamp_ms_to_datetime(value),
-                        };
-                        worksheet.write_datetime_with_format(
-                            row_num,
-                            col_num,
-                            datetime,
-                            &options.datetime_format,
-                        )?;
-                        worksheet.set_column_width(col_num, 18)?;
-                    }
-                    AnyValue::Date(value) => {
-                        let date = date32_to_date(value);
-                        worksheet.write_datetime_with_format(
-                            row_num,
-                            col_num,
-                            date,
-                            &options.date_format,
-                        )?;
-                        worksheet.set_column_width(col_num, 10)?;
-                    }
-                    AnyValue::Time(value) => {
-                        let time = time64ns_to_time(value);
-                        worksheet.write_datetime_with_format(
-                            row_num,
-                            col_num,
-                            time,
-                            &options.time_format,
-                        )?;
-                    }
-                    _ => {
-                        polars_bail!(
-                            ComputeError:
-                            "Polars AnyValue data type '{}' is not supported by Excel",
-                            data.dtype()
-                        );
-                    }
-                }
-            }
+        if let Some(name) = &self.name {
+            style.font_name = Some(name.clone());
         }
-
-        // Create a table for the dataframe range.
-        let (mut max_row, max_col) = df.shape();
-        if !options.table.has_header_row() {
-            max_row -= 1;
+        if let Some(size) = self.size {
+            style.font_size = Some(size);
         }
-        if options.table.has_total_row() {
-            max_row += 1;
+        if let Some(color) = self.color {
+            style.font_color = Some(color);
         }
+    }
+}
 
-        // Add the table to the worksheet.
-        worksheet.add_table(
-            row_offset,
-            col_offset,
-            row_offset + max_row as u32,
-            col_offset + max_col as u16 - 1,
-            &options.table,
-        )?;
+/// The cell range that a dataframe was written to.
+///
+/// `WrittenRange` is returned by the `write_dataframe*()` methods such as
+/// [`PolarsXlsxWriter::write_dataframe()`]. It can be used to refer to the
+/// exact output area of the dataframe in charts, conditional formats or
+/// defined names without having to recalculate it from the dataframe shape.
+///
+/// The range covers the full output table, including the header row, if
+/// present, any total row, and any inserted or expanded columns. If the
+/// dataframe was split across worksheets or files by the overflow policy it is
+/// the range in the first worksheet.
+///
+/// # Examples
+///
+/// An example of writing a Polar Rust dataframe to an Excel file. This
+/// demonstrates using the range returned by `write_dataframe()` to add a chart
+/// that plots the data.
+///
+/// ```
+/// # // This code is available in examples/write_excel_written_range.rs
+/// #
+/// # use polars::prelude::*;
+/// #
+/// use polars_excel_writer::PolarsXlsxWriter;
+/// use rust_xlsxwriter::{Chart, ChartType};
+///
+/// fn main() -> PolarsResult<()> {
+///     // Create a sample dataframe for the example.
+///     let df: DataFrame = df!(
+///         "Region" => &["North", "South", "East", "West"],
+///         "Sales" => &[1000, 2300, 1900, 2500],
+///     )?;
+///
+///     // Write the dataframe to an Excel file.
+///     let mut xlsx_writer = PolarsXlsxWriter::new();
+///
+///     // Write the dataframe and get the range that it was written to.
+///     let range = xlsx_writer.write_dataframe(&df)?;
+///
+///     // Create a chart using the range of the data, below the header row.
+///     let sheet_name = range.sheet_name.as_str();
+///     let mut chart = Chart::new(ChartType::Column);
+///     chart
+///         .add_series()
+///         .set_categories((
+///             sheet_name,
+///             range.first_row + 1,
+///             range.first_col,
+///             range.last_row,
+///             range.first_col,
+///         ))
+///         .set_values((
+///             sheet_name,
+///             range.first_row + 1,
+///             range.first_col + 1,
+///             range.last_row,
+///             range.first_col + 1,
+///         ));
+///
+///     // Insert the chart to the right of the dataframe.
+///     let worksheet = xlsx_writer.worksheet()?;
+///     worksheet.insert_chart(0, range.last_col + 2, &chart)?;
+///
+///     // Save the file to disk.
+///     xlsx_writer.save("dataframe.xlsx")?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WrittenRange {
+    /// The name of the worksheet that the dataframe was written to.
+    pub sheet_name: String,
 
-        // Autofit the columns.
-        if options.use_autofit {
-            worksheet.autofit();
-        }
+    /// The zero indexed first row of the range.
+    pub first_row: u32,
 
-        // Set the zoom level.
-        worksheet.set_zoom(options.zoom);
+    /// The zero indexed first column of the range.
+    pub first_col: u16,
 
-        // Set the screen gridlines.
-        worksheet.set_screen_gridlines(options.screen_gridlines);
+    /// The zero indexed last row of the range.
+    pub last_row: u32,
 
-        // Set the worksheet panes.
-        worksheet.set_freeze_panes(options.freeze_cell.0, options.freeze_cell.1)?;
-        worksheet.set_freeze_panes_top_cell(options.top_cell.0, options.top_cell.1)?;
+    /// The zero indexed last column of the range.
+    pub last_col: u16,
 
-        Ok(())
-    }
+    /// The name of the worksheet table, if it was set via
+    /// [`PolarsXlsxWriter::set_table_name()`]. Otherwise the default name is
+    /// assigned by Excel when the file is saved.
+    pub table_name: Option<String>,
+}
+
+/// A warning for a writer option that was ignored when a dataframe was
+/// written.
+///
+/// Returned by [`PolarsXlsxWriter::warnings()`]. See that method for an
+/// example.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExportWarning {
+    /// The type of the warning.
+    pub kind: WarningKind,
+
+    /// The name of the worksheet that the dataframe was written to.
+    pub sheet_name: String,
+
+    /// The name of the dataframe column that the warning applies to, or the
+    /// pattern for a format set via
+    /// [`PolarsXlsxWriter::set_format_matching()`]. This is an empty string
+    /// for warnings that apply to all of the columns.
+    pub column_name: String,
+
+    /// A description of the warning.
+    pub message: String,
 }
 
 // -----------------------------------------------------------------------
 // Helper structs.
 // -----------------------------------------------------------------------
 
+// A struct to store the layout of the last dataframe written to a worksheet.
+#[derive(Clone)]
+pub(crate) struct WrittenLayout {
+    pub(crate) range: WrittenRange,
+    pub(crate) column_names: Vec<String>,
+    pub(crate) has_header: bool,
+    pub(crate) has_total_row: bool,
+}
+
+// A struct to store the dataframes appended to a worksheet table until the
+// table is written.
+pub(crate) struct AppendedFrame {
+    pub(crate) df: DataFrame,
+    pub(crate) options: WriterOptions,
+    pub(crate) sheet_index: usize,
+    pub(crate) row: u32,
+    pub(crate) col: u16,
+}
+
 // A struct for storing and passing configuration settings.
 #[derive(Clone)]
 pub(crate) struct WriterOptions {
     pub(crate) use_autofit: bool,
-    pub(crate) date_format: Format,
-    pub(crate) time_format: Format,
-    pub(crate) float_format: Format,
-    pub(crate) datetime_format: Format,
+    pub(crate) autofit_with_formats: bool,
+    pub(crate) auto_datetime_width: bool,
+    pub(crate) autofit_max_width: Option<f64>,
+    pub(crate) autofit_exclude: HashSet<String>,
+    pub(crate) is_autofit_deferred: bool,
+    pub(crate) date_format: CellFormat,
+    pub(crate) time_format: CellFormat,
+    pub(crate) float_format: CellFormat,
+    pub(crate) float_scientific: Option<(f64, String)>,
+    pub(crate) datetime_format: CellFormat,
+    pub(crate) duration_format: CellFormat,
+    pub(crate) temporal_converter: Arc<dyn TemporalToExcel>,
+    pub(crate) dtype_formats: HashMap<DataType, CellFormat>,
+    pub(crate) dtype_styles: HashMap<DataType, ColumnFormat>,
+    pub(crate) pattern_formats: Vec<(Regex, CellFormat)>,
     pub(crate) null_value: Option<String>,
+    pub(crate) null_format: Option<Format>,
+    pub(crate) boolean_values: BooleanValues,
+    pub(crate) list_delimiter: String,
+    pub(crate) expand_lists: bool,
     pub(crate) nan_value: Option<String>,
     pub(crate) infinity_value: Option<String>,
     pub(crate) neg_infinity_value: Option<String>,
+    pub(crate) nan_handling: NanHandling,
     pub(crate) table: Table,
+    pub(crate) table_columns: HashMap<String, TableColumn>,
+    pub(crate) use_table: bool,
+    pub(crate) use_autofilter: bool,
+    pub(crate) column_totals: HashMap<String, TableFunction>,
+    pub(crate) column_totals_all: Option<TableFunction>,
+    pub(crate) column_total_labels: HashMap<String, String>,
+    pub(crate) conditional_formats: Vec<(String, ColumnConditionalFormat)>,
+    pub(crate) heatmaps: Vec<(Vec<String>, ColorScale)>,
+    pub(crate) column_validations: Vec<(String, ColumnValidation)>,
+    pub(crate) diff_format: Format,
+    pub(crate) banded_row_formats: Option<(ColumnFormat, ColumnFormat)>,
+    pub(crate) diff_key_column: Option<String>,
+    pub(crate) filter_conditions: Vec<(String, FilterCondition)>,
+    pub(crate) sparkline_columns: Vec<SparklineColumn>,
+    pub(crate) formula_columns: Vec<FormulaColumn>,
     pub(crate) zoom: u16,
     pub(crate) screen_gridlines: bool,
+    pub(crate) tab_color: Option<Color>,
+    pub(crate) sheet_hidden: bool,
+    pub(crate) sheet_active: bool,
+    pub(crate) sheet_position: Option<usize>,
+    pub(crate) right_to_left: bool,
+    pub(crate) worksheet_protected: bool,
+    pub(crate) worksheet_password: Option<String>,
+    pub(crate) landscape: bool,
+    pub(crate) paper_size: u8,
+    pub(crate) print_area_to_table: bool,
+    pub(crate) repeat_header_row: bool,
+    pub(crate) fit_to_pages: Option<(u16, u16)>,
+    pub(crate) print_header: Option<String>,
+    pub(crate) print_footer: Option<String>,
     pub(crate) freeze_cell: (u32, u16),
     pub(crate) top_cell: (u32, u16),
+    pub(crate) selection: Option<(u32, u16)>,
+    pub(crate) overflow_policy: OverflowPolicy,
+    pub(crate) constant_memory: bool,
+    pub(crate) write_order: WriteOrder,
+    #[cfg(feature = "parallel")]
+    pub(crate) parallel: bool,
+    pub(crate) table_name: Option<String>,
+    pub(crate) defined_names_prefix: Option<String>,
+    pub(crate) column_widths: HashMap<String, ColumnWidth>,
+    pub(crate) hidden_columns: HashSet<String>,
+    pub(crate) column_alignments: HashMap<String, Vec<FormatAlign>>,
+    pub(crate) column_formats: HashMap<String, ColumnFormat>,
+    pub(crate) column_transforms: HashMap<String, ValueTransform>,
+    pub(crate) cell_styles: HashMap<String, CellStyleCallback>,
+    pub(crate) row_filter: Option<RowFilter>,
+    pub(crate) struct_handling: StructHandling,
+    pub(crate) binary_handling: BinaryHandling,
+    #[cfg(feature = "object")]
+    pub(crate) object_handling: ObjectHandling,
+    pub(crate) value_error_policy: ErrorPolicy,
+    pub(crate) large_int_handling: Option<LargeIntHandling>,
+    pub(crate) columns: Vec<String>,
+    pub(crate) column_headers: HashMap<String, String>,
+    pub(crate) duplicate_header_policy: DuplicateHeaderPolicy,
+    pub(crate) header_format: Option<Format>,
+    pub(crate) column_header_formats: HashMap<String, Format>,
+    pub(crate) header_split: Option<String>,
+    pub(crate) header_row_height: Option<u16>,
+    pub(crate) header_rotation: Option<i16>,
+    pub(crate) row_heights: HashMap<usize, u16>,
+    pub(crate) row_height_all: Option<u16>,
+    pub(crate) row_total_column: Option<RowTotalColumn>,
+    pub(crate) row_numbers: Option<(String, u32)>,
+    pub(crate) hyperlink_columns: HashSet<String>,
+    pub(crate) merge_repeated_columns: HashSet<String>,
+    pub(crate) row_grouping: Option<(String, bool)>,
+    pub(crate) hyperlink_pairs: HashMap<String, String>,
+    pub(crate) column_notes: HashMap<String, String>,
+    pub(crate) image_columns: HashMap<String, ImageOptions>,
 }
 
 impl Default for WriterOptions {
@@ -1840,22 +14556,350 @@ impl Default for WriterOptions {
 }
 
 impl WriterOptions {
+    #[allow(clippy::too_many_lines)]
     fn new() -> WriterOptions {
         WriterOptions {
             use_autofit: false,
+            autofit_with_formats: false,
+            auto_datetime_width: true,
+            autofit_max_width: None,
+            autofit_exclude: HashSet::new(),
+            is_autofit_deferred: false,
             time_format: "hh:mm:ss;@".into(),
             date_format: "yyyy\\-mm\\-dd;@".into(),
             datetime_format: "yyyy\\-mm\\-dd\\ hh:mm:ss".into(),
+            duration_format: "[h]:mm:ss".into(),
+            temporal_converter: Arc::new(DefaultTemporalConverter),
+            dtype_formats: HashMap::new(),
+            dtype_styles: HashMap::new(),
+            pattern_formats: vec![],
             null_value: None,
+            null_format: None,
+            boolean_values: BooleanValues::Native,
+            list_delimiter: ", ".to_string(),
+            expand_lists: false,
             nan_value: None,
             infinity_value: None,
             neg_infinity_value: None,
-            float_format: Format::default(),
+            nan_handling: NanHandling::Strings,
+            float_format: CellFormat::default(),
+            float_scientific: None,
             table: Table::new(),
+            table_columns: HashMap::new(),
+            use_table: true,
+            use_autofilter: true,
+            column_totals: HashMap::new(),
+            column_totals_all: None,
+            column_total_labels: HashMap::new(),
+            conditional_formats: vec![],
+            heatmaps: vec![],
+            column_validations: vec![],
+            diff_format: Format::new()
+                .set_font_color("9C5700")
+                .set_background_color("FFEB9C"),
+            diff_key_column: None,
+            banded_row_formats: None,
+            filter_conditions: vec![],
+            sparkline_columns: vec![],
+            formula_columns: vec![],
             zoom: 100,
             screen_gridlines: true,
+            tab_color: None,
+            sheet_hidden: false,
+            sheet_active: false,
+            sheet_position: None,
+            right_to_left: false,
+            worksheet_protected: false,
+            worksheet_password: None,
+            landscape: false,
+            paper_size: 0,
+            print_area_to_table: false,
+            repeat_header_row: false,
+            fit_to_pages: None,
+            print_header: None,
+            print_footer: None,
             freeze_cell: (0, 0),
             top_cell: (0, 0),
+            selection: None,
+            overflow_policy: OverflowPolicy::Error,
+            constant_memory: false,
+            write_order: WriteOrder::ColumnMajor,
+            #[cfg(feature = "parallel")]
+            parallel: false,
+            table_name: None,
+            defined_names_prefix: None,
+            column_widths: HashMap::new(),
+            hidden_columns: HashSet::new(),
+            column_alignments: HashMap::new(),
+            column_formats: HashMap::new(),
+            column_transforms: HashMap::new(),
+            cell_styles: HashMap::new(),
+            row_filter: None,
+            struct_handling: StructHandling::default(),
+            binary_handling: BinaryHandling::default(),
+            #[cfg(feature = "object")]
+            object_handling: ObjectHandling::default(),
+            value_error_policy: ErrorPolicy::default(),
+            large_int_handling: None,
+            columns: vec![],
+            column_headers: HashMap::new(),
+            duplicate_header_policy: DuplicateHeaderPolicy::default(),
+            header_format: None,
+            column_header_formats: HashMap::new(),
+            header_split: None,
+            header_row_height: None,
+            header_rotation: None,
+            row_heights: HashMap::new(),
+            row_height_all: None,
+            row_total_column: None,
+            row_numbers: None,
+            hyperlink_columns: HashSet::new(),
+            merge_repeated_columns: HashSet::new(),
+            row_grouping: None,
+            hyperlink_pairs: HashMap::new(),
+            column_notes: HashMap::new(),
+            image_columns: HashMap::new(),
+        }
+    }
+
+    // Set the table and header row options for a header mode.
+    pub(crate) fn set_header_mode(&mut self, header_mode: HeaderMode) {
+        let has_header = header_mode != HeaderMode::None;
+        self.table = self.table.clone().set_header_row(has_header);
+
+        match header_mode {
+            HeaderMode::TableHeader => self.use_table = true,
+            HeaderMode::WrittenHeaderRow => self.use_table = false,
+            HeaderMode::None => {}
+        }
+    }
+
+    // Get the user defined name of the output table, if any. The name is
+    // ignored if the data isn't written as a table.
+    pub(crate) fn output_table_name(&self) -> Option<&str> {
+        self.table_name.as_deref().filter(|_| self.use_table)
+    }
+}
+
+// A column in the output worksheet table. This is either a column from the
+// dataframe or a column inserted by the writer.
+pub(crate) enum OutputColumn<'a> {
+    Data(&'a Column),
+    ListElement(&'a Column, usize, String),
+    Sparkline(&'a SparklineColumn),
+    Formula(&'a FormulaColumn),
+    RowTotal(&'a RowTotalColumn),
+}
+
+impl<'a> OutputColumn<'a> {
+    // Get the name of the column, which is also used as the table header.
+    pub(crate) fn name(&self) -> &str {
+        match self {
+            OutputColumn::Data(column) => column.column_name(),
+            OutputColumn::ListElement(_, _, name) => name,
+            OutputColumn::Sparkline(sparkline_column) => &sparkline_column.name,
+            OutputColumn::Formula(formula_column) => &formula_column.name,
+            OutputColumn::RowTotal(row_total_column) => &row_total_column.name,
+        }
+    }
+
+    // Get the table header for the column, which is the user defined header,
+    // if any, or the column name.
+    pub(crate) fn header<'b>(&'b self, options: &'b WriterOptions) -> &'b str {
+        options
+            .column_headers
+            .get(self.name())
+            .map_or(self.name(), String::as_str)
+    }
+
+    // Get the dataframe column that supplies the data, if any.
+    pub(crate) fn column(&self) -> Option<&'a Column> {
+        match self {
+            OutputColumn::Data(column) | OutputColumn::ListElement(column, _, _) => Some(column),
+            OutputColumn::Sparkline(_) | OutputColumn::Formula(_) | OutputColumn::RowTotal(_) => {
+                None
+            }
+        }
+    }
+
+    // Get the user defined format for the column name pattern, if the name
+    // matches one, or for the column data type. For expanded list columns the
+    // type is the list element type. The most recently added matching pattern
+    // is used.
+    pub(crate) fn dtype_format<'b>(&self, options: &'b WriterOptions) -> Option<&'b CellFormat> {
+        let dtype = self.dtype()?;
+
+        options
+            .pattern_formats
+            .iter()
+            .rev()
+            .find(|(pattern, _)| pattern.is_match(self.name()))
+            .map(|(_, format)| format)
+            .or_else(|| options.dtype_formats.get(dtype))
+    }
+
+    // Get the data type of the column data. For expanded list columns this is
+    // the list element type.
+    pub(crate) fn dtype(&self) -> Option<&'a DataType> {
+        match self {
+            OutputColumn::Data(column) => Some(column.dtype()),
+            OutputColumn::ListElement(column, _, _) => column.dtype().inner_dtype(),
+            OutputColumn::Sparkline(_) | OutputColumn::Formula(_) | OutputColumn::RowTotal(_) => {
+                None
+            }
+        }
+    }
+
+    // Get the format for the column data. This combines, in order, the
+    // default format for the data type, the user defined format for the type,
+    // the font and fill for the type, the column format and the column
+    // alignment. Returns `None` if the data is written with the default format
+    // for the type.
+    pub(crate) fn column_format<'b>(&self, options: &'b WriterOptions) -> Option<Cow<'b, Format>> {
+        let dtype = self.dtype()?;
+        let dtype_format = self.dtype_format(options);
+        let dtype_style = options.dtype_styles.get(dtype);
+        let column_format = options.column_formats.get(self.name());
+        let alignments = options.column_alignments.get(self.name());
+
+        // Formats without a number format keep the default number format for
+        // the type, if it is known.
+        let inherited_num_format = dtype_format
+            .filter(|format| format.num_format.as_deref() == Some(""))
+            .and_then(|_| PolarsXlsxWriter::default_num_format(dtype, options))
+            .filter(|num_format| !num_format.is_empty());
+
+        if column_format.is_none()
+            && alignments.is_none()
+            && dtype_style.is_none()
+            && inherited_num_format.is_none()
+        {
+            return dtype_format.map(|format| Cow::Borrowed(&format.format));
+        }
+
+        let mut format = match (dtype_format, inherited_num_format) {
+            (Some(format), Some(num_format)) => format.format.clone().set_num_format(num_format),
+            (Some(format), None) => format.format.clone(),
+            (None, _) => PolarsXlsxWriter::default_dtype_format(dtype, options),
+        };
+
+        if let Some(dtype_style) = dtype_style {
+            format = dtype_style.apply(format);
+        }
+
+        if let Some(column_format) = column_format {
+            format = column_format.apply(format);
+        }
+
+        if let Some(alignments) = alignments {
+            format = alignments
+                .iter()
+                .fold(format, |format, align| format.set_align(*align));
+        }
+
+        Some(Cow::Owned(format))
+    }
+
+    // Get the formats for the even and odd data rows of the column, if banded
+    // row formats are set. The band properties are applied to the column
+    // format, or to the default format for the type.
+    pub(crate) fn banded_formats(&self, options: &WriterOptions) -> Option<[Format; 2]> {
+        let (even, odd) = options.banded_row_formats.as_ref()?;
+        let dtype = self.dtype()?;
+
+        let format = self.column_format(options).map_or_else(
+            || PolarsXlsxWriter::default_dtype_format(dtype, options),
+            Cow::into_owned,
+        );
+
+        Some([even.apply(format.clone()), odd.apply(format)])
+    }
+
+    // Get the number format of the column data, in the same order of
+    // precedence as `column_format()`. An empty string is the Excel General
+    // format. Returns `None` if the number format isn't known, see
+    // `CellFormat`.
+    pub(crate) fn num_format(&self, options: &WriterOptions) -> Option<String> {
+        let dtype = self.dtype()?;
+
+        let column_num_format = options
+            .column_formats
+            .get(self.name())
+            .and_then(|format| format.num_format.as_ref())
+            .or_else(|| {
+                options
+                    .dtype_styles
+                    .get(dtype)
+                    .and_then(|style| style.num_format.as_ref())
+            });
+
+        if let Some(num_format) = column_num_format {
+            return Some(num_format.clone());
+        }
+
+        match self.dtype_format(options) {
+            Some(format) if format.num_format.as_deref() != Some("") => format.num_format.clone(),
+            _ => PolarsXlsxWriter::default_num_format(dtype, options),
         }
     }
 }
+
+// The output representation of Polars boolean values.
+#[derive(Clone)]
+pub(crate) enum BooleanValues {
+    Native,
+    Numbers,
+    Strings(String, String),
+}
+
+// A user defined column width in character or pixel units.
+#[derive(Clone, Copy)]
+pub(crate) enum ColumnWidth {
+    Characters(f64),
+    Pixels(u16),
+}
+
+// A function that transforms the values of a column before they are written.
+pub(crate) type ValueTransform = Arc<dyn Fn(AnyValue) -> AnyValue + Send + Sync>;
+
+// A duplicate table header that was renamed, as the column name, the original
+// header and the new header.
+pub(crate) type RenamedHeader = (String, String, String);
+
+// A function that selects the rows of a dataframe that are written.
+pub(crate) type RowFilter = Arc<dyn Fn(usize, &[AnyValue]) -> bool + Send + Sync>;
+
+// A function that computes the format of a cell from its value.
+pub(crate) type CellStyleCallback = Arc<dyn Fn(&AnyValue) -> Option<Format> + Send + Sync>;
+
+// A struct to store the configuration of an inserted sparkline column.
+#[derive(Clone)]
+pub(crate) struct SparklineColumn {
+    pub(crate) name: String,
+    pub(crate) source_columns: Vec<String>,
+    pub(crate) sparkline: Sparkline,
+    pub(crate) position: ColumnPosition,
+}
+
+// A struct to store the configuration of an inserted formula column.
+#[derive(Clone)]
+pub(crate) struct FormulaColumn {
+    pub(crate) name: String,
+    pub(crate) formula: Formula,
+    pub(crate) position: ColumnPosition,
+}
+
+// A struct to store the configuration of an appended row total column.
+#[derive(Clone)]
+pub(crate) struct RowTotalColumn {
+    pub(crate) name: String,
+    pub(crate) source_columns: Vec<String>,
+}
+
+// The data validation for a column. This is either a user defined validation
+// or a dropdown list created from the categories of a Categorical column.
+#[derive(Clone)]
+pub(crate) enum ColumnValidation {
+    Rule(DataValidation),
+    Categories,
+}
@@ -8,7 +8,7 @@
 use crate::common;
 
 use polars::prelude::*;
-use polars_excel_writer::{ExcelWriter, PolarsXlsxWriter};
+use polars_excel_writer::{ExcelWriter, PolarsXlsxWriter, WriteOrder};
 use rust_xlsxwriter::{Workbook, XlsxError};
 
 // Compare output against target Excel file using ExcelWriter.
@@ -76,6 +76,27 @@ fn create_new_xlsx_file_4(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Compare using write_dataframe_to_worksheet() with the data written in row
+// order.
+fn create_new_xlsx_file_5(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Foo" => &[1, 1, 1],
+        "Bar" => &[2, 2, 2],
+    )?;
+
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_write_order(WriteOrder::RowMajor);
+
+    xlsx_writer.write_dataframe_to_worksheet(&df, worksheet, 0, 0)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn dataframe_excelwriter01() {
     let test_runner = common::TestRunner::new()
@@ -123,3 +144,15 @@ fn dataframe_from_csv01() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn dataframe_to_worksheet01_5() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe01")
+        .set_function(create_new_xlsx_file_5)
+        .unique("5")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
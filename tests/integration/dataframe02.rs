@@ -8,7 +8,7 @@
 use crate::common;
 
 use polars::prelude::*;
-use polars_excel_writer::{ExcelWriter, PolarsXlsxWriter};
+use polars_excel_writer::{ExcelWriter, PolarsXlsxWriter, WriteOrder};
 use rust_xlsxwriter::{Table, XlsxError};
 
 // Compare output against target Excel file using ExcelWriter.
@@ -64,6 +64,24 @@ fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Compare using PolarsXlsxWriter with the data written in row order.
+fn create_new_xlsx_file_4(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Column1" => &["Foo", "Foo", "Foo"],
+        "Column2" => &["Bar", "Bar", "Bar"],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_header(false);
+    xlsx_writer.set_autofit(true);
+    xlsx_writer.set_write_order(WriteOrder::RowMajor);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn dataframe_excelwriter02() {
     let test_runner = common::TestRunner::new()
@@ -99,3 +117,15 @@ fn dataframe_write_excel02_3() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn dataframe_write_excel02_4() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe02")
+        .set_function(create_new_xlsx_file_4)
+        .unique("4")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
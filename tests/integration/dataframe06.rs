@@ -44,6 +44,24 @@ fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Get the workbook and set the name via the worksheet.
+fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Foo" => &[1, 1, 1],
+        "Bar" => &[2, 2, 2],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    let workbook = xlsx_writer.workbook();
+    workbook.worksheet_from_index(0)?.set_name("Data")?;
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn dataframe_write_excel06_1() {
     let test_runner = common::TestRunner::new()
@@ -67,3 +85,15 @@ fn dataframe_write_excel06_2() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn dataframe_write_excel06_3() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe06")
+        .set_function(create_new_xlsx_file_3)
+        .unique("3")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
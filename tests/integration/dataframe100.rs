@@ -0,0 +1,112 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter. Check the
+// adjusted worksheet names.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    let names = [
+        "Acme: North/South",
+        "ACME: NORTH/SOUTH",
+        "Smith & Sons [Wholesale Division]",
+        "History",
+    ];
+
+    for (index, name) in names.iter().enumerate() {
+        if index > 0 {
+            xlsx_writer.add_worksheet();
+        }
+
+        xlsx_writer.set_worksheet_name_lossy(name)?;
+        xlsx_writer.write_dataframe(&df)?;
+    }
+
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare with the names set explicitly.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    let names = [
+        "Acme_ North_South",
+        "ACME_ NORTH_SOUTH (2)",
+        "Smith & Sons _Wholesale Divisio",
+        "History (2)",
+    ];
+
+    for (index, name) in names.iter().enumerate() {
+        if index > 0 {
+            xlsx_writer.add_worksheet();
+        }
+
+        xlsx_writer.set_worksheet_name(*name)?;
+        xlsx_writer.write_dataframe(&df)?;
+    }
+
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Quarter" => &["Q1", "Q2"],
+        "Sales" => &[1000, 1200],
+    )
+}
+
+#[test]
+fn dataframe_write_excel100_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe100")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel100_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe100")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel100_rename() {
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // The current worksheet name can be set again.
+    xlsx_writer.set_worksheet_name_lossy("Data").unwrap();
+    xlsx_writer.set_worksheet_name_lossy("data").unwrap();
+    assert_eq!(xlsx_writer.worksheet().unwrap().name(), "data");
+
+    xlsx_writer.add_worksheet();
+    xlsx_writer.set_worksheet_name_lossy("''").unwrap();
+    assert_eq!(xlsx_writer.worksheet().unwrap().name(), "_");
+}
@@ -0,0 +1,121 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{Format, Url, Worksheet, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.add_table_of_contents("Contents")?;
+
+    write_dataframes(&mut xlsx_writer)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare with the table of contents written directly.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    write_dataframes(&mut xlsx_writer)?;
+
+    let mut worksheet = Worksheet::new();
+    worksheet.set_name("Contents")?;
+
+    let bold = Format::new().set_bold();
+    worksheet.write_with_format(0, 0, "Sheet", &bold)?;
+    worksheet.write_with_format(0, 1, "Range", &bold)?;
+    worksheet.write_with_format(0, 2, "Rows", &bold)?;
+    worksheet.write_with_format(0, 3, "Columns", &bold)?;
+
+    worksheet.write_url(1, 0, Url::new("internal:Sales!A1").set_text("Sales"))?;
+    worksheet.write(1, 1, "A1:B5")?;
+    worksheet.write(1, 2, 4)?;
+    worksheet.write(1, 3, 2)?;
+
+    worksheet.write_url(2, 0, Url::new("internal:Sales!E1").set_text("Sales"))?;
+    worksheet.write(2, 1, "E1:G3")?;
+    worksheet.write(2, 2, 2)?;
+    worksheet.write(2, 3, 3)?;
+
+    worksheet.write_url(
+        3,
+        0,
+        Url::new("internal:'Product List'!B3").set_text("Product List"),
+    )?;
+    worksheet.write(3, 1, "B3:D5")?;
+    worksheet.write(3, 2, 2)?;
+    worksheet.write(3, 3, 3)?;
+
+    worksheet.autofit();
+
+    xlsx_writer.workbook().worksheets_mut().insert(0, worksheet);
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Write the dataframes that are listed in the table of contents.
+fn write_dataframes(xlsx_writer: &mut PolarsXlsxWriter) -> Result<(), XlsxError> {
+    let df1: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000, 2300, 1900, 2500],
+    )?;
+
+    let df2: DataFrame = df!(
+        "Product" => &["Apple", "Pear"],
+        "Units" => &[120, 80],
+        "Price" => &[1.2, 0.9],
+    )?;
+
+    xlsx_writer.set_worksheet_name("Sales")?;
+    xlsx_writer.write_dataframe(&df1)?;
+    xlsx_writer.write_dataframe_to_cell(&df2, 0, 4)?;
+
+    xlsx_writer.add_worksheet();
+    xlsx_writer.set_worksheet_name("Product List")?;
+    xlsx_writer.write_dataframe_to_cell(&df2, 2, 1)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel101_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe101")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel101_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe101")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel101_invalid_name() {
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    let result = xlsx_writer.add_table_of_contents("Contents/Index");
+    assert!(matches!(result, Err(PolarsError::ComputeError(_))));
+}
@@ -0,0 +1,81 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{Format, XlsxError};
+
+// Compare output against target Excel file using the SerWriter interface.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let mut df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    set_options(&mut xlsx_writer)?;
+
+    let mut file = std::fs::File::create(filename)?;
+
+    xlsx_writer.into_ser_writer(&mut file).finish(&mut df)?;
+
+    Ok(())
+}
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    set_options(&mut xlsx_writer)?;
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Set options that aren't supported by the ExcelWriter methods.
+fn set_options(xlsx_writer: &mut PolarsXlsxWriter) -> Result<(), XlsxError> {
+    xlsx_writer
+        .set_header_format(Format::new().set_bold().set_font_color("#1F497D"))
+        .set_dtype_format(DataType::Float64, Format::new().set_num_format("#,##0.00"))
+        .set_zoom(150)
+        .add_table_of_contents("Contents")?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000.0, 2300.0, 1900.0, 2500.0],
+    )
+}
+
+#[test]
+fn dataframe_write_excel102_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe102")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel102_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe102")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
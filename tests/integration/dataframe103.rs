@@ -0,0 +1,95 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use std::io::{Cursor, Read};
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{DocProperties, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_deterministic(true);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000, 2300, 1900, 2500],
+    )
+}
+
+// Get the document properties part of an xlsx file.
+fn core_properties(buffer: Vec<u8>) -> String {
+    let mut xlsx_zip = ::zip::ZipArchive::new(Cursor::new(buffer)).unwrap();
+    let mut core = String::new();
+
+    xlsx_zip
+        .by_name("docProps/core.xml")
+        .unwrap()
+        .read_to_string(&mut core)
+        .unwrap();
+
+    core
+}
+
+#[test]
+fn dataframe_write_excel103_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe103")
+        .set_function(create_new_xlsx_file_1)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel103_creation_time() {
+    let df = dataframe().unwrap();
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_deterministic(true);
+    xlsx_writer.set_properties(&DocProperties::new().set_title("Sales"));
+
+    xlsx_writer.write_dataframe(&df).unwrap();
+    let core = core_properties(xlsx_writer.save_to_buffer().unwrap());
+
+    assert!(core.contains(
+        r#"<dcterms:created xsi:type="dcterms:W3CDTF">1980-01-01T00:00:00Z</dcterms:created>"#
+    ));
+    assert!(core.contains("<dc:title>Sales</dc:title>"));
+}
+
+#[test]
+fn dataframe_write_excel103_identical_files() {
+    let write_file = || {
+        let df = dataframe().unwrap();
+
+        let mut xlsx_writer = PolarsXlsxWriter::new();
+        xlsx_writer.set_deterministic(true);
+
+        xlsx_writer.write_dataframe(&df).unwrap();
+        xlsx_writer.save_to_buffer().unwrap()
+    };
+
+    let first = write_file();
+    std::thread::sleep(std::time::Duration::from_millis(1100));
+    let second = write_file();
+
+    assert_eq!(first, second);
+}
@@ -0,0 +1,70 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_zip_large_file(true);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_zip_large_file(true);
+    xlsx_writer.set_zip_large_file(false);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000, 2300, 1900, 2500],
+    )
+}
+
+#[test]
+fn dataframe_write_excel104_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe104")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel104_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe104")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,138 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{ExcelExport, PolarsXlsxWriter};
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let (df1, df2, df3) = dataframes()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_worksheet_name("Summary")?;
+    xlsx_writer.set_autofilter(false);
+    xlsx_writer.set_autofit(true);
+
+    xlsx_writer.write_dataframe_to_cell(&df1, 0, 0)?;
+    xlsx_writer.write_dataframe_to_cell(&df2, 0, 3)?;
+    xlsx_writer.write_dataframe_to_cell(&df3, 6, 3)?;
+
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare output against target Excel file using the ExcelExport batch
+// interface.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let (df1, df2, df3) = dataframes()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_autofit(true);
+
+    ExcelExport::from_writer(xlsx_writer)
+        .sheet("Summary", |sheet| {
+            sheet.dataframe(&df1).at(0, 0);
+            sheet.dataframe(&df2).at(0, 3);
+            sheet.dataframe(&df3).at(6, 3);
+        })
+        .save(filename)?;
+
+    Ok(())
+}
+
+// Compare output against target Excel file using the ExcelExport batch
+// interface with the default positions.
+fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
+    let (df1, df2, df3) = dataframes()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_autofit(true);
+
+    ExcelExport::from_writer(xlsx_writer)
+        .sheet("Summary", |sheet| {
+            sheet.dataframe(&df1);
+            sheet.dataframe(&df2).at(0, 3);
+            sheet.dataframe(&df3);
+        })
+        .save(filename)?;
+
+    Ok(())
+}
+
+fn dataframes() -> PolarsResult<(DataFrame, DataFrame, DataFrame)> {
+    let df1 = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000, 2300, 1900, 2500],
+    )?;
+
+    let df2 = df!(
+        "Quarter" => &["Q1", "Q2", "Q3", "Q4"],
+        "Costs" => &[450.25, 610.50, 520.75, 700.00],
+    )?;
+
+    let df3 = df!(
+        "Department" => &["Marketing", "Engineering"],
+        "Headcount" => &[12, 48],
+    )?;
+
+    Ok((df1, df2, df3))
+}
+
+#[test]
+fn dataframe_write_excel105_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe105")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel105_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe105")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel105_3() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe105")
+        .set_function(create_new_xlsx_file_3)
+        .unique("3")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel105_overlap_error() {
+    let (df1, df2, _) = dataframes().unwrap();
+
+    let result = ExcelExport::new()
+        .sheet("Summary", |sheet| {
+            sheet.dataframe(&df1).at(0, 0);
+            sheet.dataframe(&df2).at(2, 1);
+        })
+        .save_to_buffer();
+
+    let error = result.unwrap_err().to_string();
+    assert!(error.contains("overlaps the range Summary!A1:B5"));
+}
@@ -0,0 +1,95 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use chrono::prelude::*;
+use polars::prelude::*;
+use polars_excel_writer::{FontOptions, PolarsXlsxWriter};
+use rust_xlsxwriter::{Format, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    let date_format = Format::new()
+        .set_num_format("yyyy\\-mm\\-dd;@")
+        .set_italic()
+        .set_font_color("#595959")
+        .set_background_color("#EDEDED");
+    let float_format = Format::new().set_num_format("0.00").set_bold();
+
+    xlsx_writer.set_dtype_format(DataType::Date, date_format);
+    xlsx_writer.set_dtype_format(DataType::Float64, float_format);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    let font = FontOptions::new().set_italic().set_color("#595959");
+    xlsx_writer.set_dtype_font(DataType::Date, &font);
+    xlsx_writer.set_dtype_fill(DataType::Date, "#EDEDED");
+
+    xlsx_writer.set_dtype_format(DataType::Float64, "0.00");
+    xlsx_writer.set_dtype_font(DataType::Float64, &FontOptions::new().set_bold());
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Order" => &["A-100", "A-101", "A-102"],
+        "Ordered" => &[
+            NaiveDate::from_ymd_opt(2024, 1, 5).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 1, 9).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 2, 2).unwrap(),
+        ],
+        "Shipped" => &[
+            NaiveDate::from_ymd_opt(2024, 1, 8).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 2, 6).unwrap(),
+        ],
+        "Amount" => &[125.5, 310.0, 87.25],
+    )
+}
+
+#[test]
+fn dataframe_write_excel106_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe106")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel106_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe106")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,73 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{PolarsXlsxWriter, WriteOrder};
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_float_precision(1);
+    xlsx_writer.set_float_scientific(1_000_000.0, 3);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_float_precision(1);
+    xlsx_writer.set_float_scientific(1_000_000.0, 3);
+    xlsx_writer.set_write_order(WriteOrder::RowMajor);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Body" => &["Moon", "Earth", "Jupiter", "Sun"],
+        "Mass" => &[7.342e22, 5.972e24, 1.898e27, -1.989e30],
+        "Radius" => &[1737.4_f32, 6371.0, 69911.0, 6957000.0],
+    )
+}
+
+#[test]
+fn dataframe_write_excel107_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe107")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel107_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe107")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,152 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let (sales, summary) = dataframes()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    xlsx_writer.set_worksheet_name("Sales")?;
+    xlsx_writer.write_dataframe(&sales)?;
+
+    xlsx_writer.add_worksheet();
+    xlsx_writer.set_worksheet_name("Summary")?;
+    xlsx_writer.write_dataframe(&summary)?;
+
+    let worksheet = xlsx_writer.worksheet()?;
+    worksheet.set_active(true);
+    worksheet.set_selection(1, 0, 1, 0)?;
+
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let (sales, summary) = dataframes()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    xlsx_writer.set_worksheet_name("Sales")?;
+    xlsx_writer.write_dataframe(&sales)?;
+
+    xlsx_writer.add_worksheet();
+    xlsx_writer.set_worksheet_name("Summary")?;
+    xlsx_writer.set_sheet_active(true);
+    xlsx_writer.set_selection(1, 0);
+    xlsx_writer.write_dataframe(&summary)?;
+
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare output against target Excel file using SheetSpec options.
+fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
+    let (sales, summary) = dataframes()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    xlsx_writer.add_sheet("Sales", &sales);
+    xlsx_writer
+        .add_sheet("Summary", &summary)
+        .with_sheet_active(true)
+        .with_selection(1, 0);
+
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare output against target Excel file using PolarsXlsxWriter. The active
+// worksheet is also hidden, which isn't allowed, so it is made visible.
+fn create_new_xlsx_file_4(filename: &str) -> Result<(), XlsxError> {
+    let (sales, summary) = dataframes()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    xlsx_writer.set_worksheet_name("Sales")?;
+    xlsx_writer.write_dataframe(&sales)?;
+
+    xlsx_writer.add_worksheet();
+    xlsx_writer.set_worksheet_name("Summary")?;
+    xlsx_writer.set_sheet_active(true);
+    xlsx_writer.set_sheet_hidden(true);
+    xlsx_writer.set_selection(1, 0);
+    xlsx_writer.write_dataframe(&summary)?;
+
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframes() -> PolarsResult<(DataFrame, DataFrame)> {
+    let sales = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[100, 120, 140, 130],
+    )?;
+
+    let summary = df!("Total" => &[490])?;
+
+    Ok((sales, summary))
+}
+
+#[test]
+fn dataframe_write_excel108_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe108")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel108_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe108")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel108_3() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe108")
+        .set_function(create_new_xlsx_file_3)
+        .unique("3")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel108_4() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe108")
+        .set_function(create_new_xlsx_file_4)
+        .unique("4")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,134 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{PolarsXlsxWriter, WriteOrder};
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = df!(
+        "Sales" => &[2300, 2500],
+        "Region" => &["South", "West"],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_columns(&["Sales", "Region"]);
+    xlsx_writer.set_row_filter(
+        |_row, values| matches!(values[2], AnyValue::Int32(sales) if sales >= 2000),
+    );
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare output against target Excel file using PolarsXlsxWriter and the row
+// numbers.
+fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_columns(&["Sales", "Region"]);
+    xlsx_writer.set_row_filter(|row, _values| row % 2 == 1);
+    xlsx_writer.set_write_order(WriteOrder::RowMajor);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare output against target Excel file using a rust_xlsxwriter worksheet.
+fn create_new_xlsx_file_4(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_columns(&["Sales", "Region"]);
+    xlsx_writer.set_row_filter(|_row, values| {
+        values[1] != AnyValue::String("N") && values[1] != AnyValue::String("E")
+    });
+
+    xlsx_writer.write_dataframe_to_worksheet(&df, worksheet, 0, 0)?;
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Code" => &["N", "S", "E", "W"],
+        "Sales" => &[1000, 2300, 1900, 2500],
+    )
+}
+
+#[test]
+fn dataframe_write_excel109_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe109")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel109_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe109")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel109_3() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe109")
+        .set_function(create_new_xlsx_file_3)
+        .unique("3")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel109_4() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe109")
+        .set_function(create_new_xlsx_file_4)
+        .unique("4")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
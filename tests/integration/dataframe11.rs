@@ -28,6 +28,28 @@ fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test with A1 style cell references.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Foo" => &[1, 1, 1],
+        "Bar" => &[2, 2, 2],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_freeze_panes_cell("A2")?;
+    xlsx_writer.set_freeze_panes_top_cell_ref("$A$3")?;
+
+    // Invalid cell references should be rejected.
+    assert!(xlsx_writer.set_freeze_panes_cell("2A").is_err());
+    assert!(xlsx_writer.set_freeze_panes_cell("XFE1").is_err());
+    assert!(xlsx_writer.set_freeze_panes_top_cell_ref("A0").is_err());
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn dataframe_write_excel11() {
     let test_runner = common::TestRunner::new()
@@ -39,3 +61,16 @@ fn dataframe_write_excel11() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn dataframe_write_excel11_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe11")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .ignore_calc_chain()
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,138 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use std::path::Path;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = df!(
+        "Customer" => &["Globex", "Globex"],
+        "Item" => &["Nuts", "Bolts"],
+        "Amount" => &[99.0, 310.75],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    set_options(&mut xlsx_writer);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare output against target Excel file using save_partitioned(). The file
+// for one of the partitions is moved to the test output file.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let path = Path::new(filename);
+    let dir = path.parent().unwrap();
+    let stem = path.file_stem().unwrap().to_string_lossy();
+
+    let mut frames = vec![];
+    for partition in df.partition_by_stable(["Customer"], true)? {
+        let customer = partition.column("Customer")?.str()?.get(0).unwrap();
+        frames.push((format!("{stem}_{customer}"), partition));
+    }
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    set_options(&mut xlsx_writer);
+
+    let paths = xlsx_writer.save_partitioned(dir, &frames)?;
+    assert_eq!(paths.len(), 3);
+
+    for path in &paths {
+        if path.ends_with(format!("{stem}_Globex.xlsx")) {
+            std::fs::rename(path, filename)?;
+        } else {
+            std::fs::remove_file(path)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn set_options(xlsx_writer: &mut PolarsXlsxWriter) {
+    xlsx_writer.set_autofit(true);
+    xlsx_writer.set_float_format("$#,##0.00");
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Customer" => &["Acme", "Globex", "Acme", "Initech", "Globex"],
+        "Item" => &["Bolts", "Nuts", "Screws", "Washers", "Bolts"],
+        "Amount" => &[120.5, 99.0, 45.25, 10.0, 310.75],
+    )
+}
+
+#[test]
+fn dataframe_write_excel110_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe110")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel110_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe110")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel110_duplicate_names() {
+    let df = dataframe().unwrap();
+    let frames = vec![("Acme".to_string(), df.clone()), ("Acme".to_string(), df)];
+
+    let xlsx_writer = PolarsXlsxWriter::new();
+    let result = xlsx_writer.save_partitioned(std::env::temp_dir(), &frames);
+
+    assert!(matches!(result, Err(PolarsError::Duplicate(_))));
+}
+
+#[test]
+fn dataframe_write_excel110_invalid_names() {
+    let dir = std::env::temp_dir().join("polars_excel_writer_110");
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let xlsx_writer = PolarsXlsxWriter::new();
+
+    for name in ["../x", "..\\x", "..", "", "a/b", "a:b", "a?b"] {
+        let frames = vec![
+            ("Acme".to_string(), dataframe().unwrap()),
+            (name.to_string(), dataframe().unwrap()),
+        ];
+        let result = xlsx_writer.save_partitioned(&dir, &frames);
+
+        assert!(
+            matches!(result, Err(PolarsError::ComputeError(_))),
+            "{name}"
+        );
+    }
+
+    // No files are written if any name is invalid.
+    assert!(!dir.join("Acme.xlsx").exists());
+    assert!(!std::env::temp_dir().join("x.xlsx").exists());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
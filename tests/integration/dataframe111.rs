@@ -0,0 +1,89 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{PolarsXlsxWriter, WriteOrder};
+use rust_xlsxwriter::{Color, Format, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    set_cell_styles(&mut xlsx_writer);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    set_cell_styles(&mut xlsx_writer);
+    xlsx_writer.set_write_order(WriteOrder::RowMajor);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn set_cell_styles(xlsx_writer: &mut PolarsXlsxWriter) {
+    let negative_format = Format::new().set_background_color(Color::RGB(0xFFC7CE));
+    let count_format = Format::new().set_bold().set_num_format("0.0");
+
+    xlsx_writer.set_float_format("#,##0.00");
+
+    xlsx_writer.set_cell_style_callback("Balance", move |value| match value {
+        AnyValue::Float64(balance) if *balance < 0.0 => Some(negative_format.clone()),
+        _ => None,
+    });
+
+    xlsx_writer.set_cell_style_callback("Count", move |value| match value {
+        AnyValue::Int32(count) if *count > 10 => Some(count_format.clone()),
+        AnyValue::Null => Some(Format::new().set_italic()),
+        _ => None,
+    });
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Account" => &["Rent", "Sales", "Refunds", "Services"],
+        "Balance" => &[-1200.0, 3450.5, -230.25, 870.0],
+        "Count" => &[Some(3), Some(12), None, Some(25)],
+    )
+}
+
+#[test]
+fn dataframe_write_excel111_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe111")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel111_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe111")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,53 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{LargeIntHandling, PolarsXlsxWriter};
+use rust_xlsxwriter::{Format, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter. Test the
+// formats derived for individual cells in more than one worksheet.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_float_scientific(1_000_000.0, 2);
+    xlsx_writer.set_large_int_handling(LargeIntHandling::TextFormat);
+    xlsx_writer.set_cell_style_callback("Mass", |value| match value {
+        AnyValue::Float64(mass) if *mass < 0.0 => Some(Format::new().set_bold()),
+        _ => None,
+    });
+
+    xlsx_writer.write_dataframe(&df)?;
+
+    xlsx_writer.add_worksheet();
+    xlsx_writer.write_dataframe(&df)?;
+
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Mass" => &[7.342e22, 5.972e24, -1.898e27, -1.989e30, 1.5],
+        "Id" => &[u64::MAX, 1, u64::MAX - 1, 2, 3],
+    )
+}
+
+#[test]
+fn dataframe_write_excel112() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe112")
+        .set_function(create_new_xlsx_file_1)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
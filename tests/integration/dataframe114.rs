@@ -0,0 +1,56 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{Color, Format, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    set_header_formats(&mut xlsx_writer);
+    xlsx_writer.set_use_table(false);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn set_header_formats(xlsx_writer: &mut PolarsXlsxWriter) {
+    let header_format = Format::new().set_italic();
+    let key_format = Format::new()
+        .set_bold()
+        .set_background_color(Color::RGB(0xFFC7CE));
+
+    xlsx_writer.set_header_format(&header_format);
+    xlsx_writer.set_column_header_format("Id", &key_format);
+    xlsx_writer.set_column_header_format("Missing", &key_format);
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Id" => &[101, 102, 103, 104],
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000, 2300, 1900, 2500],
+    )
+}
+
+#[test]
+fn dataframe_write_excel114() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe114")
+        .set_function(create_new_xlsx_file_1)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,154 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use polars::prelude::*;
+use polars_excel_writer::{ObjectHandling, PolarsXlsxWriter};
+use polars_utils::total_ord::{TotalEq, TotalHash};
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = df!(
+        "Package" => &["core", "io", "cli"],
+        "Version" => &["v1.2", "v2.0", "v2.1"],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare output against target Excel file using PolarsXlsxWriter. The object
+// column is written as strings.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = DataFrame::new(vec![
+        Column::new("Package".into(), &["core", "io", "cli"]),
+        version_column("Version"),
+    ])?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_object_handling(ObjectHandling::DisplayString);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare output against target Excel file using PolarsXlsxWriter. The object
+// column is skipped.
+fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
+    let df = DataFrame::new(vec![
+        Column::new("Package".into(), &["core", "io", "cli"]),
+        version_column("Skipped"),
+        Column::new("Version".into(), &["v1.2", "v2.0", "v2.1"]),
+    ])?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_object_handling(ObjectHandling::Skip);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+struct Version {
+    major: u32,
+    minor: u32,
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "v{}.{}", self.major, self.minor)
+    }
+}
+
+impl TotalEq for Version {
+    fn tot_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl TotalHash for Version {
+    fn tot_hash<H: Hasher>(&self, state: &mut H) {
+        self.hash(state);
+    }
+}
+
+impl PolarsObject for Version {
+    fn type_name() -> &'static str {
+        "version"
+    }
+}
+
+fn version_column(name: &str) -> Column {
+    let versions = vec![
+        Version { major: 1, minor: 2 },
+        Version { major: 2, minor: 0 },
+        Version { major: 2, minor: 1 },
+    ];
+
+    ObjectChunked::new_from_vec(name.into(), versions)
+        .into_series()
+        .into_column()
+}
+
+#[test]
+fn dataframe_write_excel116_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe116")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel116_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe116")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel116_3() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe116")
+        .set_function(create_new_xlsx_file_3)
+        .unique("3")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel116_error() {
+    let df = DataFrame::new(vec![version_column("Version")]).unwrap();
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    let result = xlsx_writer.write_dataframe(&df);
+
+    assert!(matches!(result, Err(PolarsError::ComputeError(_))));
+}
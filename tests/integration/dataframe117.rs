@@ -0,0 +1,163 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{
+    ColumnFormat, DuplicateHeaderPolicy, ErrorPolicy, PolarsXlsxWriter, WarningKind,
+};
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter. The
+// format for the unknown column is ignored.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    let currency = ColumnFormat::new().set_num_format("$#,##0");
+    xlsx_writer.set_column_format("Sale", &currency);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000, 2300, 1900, 2500],
+    )
+}
+
+#[test]
+fn dataframe_write_excel117() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe117")
+        .set_function(create_new_xlsx_file_1)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel117_unknown_column() {
+    let df = dataframe().unwrap();
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    let currency = ColumnFormat::new().set_num_format("$#,##0");
+    xlsx_writer.set_column_format("Sale", &currency);
+    xlsx_writer.set_column_hidden("Sale");
+    xlsx_writer.set_column_hidden("Region");
+
+    // The repeated warnings for the second dataframe are only stored once.
+    xlsx_writer.write_dataframe(&df).unwrap();
+    xlsx_writer.write_dataframe_to_cell(&df, 0, 3).unwrap();
+
+    let warnings = xlsx_writer.warnings();
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(warnings[0].kind, WarningKind::UnknownColumn);
+    assert_eq!(warnings[0].sheet_name, "Sheet1");
+    assert_eq!(warnings[0].column_name, "Sale");
+    assert!(warnings[0].message.contains("set_column_hidden()"));
+    assert!(warnings[1].message.contains("set_column_format()"));
+}
+
+#[test]
+fn dataframe_write_excel117_clipped_width() {
+    let df = dataframe().unwrap();
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_column_width("Sales", 300.0);
+    xlsx_writer.set_column_width("Region", 20.0);
+
+    xlsx_writer.write_dataframe(&df).unwrap();
+
+    let warnings = xlsx_writer.warnings();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].kind, WarningKind::ClippedWidth);
+    assert_eq!(warnings[0].column_name, "Sales");
+}
+
+#[test]
+fn dataframe_write_excel117_renamed_header() {
+    let df = df!(
+        "Product" => &["Apple", "Pear", "Plum"],
+        "Price" => &[1.20, 0.90, 1.50],
+        "price" => &[1.10, 0.95, 1.40],
+    )
+    .unwrap();
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_duplicate_header_policy(DuplicateHeaderPolicy::Rename);
+
+    xlsx_writer.write_dataframe(&df).unwrap();
+
+    let warnings = xlsx_writer.warnings();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].kind, WarningKind::RenamedHeader);
+    assert_eq!(warnings[0].column_name, "price");
+}
+
+#[test]
+fn dataframe_write_excel117_no_warnings() {
+    let df = dataframe().unwrap();
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_column_hidden("Region");
+
+    xlsx_writer.write_dataframe(&df).unwrap();
+
+    assert!(xlsx_writer.warnings().is_empty());
+}
+
+#[test]
+fn dataframe_write_excel117_coerced_value() {
+    let df = df!(
+        "Foo" => &[1_i64, 9_007_199_254_740_993],
+        "Bar" => &[4, 5],
+    )
+    .unwrap();
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.write_dataframe(&df).unwrap();
+
+    let warnings = xlsx_writer.warnings();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].kind, WarningKind::CoercedValue);
+    assert_eq!(warnings[0].column_name, "Foo");
+
+    // The values aren't coerced with the other policies.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_value_error_policy(ErrorPolicy::WriteAsString);
+    xlsx_writer.write_dataframe(&df).unwrap();
+
+    assert!(xlsx_writer.warnings().is_empty());
+}
+
+#[test]
+fn dataframe_write_excel117_constant_memory_merge() {
+    let df = df!(
+        "Region" => &["North", "North", "South"],
+        "Sales" => &[1000, 2300, 1900],
+    )
+    .unwrap();
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_constant_memory(true);
+    xlsx_writer.set_merge_repeated("Region");
+
+    xlsx_writer.write_dataframe(&df).unwrap();
+
+    let warnings = xlsx_writer.warnings();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].kind, WarningKind::UnsupportedOption);
+    assert_eq!(warnings[0].column_name, "Region");
+    assert!(warnings[0].message.contains("set_merge_repeated()"));
+}
@@ -0,0 +1,100 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{ColumnFormat, PolarsXlsxWriter};
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter. The
+// workbook is passed between two writers with different options.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let (sales, more_sales, staff) = dataframes()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::from_workbook(Workbook::new());
+    xlsx_writer.add_worksheet();
+    xlsx_writer.set_worksheet_name("Sales")?;
+    xlsx_writer.set_column_format("Sales", &ColumnFormat::new().set_num_format("$#,##0"));
+    xlsx_writer.write_dataframe(&sales)?;
+    xlsx_writer.append_dataframe(&more_sales)?;
+    let workbook = xlsx_writer.into_workbook()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::from_workbook(workbook);
+    xlsx_writer.add_worksheet();
+    xlsx_writer.set_worksheet_name("Staff")?;
+    xlsx_writer.set_column_width("Name", 20.0);
+    xlsx_writer.write_dataframe(&staff)?;
+    let mut workbook = xlsx_writer.into_workbook()?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Compare with the dataframes written by a single writer.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let (sales, more_sales, staff) = dataframes()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_worksheet_name("Sales")?;
+    xlsx_writer.set_column_format("Sales", &ColumnFormat::new().set_num_format("$#,##0"));
+    xlsx_writer.set_column_width("Name", 20.0);
+    xlsx_writer.write_dataframe(&sales)?;
+    xlsx_writer.append_dataframe(&more_sales)?;
+
+    xlsx_writer.add_worksheet();
+    xlsx_writer.set_worksheet_name("Staff")?;
+    xlsx_writer.write_dataframe(&staff)?;
+
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframes() -> PolarsResult<(DataFrame, DataFrame, DataFrame)> {
+    let sales = df!(
+        "Region" => &["North", "South"],
+        "Sales" => &[1000, 2300],
+    )?;
+
+    let more_sales = df!(
+        "Region" => &["East", "West"],
+        "Sales" => &[1900, 2500],
+    )?;
+
+    let staff = df!(
+        "Name" => &["Anna Lopez", "Ben Smith", "Chen Wei"],
+        "Region" => &["North", "South", "East"],
+    )?;
+
+    Ok((sales, more_sales, staff))
+}
+
+#[test]
+fn dataframe_write_excel118_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe118")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel118_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe118")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,72 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_format_for_dtypes(&[DataType::Int32, DataType::Int64], "#,##0");
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare with the format set for each data type.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_dtype_format(DataType::Int32, "#,##0");
+    xlsx_writer.set_dtype_format(DataType::Int64, "#,##0");
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Product" => &["Apple", "Pear", "Plum"],
+        "Units" => &[12500i32, 8300, 21750],
+        "Revenue" => &[1562500i64, 1145400, 2175000],
+        "Price" => &[1.25, 1.38, 1.00],
+    )
+}
+
+#[test]
+fn dataframe_write_excel119_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe119")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel119_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe119")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
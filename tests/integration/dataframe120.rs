@@ -0,0 +1,122 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{PolarsXlsxWriter, WarningKind};
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_format_matching("_pct$", "0.0%")?;
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare with a data type format. The pattern takes precedence and the most
+// recently added matching pattern is used.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_dtype_format(DataType::Float64, "0.00");
+    xlsx_writer.set_format_matching("pct", "0.00%")?;
+    xlsx_writer.set_format_matching("_pct$", "0.0%")?;
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare with the format set for the data type of the matching columns.
+fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_dtype_format(DataType::Float64, "0.0%");
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000, 2300, 1900, 2500],
+        "Growth_pct" => &[0.051, 0.124, -0.032, 0.087],
+        "Margin_pct" => &[0.25, 0.31, 0.22, 0.28],
+    )
+}
+
+#[test]
+fn dataframe_write_excel120_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe120")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel120_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe120")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel120_3() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe120")
+        .set_function(create_new_xlsx_file_3)
+        .unique("3")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel120_invalid_pattern() {
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    let result = xlsx_writer.set_format_matching("_pct(", "0.0%");
+
+    assert!(matches!(result, Err(PolarsError::ComputeError(_))));
+}
+
+#[test]
+fn dataframe_write_excel120_unmatched_pattern() {
+    let df = dataframe().unwrap();
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_format_matching("_ratio$", "0.0%").unwrap();
+
+    xlsx_writer.write_dataframe(&df).unwrap();
+
+    let warnings = xlsx_writer.warnings();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].kind, WarningKind::UnknownColumn);
+    assert_eq!(warnings[0].column_name, "_ratio$");
+}
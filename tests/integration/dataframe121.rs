@@ -0,0 +1,65 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use chrono::prelude::*;
+use polars::prelude::*;
+use polars_excel_writer::{CellFormat, ColumnFormat, PolarsXlsxWriter};
+use rust_xlsxwriter::{Format, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter. Test the
+// format aware autofit with color, scientific and elapsed time formats.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Color" => &[1234.5, -98765.25, 0.5],
+        "Scientific" => &[12345.678, 0.000123, -5.5],
+        "Elapsed" => &[1.5, 2.25, 0.75],
+        "Clamped" => &[1234567891.5, 2.0, 3.0],
+        "Date" => &[
+            NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 9, 2).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 9, 3).unwrap(),
+        ],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer
+        .set_column_format(
+            "Color",
+            &ColumnFormat::new().set_num_format("[Magenta]0.0;[Red]-0.0"),
+        )
+        .set_column_format(
+            "Scientific",
+            &ColumnFormat::new().set_num_format("0.00E+00"),
+        )
+        .set_column_format("Elapsed", &ColumnFormat::new().set_num_format("[hh]:mm"))
+        .set_column_format(
+            "Clamped",
+            &ColumnFormat::new().set_num_format("#,##0.000000"),
+        )
+        .set_date_format(CellFormat::new(&Format::new().set_italic(), "dd mmm yyyy"))
+        .set_autofit(true)
+        .set_autofit_with_formats(true)
+        .set_autofit_max_width(12.0);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel121() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe121")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,48 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{Format, Table, TableColumn, TableFunction, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter. Test that
+// a user defined table column total function is kept with a column header
+// format.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000, 2300, 1500, 1750],
+        "Units" => &[10, 23, 15, 17],
+    )?;
+
+    let column = TableColumn::new().set_total_function(TableFunction::Average);
+    let header_format = Format::new().set_bold().set_font_color("#FF0000");
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer
+        .set_table(&Table::new().set_total_row(true))
+        .set_table_column("Units", &column)
+        .set_column_header_format("Sales", &header_format);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel122() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe122")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
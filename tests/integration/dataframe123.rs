@@ -0,0 +1,46 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter. Test a row
+// total structured reference with a table name and the source column format.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East"],
+        "Q1" => &[1.5, 2.5, 3.5],
+        "Q2" => &[4.25, 5.25, 6.25],
+        "Q3" => &[7.0, 8.0, 9.0],
+        "Q4" => &[10.75, 11.75, 12.75],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer
+        .set_dtype_format(DataType::Float64, "0.00")
+        .set_table_name("Sales")?
+        .set_row_totals(&["Q1", "Q2", "Q4"], "Total");
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel123() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe123")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,44 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter. Test that
+// the header isn't split, or moved down a row, if no column names contain the
+// delimiter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East"],
+        "2023 Q1" => &[100, 200, 300],
+        "2023 Q2" => &[110, 210, 310],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_header_split("|");
+
+    let range = xlsx_writer.write_dataframe(&df)?;
+    assert_eq!(range.first_row, 0);
+
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel124() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe124")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
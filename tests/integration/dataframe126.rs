@@ -0,0 +1,47 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter. Test the
+// table of contents links to worksheets with names that look like cell
+// references.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000, 2300, 1900, 2500],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.add_table_of_contents("Contents")?;
+
+    xlsx_writer.set_worksheet_name("A1")?;
+    xlsx_writer.write_dataframe(&df)?;
+
+    xlsx_writer.add_worksheet();
+    xlsx_writer.set_worksheet_name("C")?;
+    xlsx_writer.write_dataframe(&df)?;
+
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel126() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe126")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,54 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{ConditionalFormat2ColorScale, XlsxError};
+
+// Test adding a conditional format to a cell range instead of a column.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Foo" => &[1, 2, 3],
+        "Bar" => &[10, 20, 30],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_conditional_format("$C$3:$C$5", &ConditionalFormat2ColorScale::new());
+
+    xlsx_writer.write_dataframe_to_cell(&df, 1, 1)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel127() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe127")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel127_unknown_column() {
+    let df: DataFrame = df!(
+        "Foo" => &[1, 2, 3],
+        "Bar" => &[10, 20, 30],
+    )
+    .unwrap();
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_conditional_format("Baz", &ConditionalFormat2ColorScale::new());
+
+    let result = xlsx_writer.write_dataframe(&df);
+    assert!(matches!(result, Err(PolarsError::ColumnNotFound(_))));
+}
@@ -0,0 +1,54 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Test collapsed row groups for the repeated values of a key column.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Account" => &[Some("A"), Some("A"), Some("A"), Some("B"), Some("C"), Some("C"), None, None],
+        "Amount" => &[1, 2, 3, 4, 5, 6, 7, 8],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_row_grouping("Account", true);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel128() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe128")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel128_unknown_column() {
+    let df: DataFrame = df!(
+        "Account" => &["A", "A"],
+        "Amount" => &[1, 2],
+    )
+    .unwrap();
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_row_grouping("Customer", true);
+
+    let result = xlsx_writer.write_dataframe(&df);
+    assert!(matches!(result, Err(PolarsError::ColumnNotFound(_))));
+}
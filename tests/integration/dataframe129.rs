@@ -0,0 +1,43 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{Color, XlsxError};
+
+// Test expanded row groups with other worksheet and row properties, saved via
+// a buffer.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Account" => &[Some("A"), Some("A"), Some("A"), Some("B"), Some("C"), Some("C"), None, None],
+        "Amount" => &[1, 2, 3, 4, 5, 6, 7, 8],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_row_grouping("Account", false);
+    xlsx_writer.set_tab_color(Color::Red);
+    xlsx_writer.set_row_height(2, 30);
+
+    xlsx_writer.write_dataframe(&df)?;
+    let buffer = xlsx_writer.save_to_buffer()?;
+    std::fs::write(filename, buffer)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel129() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe129")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
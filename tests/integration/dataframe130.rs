@@ -0,0 +1,64 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use chrono::NaiveDate;
+use polars::prelude::*;
+use polars_excel_writer::{ColumnFormat, PolarsXlsxWriter};
+use rust_xlsxwriter::{Color, Format, XlsxError};
+
+// Test writing the columns in parallel. The output is the same as for a
+// sequential write.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Region" => &[Some("North"), None, Some("East"), Some("West"), Some("North")],
+        "Units" => &[Some(10), Some(20), None, Some(40), Some(50)],
+        "Price" => &[1.5, f64::NAN, 3.25, -4.0, 5.125],
+        "Active" => &[true, false, true, true, false],
+        "Date" => &[
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 2, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 4, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 5, 1).unwrap(),
+        ],
+        "Link" => &[
+            "https://example.com/1",
+            "https://example.com/2",
+            "https://example.com/3",
+            "https://example.com/4",
+            "https://example.com/5",
+        ],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_parallel(true);
+    xlsx_writer.set_null_value("-");
+    xlsx_writer.set_column_format("Units", &ColumnFormat::new().set_bold());
+    xlsx_writer.set_column_as_hyperlinks("Link");
+    xlsx_writer.set_cell_style_callback("Price", |value| match value {
+        AnyValue::Float64(value) if *value < 0.0 => Some(Format::new().set_font_color(Color::Red)),
+        _ => None,
+    });
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel130() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe130")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
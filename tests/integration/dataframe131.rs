@@ -0,0 +1,113 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{Layout, PolarsXlsxWriter, WarningKind};
+use rust_xlsxwriter::{Color, XlsxError};
+
+// Test that changes made to the worksheet in constant memory mode, before the
+// dataframes are written, are kept.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_constant_memory(true);
+    xlsx_writer.worksheet()?.set_tab_color(Color::Red);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.write_dataframe_to_cell(&df, 6, 0)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000, 2300, 1900, 2500],
+    )
+}
+
+#[test]
+fn dataframe_write_excel131() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe131")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel131_written_rows() {
+    let df = dataframe().unwrap();
+
+    // A dataframe beside the previous data would be written to rows that
+    // have already been written.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_constant_memory(true);
+    xlsx_writer.write_dataframe(&df).unwrap();
+
+    let result = xlsx_writer.write_dataframe_to_cell(&df, 0, 3);
+    assert!(matches!(result, Err(PolarsError::ComputeError(_))));
+
+    let result = xlsx_writer.write_dataframe_to_cell(&df, 4, 0);
+    assert!(matches!(result, Err(PolarsError::ComputeError(_))));
+
+    xlsx_writer.write_dataframe_to_cell(&df, 5, 0).unwrap();
+
+    // The same applies to a horizontal layout.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_constant_memory(true);
+
+    let result = xlsx_writer.write_dataframes(&[&df, &df], Layout::Horizontal { gap: 1 });
+    assert!(matches!(result, Err(PolarsError::ComputeError(_))));
+}
+
+#[test]
+fn dataframe_write_excel131_used_worksheet() {
+    let df = dataframe().unwrap();
+
+    // A worksheet that has been changed can't be replaced.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.worksheet().unwrap().set_tab_color(Color::Red);
+
+    let result = xlsx_writer.write_dataframe_chunked(&df, 2);
+    assert!(matches!(result, Err(PolarsError::ComputeError(_))));
+
+    // A worksheet that has been written to can't be replaced.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.write_dataframe(&df).unwrap();
+    xlsx_writer.set_constant_memory(true);
+
+    let result = xlsx_writer.write_dataframe_to_cell(&df, 6, 0);
+    assert!(matches!(result, Err(PolarsError::ComputeError(_))));
+
+    // A new worksheet can be replaced.
+    xlsx_writer.add_worksheet();
+    xlsx_writer.write_dataframe(&df).unwrap();
+}
+
+#[test]
+fn dataframe_write_excel131_autofit() {
+    let df = dataframe().unwrap();
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_constant_memory(true);
+    xlsx_writer.set_autofit(true);
+
+    xlsx_writer.write_dataframe(&df).unwrap();
+
+    let warnings = xlsx_writer.warnings();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].kind, WarningKind::UnsupportedOption);
+    assert_eq!(warnings[0].column_name, "");
+    assert!(warnings[0].message.contains("set_autofit()"));
+}
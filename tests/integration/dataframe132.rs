@@ -0,0 +1,76 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter. Test a diff
+// with a row filter on the new dataframe.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let (df_old, df_new) = dataframes()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_row_filter(|_row, values| values[0] != AnyValue::Int32(1));
+    xlsx_writer.write_dataframe_diff(&df_old, &df_new)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Test with the rows matched by a key column.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let (df_old, df_new) = dataframes()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_diff_key_column("Foo");
+    xlsx_writer.set_row_filter(|row, _values| row > 0);
+    xlsx_writer.write_dataframe_diff(&df_old, &df_new)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframes() -> PolarsResult<(DataFrame, DataFrame)> {
+    let df_old: DataFrame = df!(
+        "Foo" => &[1, 2, 3, 4],
+        "Bar" => &[10, 20, 30, 40],
+    )?;
+
+    let df_new: DataFrame = df!(
+        "Foo" => &[1, 2, 3, 4],
+        "Bar" => &[10, 0, 30, 0],
+    )?;
+
+    Ok((df_old, df_new))
+}
+
+#[test]
+fn dataframe_write_excel132_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe132")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel132_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe132")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
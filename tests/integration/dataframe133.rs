@@ -0,0 +1,73 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{PolarsXlsxWriter, WriteOrder};
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter. Test a row
+// filter that selects long runs of rows.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_row_filter(|row, _values| !(40..60).contains(&row));
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Test with the rows written in row order.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_write_order(WriteOrder::RowMajor);
+    xlsx_writer.set_row_filter(
+        |_row, values| !matches!(values[0], AnyValue::Int32(id) if (40..60).contains(&id)),
+    );
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    let ids: Vec<i32> = (0..100).collect();
+
+    df!(
+        "Id" => &ids,
+        "Value" => ids.iter().map(|id| f64::from(*id) * 1.5).collect::<Vec<_>>(),
+    )
+}
+
+#[test]
+fn dataframe_write_excel133_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe133")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel133_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe133")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
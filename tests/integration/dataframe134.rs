@@ -0,0 +1,82 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{ColumnFormat, PolarsXlsxWriter, WriteOrder};
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter. Test banded
+// row formats combined with the column formats and with null values.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = writer();
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Test with the rows written in row order.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = writer();
+    xlsx_writer.set_write_order(WriteOrder::RowMajor);
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn writer() -> PolarsXlsxWriter {
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer
+        .set_float_format("0.00")
+        .set_column_format("Foo", &ColumnFormat::new().set_bold())
+        .set_column_format("Bar", &ColumnFormat::new().set_bold())
+        .set_table_banded_rows(false)
+        .set_banded_row_formats(
+            &ColumnFormat::new().set_background_color("DDEBF7"),
+            &ColumnFormat::new().set_background_color("FFFFFF"),
+        );
+
+    xlsx_writer
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Foo" => &[Some(1.5), None, Some(3.25)],
+        "Bar" => &[4.0, 5.5, 6.75],
+    )
+}
+
+#[test]
+fn dataframe_write_excel134_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe134")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel134_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe134")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
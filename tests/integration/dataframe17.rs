@@ -0,0 +1,45 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let col1 = Series::new("Foo".into(), [1, 2, 3]);
+    let col2 = Series::new(
+        "Bar".into(),
+        [
+            Series::new("".into(), [10, 20]),
+            Series::new("".into(), [30]),
+            Series::new("".into(), [40, 50]),
+        ],
+    );
+    let df = DataFrame::new(vec![col1.into(), col2.into()])?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_expand_lists(true);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel17() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe17")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,45 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df1: DataFrame = df!(
+        "Foo" => &["A", "B", "C"],
+        "Bar" => &[1, 2, 3],
+    )?;
+
+    let df2: DataFrame = df!(
+        "Baz" => &["D", "E", "F"],
+        "Bar" => &[4, 5, 6],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_column_hidden("Bar");
+
+    xlsx_writer.write_dataframe_to_cell(&df1, 0, 0)?;
+    xlsx_writer.write_dataframe_to_cell(&df2, 0, 3)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel23() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe23")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,50 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{TableStyle, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df1: DataFrame = df!(
+        "Foo" => &["A", "B", "C"],
+        "Bar" => &[1, 2, 3],
+    )?;
+
+    let df2: DataFrame = df!(
+        "Baz" => &[1.5, 2.5, 3.5],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    xlsx_writer
+        .add_sheet("Sales", &df1)
+        .with_table_style(TableStyle::Medium4);
+
+    xlsx_writer
+        .add_sheet("Costs", &df2)
+        .with_float_format("0.00")
+        .with_zoom(150);
+
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel24() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe24")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,39 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Foo" => &["A", "B", "C", "D", "E"],
+        "Bar" => &[1, 2, 3, 4, 5],
+    )?;
+
+    let lf = df.lazy().filter(col("Bar").gt(lit(2)));
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.write_lazyframe(lf)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel25() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe25")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,48 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df1: DataFrame = df!(
+        "Foo" => &[Some(1i64), Some(2), None],
+        "Bar" => &[1.5f64, 2.5, 3.5],
+        "Baz" => &["A", "B", "C"],
+    )?;
+
+    let df2: DataFrame = df!(
+        "Foo" => &[Some(4i64), None, Some(6)],
+        "Bar" => &[4.5f64, 5.5, 6.5],
+        "Baz" => &["D", "E", "F"],
+    )?;
+
+    // Create a dataframe with multiple chunks per column.
+    let df = df1.vstack(&df2)?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_null_value("Null");
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel26() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe26")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,54 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{Layout, PolarsXlsxWriter};
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df1: DataFrame = df!(
+        "Foo" => &[1, 2, 3],
+        "Bar" => &[4, 5, 6],
+    )?;
+
+    let df2: DataFrame = df!(
+        "Baz" => &[7, 8],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    let ranges = xlsx_writer.write_dataframes(&[&df1, &df2], Layout::Vertical { gap: 2 })?;
+    xlsx_writer.save(filename)?;
+
+    let ranges: Vec<_> = ranges
+        .iter()
+        .map(|range| {
+            (
+                range.first_row,
+                range.first_col,
+                range.last_row,
+                range.last_col,
+            )
+        })
+        .collect();
+    assert_eq!(ranges, vec![(0, 0, 3, 1), (6, 0, 8, 0)]);
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel28() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe28")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
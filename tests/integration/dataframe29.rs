@@ -0,0 +1,50 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{PolarsXlsxWriter, WrittenRange};
+use rust_xlsxwriter::{TableFunction, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Foo" => &[1, 2, 3],
+        "Bar" => &[4, 5, 6],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_table_name("Data")?;
+    xlsx_writer.set_column_total("Bar", TableFunction::Sum);
+
+    let range = xlsx_writer.write_dataframe_to_cell(&df, 2, 1)?;
+    xlsx_writer.save(filename)?;
+
+    let expected = WrittenRange {
+        sheet_name: "Sheet1".to_string(),
+        first_row: 2,
+        first_col: 1,
+        last_row: 6,
+        last_col: 2,
+        table_name: Some("Data".to_string()),
+    };
+    assert_eq!(range, expected);
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel29() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe29")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,46 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_arrow::bitmap::Bitmap;
+use polars_excel_writer::{PolarsXlsxWriter, StructHandling};
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let x = Series::new("x".into(), [1, 2, 3]);
+    let name = Series::new("name".into(), ["A", "B", "C"]);
+    let inner = StructChunked::from_series("inner".into(), 3, [name].iter())?.into_series();
+    let point = StructChunked::from_series("Point".into(), 3, [x, inner].iter())?;
+
+    // Set the second struct value to null.
+    let validity = Bitmap::from([true, false, true]);
+    let point = point.with_outer_validity(Some(validity)).into_series();
+
+    let ids = Series::new("Id".into(), [10, 20, 30]);
+    let df = DataFrame::new(vec![ids.into(), point.into()])?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_struct_handling(StructHandling::Flatten);
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel31() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe31")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,42 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{TableFunction, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "foo_bar" => &["A", "B", "C"],
+        "total_value" => &[1, 2, 3],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_column_header("foo_bar", "Foo Bar");
+    xlsx_writer.set_column_header("total_value", "Total Value");
+    xlsx_writer.set_column_header("not_a_column", "Ignored");
+    xlsx_writer.set_column_total("total_value", TableFunction::Sum);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel33() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe33")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,41 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Name" => &["Foo", "Bar", "Baz"],
+        "Link" => &[Some("https://www.example.com/foo"), None, Some("https://www.example.com/baz")],
+        "Url" => &["https://www.example.com/1", "https://www.example.com/2", "https://www.example.com/3"],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_column_as_hyperlinks("Link");
+    xlsx_writer.set_hyperlink_pair("Url", "Name");
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel35() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe35")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,47 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Foo" => &[1.5, 2.25, -3.75],
+        "Bar" => &[1000.0, 2000.0, 3000.0],
+    )?;
+
+    let df = df
+        .lazy()
+        .with_columns([
+            col("Foo").cast(DataType::Decimal(Some(10), Some(2))),
+            col("Bar").cast(DataType::Decimal(Some(10), Some(0))),
+        ])
+        .collect()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_dtype_format(DataType::Decimal(Some(10), Some(0)), "#,##0");
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel36() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe36")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,56 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df1: DataFrame = df!(
+        "Foo" => &[1, 2, 3],
+        "Bar" => &[4, 5, 6],
+    )?;
+
+    let df2: DataFrame = df!(
+        "Foo" => &[7, 9],
+        "Bar" => &[8, 10],
+    )?;
+
+    let df3: DataFrame = df!(
+        "Foo" => &[11, 13],
+        "Bar" => &[12, 14],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.write_dataframe(&df1)?;
+    xlsx_writer.append_dataframe(&df2)?;
+    xlsx_writer.append_dataframe(&df3)?;
+
+    // Mismatched columns should be rejected.
+    let df4: DataFrame = df!(
+        "Baz" => &[15],
+    )?;
+    assert!(xlsx_writer.append_dataframe(&df4).is_err());
+
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel37() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe37")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
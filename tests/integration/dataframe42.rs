@@ -0,0 +1,79 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{OverflowPolicy, PolarsXlsxWriter};
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Foo" => &[1, 2, 3],
+        "Bar" => &[4, 5, 6],
+    )?;
+
+    let runtime = tokio::runtime::Builder::new_current_thread().build()?;
+
+    runtime.block_on(async {
+        let mut xlsx_writer = PolarsXlsxWriter::new();
+        xlsx_writer.write_dataframe(&df)?;
+        xlsx_writer.save_async(filename).await
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel42() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe42")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel42_split_files() {
+    let df: DataFrame = df!(
+        "Foo" => &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10],
+    )
+    .unwrap();
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+
+    runtime.block_on(async {
+        let mut xlsx_writer = PolarsXlsxWriter::new();
+        xlsx_writer.set_overflow_policy(OverflowPolicy::SplitFiles);
+        xlsx_writer
+            .write_dataframe_to_cell(&df, 1_048_570, 0)
+            .unwrap();
+
+        // The split files can't be written to a single writer.
+        let mut buffer = vec![];
+        let result = xlsx_writer.save_to_writer_async(&mut buffer).await;
+
+        assert!(matches!(result, Err(PolarsError::ComputeError(_))));
+        assert!(buffer.is_empty());
+
+        // The workbooks are kept so that they can still be saved.
+        let dir = std::env::temp_dir();
+        let path = dir.join("polars_excel_writer_42.xlsx");
+        xlsx_writer.save_async(&path).await.unwrap();
+
+        let split_path = dir.join("polars_excel_writer_42_2.xlsx");
+        assert!(path.exists() && split_path.exists());
+
+        std::fs::remove_file(path).unwrap();
+        std::fs::remove_file(split_path).unwrap();
+    });
+}
@@ -0,0 +1,40 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{FilterCondition, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Region" => &["East", "West", "East", "North"],
+        "Sales" => &[1, 2, 3, 4],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    let condition = FilterCondition::new().add_list_filter("East");
+    xlsx_writer.set_filter("Region", &condition);
+    xlsx_writer.set_filter("Unknown", &condition);
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel43() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe43")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,46 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Foo" => &[5_400_000_i64, 129_600_000, 45_000],
+        "Bar" => &[45_000_000_i64, 90_000_000, 1_500_000],
+    )?;
+
+    let df = df
+        .lazy()
+        .with_columns([
+            col("Foo").cast(DataType::Duration(TimeUnit::Milliseconds)),
+            col("Bar").cast(DataType::Duration(TimeUnit::Microseconds)),
+        ])
+        .collect()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_dtype_format(DataType::Duration(TimeUnit::Microseconds), "[m]:ss");
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel45() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe45")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
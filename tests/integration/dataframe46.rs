@@ -0,0 +1,41 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{Format, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Long header name" => &[1, 2, 3],
+        "Bar" => &[4, 5, 6],
+    )?;
+
+    let header_format = Format::new().set_bold();
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_header_format(&header_format);
+    xlsx_writer.set_header_rotation(90);
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel46() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe46")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
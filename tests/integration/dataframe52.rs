@@ -0,0 +1,44 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{DataValidation, DataValidationRule, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Foo" => &[1, 2, 3],
+        "Bar" => &["b", "a", "b"],
+    )?;
+    let df = df
+        .lazy()
+        .with_column(col("Bar").cast(DataType::Categorical(None, Default::default())))
+        .collect()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    let validation = DataValidation::new().allow_whole_number(DataValidationRule::Between(0, 10));
+    xlsx_writer.set_column_validation("Foo", &validation);
+    xlsx_writer.set_column_dropdown("Bar");
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel52() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe52")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,75 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df_old: DataFrame = df!(
+        "Foo" => &[1, 2, 3],
+        "Bar" => &[4, 5, 6],
+    )?;
+
+    let df_new: DataFrame = df!(
+        "Foo" => &[1, 2, 9],
+        "Bar" => &[4, 0, 0],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.write_dataframe_diff(&df_old, &df_new)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Test with the rows matched by a key column.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df_old: DataFrame = df!(
+        "Foo" => &[2, 1, 3],
+        "Bar" => &[5, 4, 6],
+    )?;
+
+    let df_new: DataFrame = df!(
+        "Foo" => &[1, 2, 9],
+        "Bar" => &[4, 0, 0],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_diff_key_column("Foo");
+    xlsx_writer.write_dataframe_diff(&df_old, &df_new)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel53() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe53")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel53_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe53")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
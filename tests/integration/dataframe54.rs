@@ -0,0 +1,40 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Region" => &["East", "West", "East"],
+        "Sales" => &[1, 2, 3],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    assert!(xlsx_writer.write_dataframe_partitioned(&df, "Foo").is_err());
+
+    xlsx_writer.write_dataframe_partitioned(&df, "Region")?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel54() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe54")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,53 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use chrono::prelude::*;
+use polars::prelude::*;
+use polars_excel_writer::{ErrorPolicy, PolarsXlsxWriter};
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Foo" => &[1_i64, 9_007_199_254_740_993],
+        "Bar" => &[4, 5],
+    )?;
+
+    // Test the error policy with out of range integers and dates.
+    let df_u64: DataFrame = df!(
+        "Foo" => &[1_u64, 9_007_199_254_740_993],
+    )?;
+
+    let df_date: DataFrame = df!(
+        "Foo" => &[NaiveDate::from_ymd_opt(1850, 1, 1)],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_value_error_policy(ErrorPolicy::Error);
+    assert!(xlsx_writer.write_dataframe(&df_u64).is_err());
+    assert!(xlsx_writer.write_dataframe(&df_date).is_err());
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_value_error_policy(ErrorPolicy::WriteAsString);
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel59() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe59")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,44 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{ErrorPolicy, LargeIntHandling, PolarsXlsxWriter};
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Foo" => &[1_i64, 9_007_199_254_740_993],
+        "Bar" => &[2_u64, u64::MAX],
+    )?;
+
+    // Test that the large integer handling takes precedence over the policy.
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_value_error_policy(ErrorPolicy::Error);
+    xlsx_writer.set_large_int_handling(LargeIntHandling::Float);
+    assert!(xlsx_writer.write_dataframe(&df).is_ok());
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_large_int_handling(LargeIntHandling::TextFormat);
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel60() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe60")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
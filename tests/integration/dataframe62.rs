@@ -0,0 +1,77 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{ImageOptions, PolarsXlsxWriter};
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Product" => &["Red", "Blue", "Yellow"],
+        "Image" => &[
+            "tests/input/images/red.png",
+            "tests/input/images/blue.png",
+            "tests/input/images/yellow.png",
+        ],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_column_as_images("Image", &ImageOptions::new());
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare using a Binary column of image data.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let images = ["red", "blue", "yellow"]
+        .iter()
+        .map(|name| std::fs::read(format!("tests/input/images/{name}.png")))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let df: DataFrame = df!(
+        "Product" => &["Red", "Blue", "Yellow"],
+        "Image" => images,
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_column_as_images("Image", &ImageOptions::new());
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel62_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe62")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel62_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe62")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
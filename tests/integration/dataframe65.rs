@@ -0,0 +1,69 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{HeaderMode, PolarsXlsxWriter};
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Foo" => &[1, 2, 3],
+        "Bar" => &[4, 5, 6],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_header_mode(HeaderMode::None);
+
+    xlsx_writer.write_dataframe_to_cell(&df, 2, 1)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Check that an empty dataframe without a header doesn't add a table.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Foo" => &[1, 2, 3],
+        "Bar" => &[4, 5, 6],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_header(false);
+
+    xlsx_writer.write_dataframe_to_cell(&df, 2, 1)?;
+    xlsx_writer.write_dataframe_to_cell(&df.head(Some(0)), 10, 1)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel65_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe65")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel65_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe65")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
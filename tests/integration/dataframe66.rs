@@ -0,0 +1,71 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{HeaderMode, PolarsXlsxWriter};
+use rust_xlsxwriter::{Format, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Foo" => &[1, 2, 3],
+        "Bar" => &[4, 5, 6],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_header_mode(HeaderMode::WrittenHeaderRow);
+    xlsx_writer.set_header_format(Format::new().set_bold());
+
+    xlsx_writer.write_dataframe_to_cell(&df, 2, 1)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare with the equivalent header and table options.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Foo" => &[1, 2, 3],
+        "Bar" => &[4, 5, 6],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_header(true);
+    xlsx_writer.set_use_table(false);
+    xlsx_writer.set_header_format(Format::new().set_bold());
+
+    xlsx_writer.write_dataframe_to_cell(&df, 2, 1)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel66_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe66")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel66_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe66")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,93 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{ExportConfig, PolarsXlsxWriter};
+use rust_xlsxwriter::{TableFunction, TableStyle, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Foo" => &[1.0, 2.0, 3.0],
+        "Bar" => &[4, 5, 6],
+    )?;
+
+    let json = r##"{
+        "float_format": "0.000",
+        "table_style": "Light9",
+        "freeze_panes": "A2",
+        "column_options": {
+            "Foo": {"header": "Baz", "width": 20},
+            "Bar": {"total": "sum"}
+        }
+    }"##;
+
+    let config: ExportConfig = serde_json::from_str(json).unwrap();
+
+    let mut xlsx_writer = PolarsXlsxWriter::from_config(&config)?;
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare with the equivalent writer options.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Foo" => &[1.0, 2.0, 3.0],
+        "Bar" => &[4, 5, 6],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_float_format("0.000");
+    xlsx_writer.set_table_style(TableStyle::Light9);
+    xlsx_writer.set_freeze_panes(1, 0);
+    xlsx_writer.set_column_header("Foo", "Baz");
+    xlsx_writer.set_column_width("Foo", 20.0);
+    xlsx_writer.set_column_total("Bar", TableFunction::Sum);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel67_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe67")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel67_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe67")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel67_errors() {
+    let config: ExportConfig = serde_json::from_str(r#"{"table_style": "Medium99"}"#).unwrap();
+    assert!(PolarsXlsxWriter::from_config(&config).is_err());
+
+    let result = serde_json::from_str::<ExportConfig>(r#"{"table_styel": "Medium9"}"#);
+    assert!(result.is_err());
+}
@@ -0,0 +1,96 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+// Create a template workbook with a layout on two worksheets.
+fn template_workbook() -> Result<Workbook, XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet().set_name("First")?;
+    worksheet.write(0, 0, "Title")?;
+
+    let worksheet = workbook.add_worksheet().set_name("Second")?;
+    worksheet.write(0, 0, "Other")?;
+
+    Ok(workbook)
+}
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Foo" => &[1, 2, 3],
+        "Bar" => &[4, 5, 6],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::from_workbook(template_workbook()?);
+
+    xlsx_writer.write_dataframe_to_sheet(&df, "First", 2, 0)?;
+    xlsx_writer.write_dataframe_to_sheet(&df, "Second", 2, 1)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare with the dataframes written to the template worksheets directly.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Foo" => &[1, 2, 3],
+        "Bar" => &[4, 5, 6],
+    )?;
+
+    let mut workbook = template_workbook()?;
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    let worksheet = workbook.worksheet_from_name("First")?;
+    xlsx_writer.write_dataframe_to_worksheet(&df, worksheet, 2, 0)?;
+
+    let worksheet = workbook.worksheet_from_name("Second")?;
+    xlsx_writer.write_dataframe_to_worksheet(&df, worksheet, 2, 1)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel68_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe68")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel68_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe68")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel68_unknown_sheet() {
+    let df: DataFrame = df!("Foo" => &[1, 2, 3]).unwrap();
+
+    let mut xlsx_writer = PolarsXlsxWriter::from_workbook(Workbook::new());
+    let result = xlsx_writer.write_dataframe_to_sheet(&df, "Missing", 0, 0);
+
+    assert!(result.is_err());
+}
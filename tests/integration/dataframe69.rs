@@ -0,0 +1,59 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use chrono::prelude::*;
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Float" => &[1234567.891, -22345678.5, 0.25],
+        "Datetime" => &[
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(9, 30, 0).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 1, 2).unwrap().and_hms_opt(10, 0, 0).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 1, 3).unwrap().and_hms_opt(11, 15, 0).unwrap(),
+        ],
+        "Date" => &[
+            NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 9, 2).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 9, 3).unwrap(),
+        ],
+        "String" => &[
+            "Short",
+            "A long string that is wider than the maximum autofit width",
+            "Short",
+        ],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer
+        .set_dtype_format(DataType::Float64, "#,##0.00")
+        .set_date_format("dddd, mmmm d, yyyy")
+        .set_autofit(true)
+        .set_autofit_with_formats(true)
+        .set_autofit_max_width(20.0);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel69() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe69")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,51 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Check that the fixed widths aren't changed by the autofit of a later
+// dataframe in the same worksheet.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df1: DataFrame = df!(
+        "Name" => &["Alpha", "Beta", "Gamma"],
+        "Size" => &[1, 22, 333],
+        "Notes" => &["A long note that isn't autofitted", "", "Another note"],
+    )?;
+
+    let df2: DataFrame = df!(
+        "Item" => &["A much longer item name than the others"],
+        "Count" => &[1],
+        "Comment" => &["A much longer comment than the others"],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer
+        .set_autofit(true)
+        .set_autofit_exclude(&["Notes"])
+        .set_column_width("Name", 20.0);
+
+    xlsx_writer.write_dataframe_to_cell(&df1, 0, 0)?;
+    xlsx_writer.write_dataframe_to_cell(&df2, 6, 0)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel71() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe71")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
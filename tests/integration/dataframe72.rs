@@ -0,0 +1,86 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use chrono::prelude::*;
+use polars::prelude::*;
+use polars_excel_writer::{PolarsXlsxWriter, WriteOrder};
+use rust_xlsxwriter::{FormatAlign, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    set_alignments(&mut xlsx_writer);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Check the alignment for data written in row order.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    set_alignments(&mut xlsx_writer);
+    xlsx_writer.set_write_order(WriteOrder::RowMajor);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Status" => &["Open", "Closed", "Open"],
+        "Date" => &[
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 1, 3).unwrap(),
+        ],
+        "Total" => &[1.5, 22.25, 333.0],
+        "Count" => &[1, 2, 3],
+    )
+}
+
+fn set_alignments(xlsx_writer: &mut PolarsXlsxWriter) {
+    xlsx_writer
+        .set_dtype_format(DataType::Float64, "#,##0.00")
+        .set_column_alignment("Status", FormatAlign::Center)
+        .set_column_alignment("Date", FormatAlign::Left)
+        .set_column_alignment("Total", FormatAlign::Right)
+        .set_column_alignment("Total", FormatAlign::Top);
+}
+
+#[test]
+fn dataframe_write_excel72_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe72")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel72_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe72")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
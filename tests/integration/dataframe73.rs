@@ -0,0 +1,64 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use chrono::prelude::*;
+use polars::prelude::*;
+use polars_excel_writer::{ColumnFormat, PolarsXlsxWriter};
+use rust_xlsxwriter::{Color, Format, FormatAlign, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Date" => &[
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+        ],
+        "Due" => &[
+            NaiveDate::from_ymd_opt(2024, 2, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 2, 2).unwrap(),
+        ],
+        "Amount" => &[1.5, 2.25],
+        "Ratio" => &[0.5, 0.25],
+        "Name" => &["Alpha", "Beta"],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    // A dtype format without a number format keeps the date format.
+    xlsx_writer
+        .set_dtype_format(DataType::Date, Format::new().set_font_color(Color::Blue))
+        .set_dtype_format(DataType::Float64, "#,##0.00");
+
+    // Column formats are combined with the dtype formats.
+    xlsx_writer
+        .set_column_format("Due", &ColumnFormat::new().set_bold())
+        .set_column_format(
+            "Amount",
+            &ColumnFormat::new().set_background_color(Color::Yellow),
+        )
+        .set_column_format("Ratio", &ColumnFormat::new().set_num_format("0%"))
+        .set_column_format("Name", &ColumnFormat::new().set_italic())
+        .set_column_alignment("Name", FormatAlign::Center);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel73() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe73")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,46 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{NanHandling, PolarsXlsxWriter};
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Float64" => &[1.5, f64::NAN, f64::INFINITY, f64::NEG_INFINITY],
+        "Float32" => &[1.5_f32, f32::NAN, f32::INFINITY, 2.0],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_nan_handling(NanHandling::ErrorValue);
+
+    xlsx_writer.write_dataframe_to_cell(&df, 0, 0)?;
+
+    xlsx_writer.set_nan_handling(NanHandling::Blank);
+    xlsx_writer.write_dataframe_to_cell(&df, 0, 3)?;
+
+    xlsx_writer.set_nan_handling(NanHandling::String("N/A".to_string()));
+    xlsx_writer.write_dataframe_to_cell(&df, 0, 6)?;
+
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel74() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe74")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,64 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Name" => &["A", "B", "C"],
+        "Value" => &[1.5, 2.5, 3.5],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Check writing the same data as a set of series.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let name = Series::new("Name".into(), &["A", "B", "C"]);
+    let value = Series::new("Value".into(), &[1.5, 2.5, 3.5]);
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    xlsx_writer.write_columns(&[name, value])?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel75_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe75")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel75_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe75")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,80 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{PolarsXlsxWriter, WriteOrder};
+use rust_xlsxwriter::{Format, FormatAlign, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    set_null_options(&mut xlsx_writer);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Check the Null format for data written in row order.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    set_null_options(&mut xlsx_writer);
+    xlsx_writer.set_write_order(WriteOrder::RowMajor);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Float" => &[Some(1.5), None, Some(3.5)],
+        "Name" => &[Some("A"), None, Some("C")],
+        "Integer" => &[None, Some(2), Some(3)],
+    )
+}
+
+fn set_null_options(xlsx_writer: &mut PolarsXlsxWriter) {
+    let null_format = Format::new().set_italic().set_align(FormatAlign::Center);
+
+    xlsx_writer
+        .set_float_format("0.00")
+        .set_null_value("N/A")
+        .set_null_format(&null_format);
+}
+
+#[test]
+fn dataframe_write_excel77_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe77")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel77_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe77")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
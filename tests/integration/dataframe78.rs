@@ -0,0 +1,80 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use std::fs;
+use std::process::Command;
+
+use polars::prelude::*;
+use polars_excel_writer::{ColumnFormat, PolarsXlsxWriter};
+use rust_xlsxwriter::{TableStyle, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East"],
+        "Sales" => &[1000, 2300, 1900],
+        "Margin" => &[0.125, 0.25, 0.5],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer
+        .set_worksheet_name("Sales")?
+        .set_autofit(true)
+        .set_float_precision(2)
+        .set_table_style(TableStyle::Medium2)
+        .set_column_format("Sales", &ColumnFormat::new().set_num_format("#,##0"));
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Check the same output from a CSV file via the command line application.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let input = format!("{filename}.csv");
+    let csv = "Region,Sales,Margin\nNorth,1000,0.125\nSouth,2300,0.25\nEast,1900,0.5\n";
+    fs::write(&input, csv)?;
+
+    let status = Command::new(env!("CARGO_BIN_EXE_polars-to-excel"))
+        .args(["--sheet-name", "Sales", "--autofit"])
+        .args(["--float-precision", "2"])
+        .args(["--table-style", "Medium2", "--column-format", "Sales=#,##0"])
+        .args([&input, filename])
+        .status()?;
+
+    fs::remove_file(&input)?;
+    assert!(status.success());
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel78_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe78")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel78_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe78")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,55 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{Format, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter. Check the
+// grouped header in a constant memory worksheet.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    set_header_options(&mut xlsx_writer);
+    xlsx_writer.set_constant_memory(true);
+
+    xlsx_writer.write_dataframe_to_cell(&df, 1, 1)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// The leaf names repeat so the data is written without a table.
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Region" => &["North", "South", "East"],
+        "2023|Q1" => &[100, 200, 300],
+        "2023|Q2" => &[110, 210, 310],
+        "2024|Q1" => &[120, 220, 320],
+        "2024|Q2" => &[130, 230, 330],
+    )
+}
+
+fn set_header_options(xlsx_writer: &mut PolarsXlsxWriter) {
+    xlsx_writer
+        .set_header_split("|")
+        .set_header_format(Format::new().set_italic());
+}
+
+#[test]
+fn dataframe_write_excel81() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe81")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
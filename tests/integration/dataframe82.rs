@@ -0,0 +1,96 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{PolarsXlsxWriter, WarningKind, WriteOrder};
+use rust_xlsxwriter::{FormatAlign, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    set_merge_options(&mut xlsx_writer);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Check the merged cells for data written in row order.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    set_merge_options(&mut xlsx_writer);
+    xlsx_writer.set_write_order(WriteOrder::RowMajor);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Year" => &[2023, 2023, 2023, 2024, 2024],
+        "Region" => &[Some("North"), Some("North"), None, None, Some("South")],
+        "Sales" => &[1.5, 2.5, 3.5, 4.5, 5.5],
+    )
+}
+
+fn set_merge_options(xlsx_writer: &mut PolarsXlsxWriter) {
+    xlsx_writer
+        .set_merge_repeated("Year")
+        .set_merge_repeated("Region")
+        .set_column_alignment("Year", FormatAlign::Top);
+}
+
+#[test]
+fn dataframe_write_excel82_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe82")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel82_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe82")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel82_chunked() {
+    let df = dataframe().unwrap();
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    set_merge_options(&mut xlsx_writer);
+
+    // The merged cells aren't supported for chunked writes.
+    xlsx_writer.write_dataframe_chunked(&df, 2).unwrap();
+
+    let warnings = xlsx_writer.warnings();
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(warnings[0].kind, WarningKind::UnsupportedOption);
+    assert_eq!(warnings[0].column_name, "Region");
+    assert_eq!(warnings[1].kind, WarningKind::UnsupportedOption);
+    assert_eq!(warnings[1].column_name, "Year");
+}
@@ -0,0 +1,79 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{Formula, TableFunction, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer
+        .set_column_totals_all(TableFunction::Sum)
+        .set_total_row_label("Region", "Total")
+        .set_total_row_formula("Units", "SUM([Sales])/SUM([Units])");
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Check the equivalent custom total function and Formula.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer
+        .set_total_row_label("Region", "Total")
+        .set_column_total("Sales", TableFunction::Sum)
+        .set_column_total(
+            "Units",
+            TableFunction::Custom(Formula::new("SUM([Sales])/SUM([Units])")),
+        );
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Region" => &["North", "South", "East"],
+        "Sales" => &[1000, 2300, 1500],
+        "Units" => &[10, 23, 15],
+    )
+}
+
+#[test]
+fn dataframe_write_excel83_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe83")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel83_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe83")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,89 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::{Chart, ChartType, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.write_dataframe(&df)?;
+
+    xlsx_writer.add_chartsheet(ChartType::Column, Some("Month"), &["Sales", "Costs"])?;
+
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Check the equivalent chartsheet added via rust_xlsxwriter.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.write_dataframe(&df)?;
+
+    let mut chart = Chart::new(ChartType::Column);
+    chart
+        .add_series()
+        .set_name(("Sheet1", 0, 1))
+        .set_values(("Sheet1", 1, 1, 3, 1))
+        .set_categories(("Sheet1", 1, 0, 3, 0));
+    chart
+        .add_series()
+        .set_name(("Sheet1", 0, 2))
+        .set_values(("Sheet1", 1, 2, 3, 2))
+        .set_categories(("Sheet1", 1, 0, 3, 0));
+
+    let workbook = xlsx_writer.workbook();
+    workbook.add_chartsheet().insert_chart(0, 0, &chart)?;
+
+    let worksheets = workbook.worksheets_mut();
+    let chartsheet = worksheets.pop().unwrap();
+    worksheets.insert(0, chartsheet);
+
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Month" => &["Jan", "Feb", "Mar"],
+        "Sales" => &[100, 120, 140],
+        "Costs" => &[80, 90, 85],
+    )
+}
+
+#[test]
+fn dataframe_write_excel84_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe84")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel84_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe84")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
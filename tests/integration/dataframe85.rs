@@ -0,0 +1,79 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{ColorScale, PolarsXlsxWriter};
+use rust_xlsxwriter::{ConditionalFormat2ColorScale, XlsxError};
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_heatmap(
+        &["Q4", "Q1", "Q2", "Missing"],
+        ColorScale::TwoColor(ConditionalFormat2ColorScale::new()),
+    );
+
+    xlsx_writer.write_dataframe_to_cell(&df, 1, 1)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Check the equivalent conditional format added via rust_xlsxwriter.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.write_dataframe_to_cell(&df, 1, 1)?;
+
+    let conditional_format = ConditionalFormat2ColorScale::new().set_multi_range("C3:D5 F3:F5");
+    xlsx_writer
+        .worksheet()?
+        .add_conditional_format(2, 2, 4, 3, &conditional_format)?;
+
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Region" => &["North", "South", "East"],
+        "Q1" => &[100, 250, 130],
+        "Q2" => &[120, 210, 160],
+        "Total" => &[220, 460, 290],
+        "Q4" => &[170, 160, 200],
+    )
+}
+
+#[test]
+fn dataframe_write_excel85_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe85")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel85_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe85")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,92 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use chrono::{NaiveDate, NaiveDateTime, TimeDelta};
+use polars::prelude::*;
+use polars_excel_writer::{DefaultTemporalConverter, PolarsXlsxWriter, TemporalToExcel};
+use rust_xlsxwriter::XlsxError;
+
+// A converter that moves dates and datetimes forward by one day.
+struct NextDayConverter;
+
+impl TemporalToExcel for NextDayConverter {
+    fn datetime(&self, value: i64, time_unit: TimeUnit) -> Option<NaiveDateTime> {
+        let datetime = DefaultTemporalConverter.datetime(value, time_unit)?;
+        datetime.checked_add_signed(TimeDelta::days(1))
+    }
+
+    fn date(&self, value: i32) -> Option<NaiveDate> {
+        DefaultTemporalConverter.date(value)?.succ_opt()
+    }
+}
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe(11)?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_temporal_converter(NextDayConverter);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare with the default converter and the shifted values.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe(12)?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe(day: u32) -> PolarsResult<DataFrame> {
+    let date = NaiveDate::from_ymd_opt(2023, 1, day).unwrap();
+
+    df!(
+        "Date" => &[date, date.succ_opt().unwrap()],
+        "Datetime" => &[
+            date.and_hms_opt(1, 0, 0).unwrap(),
+            date.and_hms_opt(2, 30, 0).unwrap(),
+        ],
+        "Time" => &[
+            date.and_hms_opt(3, 0, 0).unwrap().time(),
+            date.and_hms_opt(4, 15, 0).unwrap().time(),
+        ],
+    )
+}
+
+#[test]
+fn dataframe_write_excel86_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe86")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel86_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe86")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,110 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = df!(
+        "Region" => &["North", "South", "East"],
+        "Sales" => &[100, 120, 140],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_row_numbers(true, "No.", 1);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare with a dataframe that has the row numbers as data.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = df!(
+        "No." => &[1u32, 2, 3],
+        "Region" => &["North", "South", "East"],
+        "Sales" => &[100, 120, 140],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Check that the row number column isn't removed by set_columns().
+fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
+    let df = df!(
+        "Region" => &["North", "South", "East"],
+        "Other" => &[1, 2, 3],
+        "Sales" => &[100, 120, 140],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer
+        .set_columns(&["Region", "Sales"])
+        .set_row_numbers(true, "No.", 1);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel87_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe87")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel87_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe87")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel87_3() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe87")
+        .set_function(create_new_xlsx_file_3)
+        .unique("3")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel87_duplicate_name() {
+    let df = df!("No." => &[1, 2, 3]).unwrap();
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_row_numbers(true, "No.", 1);
+
+    let result = xlsx_writer.write_dataframe(&df);
+    assert!(matches!(result, Err(PolarsError::Duplicate(_))));
+}
@@ -0,0 +1,72 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df1 = df!("Lookup" => &["A", "B"])?;
+    let df2 = df!("Data" => &[10, 20, 15])?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_sheet_hidden(true);
+    xlsx_writer.write_dataframe(&df1)?;
+
+    xlsx_writer.add_worksheet();
+    xlsx_writer.set_sheet_hidden(false);
+    xlsx_writer.write_dataframe(&df2)?;
+
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare with a hidden first worksheet and an active second worksheet.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df1 = df!("Lookup" => &["A", "B"])?;
+    let df2 = df!("Data" => &[10, 20, 15])?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.write_dataframe(&df1)?;
+    xlsx_writer.worksheet()?.set_hidden(true);
+
+    xlsx_writer.add_worksheet();
+    xlsx_writer.write_dataframe(&df2)?;
+    xlsx_writer.worksheet()?.set_active(true);
+
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel89_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe89")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel89_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe89")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,87 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{ColumnFormat, CurrencyPreset, PolarsXlsxWriter};
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer
+        .set_column_format("USD", &ColumnFormat::new().set_bold())
+        .set_column_currency("USD", CurrencyPreset::USD)
+        .set_column_currency("EUR", CurrencyPreset::EUR)
+        .set_column_currency("GBP", CurrencyPreset::GBP)
+        .set_column_currency("CHF", CurrencyPreset::Custom("CHF".to_string()));
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare with the currency formats created by Excel.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let usd = ColumnFormat::new()
+        .set_bold()
+        .set_num_format("[$$-409]#,##0.00;[Red]\\-[$$-409]#,##0.00");
+    let eur = ColumnFormat::new().set_num_format("[$€-2]\\ #,##0.00;[Red]\\-[$€-2]\\ #,##0.00");
+    let gbp = ColumnFormat::new().set_num_format("[$£-809]#,##0.00;[Red]\\-[$£-809]#,##0.00");
+    let chf = ColumnFormat::new().set_num_format("\"CHF\"\\ #,##0.00;[Red]\\-\"CHF\"\\ #,##0.00");
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer
+        .set_column_format("USD", &usd)
+        .set_column_format("EUR", &eur)
+        .set_column_format("GBP", &gbp)
+        .set_column_format("CHF", &chf);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "USD" => &[1250.0, -99.99],
+        "EUR" => &[1120.0, -89.5],
+        "GBP" => &[980.25, -75.0],
+        "CHF" => &[1180.0, -95.0],
+    )
+}
+
+#[test]
+fn dataframe_write_excel91_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe91")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel91_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe91")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
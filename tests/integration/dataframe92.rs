@@ -0,0 +1,110 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{ColumnFormat, PolarsXlsxWriter, WriteOrder};
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = df!(
+        "Share" => &[0.25, 0.35, 0.4],
+        "Growth" => &[Some(5.5), None, Some(-2.25)],
+        "Rate" => &[50, 25, 5],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    set_percentages(&mut xlsx_writer);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Check the percentages for data written in row order.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = df!(
+        "Share" => &[0.25, 0.35, 0.4],
+        "Growth" => &[Some(5.5), None, Some(-2.25)],
+        "Rate" => &[50, 25, 5],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    set_percentages(&mut xlsx_writer);
+    xlsx_writer.set_write_order(WriteOrder::RowMajor);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare with fractional values and percentage number formats.
+fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
+    let df = df!(
+        "Share" => &[0.25, 0.35, 0.4],
+        "Growth" => &[Some(0.055), None, Some(-0.0225)],
+        "Rate" => &[0.5, 0.25, 0.05],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer
+        .set_column_format("Share", &ColumnFormat::new().set_num_format("0%"))
+        .set_column_format("Growth", &ColumnFormat::new().set_num_format("0.0%"))
+        .set_column_format("Rate", &ColumnFormat::new().set_num_format("0.00%"));
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn set_percentages(xlsx_writer: &mut PolarsXlsxWriter) {
+    xlsx_writer
+        .set_column_percentage("Share", 0, true)
+        .set_column_percentage("Growth", 1, false)
+        .set_column_percentage("Rate", 2, false);
+}
+
+#[test]
+fn dataframe_write_excel92_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe92")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel92_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe92")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel92_3() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe92")
+        .set_function(create_new_xlsx_file_3)
+        .unique("3")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,110 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{PolarsXlsxWriter, WriteOrder};
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    set_transforms(&mut xlsx_writer);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Check the transforms for data written in row order.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    set_transforms(&mut xlsx_writer);
+    xlsx_writer.set_write_order(WriteOrder::RowMajor);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare with a dataframe with the transformed values.
+fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
+    let df = df!(
+        "Name" => &["ALICE", "BOB", "N/A"],
+        "Meters" => &[1500.0, 250.0, 42000.0],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Name" => &[Some("Alice"), Some("Bob"), None],
+        "Meters" => &[1.5, 0.25, 42.0],
+    )
+}
+
+fn set_transforms(xlsx_writer: &mut PolarsXlsxWriter) {
+    xlsx_writer
+        .set_column_transform("Name", |value| match value {
+            AnyValue::String(name) => AnyValue::StringOwned(name.to_uppercase().into()),
+            AnyValue::Null => AnyValue::String("N/A"),
+            value => value,
+        })
+        .set_column_transform("Meters", |value| match value {
+            AnyValue::Float64(km) => AnyValue::Float64(km * 1000.0),
+            value => value,
+        });
+}
+
+#[test]
+fn dataframe_write_excel93_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe93")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel93_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe93")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel93_3() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe93")
+        .set_function(create_new_xlsx_file_3)
+        .unique("3")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
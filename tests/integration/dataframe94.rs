@@ -0,0 +1,76 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{MaskStyle, PolarsXlsxWriter};
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = df!(
+        "Name" => &[Some("a"), None, Some("a")],
+        "Card" => &["4111222233334444", "123", "55001111"],
+        "Email" => &["x@example.com", "y@example.com", "z@example.com"],
+        "Account" => &[12345, 678, 90],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer
+        .set_column_masked("Name", MaskStyle::Hash)
+        .set_column_masked("Card", MaskStyle::Partial(4))
+        .set_column_masked("Email", MaskStyle::Replace("REDACTED".to_string()))
+        .set_column_masked("Account", MaskStyle::Partial(2));
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare with a dataframe with the masked values. The hash is the FNV-1a
+// test vector for "a".
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = df!(
+        "Name" => &[Some("af63dc4c8601ec8c"), None, Some("af63dc4c8601ec8c")],
+        "Card" => &["************4444", "123", "****1111"],
+        "Email" => &["REDACTED", "REDACTED", "REDACTED"],
+        "Account" => &["***45", "*78", "90"],
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel94_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe94")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel94_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe94")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,65 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Check that a dataframe without columns doesn't write any data.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = DataFrame::empty();
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_autofit(true);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Check that a dataframe without rows or a header doesn't write any data.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df: DataFrame = df!(
+        "Foo" => Vec::<i32>::new(),
+        "Bar" => Vec::<&str>::new(),
+    )?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_header(false);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn dataframe_write_excel96_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe96")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel96_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe96")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
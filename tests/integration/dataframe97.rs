@@ -0,0 +1,104 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::PolarsXlsxWriter;
+use rust_xlsxwriter::XlsxError;
+
+// Check that adjacent dataframes don't overlap.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    xlsx_writer.write_dataframe_to_cell(&df, 0, 0)?;
+    xlsx_writer.write_dataframe_to_cell(&df, 0, 2)?;
+    xlsx_writer.write_dataframe_to_cell(&df, 4, 0)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare with the dataframes written to a named worksheet.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    xlsx_writer.write_dataframe_to_sheet(&df, "Sheet1", 0, 0)?;
+    xlsx_writer.write_dataframe_to_sheet(&df, "Sheet1", 0, 2)?;
+    xlsx_writer.write_dataframe_to_sheet(&df, "Sheet1", 4, 0)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Foo" => &[1, 2, 3],
+        "Bar" => &[4, 5, 6],
+    )
+}
+
+#[test]
+fn dataframe_write_excel97_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe97")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel97_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe97")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel97_overlap() {
+    let df = dataframe().unwrap();
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.write_dataframe_to_cell(&df, 0, 0).unwrap();
+
+    let result = xlsx_writer.write_dataframe_to_cell(&df, 3, 1);
+    let Err(PolarsError::ComputeError(message)) = result else {
+        panic!("expected an overlap error");
+    };
+
+    assert_eq!(
+        message.to_string(),
+        "dataframe range Sheet1!B4:C7 overlaps the range Sheet1!A1:B4 of a previous dataframe"
+    );
+}
+
+#[test]
+fn dataframe_write_excel97_overlap_named_sheet() {
+    let df = dataframe().unwrap();
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.write_dataframe(&df).unwrap();
+
+    let result = xlsx_writer.write_dataframe_to_sheet(&df, "Sheet1", 1, 1);
+    assert!(matches!(result, Err(PolarsError::ComputeError(_))));
+
+    // Dataframes in other worksheets don't overlap.
+    xlsx_writer.add_worksheet();
+    xlsx_writer.write_dataframe(&df).unwrap();
+}
@@ -0,0 +1,85 @@
+// Test case that compares a file generated by polars_excel_writer with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+
+use polars::prelude::*;
+use polars_excel_writer::{DuplicateHeaderPolicy, PolarsXlsxWriter};
+use rust_xlsxwriter::XlsxError;
+
+// Compare output against target Excel file using PolarsXlsxWriter. Rename
+// the duplicate headers in the same way as Excel.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_duplicate_header_policy(DuplicateHeaderPolicy::Rename);
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+// Compare with the header set explicitly.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let df = dataframe()?;
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+    xlsx_writer.set_column_header("price", "price2");
+
+    xlsx_writer.write_dataframe(&df)?;
+    xlsx_writer.save(filename)?;
+
+    Ok(())
+}
+
+fn dataframe() -> PolarsResult<DataFrame> {
+    df!(
+        "Product" => &["Apple", "Pear", "Plum"],
+        "Price" => &[1.20, 0.90, 1.50],
+        "price" => &[1.10, 0.95, 1.40],
+    )
+}
+
+#[test]
+fn dataframe_write_excel98_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe98")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel98_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("dataframe98")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn dataframe_write_excel98_duplicate_error() {
+    let df = dataframe().unwrap();
+
+    let mut xlsx_writer = PolarsXlsxWriter::new();
+
+    let result = xlsx_writer.write_dataframe(&df);
+    assert!(matches!(result, Err(PolarsError::Duplicate(_))));
+
+    // Duplicate headers are allowed without a table.
+    xlsx_writer.set_use_table(false);
+    xlsx_writer.write_dataframe(&df).unwrap();
+}
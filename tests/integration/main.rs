@@ -10,5 +10,132 @@ mod dataframe07;
 mod dataframe08;
 mod dataframe09;
 mod dataframe10;
+mod dataframe100;
+mod dataframe101;
+mod dataframe102;
+mod dataframe103;
+mod dataframe104;
+mod dataframe105;
+mod dataframe106;
+mod dataframe107;
+mod dataframe108;
+mod dataframe109;
 mod dataframe11;
+mod dataframe110;
+mod dataframe111;
+mod dataframe112;
+mod dataframe113;
+mod dataframe114;
+mod dataframe115;
+#[cfg(feature = "object")]
+mod dataframe116;
+mod dataframe117;
+mod dataframe118;
+mod dataframe119;
 mod dataframe12;
+mod dataframe120;
+mod dataframe121;
+mod dataframe122;
+mod dataframe123;
+mod dataframe124;
+mod dataframe125;
+mod dataframe126;
+mod dataframe127;
+mod dataframe128;
+mod dataframe129;
+mod dataframe13;
+#[cfg(feature = "parallel")]
+mod dataframe130;
+mod dataframe131;
+mod dataframe132;
+mod dataframe133;
+mod dataframe134;
+mod dataframe14;
+mod dataframe15;
+mod dataframe16;
+mod dataframe17;
+mod dataframe18;
+mod dataframe19;
+mod dataframe20;
+mod dataframe21;
+mod dataframe22;
+mod dataframe23;
+mod dataframe24;
+mod dataframe25;
+mod dataframe26;
+mod dataframe27;
+mod dataframe28;
+mod dataframe29;
+mod dataframe30;
+mod dataframe31;
+mod dataframe32;
+mod dataframe33;
+mod dataframe34;
+mod dataframe35;
+mod dataframe36;
+mod dataframe37;
+mod dataframe38;
+mod dataframe39;
+mod dataframe40;
+mod dataframe41;
+#[cfg(feature = "async")]
+mod dataframe42;
+mod dataframe43;
+mod dataframe44;
+mod dataframe45;
+mod dataframe46;
+mod dataframe47;
+mod dataframe48;
+mod dataframe49;
+mod dataframe50;
+mod dataframe51;
+mod dataframe52;
+mod dataframe53;
+mod dataframe54;
+mod dataframe55;
+mod dataframe56;
+mod dataframe57;
+mod dataframe58;
+mod dataframe59;
+mod dataframe60;
+mod dataframe61;
+mod dataframe62;
+mod dataframe63;
+mod dataframe64;
+mod dataframe65;
+mod dataframe66;
+#[cfg(feature = "serde")]
+mod dataframe67;
+mod dataframe68;
+mod dataframe69;
+mod dataframe70;
+mod dataframe71;
+mod dataframe72;
+mod dataframe73;
+mod dataframe74;
+mod dataframe75;
+mod dataframe76;
+mod dataframe77;
+#[cfg(feature = "cli")]
+mod dataframe78;
+mod dataframe79;
+mod dataframe80;
+mod dataframe81;
+mod dataframe82;
+mod dataframe83;
+mod dataframe84;
+mod dataframe85;
+mod dataframe86;
+mod dataframe87;
+mod dataframe88;
+mod dataframe89;
+mod dataframe90;
+mod dataframe91;
+mod dataframe92;
+mod dataframe93;
+mod dataframe94;
+mod dataframe95;
+mod dataframe96;
+mod dataframe97;
+mod dataframe98;
+mod dataframe99;